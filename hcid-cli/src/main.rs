@@ -0,0 +1,462 @@
+//! Command line tools for the holochain hcid encoding.
+
+extern crate hcid;
+
+use std::io::{IsTerminal, Write};
+use std::process::exit;
+
+use hcid::phonetic::{self, PhoneticForm};
+use hcid::qr::HcidQr;
+use hcid::vanity::{self, VanityPattern, VanityPosition};
+use hcid::{Explanation, HcidEncoding, HcidError, HcidResult};
+
+static USAGE: &str = "usage:
+  hcid vanity <pattern> --secret-file <file> [--kind <kind>] [--start|--end|--at <index>|--anywhere]
+              [--threads <n>]
+      generate an ed25519 keypair whose id contains <pattern> (case-insensitive), writing its
+      secret seed to a new <file>
+  hcid decode <id> [--kind <kind>]
+      decode <id> to its key, correcting damage; write `?` for unreadable characters
+  hcid qr <id> [--kind <kind>] [--scheme] [--svg <file>|--png <file>] [--scale <px>]
+      render <id> as a qr code, to the terminal unless an image file is given
+  hcid fingerprint <id> [--kind <kind>] [--svg <file>] [--size <px>]
+      draw the randomart of the key of <id>, or write its identicon to an svg file
+  hcid explain <id> [--kind <kind>] [--no-color]
+      show where the prefix, key, parity and cap segments of <id> sit, and what is damaged
+  hcid words <id> [--kind <kind>]
+  hcid words --parse <word>...
+      render <id> as BIP-39 words for reading aloud, or parse such words back into the id
+  hcid spell <id> [--compact]
+  hcid spell --parse <text>...
+      spell <id> with the NATO alphabet, or parse a spelled out id (e.g. from call notes)
+";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let res = match args.first().map(|a| a.as_str()) {
+        Some("vanity") => cmd_vanity(&args[1..]),
+        Some("decode") => cmd_decode(&args[1..]),
+        Some("qr") => cmd_qr(&args[1..]),
+        Some("fingerprint") => cmd_fingerprint(&args[1..]),
+        Some("explain") => cmd_explain(&args[1..]),
+        Some("words") => cmd_words(&args[1..]),
+        Some("spell") => cmd_spell(&args[1..]),
+        _ => {
+            eprint!("{}", USAGE);
+            exit(2);
+        }
+    };
+
+    if let Err(HcidError(e)) = res {
+        eprintln!("error: {}", e);
+        exit(1);
+    }
+}
+
+/// pull the value following flag `args[*i]`
+fn flag_value<'a>(args: &'a [String], i: &mut usize) -> HcidResult<&'a str> {
+    *i += 1;
+    match args.get(*i) {
+        Some(v) => Ok(v),
+        None => Err(format!("missing value for {}", args[*i - 1]).into()),
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn cmd_vanity(args: &[String]) -> HcidResult<()> {
+    let mut pattern = None;
+    let mut secret_file = None;
+    let mut kind = "hca0";
+    let mut position = VanityPosition::End;
+    let mut threads = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = flag_value(args, &mut i)?,
+            "--secret-file" => secret_file = Some(flag_value(args, &mut i)?),
+            "--start" => position = VanityPosition::Start,
+            "--end" => position = VanityPosition::End,
+            "--anywhere" => position = VanityPosition::Anywhere,
+            "--at" => position = VanityPosition::At(flag_value(args, &mut i)?.parse()?),
+            "--threads" => threads = flag_value(args, &mut i)?.parse()?,
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if pattern.is_none() => pattern = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let pattern = match pattern {
+        Some(p) => VanityPattern::new(p, position)?,
+        None => return Err("missing <pattern>".into()),
+    };
+    let secret_file = match secret_file {
+        Some(f) => f,
+        None => return Err("missing --secret-file <file>".into()),
+    };
+    // fail before the search, not after it
+    let mut secret_out = create_secret_file(secret_file)?;
+
+    let enc = HcidEncoding::with_kind(kind)?;
+    let estimate = vanity::estimate_difficulty(&enc, &pattern)?;
+    eprintln!(
+        "searching on {} threads, expecting ~{:.0} attempts",
+        threads, estimate
+    );
+
+    let key = vanity::generate(kind, &pattern, threads, |attempts| {
+        eprint!(
+            "\r{} attempts ({:.1}% of expected)",
+            attempts,
+            100.0 * attempts as f64 / estimate
+        );
+        let _ = std::io::stderr().flush();
+    })?;
+    eprintln!();
+
+    writeln!(secret_out, "{}", hex(&key.secret_seed)).map_err(|e| e.to_string())?;
+    eprintln!("secret seed written to {}", secret_file);
+
+    println!("id:     {}", key.id);
+    println!("public: {}", hex(&key.public_key));
+
+    Ok(())
+}
+
+/// create `path` for a secret seed, readable by the owner only. Existing files are not
+/// overwritten.
+fn create_secret_file(path: &str) -> HcidResult<std::fs::File> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options
+        .open(path)
+        .map_err(|e| format!("cannot create {}: {}", path, e).into())
+}
+
+fn cmd_decode(args: &[String]) -> HcidResult<()> {
+    let mut id = None;
+    let mut kind = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = Some(flag_value(args, &mut i)?.to_string()),
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => return Err("missing <id>".into()),
+    };
+    let kind = match kind {
+        Some(kind) => kind,
+        None => hcid::detect_kind(id)?,
+    };
+    let enc = HcidEncoding::with_kind(&kind)?;
+    let report = enc.decode_report(id, hcid::DecodePolicy::Full)?;
+
+    println!("kind:       {}", kind);
+    println!("key:        {}", hex(&report.data));
+    println!("id:         {}", enc.encode(&report.data)?);
+    if !report.erased_chars.is_empty() {
+        let erased: Vec<String> = report.erased_chars.iter().map(|i| i.to_string()).collect();
+        println!("unreadable: chars {}", erased.join(", "));
+    }
+    if report.prefix_repairs > 0 {
+        println!("prefix:     {} chars repaired", report.prefix_repairs);
+    }
+    println!("confidence: {}", report.confidence);
+
+    Ok(())
+}
+
+fn cmd_qr(args: &[String]) -> HcidResult<()> {
+    let mut id = None;
+    let mut kind = None;
+    let mut scheme = false;
+    let mut svg = None;
+    let mut png = None;
+    let mut scale = 8;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = Some(flag_value(args, &mut i)?.to_string()),
+            "--scheme" => scheme = true,
+            "--svg" => svg = Some(flag_value(args, &mut i)?),
+            "--png" => png = Some(flag_value(args, &mut i)?),
+            "--scale" => scale = flag_value(args, &mut i)?.parse()?,
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => return Err("missing <id>".into()),
+    };
+    let kind = match kind {
+        Some(kind) => kind,
+        None => hcid::detect_kind(id)?,
+    };
+
+    // render the corrected id, never a damaged one
+    let enc = HcidEncoding::with_kind(&kind)?;
+    let qr = HcidQr::new(&enc.encode(&enc.decode(id)?)?, scheme)?;
+
+    let write = |path: &str, data: &[u8]| {
+        std::fs::write(path, data).map_err(|e| HcidError(format!("{}: {}", path, e)))
+    };
+    match (svg, png) {
+        (Some(path), None) => write(path, qr.to_svg(scale).as_bytes())?,
+        (None, Some(path)) => write(path, &qr.to_png(scale)?)?,
+        (None, None) => print!("{}", qr.to_terminal()),
+        _ => return Err("give only one of --svg and --png".into()),
+    }
+
+    Ok(())
+}
+
+fn cmd_fingerprint(args: &[String]) -> HcidResult<()> {
+    let mut id = None;
+    let mut kind = None;
+    let mut svg = None;
+    let mut size = 64;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = Some(flag_value(args, &mut i)?.to_string()),
+            "--svg" => svg = Some(flag_value(args, &mut i)?),
+            "--size" => size = flag_value(args, &mut i)?.parse()?,
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => return Err("missing <id>".into()),
+    };
+    let kind = match kind {
+        Some(kind) => kind,
+        None => hcid::detect_kind(id)?,
+    };
+    let enc = HcidEncoding::with_kind(&kind)?;
+
+    match svg {
+        Some(path) => std::fs::write(path, enc.identicon_svg(id, size)?)
+            .map_err(|e| HcidError(format!("{}: {}", path, e)))?,
+        None => print!("{}", enc.randomart(id)?),
+    }
+
+    Ok(())
+}
+
+/// wrap `s` in the ansi color `code`, if coloring
+fn paint(s: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", code, s)
+    } else {
+        s.to_string()
+    }
+}
+
+/// the ansi color of char `i` of an explained id: damage first, then the part it renders
+fn char_color(ex: &Explanation, i: usize) -> &'static str {
+    if ex.erased_chars.contains(&i) {
+        "1;41"
+    } else if ex.transliterations.iter().any(|t| t.0 == i) {
+        "1;35"
+    } else if ex.prefix_chars.contains(&i) && !ex.key_chars.contains(&i) {
+        "36"
+    } else if ex.key_chars.contains(&i) && !ex.base_parity_chars.contains(&i) {
+        "32"
+    } else {
+        "33"
+    }
+}
+
+fn cmd_explain(args: &[String]) -> HcidResult<()> {
+    let mut id = None;
+    let mut kind = None;
+    let mut color = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = Some(flag_value(args, &mut i)?.to_string()),
+            "--no-color" => color = false,
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => return Err("missing <id>".into()),
+    };
+    let kind = match kind {
+        Some(kind) => kind,
+        None => hcid::detect_kind(id)?,
+    };
+    let enc = HcidEncoding::with_kind(&kind)?;
+    let ex = enc.explain(id)?;
+
+    // the id, colored by part, with a ruler marking the straddling chars and cap segments
+    let mut painted = String::new();
+    let mut straddles = String::new();
+    let mut segments = String::new();
+    for (i, c) in ex.text.chars().enumerate() {
+        painted.push_str(&paint(&c.to_string(), char_color(&ex, i), color));
+        straddles.push(if ex.straddling_chars.contains(&i) { '^' } else { ' ' });
+        segments.push(match ex.cap_segments.iter().position(|s| s.chars.contains(&i)) {
+            Some(s) if ex.cap_segments[s].chars.start == i => '|',
+            Some(s) => std::char::from_digit(s as u32 % 10, 10).unwrap(),
+            None => ' ',
+        });
+    }
+
+    let range = |r: &std::ops::Range<usize>| format!("chars {}..{}", r.start, r.end);
+    println!("kind:        {}", kind);
+    println!("id:          {}", painted);
+    println!("straddles:   {}", straddles.trim_end());
+    println!("cap parity:  {}", segments.trim_end());
+    println!();
+    println!(
+        "{} {} (expected {})",
+        paint("prefix:     ", "36", color),
+        range(&ex.prefix_chars),
+        ex.prefix
+    );
+    println!("{} {}", paint("key:        ", "32", color), range(&ex.key_chars));
+    println!(
+        "{} {}",
+        paint("base parity:", "33", color),
+        range(&ex.base_parity_chars)
+    );
+    for (n, seg) in ex.cap_segments.iter().enumerate() {
+        let byte = match seg.byte {
+            Some(b) => format!("{:#04x}", b),
+            None => "erased".to_string(),
+        };
+        println!(
+            "cap {}:       {} bits {:<8} -> {}",
+            n,
+            range(&seg.chars),
+            seg.bits,
+            byte
+        );
+    }
+    if ex.case_lost {
+        println!("case:        lost, cap parity is ignored");
+    }
+    for i in ex.erased_chars.iter() {
+        println!("{} char {} is unreadable", paint("erasure:    ", "1;41", color), i);
+    }
+    for (i, found, read) in ex.transliterations.iter() {
+        println!(
+            "{} char {} `{}` is read as `{}`",
+            paint("translit:   ", "1;35", color),
+            i,
+            found,
+            read
+        );
+    }
+
+    println!();
+    match enc.decode_report(id, hcid::DecodePolicy::Full) {
+        Ok(report) => println!(
+            "decodes to:  {} ({})",
+            hex(&report.data),
+            report.confidence
+        ),
+        Err(HcidError(e)) => println!("decodes to:  nothing, {}", e),
+    }
+
+    Ok(())
+}
+
+fn cmd_words(args: &[String]) -> HcidResult<()> {
+    if args.first().map(|a| a.as_str()) == Some("--parse") {
+        let words = args[1..].join(" ");
+        let enc = HcidEncoding::with_kind(&hcid::words::kind_of_words(&words)?)?;
+        println!("{}", enc.encode(&enc.from_words(&words)?)?);
+        return Ok(());
+    }
+
+    let mut id = None;
+    let mut kind = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--kind" => kind = Some(flag_value(args, &mut i)?.to_string()),
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+        i += 1;
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => return Err("missing <id>".into()),
+    };
+    let kind = match kind {
+        Some(kind) => kind,
+        None => hcid::detect_kind(id)?,
+    };
+    let enc = HcidEncoding::with_kind(&kind)?;
+    println!("{}", enc.to_words(&enc.decode(id)?)?);
+
+    Ok(())
+}
+
+fn cmd_spell(args: &[String]) -> HcidResult<()> {
+    if args.first().map(|a| a.as_str()) == Some("--parse") {
+        println!("{}", phonetic::from_phonetic(&args[1..].join(" "))?);
+        return Ok(());
+    }
+
+    let mut id = None;
+    let mut form = PhoneticForm::Cased;
+
+    for a in args {
+        match a.as_str() {
+            "--compact" => form = PhoneticForm::Compact,
+            a if a.starts_with("--") => return Err(format!("unknown option {}", a).into()),
+            a if id.is_none() => id = Some(a),
+            a => return Err(format!("unexpected argument {}", a).into()),
+        }
+    }
+
+    match id {
+        Some(id) => println!("{}", phonetic::to_phonetic(id, form)),
+        None => return Err("missing <id>".into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! Smoke tests running the `hcid` binary.
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+// the rfc8032 test 1 public key, as an hck0 id
+static TEST_ID: &str = "HcKCjw44UaaZfnikx9kVY9XU3fTApprp6f3QHxwgeNt48ar4oD5rpVI4P8ru53a";
+
+fn hcid(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_hcid"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// stdout of a successful run
+fn stdout(args: &[&str]) -> String {
+    let out = hcid(args);
+    assert!(
+        out.status.success(),
+        "hcid {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8(out.stdout).unwrap()
+}
+
+/// a path in the temp dir that does not exist yet
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("hcid-cli-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn it_prints_usage() {
+    let out = hcid(&[]);
+    assert_eq!(Some(2), out.status.code());
+    assert!(String::from_utf8_lossy(&out.stderr).starts_with("usage:"));
+
+    let out = hcid(&["vanity", "holo!"]);
+    assert_eq!(Some(1), out.status.code());
+    assert!(String::from_utf8_lossy(&out.stderr).starts_with("error: "));
+}
+
+#[test]
+fn it_explains_ids() {
+    let out = stdout(&["explain", TEST_ID, "--no-color"]);
+    assert!(out.contains(&format!("id:          {}", TEST_ID)));
+    assert!(!out.contains('\x1b'));
+
+    let damaged = TEST_ID.replacen("jw4", "j\u{2603}4", 1);
+    let out = stdout(&["explain", &damaged, "--no-color"]);
+    assert!(out.contains("char 5 is unreadable"));
+}
+
+#[test]
+fn it_decodes_damaged_ids() {
+    let damaged = TEST_ID.replacen("jw4", "??4", 1);
+    let out = stdout(&["decode", &damaged]);
+    assert!(out.contains("kind:       hck0"));
+    assert!(out.contains(&format!("id:         {}", TEST_ID)));
+    assert!(out.contains("unreadable: chars 4, 5"));
+
+    let out = hcid(&["decode", "HcKnotanid"]);
+    assert_eq!(Some(1), out.status.code());
+    assert!(String::from_utf8_lossy(&out.stderr).starts_with("error: "));
+}
+
+#[test]
+fn it_renders_qr_codes() {
+    assert!(!stdout(&["qr", TEST_ID]).is_empty());
+
+    let svg = temp_path("qr.svg");
+    stdout(&["qr", TEST_ID, "--svg", svg.to_str().unwrap()]);
+    assert!(std::fs::read_to_string(&svg).unwrap().contains("<svg"));
+    std::fs::remove_file(&svg).unwrap();
+}
+
+#[test]
+fn it_draws_fingerprints() {
+    assert!(stdout(&["fingerprint", TEST_ID]).contains("[hck0]"));
+
+    let svg = temp_path("identicon.svg");
+    stdout(&["fingerprint", TEST_ID, "--svg", svg.to_str().unwrap()]);
+    assert!(std::fs::read_to_string(&svg).unwrap().starts_with("<svg"));
+    std::fs::remove_file(&svg).unwrap();
+}
+
+#[test]
+fn it_round_trips_words() {
+    let words = stdout(&["words", TEST_ID]);
+    let mut args = vec!["words", "--parse"];
+    args.extend(words.split_whitespace());
+    assert_eq!(TEST_ID, stdout(&args).trim());
+}
+
+#[test]
+fn it_round_trips_spelling() {
+    let spelled = stdout(&["spell", TEST_ID]);
+    assert_eq!(TEST_ID, stdout(&["spell", "--parse", spelled.trim()]).trim());
+
+    let compact = stdout(&["spell", TEST_ID, "--compact"]);
+    assert_eq!(
+        TEST_ID.to_lowercase(),
+        stdout(&["spell", "--parse", compact.trim()]).trim()
+    );
+}
+
+#[test]
+fn it_writes_vanity_secrets_to_a_file_only() {
+    // the secret file is required
+    let out = hcid(&["vanity", "9", "--start"]);
+    assert_eq!(Some(1), out.status.code());
+
+    let secret = temp_path("vanity.key");
+    let out = stdout(&[
+        "vanity",
+        "9",
+        "--start",
+        "--threads",
+        "1",
+        "--secret-file",
+        secret.to_str().unwrap(),
+    ]);
+    let seed = std::fs::read_to_string(&secret).unwrap();
+    assert_eq!(64, seed.trim().len());
+    assert!(!out.contains(seed.trim()));
+    let id = out.lines().next().unwrap().trim_start_matches("id:").trim();
+    assert_eq!(Some('9'), id.chars().nth(5));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&secret).unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+    }
+
+    // an existing file is not overwritten
+    let out = hcid(&["vanity", "9", "--secret-file", secret.to_str().unwrap()]);
+    assert_eq!(Some(1), out.status.code());
+    assert_eq!(seed, std::fs::read_to_string(&secret).unwrap());
+    std::fs::remove_file(&secret).unwrap();
+}
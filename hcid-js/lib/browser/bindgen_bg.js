@@ -1,40 +1,349 @@
-import * as import_b from './bindgen';
-
-            
-                    let bytes;
-                    const base64 = "AGFzbQEAAAAB4oCAgAAPYAN/f38Bf2ACf38Bf2AEf39/fwBgAX8Bf2ABfwBgAn9/AGAAAGADf39/AGAGf39/f39/AGAFf39/f38Bf2AAAX9gBH9/f38Bf2AFf39/f38AYAF/AX5gB39/f39/f38BfwLfgICAAAMJLi9iaW5kZ2VuFV9fd2JpbmRnZW5fc3RyaW5nX25ldwABCS4vYmluZGdlbhBfX3diaW5kZ2VuX3Rocm93AAUJLi9iaW5kZ2VuEl9fd2JpbmRnZW5fcmV0aHJvdwAEA+mAgIAAaAgCAgEJAAACAgECCQAFAQAFDgEBDAEBCAIBAQEAAgMBBwIBBQcBAQUCAAQGBwUFCQEBAQEEBAEABQUEAAMHBAsAAAAEAQMFAQEBCwAHBgYFAQcBBAYEAQEGBgEDAQMKBg0EBAQEBAQEBIWAgIAAAXABJycFg4CAgAABABEGiYCAgAABfwFBgIDAAAsHxYGAgAAKBm1lbW9yeQIAE19fd2JnX2VuY29kaW5nX2ZyZWUALQxlbmNvZGluZ19uZXcABg9lbmNvZGluZ19lbmNvZGUABQ9lbmNvZGluZ19kZWNvZGUACxNlbmNvZGluZ19pc19jb3JydXB0AB8eX193YmluZGdlbl9nbG9iYWxfYXJndW1lbnRfcHRyAGERX193YmluZGdlbl9tYWxsb2MAPxJfX3diaW5kZ2VuX3JlYWxsb2MARQ9fX3diaW5kZ2VuX2ZyZWUASQmsgICAAAEAQQELJkoYGSUzHDkRTCkVWUdLHWUkX2BkK11eZmdDFjRoY2kPHjZqTlM1CqWSgoAAaO9AARd/IwBBgB9rIgYkACABKAIAIQcgBkH4HGpBAEGBAhBEGiAGIAM2AsgQIAZByBBqQQRyIAZB+BxqQYQCED4hCAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIANBgQJPDQAgCCADIAIgAxAgIAYgBkHIEGpBiAIQPiICIAMgB2s2AogCIAIoAgAiCUGAAk8NAQJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgBEUNAAJAIAVFDQAgBSEDIAQhBgNAIAkgBi0AACIITQ0EIAZBAWohBiACIAhqQQRqQQA6AAAgA0F/aiIDDQALCyAHIAVPDQEMGAtByLDAACEEQQAhBQsgAkH4HGpBAEGBAhBEGiACIAdBAWo2AsgQIAJByBBqQQRyIAJB+BxqQYQCED4aAkACQAJAAkACQCAHRQ0AIAJBBHIiCiAJaiELQQAhDANAIAxB/wFvIgZB/wFqIAYgBkEASBsiBkGABE8NAiAJRQ0DIAxBAWohDCAGQcCjwABqLQAAIQ1BASEDIAItAAQhDiAKIQYCQANAAkACQCADRQ0AIAsgBmsgA00NAyAGIANqIg9FDQMgD0EBaiEGDAELIAYgC0YNAiAGIQ8gBkEBaiEGC0EAIQNBACEIAkAgDUUNAEEAIQggDkH/AXEiDkUNACANQcCnwABqLQAAIA5BwKfAAGotAABqQcCjwABqLQAAIQgLIA8tAAAgCHMhDgwACwsgAigCyBAiBkGBAk8NBCAMIAZPDQUgAkHIEGogDGpBBGogDjoAACAMIAdJDQALCyACQZACaiACQcgQakGIAhA+GiACKAKQAiIOQYECTw0SIAJBkAJqQQRyIhAgDmohD0EAIQMgECEIAkACQAJAAkACQAJAAkACQAJAAkACQAJAA0AgAkGQAmogA2ohBiAPIAhrQQNNDQEgBkEEai0AAA0CIAZBBWotAAANAiAGQQZqLQAADQIgA0EEaiEDIAZBCGohCCAGQQdqLQAARQ0ADAILCyAOIANrIQMgBkEEaiEGA0AgA0UNAiADQX9qIQMgBi0AACEIIAZBAWohBiAIRQ0ACwsgAkHIEGpBAEGBAhBEGiACIAU2AvAaIAJB8BpqQQRyIgogAkHIEGpBhAIQPhoCQCAFRQ0AIAVBgAJLDSIgBUF/aiEIIAJB8BpqQQRyIQ9BACEGIAUhAwNAIAYgA08NESAPIAZqIAQgBmotAABBf3MgCWo6AAAgCCAGRg0BIAZBAWohBiACKALwGiIDQYACTQ0ADFULCyAORQ0dIAJB+BxqQQBBgQIQRBogAiAOQX9qIgY2AsgQIAJByBBqQQRyIAJB+BxqQYQCED4hAyAGQYECTw0eIAMgBiACQZACakEFciAGECAgAkH4HGogAkHIEGpBiAIQPhogAigC8BoiBkGBAk8NHwJAIAZFDQAgAkHwGmogBmpBBGohEQNAAkAgAigC+BwiCEF/aiIJRQ0AIAhBgAJLDQkgCi0AACIGIAZB/wFGakH/AXFBwKPAAGotAAAhC0EAIQYDQCAGIAhPDQggBkEBaiEDIAJB+BxqIAZqIg1BBGohD0EAIQ4CQCALRQ0AIA8tAAAiDEUNACALQcCnwABqLQAAIAxBwKfAAGotAABqQcCjwABqLQAAIQ4LIAMgCE8NCSAPIA1BBWotAAAgDnM6AAAgAyAJTw0BIAMhBiACKAL4HCIIQYACTQ0ADAoLCyAKQQFqIgogEUcNAAsLIAJBmARqIAJB+BxqQYgCED4aIAIoApgEIglBgQJPDSBBACEGIAJByBBqQQBBgAIQRBogAkH4HGpBAEGAAhBEGiACQQE6ANwUIAJBATYC2BQgAkHYFGpBBXIiAyACQcgQakGDAhA+GiACQQE6AOQWIAJBATYC4BYgAkHgFmpBBXIgAkH4HGpBgwIQPhogAkHYFGpBBHIhEgJAAkAgByAFayITRQ0AIAkgByAHIAlJGyIGIAdrIRQgBkEDaiAHayEVIAJB2BRqQQJyIQogAkHwGmpBBHIhFiACQfgcakEEciEXIAJByBBqQQRyIRggAkH4HGpBBHIhGSACQfgcakEEciEaIAJB2BRqQQRyIRsgAkHgFmpBBHIhHEEAIREDQCARIBRqIgYgCU8NEyACQZgEaiAGakEEai0AACENAkAgAigC2BQiCEECSQ0AQQEhDyAIIQYgFSEDA0AgCEGAAksNDCAGQX5qIAhPDQ0gA0F8aiAJTw0OIA9BAWohD0EAIQ4CQCAKIAZqLQAAIgtFDQAgAkGYBGogA2otAAAiDEUNACAMQcCnwABqLQAAIAtBwKfAAGotAABqQcCjwABqLQAAIQ4LIAZBf2ohBiADQX9qIQMgDiANcyENIA8gCEkNAAsLIAIoAuAWIgZBgAJPDRQgAkHgFmogBmpBBGpBADoAACACIAIoAuAWQQFqIgY2AuAWAkAgDUH/AXEiDkUNAAJAIAYgCE0NACAGQYECTw0aIAJB8BpqQQBBgQIQRBogAiAGNgLIECAYIAJB8BpqQYQCED4iDyAGIBwgBhAgIAJB+BxqIAJByBBqQYgCED4aIAIoAvgcIgNBgQJPDRsCQCADRQ0AIBohBgNAAkACQCAGLQAAIghFDQAgDkHAp8AAai0AACAIQcCnwABqLQAAakHAo8AAai0AACEIDAELQQAhCAsgBiAIOgAAIAZBAWohBiADQX9qIgMNAAsLIAJB8BpqIAJB+BxqQYgCED4aIAIoAtgUIgZBgQJPDRwgAkHoGGpBAEGBAhBEGiACIAY2AsgQIA8gAkHoGGpBhAIQPiAGIBsgBhAgIAJB+BxqIAJByBBqQYgCED4aIAIoAvgcIghBgQJPDR0CQCAIRQ0AIA5BwKfAAGotAABB/wFzQcCjwABqLQAAIQ0gFyEPA0AgDyEGQQAhAwJAIA1FDQAgBi0AACIPRQ0AIA1BwKfAAGotAAAgD0HAp8AAai0AAGpBwKPAAGotAAAhAwsgBkEBaiEPIAYgAzoAACAIQX9qIggNAAsLIAJB4BZqIAJB+BxqQYgCED4aIAJB2BRqIAJB8BpqQYgCED4aIAIoAtgUIghBgAJLDR4gAigC4BYhBgsgBkGBAk8NFiACQcAOakEAQYECEEQaIAIgBjYCyBAgGCACQcAOakGEAhA+IAYgHCAGECAgAkH4HGogAkHIEGpBiAIQPhogAigC+BwiA0GBAk8NFwJAIANFDQAgGSEGA0ACQAJAIAYtAAAiD0UNACAOQcCnwABqLQAAIA9BwKfAAGotAABqQcCjwABqLQAAIQ8MAQtBACEPCyAGIA86AAAgBkEBaiEGIANBf2oiAw0ACwsgAkHwGmogAkH4HGpBiAIQPhogAigC8BoiBkGBAk8NGCACQegYaiAbIAggFiAGEBcgAkHYFGogAkHoGGpBiAIQPhoLIBVBAWohFSARQQFqIhEgE0kNAAsgAigC2BQiD0GBAk8NJCACQdgUaiAPakEEaiEDQQAhBgJAIA9BBEkNACACQdgUakEEciEOQQAhBgNAIA4gBmoiCC0AAA0nIAhBAWotAAANBSAIQQJqLQAADQYgCEEDai0AAA0HIAZBBGohBiADIAhBBGprQQNLDQALIAJB2BRqIAZqQQRqIRILIBIgA0cNAQwlC0EBIQ8LA0AgEi0AAA0kIAZBAWohBiADIBJBAWoiEkcNAAwkCwsgAkHIEGogAkGEAhA+GiACQfIaaiACQYcCai0AADoAACACIAIvAIUCOwHwGiACQYgCaigCACEIIAItAIQCIQYMUQsgBkEBaiEGDCELIAZBAmohBgwgCyAGQQNqIQYMHwtB4IvAACAGIAgQLwALQfCLwAAgBkEBaiAIEC8ACyAIQYACEDAACyAIQYACEDAAC0GQjMAAIAZBfmogCBAvAAtBoIvAACADQXxqIAkQLwALQaCLwAAgBkGABBAvAAtBsIvAAEEAQQAQLwALIAZBgAIQMAALQcCLwAAgDCAGEC8AC0GwjMAAIAggCRAvAAtB0IvAACAGIAMQLwALQaCLwAAgBiAJEC8AC0GAjMAAIAZBgAIQLwALIAZBgAIQMAALIANBgAIQMAALIAZBgAIQMAALIAZBgAIQMAALIANBgAIQMAALIAZBgAIQMAALIAhBgAIQMAALIAhBgAIQMAALIANBgAIQMAALQYiLwAAQNwALIA5BgAIQMAALQQFBABAxAAsgBkGAAhAwAAsgBkGAAhAwAAsgCUGAAhAwAAsgBSEDDDELIA9BgAIQMAALIA8gBkkNGSACQfgcakEAQYECEEQaIAIgDyAGayIDNgLIECACQcgQakEEciACQfgcakGEAhA+IQggA0GBAk8NGiAIIAMgAkHYFGogBmpBBGogAxAgIAIoAsgQIQYgAkH4HGogCEGAAhA+GkECIQMgAkHwGmpBAmogAkHPEmotAAA6AAAgAiACLwDNEjsB8BogAi0AzBIhDyAFIQgCQCAGQX9qIg4gBUkNACAOIAVrQQF0IAVqIQgLAkAgCCAHSw0AIAJBuAxqIAJB+BxqQYACED4aIAJBsApqQQJqIAJB8BpqQQJqLQAAOgAAIAIgAi8B8Bo7AbAKIA8hAwsgA0H/AXFBAkYNACACQagGaiACQbgMakGAAhA+GiACQaQGakECaiIIIAJBsApqQQJqLQAAOgAAIAIgAi8BsAo7AaQGIAIgBjYCyBAgAkHIEGpBBHIgAkGoBmpBgAIQPhogAkHPEmogCC0AADoAACACIAM6AMwSIAIgAi8BpAY7AM0SIAZBgQJPDRsCQCAGQQF2IghFDQAgAkHIEGogBmpBA2ohBiACQcgQakEEciEDA0AgAy0AACEPIAMgBi0AADoAACAGIA86AAAgA0EBaiEDIAZBf2ohBiAIQX9qIggNAAsLIAJB+BxqIAJByBBqQYgCED4aIAIoAvgcIhFBgQJPDRwgAigCACEHQQAhCiACQfAaakEAQYECEEQaIAJBADYCyBAgAkHIEGpBBHIgAkHwGmpBhAIQPhoCQCAHRQ0AIAJB+BxqQQRyIhUgEWohC0EAIQpBACEMA0AgDEH/AW8iBkH/AWogBiAGQQBIGyIGQYAETw0LIBFFDQwgDEEBaiEJIAZBwKPAAGotAAAhDUEBIQMgAi0A/BwhDiAVIQYCQANAAkACQCADRQ0AIAsgBmsgA00NAyAGIANqIg9FDQMgD0EBaiEGDAELIAYgC0YNAiAGIQ8gBkEBaiEGC0EAIQNBACEIAkAgDUUNAEEAIQggDkH/AXEiDkUNACANQcCnwABqLQAAIA5BwKfAAGotAABqQcCjwABqLQAAIQgLIA8tAAAgCHMhDgwACwsCQCAOQf8BcQ0AIApBgAJPDQ4gAkHIEGogCmpBBGogDEF/cyAHajoAACACIAIoAsgQQQFqIgo2AsgQCyAJIQwgCSAHSQ0ACwsgCiARQX9qRw0AIAJB6BhqIAJByBBqQYQCED4aIAJB4BZqQQJqIgYgAkHIEGpBhwJqLQAAOgAAIAIgAi8AzRI7AeAWIAItAMwSIgNBAkYNACACQagIaiACQegYakGEAhA+GiACQagIakGHAmogBi0AADoAACACIAM6AKwKIAIgAi8B4BY7AK0KAkAgBUUNACACKAKoCCEGA0AgBkGAAk8NDiACQagIaiAGakEEaiAELQAAOgAAIAIgAigCqAhBAWoiBjYCqAggBEEBaiEEIAVBf2oiBQ0ACwsgB0GBAk8NHSACKAKQAiIcQYECTw0eIAIoAqgIIhNBgQJPDR9BACEGIAJByBBqQQBBgQIQRBogAiATNgKwCiACQbAKakEEciISIAJByBBqQYQCED4hCAJAAkAgE0UNACACQbAKakEEciEPIAJBqAhqQQRyIQ4gEyEDA0AgA0GBAk8NECAGIANPDREgDyAGaiAOIAZqLQAAQX9zIAdqOgAAIAIoArAKIQMgBkEBaiIIIQYgEyAIRw0ACyADQYACSw0sIAJBsApqIANqQQRqIRQgAkG4DGohBQwBC0EAIQMgAkG4DGohBSAIIRQLIAJByBBqQQBBgAIQRBogAkEBOgD0GiACQQE2AvAaIAJB8BpqQQVyIAJByBBqQYMCED4aIAJBAToA2BQgAkEAOwDgFgJAIANFDQAgAiACLQC0CiIGIAZB/wFGakH/AXFBwKPAAGotAAA6AOAWIAJB8BpqQQRyIQwgAkHIEGpBBHIhGCACQbAKakEFciEEIAJB+BxqQQRyIRtBASENA0AgAkH4HGogAkHYFGpBASACQeAWakECEBcgAigC+BwiBkGBAk8NEyACQegYakEAQYECEEQaIAIgDSAGakF/ajYCyBAgGCACQegYakGEAhA+IRUCQCAGRQ0AIAJB+BxqIAZqQQRqIRFBACEOIBshCgNAAkAgDUUNACAVIA5qIQsgCi0AACEPQQAhBgNAQQAhAwJAIAwgBmotAAAiCEUNAEEAIQMgD0UNACAPQcCnwABqLQAAIAhBwKfAAGotAABqQcCjwABqLQAAIQMLIAIoAsgQIghBgQJPDQkgDiAGaiIJIAhPDQggCyAGaiIIIAgtAAAgA3M6AAAgBkEBaiIDIQYgDSADRw0ACwsgDkEBaiEOIApBAWoiCiARRw0ACwsgAkHwGmogAkHIEGpBiAIQPhogBCAURg0BIAIgBC0AACIGIAZB/wFGakH/AXFBwKPAAGotAAA6AOAWIARBAWohBCACKALwGiINQYACTQ0ACyANQYACEDAACyAFIAJB8BpqQYgCED4aIAJB+BxqQQBBgQIQRBogAiAcNgLIECACQcgQakEEciACQfgcakGEAhA+IBwgECAcECAgAkHADmogAkHIEGpBiAIQPhogAkHIEGogAkHADmpBiAIQPhogAigCyBAiBkGBAk8NIAJAIAZBAXYiCEUNACACQcgQaiAGakEDaiEGIAJByBBqQQRyIQMDQCADLQAAIQ8gAyAGLQAAOgAAIAYgDzoAACADQQFqIQMgBkF/aiEGIAhBf2oiCA0ACwsgAkHYFGogAkHIEGpBiAIQPhogAigC2BQiDUGBAk8NISACKAK4DCIUQYECTw0iIAJByBBqQQBBgQIQRBogAiAUQQFqIgo2AuAWIAJB4BZqQQRyIAJByBBqQYQCED4aIApBgQJPDSMgAkEBOgDkFiACQfgcakEAQYECEEQaIAIgDSAUakF/ajYCyBAgAkHIEGpBBHIgAkH4HGpBhAIQPhoCQCAURQ0AIAJBuAxqIBRqQQRqIRUgAkHYFGpBBHIhDCACQcgQakEEciEEIAJBuAxqQQRyIRFBACEOA0ACQCANRQ0AIAQgDmohCyARLQAAIQ9BACEGA0BBACEDAkAgDCAGai0AACIIRQ0AQQAhAyAPRQ0AIA9BwKfAAGotAAAgCEHAp8AAai0AAGpBwKPAAGotAAAhAwsgAigCyBAiCEGBAk8NCCAOIAZqIgkgCE8NCSALIAZqIgggCC0AACADczoAACAGQQFqIgMhBiANIANHDQALCyAOQQFqIQ4gEUEBaiIRIBVHDQALCyACQegYaiACQcgQakGIAhA+GiACKALoGCIGQYECTw0kIAJB+BxqQQBBgQIQRBogAiAGNgLIECACQcgQakEEciACQfgcakGEAhA+IAYgAkHoGGpBBHIgBhAgIAJB8BpqIAJByBBqQYgCED4aAkACQCAGIBRPDQAgAkHIEGpBAEGFAhBEGiACQcgQakGIAmoiBiACQfAaakGIAhA+GgwBCwJAIAYgFGsiCUUNACACQfAaakEFciEVIAJB4BZqQQVyIQ9BACEOIApBAkkhEQNAIAIoAvAaIgZBgAJLDRsgDiAGTw0aAkAgEQ0AIAJB8BpqIA5qQQRqLQAAIg1FDQAgFSAOaiELQQAhBgNAAkAgDyAGai0AACIDRQ0AIAIoAvAaIghBgQJPDQ0gDiAGaiIMQQFqIAhPDQ4gCyAGaiIIIAgtAAAgDUHAp8AAai0AACADQcCnwABqLQAAakHAo8AAai0AAHM6AAALIAZBAmohAyAGQQFqIQYgAyAKSQ0ACwsgDkEBaiIOIAlJDQALCyACKALwGiIGQYECTw0sIAYgCUkNLSACQfgcakEAQYECEEQaIAIgBiAJayIDNgLIECACQcgQakEEciACQfgcakGEAhA+IQggA0GBAk8NLiAIIAMgAkHwGmogCWpBBGogAxAgIAJB+BxqIAJByBBqQYgCED4aIAIgCTYC8BoCQCAGIAlNDQAgAkEBOgD0HAsgAkHIEGogAkHwGmpBiAIQPhogAkHIEGpBiAJqIgYgAkH4HGpBiAIQPhoLIAJB+BxqIAZBiAIQPhogAigC+BwiBkGBAk8NJQJAIAZBAXYiCEUNACACQfgcaiAGakEDaiEGIAJB+BxqQQRyIQMDQCADLQAAIQ8gAyAGLQAAOgAAIAYgDzoAACADQQFqIQMgBkF/aiEGIAhBf2oiCA0ACwsgAkHgFmogAkH4HGpBiAIQPhpBACEMIAJB6BhqQQBBhQIQRBogAigCsAoiA0GBAk8NJgJAIANFDQBBACEMA0BBACASLQAAQf8Bc2tB/wFvIgZB/wFqIAYgBkEASBsiBkGABE8NESAMQYACTw0SIBJBAWohEiACQegYaiAMakEEaiAGQcCjwABqLQAAOgAAIAIgAigC6BhBAWoiDDYC6BggA0F/aiIDDQALCyACQcgQakEAQYECEEQaIAIgBzYC8BogAkHwGmpBBHIgAkHIEGpBhAIQPhogAkHIEGogAkHgFmpBiAIQPhogAigCyBAiBkGBAk8NJwJAIAZBAXYiCEUNACACQcgQaiAGakEDaiEGIAJByBBqQQRyIQMDQCADLQAAIQ8gAyAGLQAAOgAAIAYgDzoAACADQQFqIQMgBkF/aiEGIAhBf2oiCA0ACwsgAkH4HGogAkHIEGpBiAIQPhogDEGBAk8NKAJAAkAgDEUNACACQegYakEEciIVIAxqIRQgAkHoGGpBBHIhESACQfgcakEEciEYIAJByBBqQQRyIRtBACELA0AgFS0AACEGIAJByBBqQQBBhQIQRBogC0EBaiESIBVBAWohBCAGQcCnwABqLQAAQf8Bc0HAo8AAai0AACEPQQAhA0EAIQYDQAJAIAsgBkYNAEEAIQgCQCAPRQ0AIBEgBmotAAAiDkUNACAOQcCnwABqLQAAIA9BwKfAAGotAABqQcCjwABqLQAAIQgLIANBgAJPDQsgAkHIEGogA2pBBGogCEEBczoAACACIAIoAsgQQQFqIgM2AsgQCyAMIAZBAWoiBkcNAAsgA0GBAk8NFUEBIQ4CQCADRQ0AIBshBgNAIA5B/wFxIQhBACEOAkAgCEUNACAGLQAAIg1FDQAgDUHAp8AAai0AACAIQcCnwABqLQAAakHAo8AAai0AACEOCyAGQQFqIQYgA0F/aiIDDQALCyACKAL4HCIGQYECTw0WIAZFDRcgAkH4HGpBBHIgBmohCkEBIQMgAi0A/BwhCSAYIQYCQANAAkACQCADRQ0AIAogBmsgA00NAyAGIANqIg1FDQMgDUEBaiEGDAELIAYgCkYNAiAGIQ0gBkEBaiEGC0EAIQNBACEIAkAgD0UNAEEAIQggCUH/AXEiCUUNACAPQcCnwABqLQAAIAlBwKfAAGotAABqQcCjwABqLQAAIQgLIA0tAAAgCHMhCQwACwtBACEGAkAgCUH/AXEiA0UNACAVLQAAQcCnwABqLQAAIgggCEH/AUZqQf8BcUHAo8AAai0AACIIRQ0AIANBwKfAAGotAAAgCEHAp8AAai0AAGpBwKPAAGotAAAiA0UNACADQcCnwABqLQAAQf8BaiAOQf8BcUHAp8AAai0AAGtB/wFwQcCjwABqLQAAIQYLIAsgE08NGCAHIAJBqAhqIAtqQQRqLQAAIgNNDRkgAkHwGmogA2pBBGogBjoAACASIQsgBCEVIAQgFEcNAAwCCwtBACESCyACQcgQaiACQQRyIAcgAkHwGmpBBHIgBxAXIAIgEjYC0BIgAkH4HGogAkHIEGpBiAIQPhogAigC+BwiBkGBAk8NKSABKAIAIgMgAkH4HGpBBHIgBhASRQ0BC0ECIQYMLQsgAigCACEGIAJByBBqIAJB+BxqQYQCED4aIAJB8hpqIAJB/x5qLQAAOgAAIAIgAi8A/R47AfAaIAYgA2shCCACLQD8HiEGDCwLQcCLwAAgCSAIEC8ACyAIQYACEDAACyAIQYACEDAAC0HAi8AAIAkgCBAvAAtBgIzAACADQYACEC8ACyAIQYACEDAAC0G4isAAIAxBAWogCBAvAAtBoIvAACAGQYAEEC8AC0Gwi8AAQQBBABAvAAtBgIzAACAKQYACEC8AC0GAjMAAIAZBgAIQLwALIANBgAIQMAALQaCMwAAgBiADEC8AC0Ggi8AAIAZBgAQQLwALQYCMwAAgDEGAAhAvAAsgBkGAAhAwAAsgA0GAAhAwAAsgBkGAAhAwAAtBsIvAAEEAQQAQLwALQaCLwAAgCyATEC8AC0HAi8AAIAMgBxAvAAtByIrAACAOIAYQLwALIAZBgAIQMAALIAYgDxAxAAsgA0GAAhAwAAsgBkGAAhAwAAsgEUGAAhAwAAsgB0GAAhAwAAsgHEGAAhAwAAsgE0GAAhAwAAsgBkGAAhAwAAsgDUGAAhAwAAsgFEGAAhAwAAsgCkGAAhAwAAsgBkGAAhAwAAsgBkGAAhAwAAsgA0GAAhAwAAsgBkGAAhAwAAsgDEGAAhAwAAsgBkGAAhAwAAsgA0GAAhAwAAsgBkGAAhAwAAsgCSAGEDEACyADQYACEDAAC0ECIQMCQCAGQf8BcUECRg0AIAAgAkHIEGpBhAIQPiIDQYgCaiAINgIAIANBhwJqIAJB8hpqLQAAOgAAIAMgAi8B8Bo7AIUCIAYhAwsgACADOgCEAiACQYAfaiQADwsgA0GAAhAwAAvoGQIefwF+IwBB8ABrIgQkAAJAAkACQAJAAkAgASgCKCADRw0AIARBEGogASgCCCABKAIEIAEoAgBqIgVqIgYQPCAEKAIUIQcgBCgCEEEwIAYQRCEIIARBCGogAxA8IAQoAgwhCSAEKAIIQTAgAxBEIQogBEEANgJgIARCATcDWCADRQ0CQQAhCyAEQeAAaiEMQQEhDUEAIQ4MAQsgBEEYakEMakEDNgIAIARB7ABqQQI2AgAgBCABQShqNgIgIARBAzYCHCAEIAM2AjggBEICNwJcIARB0InAADYCWCAEIARBOGo2AhggBCAEQRhqNgJoIARByABqIARB2ABqEBMgAEEBNgIAIABBDGogBEHQAGooAgA2AgAgACAEKQNINwIEDAMLA0ACQAJAAkACQAJAIAIgDmotAAAiEEExTA0AIBBBMkYNAiAQQewARg0DIBBBzABHDQEMAwtBzwAhDyAQQTBGDQMgEEExRg0CCwJAIBBBTWpB/wFxQQdPDQAgECEPDAMLIBAhDyAQQd8BcUG/f2pB/wFxQRlNDQIgCiAOakExOgAAQcEAIQ8MAgtB2gAhDwwBC0HJACEPCyAOQQFqIQ4CQCALIAQoAlxHDQAgBEHYAGogC0EBECcgDCgCACELIAQoAlghDQsgDSALaiAPOgAAIAwgC0EBaiILNgIAIA4gA0kNAAsgBCgCXCERIAQoAlghEgwBC0EBIRJBACELQQAhEQsgBEEANgIwIARCATcDKAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAFBCGooAgAiE0UNAEEAIRQgAUEkaiEVIAFBIGohFiAEQcgAakEIaiEXQQEhGEEBIRlBASEaQQAhGwNAIBUoAgAiAiAbbCAWKAIAaiIMIAJqIg4gDEkNByALIA5JDQggG0EBaiEcQQAhHSAXQQA2AgAgBEIBNwNIIAogDGohHiASIAxqIR9BASENQQAhDkEAIRACQAJAAkACQANAIA4gAk8NBCAOQQFqIg8gDkkNBCAMIA5qIiAgA08NCwJAIB4gDmotAABBMUYNAAJAAkACQCAfIA5qLQAAIg5Bv39qQf8BcUEZSw0AAkAgECAEKAJMRw0AIARByABqIBBBARAnIBcoAgAhECAEKAJIIQ0LIA0gEGpBMToAAAwBCyAOQZ9/akH/AXFBGUsNAQJAIBAgBCgCTEcNACAEQcgAaiAQQQEQJyAXKAIAIRAgBCgCSCENCyANIBBqQTA6AAALIBcgEEEBaiIQNgIACyAPIQ4gEEEISQ0BDAILCyAQRQ0BQQAhDyAQIQ4DQCAOQX9qIQ4gD0EBaiIPIBBJDQALIBcgDjYCAEEAIR0gDiEQIA5BCEkNAwsgDUEBaiANIA0tAABBK0YbIQ4gDSAQaiECQQAhIQNAIAIgDkYNAiAOLQAAQVBqIhBBAUsNBkECIR4gIUH/AXEiDyAPaiIPQf8BcSIMIA9HDQcgDkEBaiEOIAwgEEH/AXFqIiFB/wFxICFGDQAMBwsLQQAhHSAXQQA2AgAMAQtBASEdCwJAIAQoAkwiDkUNACANIA5BARBPCwJAAkAgHUUNACAhQf8BcSIOQf8BRiEQIA5FIQ4CQCAUIAQoAixHDQAgBEEoaiAUQQEQJyAEQShqQQhqKAIAIRQgBCgCKCEYCyAaIBBxIRogGSAOcSEZIBggFGohDiAhIRAMAQsgBiAbIAVqIg5NDQogCCAOakExOgAAAkAgBEEoakEIaiIOKAIAIhQgBCgCLEcNACAEQShqIBRBARAnIA4oAgAhFAsgBCgCKCIYIBRqIQ5BACEQCyAOIBA6AAAgBEEoakEIaiAUQQFqIhQ2AgAgHCEbIBwgE0kNAAsgGiAZckEBcUUNACABQQhqKAIAIg9FDQAgCCAFaiEMQQAhDgNAIAYgBSAOaiIQTQ0KIAwgDmpBMToAACAOQQFqIhAhDiAQIA9JDQALC0EAIQwgC0UNAiASIAtqIQ1BACEOA0ACQCASIA5qIhAtAAAiD0Gff2pB/wFxQRlLDQAgECAPQSBzOgAACyALIA5BAWoiDkcNAAwECwtBASEeCyAEIB46AFcgBEHsAGpBATYCACAEQQg2AjwgBEHoAGogBEE4ajYCACAEQgE3AlwgBEGAisAANgJYIAQgBEHXAGo2AjggBEEYaiAEQdgAahATIARBGGpBCGooAgAhDiAEKAIcIQsgBCgCGCEQAkAgBCgCTCIDRQ0AIA0gA0EBEE8LIAAgEDYCBCAAQQE2AgAgAEEMaiAONgIAIABBCGogCzYCAAwMCyASIQ1BACELCyAEQQA2AiAgBEIBNwMYAkACQAJAAkACQAJAAkAgC0UNAEEAIRBBASECQQAhDEEAIQsgEiEOA0AgDi0AAEFNakH/AXEiD0EoTw0EIA9BwaHAAGotAAAiD0H/AUYNBSAOQQFqIQ4gC0EFdCAPciELAkACQCAMQQVqIg9BCEkNACALIAxBfWoiDEEfcXYhDwJAIBAgBCgCHEcNACAEQRhqIBBBARAnIARBGGpBCGooAgAhECAEKAIYIQILIAIgEGogDzoAACAEQRhqQQhqIBBBAWoiEDYCAAwBCyAPIQwLIA0gDkcNAAsgDEEETQ0BDAILQQEhAkEAIQsLQf8BQQggDGtBH3F2IAtxRQ0EC0EOQQEQVSIORQ0KIARCDjcCXCAEIA42AlggBEHYAGpB6aHAAEH3ocAAEEAgBEHQAGogBCgCYDYCACAEIAQpA1g3A0gMAgtBCUEBEFUiDkUNCSAEQgk3AlwgBCAONgJYIARB2ABqQbihwABBwaHAABBAIARB0ABqIAQoAmA2AgAgBCAEKQNYNwNIDAELQQlBARBVIg5FDQggBEIJNwJcIAQgDjYCWCAEQdgAakG4ocAAQcGhwAAQQCAEQdAAaiAEKAJgNgIAIAQgBCkDWDcDSAsCQCAEKAIcIg5FDQAgAiAOQQEQTwsgAEEBNgIAIAAgBCkDSDcCBCAAQQxqIARB0ABqKAIANgIADAsLIARByABqQQhqIg4gBEEYakEIaigCADYCACAEIAQpAxg3A0ggBEE4akEIaiILIA4oAgA2AgAgBCAEKQNINwM4IAsoAgAiDyABQRRqKAIAIg5JDQkCQCAEKAI4IgsgASgCDCIQRg0AIAsgECAOEDpFDQBBDkEBEFUiDkUNByAEQg43AlwgBCAONgJYIARB2ABqQd2fwABB65/AABBAIARBIGoiDiAEKAJgNgIAIAQgBCkDWDcDGCAAQQE2AgAgAEEMaiAOKAIANgIAIAAgBCkDGDcCBCAEKAI8Ig5FDQsgCyAOQQEQTwwLCyAEQThqQQhqIhBBADYCAEEAIQwCQCAPIA5rIg9FDQACQCAORQ0AIAsgCyAOaiAPECwaCyAEQcAAaiAPNgIAIA8hDAsgBCgCKCELIARBOGogDCAEQShqQQhqIg8oAgAiDhAnIAQoAjggECgCACIMaiALIA4QPhogD0EANgIAIBAgDCAOajYCAAJAIAFBIGooAgAiDiADTw0AIA5BBWxBBGohCyABQRRqIRADQAJAIAogDmotAABBMUcNACAGIAtBfGpBA3YgECgCAGsiD00NCiAIIA9qQTE6AAAgBiALQQN2IBAoAgBrIg9NDQsgCCAPakExOgAACyALQQVqIQsgDkEBaiIOIANJDQALCyAEQQA2AiAgBEIBNwMYAkAgBkUNACAEQThqQQhqIQNBASEQQQAhC0EAIQ4DQAJAIAggDmotAABBMUcNACADKAIAIg8gDk0NCCAEKAI4IA5qQQA6AAACQCALIAQoAhxHDQAgBEEYaiALQQEQJyAEQRhqQQhqKAIAIQsgBCgCGCEQCyAQIAtqIA46AAAgBEEYakEIaiALQQFqIgs2AgALIA5BAWoiDiAGSQ0ACwsgBEHYAGpBCGoiDiAEQThqQQhqKAIANgIAIARB2ABqQRRqIARBGGpBCGooAgA2AgAgAEEANgIAIAAgBCkDOCIiNwIEIAQgBCkDGDcCZCAAQQxqIA4pAwA3AgAgAEEUaiAEQegAaikDADcCACAEICI3A1gCQCAEKAIsIg5FDQAgBCgCKCAOQQEQTwsCQCARRQ0AIBIgEUEBEE8LAkAgCUUNACAKIAlBARBPCyAHRQ0LIAggB0EBEE8MCwtB8InAACAgIAMQLwALIAwgDhAxAAsgDiALEDAAC0HgicAAIA4gBhAvAAtB4InAACAQIAYQLwALQeCJwAAgDiAPEC8ACwALQeCJwAAgDyAGEC8AC0HgicAAIA8gBhAvAAsgDiAPEDAACwJAIAQoAiwiDkUNACAEKAIoIA5BARBPCwJAIBFFDQAgEiARQQEQTwsCQCAJRQ0AIAogCUEBEE8LIAdFDQAgCCAHQQEQTwsgBEHwAGokAAuPFAIOfwJ+IwBBwAprIgQkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAUUNACABKAIAIgVBf0YNASABIAVBAWo2AgAgASgCBCADRw0CIARBqARqQQBBgQIQRBogBCADNgKwBiAEQbAGakEEciAEQagEakGEAhA+GiADQYECTw0DIARBsAZqQQRyIAMgAiADECAgBEGgAmogBEGwBmpBiAIQPhogBCgCoAIhBSAEIAMgAUEwaiIGKAIAIgdqQX9qIgg2AqACAkACQCAIIAVNDQAgBC0ApARB/wFxRQ0AIAhBgQJPDRAgCCAFa0UNASAEQaACaiAIakEEaiEJIAVBAWogA2sgB2shCiAEQaACakEEciEHAkADQAJAAkAgBUUNACAJIAdrIAVNDQMgBUEBaiELIAcgBWoiCA0BDAMLQQEhCyAHIQggByAJRg0CIAhFDQILQQAhBSAIQQA6AAAgByALaiEHIApBAWoiCg0ACwsgBigCACEHDAELIAggBU8NACAEQaQEakEBOgAACyAEQagEaiAGQYgCED4aIARBuAhqQQBBgQIQRBogBCAHNgKwBiAEQbAGakEEciAEQbgIakGEAhA+GiAEKAKoBCIGQYECTw0EAkACQAJAAkACQAJAAkACQCAGRQ0AAkAgB0GAAksNACAGQX9qIQogBEGoBGpBBXIhCSAEQbAGakEEciELQQAhBSAELQCsBCEIA0AgBSAHTw0DIAsgBWogCEH/AXFBwKfAAGotAAA6AAAgCiAFRg0CIAkgBWohByAFQQFqIQUgBy0AACEIIAQoArAGIgdBgAJNDQALCyAHQYACEDAACwJAAkACQAJAIANFDQBBACEIIAQoArAGIQsgBkECSSEMA0AgBCgCoAIiBUGAAksNBiAIIAVPDQcCQCAEQaACaiAIaiIKQQRqLQAAIgVFDQAgDA0AIAtBgAJLDQwgBUHAp8AAai0AACEJQQUhBQNAIAVBfGogC08NBCAEKAKgAiIHQYECTw0FIAggBWoiDUF8aiAHTw0GIAogBWoiByAHLQAAIARBsAZqIAVqLQAAIAlqQcCjwABqLQAAczoAACAFQX1qIQcgBUEBaiEFIAcgBkkNAAsLIAhBAWoiCCADSQ0ACwsgBCgCoAIiBUGBAk8NDyAFIANJDRAgBEGgAmpBBHIgAyACIAMQICAEQRBqIARBoAJqQYgCED4aIAQgAzYCmAIgBCgCECIFQYECTw0RIAUgAUEMaigCACINayEMIAUgDUkNEiABQRBqKAIAIQUgBEEIaiABQRhqKAIAIgcQPCAEQQA2ArgGIAQgBCkDCDcDsAYgBEGwBmogBSAFIAdqEEAgBEHACGogBCgCuAY2AgAgBCAEKQOwBjcDuAggBCgCECIFQYECTw0TIAUgAUEMaigCACIHayEIIAUgB0kNFCAEQbgIaiAEQRBqQQRyIgUgBSAIahBAIAQoAsAIIQcgBCgCuAghDkEAIQUgBEEANgK4BiAEQgE3A7AGAkACQCAHRQ0AIA4gB2ohA0EBIQlBASEKQQAhB0EAIQsgDiEGA0AgBiIIQQFqIQYgC0EIdCAILQAAciELAkAgB0EIaiIHQQZJDQADQCALIAdBe2oiB0EfcXZBH3FBmKHAAGotAAAhCAJAIAUgBCgCtAZHDQAgBEGwBmogBUEBECcgBEGwBmpBCGooAgAhBSAEKAKwBiIJIQoLIAogBWogCDoAACAEQbAGakEIaiAFQQFqIgU2AgAgB0EFSw0ACwsgBiADRw0ACyAHRQ0BIAtBBSAHa0EfcXRBH3FBmKHAAGotAAAhBwJAIAUgBCgCtAZHDQAgBEGwBmogBUEBECcgBEG4BmooAgAhBSAEKAKwBiIJIQoLIAogBWogBzoAACAEQbgGaiAFQQFqNgIADAELQQEhCQsgAUEsaiIFKAIAIAQpArQGIhJCIIinIgtHDRUgAUEkaigCACIFIAtLDRYgBEGwBmogCSAFIAFBHGooAgAgBUEDEBogBCgCsAYiBw0XAkAgDUUNACAEQRBqIAxqQQRqIQZBACEFIAFBKGohAyABQSRqIQwgBEHEBmohAiAEQbwGaiEPIARBwAZqIRADQCADKAIAIgcgBWwgDCgCAGoiCCAHaiIKIAhJDQggCiALSw0JIAJBATYCACAPQQE2AgAgBEGwBmpBCGoiEUGMn8AANgIAIARBBDYCpAIgBCAGIAVqNgKgAiAQIARBoAJqNgIAIARBATYCtAYgBEGAisAANgKwBiAEQagEaiAEQbAGahATIAQoAqwEIQogBEGwBmogCSAIaiAHIAQoAqgEIgggBEGoBGpBCGooAgBBCBAaIAQoArAGIgcNCiAFQQFqIQUCQCAKRQ0AIAggCkEBEE8LIAUgDUkNAAsLAkAgBCgCvAgiBUUNACAOIAVBARBPCyABIAEoAgBBf2o2AgAgBCASNwIUIAQgCTYCECAEIARBEGoQKiAAIAQpAwA3AgAgBEHACmokAA8LQaiKwAAgBUF8aiALEC8ACyAHQYACEDAAC0GYisAAIA1BfGogBxAvAAtBmIrAACAFIAcQLwALIAVBgAIQMAALQYiKwAAgCCAFEC8ACyAIIAoQMQALIAogCxAwAAsgETUCAEIghiAENQK0BoQhEyAKRQ0QIAggCkEBEE8MEAsgC0GAAhAwAAsQUAALEFEACyAEQbwGakEDNgIAIARBJGpBAjYCACAEIAFBBGo2ArgGIARBAzYCtAYgBCADNgKgAiAEQgI3AhQgBEGwicAANgIQIAQgBEGgAmo2ArAGIAQgBEGwBmo2AiAgBEGoBGogBEEQahATIAQpAqwEIRMgBCgCqAQhBwwNCyADQYACEDAACyAGQYACEDAACyAFQYACEDAACyADIAUQMAALIAVBgAIQMAALIAwgBRAxAAsgBUGAAhAwAAsgCCAFEDAACyAEQbQEakEDNgIAIARBxAZqQQI2AgAgBCAFNgKwBCAEQQM2AqwEIAQgCzYCnAIgBEICNwK0BiAEQcCJwAA2ArAGIAQgBEGcAmo2AqgEIAQgBEGoBGo2AsAGIARBoAJqIARBsAZqEBMgBCkCpAIhEyAEKAKgAiEHDAMLIAUgCxAwAAsgBCkCtAYhEwwBCyAIQYACEDAACwJAIBKnIgVFDQAgCSAFQQEQTwsgBCgCvAgiBUUNACAOIAVBARBPCyAEIBM3AqwEIAQgBzYCqAQgBEEkakEBNgIAIARBAjYCpAIgBEIBNwIUIARBgIrAADYCECAEIARBqARqNgKgAiAEIARBoAJqNgIgIARBsAZqIARBEGoQEyAEKAKwBiIHIAQoArgGEAAhBQJAIAQoArQGIghFDQAgByAIQQEQTwsCQCAEKAKsBCIHRQ0AIAQoAqgEIAdBARBPCyABIAEoAgBBf2o2AgAgBRBWAAvPDAENfyMAQZAMayICJAAgAiAANgLoCSACIAE2AuwJAkACQAJAAkACQAJAIAFBBEcNACAALQAAQegARw0AIAAtAAFB4wBHDQAgAC0AAyIBQTBGIAFBMUdzDQAgAC0AAkFNakH/AXEiAEHIAEkNAQsgAkHEB2pBATYCACACQQE2AowKIAJCAjcCtAcgAkGgicAANgKwByACIAJB6AlqNgKICiACIAJBiApqNgLAByACQbgCaiACQbAHahATDAELAkACQAJAAkACQCAAQQF0IAFBMEdyQeqdwABqLQAAIgFB/wFGDQBBA0EBEFUiBEUNByAEQSQ6AAIgBCABOgABIARBODoAAEEDQQEQVSIBRQ0HIAJCAzcCtAcgAiABNgKwByACQbAHakH6nsAAQf2ewAAQQCACQfgJakEIaiIBIAIoArgHNgIAIAIgAikDsAc3A/gJIAJB6AlqQQhqIAEoAgA2AgAgAiACKQP4CTcD6AkgAkGwB2pBAEGAAhBEGiACQQE6ALwCIAJBATYCuAIgAkG4AmpBBXIgAkGwB2pBgwIQPhogAkEBOwCGCiACQbgCakEEciEFIAJBsAdqQQRyIQYgAkGICmohB0EAIQgDQCAIQf8BbyIBQf8BaiABIAFBAEgbIgFBgARPDQQgAkGGCmpBAWogAUHAo8AAai0AADoAACACKAK4AiIJQYECTw0FIAhBAWohCEEAIQogAkGICmpBAEGBAhBEGiACIAlBAWo2ArAHIAYgAkGICmpBhAIQPiELIAJBhgpqIQwDQAJAIAlFDQAgCyAKaiENQQAhAQNAQQAhAAJAIAUgAWotAAAiA0UNAEEAIQAgDC0AACIORQ0AIA5BwKfAAGotAAAgA0HAp8AAai0AAGpBwKPAAGotAAAhAAsgAigCsAciA0GBAk8NBiAKIAFqIg4gA08NBSANIAFqIgMgAy0AACAAczoAACABQQFqIgAhASAJIABHDQALCyAKQQFqIQogDEEBaiIMIAdHDQALIAJBuAJqIAJBsAdqQYgCED4aIAhBCEkNAAsgAkGwB2ogAkG4AmpBiAIQPhogAkGEBWpCg4CAgDA3AgAgAkGABWogBDYCACACQfwEakEENgIAIAJBmAVqQo+AgIDwBzcDACACQYwFaiACKQPoCTcCACACQZQFaiACQegJakEIaigCADYCACACQqCAgIDAADcC9AQgAkGgBWogAkGwB2pBiAIQPhogAkGoB2pBCDYCAEEAIQEgAkEANgLwBCACQbAHaiACQfAEakEIaiIAQbQCED4aIAJBuAJqIABBtAIQPhpBICEAQQEhAwwGCyACQcQHakEBNgIAIAJBATYCjAogAkICNwK0ByACQaCJwAA2ArAHIAIgAkHoCWo2AogKIAIgAkGICmo2AsAHIAJBuAJqIAJBsAdqEBMMBAtBmIrAACAOIAMQLwALIANBgAIQMAALQYiKwAAgAUGABBAvAAsgCUGAAhAwAAsgAigCvAIhASACKAK4AiEAIAJB/ARqIgMgAigCwAI2AgAgAkHwBGpBCGogATYCACACIAA2AvQEIAJBATYC8AQgAkGICmpBCGogAygCADYCACACIAIpAvQENwOICiACQcQHakEBNgIAIAJBAjYC/AkgAkIBNwK0ByACQYCKwAA2ArAHIAIgAkGICmo2AvgJIAIgAkH4CWo2AsAHIAJBuAJqIAJBsAdqEBMgAigCuAIiASACKALAAhAAIQACQCACKAK8AiIDRQ0AIAEgA0EBEE8LAkAgAigCjAoiAUUNACACKAKICiABQQEQTwtBACEDQQEhAQsgAkEEaiACQbgCakG0AhA+GiABDQEgAkG4AmogAkEEakG0AhA+GgJAIAIoAvAERQ0AIANBAXMNACACQfgEaigCACIBRQ0AIAIoAvQEIAFBARBPCyACQbAHaiACQbgCakG0AhA+GiACQfAEaiACQbAHakG0AhA+GkG8AkEEEFUiAUUNACABIAA2AgQgAUEANgIAIAFBCGogAkHwBGpBtAIQPhogAkGQDGokACABDwsACwJAAkAgAigC8AQNACACQfAEakEEchA9DAELIANFDQAgAkH4BGooAgAiAUUNACACKAL0BCABQQEQTwsgAkGwB2ogAkG4AmpBtAIQPhogABBWAAucCwEJfyMAQRBrIgUkACAAKAIAIgZBAXEiByAEaiEIAkAgBkEEcSIJRQ0AQQAhCgJAIAJFDQAgAiELIAEhDANAIAogDC0AAEHAAXFBgAFGaiEKIAxBAWohDCALQX9qIgsNAAsLIAggAmogCmshCAtBK0GAgMQAIAcbIQ0gCUECdiEJAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgACgCCEEBRw0AIABBDGooAgAiCiAITQ0BIAZBCHENAiAKIAhrIQtBASAALQAwIgwgDEEDRhtBA3EiDEUNAyAMQQJGDQRBACEIDAULAkAgB0UNAEEBIQwgAEEYaigCACANIABBHGooAgAoAhARAQANDwsCQCAJRQ0AQQEhDCAAQRhqKAIAIAEgAiAAQRxqKAIAKAIMEQAADQ8LIABBGGooAgAgAyAEIABBHGooAgAoAgwRAAAhDAwOCwJAIAdFDQBBASEMIABBGGooAgAgDSAAQRxqKAIAKAIQEQEADQ4LAkAgCUUNAEEBIQwgAEEYaigCACABIAIgAEEcaigCACgCDBEAAA0OCyAAQRhqKAIAIAMgBCAAQRxqKAIAKAIMEQAAIQwMDQtBASEMIABBAToAMCAAQTA2AgQCQCAHRQ0AIAAoAhggDSAAQRxqKAIAKAIQEQEADQ0LAkAgCUUNACAAKAIYIAEgAiAAQRxqKAIAKAIMEQAADQ0LIAogCGshC0EBIABBMGotAAAiDCAMQQNGG0EDcSIMRQ0EIAxBAkYNA0EAIQcMBQsgCyEIQQAhCwwBCyALQQFqQQF2IQggC0EBdiELCyAFQQA2AgwCQCAAKAIEIgxB/wBLDQAgBSAMOgAMQQEhCgwFCwJAIAxB/w9LDQAgBSAMQT9xQYABcjoADSAFIAxBBnZBH3FBwAFyOgAMQQIhCgwFCyAMQf//A0sNAyAFIAxBP3FBgAFyOgAOIAUgDEEGdkE/cUGAAXI6AA0gBSAMQQx2QQ9xQeABcjoADEEDIQoMBAsgC0EBakEBdiEHIAtBAXYhCwwBCyALIQdBACELCyAFQQA2AggCQCAAQQRqKAIAIgxB/wBLDQAgBSAMOgAIQQEhCgwECyAMQf8PSw0CIAUgDEE/cUGAAXI6AAkgBSAMQQZ2QR9xQcABcjoACEECIQoMAwsgBSAMQT9xQYABcjoADyAFIAxBEnZB8AFyOgAMIAUgDEEGdkE/cUGAAXI6AA4gBSAMQQx2QT9xQYABcjoADUEEIQoLQX8hDAJAA0AgDEEBaiIMIAtPDQEgAEEYaigCACAFQQxqIAogAEEcaigCACgCDBEAAEUNAAwECwsCQCAHRQ0AIABBGGooAgAgDSAAQRxqKAIAKAIQEQEADQMLAkAgCUUNACAAQRhqKAIAIAEgAiAAQRxqKAIAKAIMEQAADQMLIABBGGoiCygCACADIAQgAEEcaiIAKAIAKAIMEQAADQJBfyEMA0AgDEEBaiIMIAhPDQQgCygCACAFQQxqIAogACgCACgCDBEAAEUNAAwDCwsCQCAMQf//A0sNACAFIAxBP3FBgAFyOgAKIAUgDEEGdkE/cUGAAXI6AAkgBSAMQQx2QQ9xQeABcjoACEEDIQoMAQsgBSAMQT9xQYABcjoACyAFIAxBEnZB8AFyOgAIIAUgDEEGdkE/cUGAAXI6AAogBSAMQQx2QT9xQYABcjoACUEEIQoLQX8hDAJAA0AgDEEBaiIMIAtPDQEgAEEYaigCACAFQQhqIAogAEEcaigCACgCDBEAAEUNAAwCCwsgAEEYaiILKAIAIAMgBCAAQRxqIgAoAgAoAgwRAAANAEF/IQwDQCAMQQFqIgwgB08NAiALKAIAIAVBCGogCiAAKAIAKAIMEQAARQ0ACwtBASEMDAELQQAhDAsgBUEQaiQAIAwLoQkBDX8jAEEQayIDJAAgACgCECEEAkACQAJAAkACQAJAAkACQAJAIAAoAggiBUEBRw0AIAQNAQwGCyAERQ0BCyACRQ0BIAEgAmohBiAAQRRqKAIAQX9zIQdBACEIIAEhBCABIQkDQCAEQQFqIQoCQAJAAkACQAJAIAQsAAAiC0EASA0AIAtB/wFxIQsMAQsCQAJAIAogBkYNACAKLQAAQT9xIQwgBEECaiIEIQoMAQtBACEMIAYhBAsgC0EfcSENAkACQAJAIAtB/wFxIgtB4AFJDQAgBCAGRg0BIAQtAABBP3EhDiAEQQFqIgohDwwCCyAMIA1BBnRyIQsMAgtBACEOIAYhDwsgDiAMQQZ0ciEMAkAgC0HwAUkNACAPIAZGDQIgD0EBaiEEIA8tAABBP3EhCwwDCyAMIA1BDHRyIQsLIAohBCAHQQFqIgcNAgwGC0EAIQsgCiEECyAMQQZ0IA1BEnRBgIDwAHFyIAtyIgtBgIDEAEYNBSAHQQFqIgdFDQQLIAggCWsgBGohCCAEIQkgBiAERw0ADAQLCyAAKAIYIAEgAiAAQRxqKAIAKAIMEQAAIQQMBQtBACECIAUNAgwDCyALQYCAxABGDQACQAJAIAhFDQAgCCACRg0AQQAhBCAIIAJPDQEgASAIaiwAAEFASA0BCyABIQQLIAggAiAEGyECIAQgASAEGyEBCyAFRQ0BC0EAIQoCQCACRQ0AIAIhCyABIQQDQCAKIAQtAABBwAFxQYABRmohCiAEQQFqIQQgC0F/aiILDQALCwJAAkACQAJAIAIgCmsgAEEMaigCACIHTw0AQQAhCgJAIAJFDQBBACEKIAIhCyABIQQDQCAKIAQtAABBwAFxQYABRmohCiAEQQFqIQQgC0F/aiILDQALCyAKIAJrIAdqIQtBACAALQAwIgQgBEEDRhtBA3EiBEUNASAEQQJGDQJBACEHDAMLIAAoAhggASACIABBHGooAgAoAgwRAAAhBAwECyALIQdBACELDAELIAtBAWpBAXYhByALQQF2IQsLIANBADYCDAJAAkAgACgCBCIEQf8ASw0AIAMgBDoADEEBIQoMAQsCQCAEQf8PSw0AIAMgBEE/cUGAAXI6AA0gAyAEQQZ2QR9xQcABcjoADEECIQoMAQsCQCAEQf//A0sNACADIARBP3FBgAFyOgAOIAMgBEEGdkE/cUGAAXI6AA0gAyAEQQx2QQ9xQeABcjoADEEDIQoMAQsgAyAEQT9xQYABcjoADyADIARBEnZB8AFyOgAMIAMgBEEGdkE/cUGAAXI6AA4gAyAEQQx2QT9xQYABcjoADUEEIQoLQX8hBAJAAkACQANAIARBAWoiBCALTw0BIABBGGooAgAgA0EMaiAKIABBHGooAgAoAgwRAABFDQAMAgsLIABBGGoiCygCACABIAIgAEEcaiIAKAIAKAIMEQAADQBBfyEEA0AgBEEBaiIEIAdPDQIgCygCACADQQxqIAogACgCACgCDBEAAEUNAAsLQQEhBAwCC0EAIQQMAQsgACgCGCABIAIgAEEcaigCACgCDBEAACEECyADQRBqJAAgBAuZCQEKfyMAQcAAayIDJAAgA0EkaiABNgIAIANBNGogAkEUaigCACIENgIAIANBAzoAOCADQSxqIAIoAhAiBSAEQQN0ajYCACADQoCAgICABDcDCCADIAA2AiBBACEGIANBADYCGCADQQA2AhAgAyAFNgIwIAMgBTYCKAJAAkACQAJAAkACQAJAAkACQCACKAIIIgdFDQAgAigCACEIIAIoAgQiCSACQQxqKAIAIgUgBSAJSxsiCkUNASAAIAgoAgAgCCgCBCABKAIMEQAADQIgCEEIaiEFIANBOGohACADQTRqIQEgA0EwaiELQQEhBgNAIAAgB0Egai0AADoAACADIAdBCGooAgA2AgwgAyAHQQxqKAIANgIIQQAhAgJAAkACQAJAIAdBGGooAgAiBEEBRg0AAkAgBEEDRg0AIARBAkcNAiADQQhqQSBqIgQoAgAiDCADQQhqQSRqKAIARg0AIAQgDEEIajYCACAMKAIEQQxHDQQgDCgCACgCACEEDAMLDAMLIAdBHGooAgAiDCABKAIAIgRPDQsgCygCACAMQQN0aiIMKAIEQQxHDQIgDCgCACgCACEEDAELIAdBHGooAgAhBAtBASECCyADQQhqQQxqIAQ2AgAgA0EIakEIaiACNgIAQQAhAgJAAkACQAJAIAdBEGooAgAiBEEBRg0AAkAgBEEDRg0AIARBAkcNAiADQQhqQSBqIgQoAgAiDCADQQhqQSRqKAIARg0AIAQgDEEIajYCACAMKAIEQQxHDQQgDCgCACgCACEEDAMLDAMLIAdBFGooAgAiDCABKAIAIgRPDQwgCygCACAMQQN0aiIMKAIEQQxHDQIgDCgCACgCACEEDAELIAdBFGooAgAhBAtBASECCyADQQhqQRRqIAQ2AgAgA0EIakEQaiACNgIAAkACQCAHKAIAQQFHDQAgB0EEaigCACICIAEoAgAiBE8NCCALKAIAIAJBA3RqIQIMAQsgA0EIakEgaiIEKAIAIgIgA0EIakEkaigCAEYNCCAEIAJBCGo2AgALIAIoAgAgA0EIaiACQQRqKAIAEQEADQMgBiAKTw0CIAVBBGohAiAFKAIAIQQgBUEIaiEFIAdBJGohByAGQQFqIQYgA0EIakEYaigCACAEIAIoAgAgA0EIakEcaigCACgCDBEAAEUNAAwDCwsgAigCACEIIAIoAgQiCSAEIAQgCUsbIgpFDQAgACAIKAIAIAgoAgQgASgCDBEAAA0BIAhBCGohByADQSBqIQAgA0EkaiEBQQEhBgNAIAUoAgAgA0EIaiAFQQRqKAIAEQEADQIgBiAKTw0BIAdBBGohAiAHKAIAIQQgBUEIaiEFIAdBCGohByAGQQFqIQYgACgCACAEIAIoAgAgASgCACgCDBEAAEUNAAwCCwsgCSAGTQ0BIANBIGooAgAgCCAGQQN0aiIHKAIAIAcoAgQgA0EkaigCACgCDBEAAEUNAQtBASEHDAELQQAhBwsgA0HAAGokACAHDwtByI/AACACIAQQLwALQdiOwAAQNwALQbiPwAAgDCAEEC8AC0G4j8AAIAwgBBAvAAvpCAEGfyMAQfAAayIEJAAgBCADNgIMIAQgAjYCCEEBIQUgASEGAkAgAUGBAkkNAEEAIAFrIQdBgAIhCAJAA0ACQCAIIAFPDQAgACAIaiwAAEG/f0oNAgsgCEF/aiEGQQAhBSAIQQFGDQIgByAIaiEJIAYhCCAJQQFHDQAMAgsLQQAhBSAIIQYLIAQgBjYCFCAEIAA2AhAgBEEAQQUgBRs2AhwgBEHIsMAAQaizwAAgBRs2AhgCQAJAAkACQAJAAkAgAiABSyIIDQAgAyABSw0AIAIgA0sNBAJAAkAgAkUNACABIAJGDQAgASACTQ0BIAAgAmosAABBQEgNAQsgAyECCyAEIAI2AiAgAkUNASACIAFGDQEgAUEBaiEJAkADQAJAIAIgAU8NACAAIAJqIgYsAABBv39KDQILIAJBf2ohCCACQQFGDQQgCSACRiEGIAghAiAGRQ0ADAQLCyACIQgMAwsgBCACIAMgCBs2AiggBEHIAGpBFGpBCTYCACAEQdQAakEJNgIAIARBMGpBFGpBAzYCACAEQQM2AkwgBEIDNwI0IARBgI7AADYCMCAEIARBGGo2AlggBCAEQRBqNgJQIAQgBEEoajYCSCAEIARByABqNgJAIARBMGpBmI7AABA7AAsgAiEICyAAIAhqIQYLIAYgACABaiICRg0BQQEhAUEAIQkCQAJAIAYsAAAiBkEASA0AIAQgBkH/AXE2AiQgBEEoaiECDAELIAIhAQJAIAAgCGoiAEEBaiACRg0AIABBAmohASAAQQFqLQAAQT9xIQkLIAZBH3EhAAJAAkACQCAGQf8BcUHgAUkNAEEAIQUgAiEHAkAgASACRg0AIAFBAWohByABLQAAQT9xIQULIAUgCUEGdHIhASAGQf8BcUHwAUkNAUEAIQYCQCAHIAJGDQAgBy0AAEE/cSEGCyABQQZ0IABBEnRBgIDwAHFyIAZyIgZBgIDEAEcNAgwFCyAJIABBBnRyIQYMAQsgASAAQQx0ciEGCyAEIAY2AiRBASEBIARBKGohAiAGQYABSQ0AQQIhASAGQYAQSQ0AQQNBBCAGQYCABEkbIQELIAQgCDYCKCAEIAEgCGo2AiwgBEHsAGpBCTYCACAEQeQAakEJNgIAIARByABqQRRqQQo2AgAgBEHUAGpBCzYCACAEQTBqQRRqQQU2AgAgBCACNgJYIARBAzYCTCAEQgU3AjQgBEHwjsAANgIwIAQgBEEYajYCaCAEIARBEGo2AmAgBCAEQSRqNgJQIAQgBEEgajYCSCAEIARByABqNgJAIARBMGpBmI/AABA7AAsgBEHkAGpBCTYCACAEQcgAakEUakEJNgIAIARB1ABqQQM2AgAgBEEwakEUakEENgIAIARBAzYCTCAEQgQ3AjQgBEGojsAANgIwIAQgBEEYajYCYCAEIARBEGo2AlggBCAEQQxqNgJQIAQgBEEIajYCSCAEIARByABqNgJAIARBMGpByI7AABA7AAtB2I7AABA3AAuqCQILfwF+IwBBgAdrIgQkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAUUNACABKAIAIgVBf0YNASABIAVBAWo2AgAgBEHQBGogAUEEaiIGIAIgAxAEIAQoAtAEQQFGDQIgBEHQBGpBCGooAgAhByAEQeQEaigCACEIIARB4ARqKAIAIQkgBCgC1AQhBSAEQegEaigCACICIAFBDGooAgAgAUEIaigCAGpBAXZLDQMgBEHQBGpBDGooAgAhAyABQbgCaiEKAkACQAJAIAJFDQAgBEHQBGogCiAFIAMgCSACEAMgBEHQBGohCiAEQcECaiECIARByAJqIQsgBEEwaiEMIARBIGohAwwBCyAKKAIAIAUgAxASRQ0BIARB0ARqIAogBSADIAlBABADIARB0ARqIQogBEHBAmohAiAEQcgCaiELIARBMGohDCAEQSBqIQMLIAsgCkGEAhA+IQogAiAEKADVBjYAACACQQRqIgsgBEHQBGpBiQJqLwAAOwAAIAJBBmoiDSAEQdAEakGLAmotAAA6AAAgBC0A1AYiDkECRg0FIAwgCkGEAhA+GiAEQTBqQYkCaiALLwAAOwAAIARBMGpBiwJqIA0tAAA6AAAgBCAOOgC0AiAEIAIoAAA2ALUCIAQoAjAiCkGBAk8NBiAKIAYoAgAiAkkNByAEQRBqIAIQPCAEQQA2AtgEIAQgBCkDEDcD0AQgBEHQBGogBEEwakEEciIKIAogAmoQQCADQQhqIAQoAtgENgIAIAMgBCkD0AQ3AgAgAykCBCEPIAMoAgAhAiAIRQ0ODA0LIAMgBigCACICSQ0LIARBGGogAhA8IARBADYC2AQgBCAEKQMYNwPQBCAEQdAEaiAFIAUgAmoQQCAEKQLUBCEPIAQoAtAEIQIgCA0MDA0LEFAACxBRAAsgBEHYBGopAwAhDyAEKALUBCECDAcLQQ1BARBVIgINAwALIARB5ARqQQE2AgAgBEEHNgL0BiAEQgE3AtQEIARBgIrAADYC0AQgBCAEQfgGajYC8AYgBCAEQfAGajYC4AQgBEHgBmogBEHQBGoQEyAEKQLkBiEPIAQoAuAGIQIgCA0DDAQLIApBgAIQMAALIAIgChAwAAsgBEINNwLUBCAEIAI2AtAEIARB0ARqQZavwABBo6/AABBAIAQpAtQEIQ8gBCgC0AQhAiAIRQ0BCyAJIAhBARBPCyAHRQ0AIAUgB0EBEE8LIAQgDzcC5AYgBCACNgLgBiAEQeQEakEBNgIAIARBAjYC9AYgBEIBNwLUBCAEQYCKwAA2AtAEIAQgBEHgBmo2AvAGIAQgBEHwBmo2AuAEIARByAJqIARB0ARqEBMgBCgCyAIiAyAEKALQAhAAIQICQCAEKALMAiIFRQ0AIAMgBUEBEE8LAkAgBCgC5AYiA0UNACAEKALgBiADQQEQTwsgASABKAIAQX9qNgIAIAIQVgALIAIgAxAwAAsgCSAIQQEQTwsCQCAHRQ0AIAUgB0EBEE8LIAEgASgCAEF/ajYCACAEIA83AtQEIAQgAjYC0AQgBEEIaiAEQdAEahAqIAAgBCkDCDcCACAEQYAHaiQAC7EIARJ/IwBBMGsiAiQAIAAoAgAiACgCCCEDIAAoAgAhBEEBIQUCQCABKAIYQSIgAUEcaigCACgCEBEBAA0AAkACQAJAIANFDQAgBCADaiEGIAJBDGohByACQQhqIQggAUEYaiEJIAFBHGohCiAEIQtBACEMQQAhDQJAA0AgCyEOIAtBAWohAAJAAkACQAJAAkAgCywAACIPQQBIDQAgD0H/AXEhDwwBCwJAAkAgACAGRg0AIAAtAABBP3EhECALQQJqIgshAAwBC0EAIRAgBiELCyAPQR9xIRECQAJAAkAgD0H/AXEiD0HgAUkNACALIAZGDQEgCy0AAEE/cSESIAtBAWoiACETDAILIBAgEUEGdHIhDwwCC0EAIRIgBiETCyASIBBBBnRyIRACQCAPQfABSQ0AIBMgBkYNAiATQQFqIQsgEy0AAEE/cSEPDAMLIBAgEUEMdHIhDwsgACELDAILQQAhDyAAIQsLIBBBBnQgEUESdEGAgPAAcXIgD3IiD0GAgMQARg0CCyACIA8QEAJAIAIoAgAiAEEBRg0AAkAgAEEDRw0AIAgoAgAgBy0AAGpBAUYNAQsgAiADNgIUIAIgBDYCECACIAw2AhggAiANNgIcAkAgDSAMSQ0AAkAgDEUNACAMIANGDQAgDCADTw0BIAQgDGosAABBv39MDQELAkAgDUUNACANIANGDQAgDSADTw0BIAQgDWosAABBv39MDQELAkAgCSgCACAEIAxqIA0gDGsgCigCACgCDBEAAA0AIActAAAhECAIKAIAIRMgAigCBCERA0ACQAJAAkACQAJAAkAgAEEBRg0AQdwAIQwCQCAAQQJGDQAgAEEDRw0FIBBB/wFxIQBBBCEQIABBf2oiEkEESw0FQQMhAAJAIBIOBQAEBQMHAAtBACEQQf0AIQwMBgtBASEADAULQQAhACARIQwgEUGAgMQARg0DDAQLQQMhEEH1ACEMQQMhAAwDCyARIBNBAnRBHHF2QQ9xIgxBMHIgDEHXAGogDEEKSRshDEECQQEgExshECATQX9qQQAgExshEwwCC0ECIRBB+wAhDAwBC0EBIQACQCAPQYABSQ0AQQIhACAPQYAQSQ0AQQNBBCAPQYCABEkbIQALIAAgDWohDAwECyAJKAIAIAwgCigCACgCEBEBAEUNAAsLQQEhBQwHCyACIAJBHGo2AiggAiACQRhqNgIkIAIgAkEQajYCICACQSBqEEYACyANIA5rIAtqIQ0gBiALRw0ACwsgDEUNASAMIANGDQECQCAMIANPDQAgBCAMaiIALAAAQb9/Sg0DCyAEIAMgDCADEAoAC0EAIQwLIAQgDGohAAsgAUEYaiILKAIAIAAgAyAMayABQRxqIg0oAgAoAgwRAAANACALKAIAQSIgDSgCACgCEBEBACEFCyACQTBqJAAgBQvDBQEHf0EAIQQCQAJAIAJBA3EiBUUNAEEEIAVrIgVFDQAgAiADIAUgBSADSxsiBGohBkEAIQUgAUH/AXEhByAEIQggAiEJAkACQANAIAYgCWtBA00NASAFIAktAAAiCiAHR2ohBSAKIAdGDQIgBSAJQQFqLQAAIgogB0dqIQUgCiAHRg0CIAUgCUECai0AACIKIAdHaiEFIAogB0YNAiAFIAlBA2otAAAiCiAHR2ohBSAIQXxqIQggCUEEaiEJIAogB0cNAAwCCwtBACEHIAFB/wFxIQYDQCAIRQ0CIAkgB2ohCiAIQX9qIQggB0EBaiEHIAotAAAiCiAGRw0ACyAKIAFB/wFxRkEBakEBcSAFaiAHakF/aiEFC0EBIQkMAQsgAUH/AXEhBwJAAkAgA0EISQ0AIAQgA0F4aiIKSw0AIAdBgYKECGwhBQJAA0AgAiAEaiIJQQRqKAIAIAVzIghBf3MgCEH//ft3anEgCSgCACAFcyIJQX9zIAlB//37d2pxckGAgYKEeHENASAEQQhqIgQgCk0NAAsLIAQgA0sNAQsgAiAEaiEJIAIgA2ohAiADIARrIQhBACEFAkACQAJAA0AgAiAJa0EDTQ0BIAUgCS0AACIKIAdHaiEFIAogB0YNAiAFIAlBAWotAAAiCiAHR2ohBSAKIAdGDQIgBSAJQQJqLQAAIgogB0dqIQUgCiAHRg0CIAUgCUEDai0AACIKIAdHaiEFIAhBfGohCCAJQQRqIQkgCiAHRw0ADAILC0EAIQcgAUH/AXEhAgNAIAhFDQIgCSAHaiEKIAhBf2ohCCAHQQFqIQcgCi0AACIKIAJHDQALIAogAUH/AXFGQQFqQQFxIAVqIAdqQX9qIQULQQEhCSAFIARqIQUMAgtBACEJIAUgB2ogBGohBQwBCyAEIAMQMQALIAAgBTYCBCAAIAk2AgAL5gQBCH8CQAJAAkACQCACKAIAIgVFDQAgAUF/aiEGIABBAnQhB0EAIAFrIQggBEEQaiEJA0AgBUEIaiEEAkAgBSgCCCIKQQFxRQ0AA0AgBCAKQX5xNgIAAkACQCAFKAIEIgpBfHEiBEUNAEEAIAQgBC0AAEEBcRshAQwBC0EAIQELAkAgBSgCACILQXxxIgxFDQAgC0ECcQ0AIAwgDCgCBEEDcSAEcjYCBCAFQQRqKAIAIgpBfHEhBAsCQCAERQ0AIAQgBCgCAEEDcSAFKAIAQXxxcjYCACAFQQRqKAIAIQoLIAVBBGogCkEDcTYCACAFIAUoAgAiBEEDcTYCAAJAIARBAnFFDQAgASABKAIAQQJyNgIACyACIAE2AgAgAUEIaiEEIAEhBSABKAIIIgpBAXENAAsgASEFCwJAIAUoAgBBfHEiASAEayAHSQ0AAkAgBCADIAAgCSgCABEBAEECdGpBCGogASAHayAIcSIBTQ0AIAYgBHENAQwECyABQQA2AgAgAUF4aiIBQgA3AgAgASAFKAIAQXxxNgIAAkAgBSgCACIMQXxxIgpFDQAgDEECcQ0AIAogCigCBEEDcSABcjYCBAsgASABKAIEQQNxIAVyNgIEIAUgBSgCAEEDcSABcjYCACAEIAQoAgBBfnE2AgACQCAFKAIAIgRBAnFFDQAgBSAEQX1xNgIAIAEgASgCAEECcjYCAAsgASABKAIAQQFyNgIAIAENBAsgAiAFKAIIIgU2AgAgBQ0ACwtBAA8LIAIgBCgCAEF8cTYCACAFIAUoAgBBAXI2AgAMAQsgASEFCyAFQQhqC80EAQ1/IwBBMGsiAyQAAkACQAJAAkACQCACRQ0AIANBKGohBCAAQQhqIQUgA0EgaiEGIANBHGohByADQSRqIQggAEEEaiEJA0ACQCAFLQAARQ0AIAAoAgBB4MvAAEEEIAkoAgAoAgwRAAANAwsgBEEKNgIAIAZCioCAgBA3AwAgByACNgIAIANBEGpBCGoiCkEANgIAIAMgAjYCFCADIAE2AhAgA0EIakEKIAEgAhANAkACQAJAAkACQCADKAIIQQFHDQAgAygCDCELA0AgCiALIAooAgBqQQFqIgs2AgACQAJAIAsgCCgCACIMTw0AIAMoAhQhDQwBCyADKAIUIg0gC0kNACAMQQVPDQUgAygCECALIAxrIg5qIg8gBEYNBCAPIAQgDBA6RQ0ECyAHKAIAIg8gC0kNAiANIA9JDQIgAyADQRBqIAxqQRdqLQAAIAMoAhAgC2ogDyALaxANIAMoAgQhCyADKAIAQQFGDQALCyAKIAcoAgA2AgALIAVBADoAACACIQsMAgsgBUEBOgAAIA5BAWohCwwBCyAMQQQQMAALIAkoAgAhDyAAKAIAIQwCQCALRSACIAtGciIKDQAgAiALTQ0FIAEgC2osAABBv39MDQULIAwgASALIA8oAgwRAAANAgJAAkAgCkUNACABIAtqIQ8MAQsgAiALTQ0GIAEgC2oiDywAAEG/f0wNBgsgDyEBIAIgC2siAg0ACwtBACELDAELQQEhCwsgA0EwaiQAIAsPCyABIAJBACALEAoACyABIAIgCyACEAoAC48FAgN/AX5BAiECAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAUF3aiIDQR5LDQBB9AAhBAJAIAMOHwsAAgIDAgICAgICAgICAgICAgICAgICAgIHAgICAgcLC0HuACEEDAMLIAFB3ABGDQULIAFBgBBPDQIgAUEDdkH4////AXFB2I/AAGohBAwDC0HyACEECwwGCwJAIAFBgIAETw0AIAFBBnZBYGoiBEHgB08NByAEQfCRwABqLQAAIgRByQBLDQggBEEDdEHgvsAAaiEEDAELIAFBDHZBcGoiBEGAAk8NCCAEQdCZwABqLQAAQQZ0IAFBBnZBP3FyIgRB/wNLDQkgBEGww8AAai0AACIEQTZLDQogBEEDdEGwx8AAaiEECwJAIAQpAwBCASABQT9xrYaDUA0AIAFBAXJnQQJ2QQdzrUKAgICA0ACEIQUMAwsCQAJAIAFB//8DSw0AIAFBqLTAAEEoQfi0wABBrwJBp7fAAEG8AhAURQ0DDAELAkAgAUH//wdLDQAgAUHjucAAQSFBpbrAAEGeAUHDu8AAQf0CEBQNAQwDCyABQe+DOEsNAiABQeKLdGpB4o0sSQ0CIAFBn6h0akGfGEkNAiABQd7idGpBDkkNAiABQf7//wBxQZ7wCkYNAiABQamydWpBKUkNAiABQcuRdWpBCk0NAgtBASECCwwCCyABQQFyZ0ECdkEHc61CgICAgNAAhCEFC0EDIQILIAEhBAsgACAENgIEIAAgAjYCACAAQQhqIAU3AgAPC0HQm8AAIARB4AcQLwALQeCbwAAgBEHKABAvAAtB8JvAACAEQYACEC8AC0GAnMAAIARBgAQQLwALQZCcwAAgBEE3EC8AC+8DAQN/IwBB4ABrIgIkACABKAIYQfrLwABBDSABQRxqKAIAKAIMEQAAIQMgAkEENgIMIAJB8cvAADYCCAJAAkACQAJAIAMNACACQQI2AhQgAkHky8AANgIQAkAgASgCACIDQQRxDQAgAkHcAGpBCTYCACACQQk2AlQgAUEYaigCACEDIAFBHGooAgAhBCACIAJBCGo2AlggAiACQRBqNgJQIAJBLGpBAjYCACACQgM3AhwgAkG4nMAANgIYIAIgAkHQAGo2AiggAyAEIAJBGGoQCQ0BQQEhAyAALQAAIAEQKA0EDAMLIAJBNGpBoJzAADYCACACQQA6AFggAiADNgIYIAIgAUEYaikCADcDUCACIAEtADA6AEggAiABKAIENgIcIAIgASkCCDcDICACIAEpAig3A0AgAiABKQIgNwM4IAIgASkCEDcDKCACIAJB0ABqNgIwIAJB0ABqQeTLwABBAhAPDQAgAkHQAGpB58vAAEEBEA8NACACQdAAaiACKAIIIAIoAgwQDw0AIAJB0ABqQejLwABBAhAPRQ0BC0EBIQMMAgtBASEDIAAtAAAgAkEYahAoDQELIAFBGGooAgBB7cvAAEHry8AAIAEoAgBBBHEbQQIgAUEcaigCACgCDBEAACEDCyACQeAAaiQAIAMLywMBCn8gASACaiEDQQAhBEEAIQUCQANAIAUgAE8NAQJAAkACQAJAIAVB/wFvIgZB/wFqIAYgBkEASBsiBkGABE8NACACRQ0BIAVBAWohBSAGQcCjwABqLQAAIQcgAS0AACEIQQEhCSABIQZBAUUNAkEFIQoMAwtBoIvAACAGQYAEEC8AC0Gwi8AAQQBBABAvAAtBByEKCwNAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAoOCQEEBQYHAAgCAwMLIAMgBmsgCU0NDEEAIQoMEQsgBiAJaiILQQFqIQYgCw0KDAkLIAMgBkYNDkEIIQoMDwsgBiELIAZBAWohBiALRQ0KQQEhCgwOC0EAIQlBACEMIAdFDQpBAiEKDA0LQQAhDCAIQf8BcSIIRQ0KQQMhCgwMCyAHQcCnwABqLQAAIAhBwKfAAGotAABqQcCjwABqLQAAIQxBBCEKDAsLIAstAAAgDHMhCEEADQIMAQsgCEH/AXFFDQpBASEEDAsLQQchCgwIC0EFIQoMBwtBBiEKDAYLQQEhCgwFC0EGIQoMBAtBBiEKDAMLQQQhCgwCC0EEIQoMAQtBBiEKDAALCwsgBAvuAgEGfyMAQTBrIgIkACABKAIAIQMCQAJAIAEoAgQiBEEDdCIFRQ0AIANBBGohBkEAIQcDQCAGKAIAIAdqIQcgBkEIaiEGIAVBeGoiBQ0ADAILC0EAIQcLAkACQAJAAkACQCABQRRqKAIARQ0AIARFDQMCQAJAIAdBD0sNACADKAIERQ0BCyAHIAdqIgYgB08NAgtBASEFQQAhBiACQQhqIQcMAgsgByEGCyAGQX9MDQIgAkEIaiEHAkAgBkUNACAGQQEQVSIFDQEAC0EBIQVBACEGCyACQQA2AhAgAiAGNgIMIAIgBTYCCCACIAJBCGo2AhQgAkEYakEQaiABQRBqKQIANwMAIAJBGGpBCGogAUEIaikCADcDACACIAEpAgA3AxgCQCACQRRqQeiMwAAgAkEYahAJDQAgACAHKQIANwIAIABBCGogB0EIaigCADYCACACQTBqJAAPCxAuAAtB2IzAAEEAQQAQLwALEFwAC94CAQZ/IAEgAkEBdGohByAAQYD+A3FBCHYhCEEAIQkgAEH/AXEhCgJAAkACQAJAAkACQANAIAFBAmohCyAJIAEtAAEiAmohDAJAAkAgAS0AACIBIAhHDQAgDCAJSQ0GIAwgBEsNByADIAlqIQEDQCACRQ0CIAJBf2ohAiABLQAAIQkgAUEBaiEBIAkgCkcNAAwFCwsgASAISw0CIAwhCSALIQEgCyAHRw0BDAILIAwhCSALIQEgCyAHRw0ACwsgAEH//wNxIQkgBSAGaiEMQQEhAgNAIAVBAWohCgJAAkAgBS0AACIBQRh0QRh1IgtBf0wNACAKIQUMAQsgCiAMRg0GIAtB/wBxQQh0IAVBAWotAAByIQEgBUECaiEFCyAJIAFrIglBAEgNAiACQQFzIQIgBSAMRw0ADAILC0EAIQILIAJBAXEPCyAJIAwQMQALIAwgBBAwAAtB2I7AABA3AAvxAgEJfyMAQRBrIgIkAEEBIQMCQCABKAIYQScgAUEcaiIEKAIAKAIQEQEADQAgAiAAKAIAEBAgAkEMai0AACEFIAJBCGooAgAhBiACKAIAIQMgAigCBCIHQYCAxABHIQggAUEYaiEJA0ACQAJAAkACQAJAAkACQCADQQFGDQBB3AAhACADQQJGDQEgA0EDRw0FIAVB/wFxIQNBBCEFIANBf2oiCkEESw0FQQMhAwJAIAoOBQADBAUHAAtBACEFQf0AIQAMBgtBACEDIAchACAIDQUMBAtBASEDDAQLIAcgBkECdEEccXZBD3EiAEEwciAAQdcAaiAAQQpJGyEAQQJBASAGGyEFIAZBf2pBACAGGyEGDAMLQQIhBUH7ACEADAILQQMhA0H1ACEAQQMhBQwBCyABQRhqKAIAQScgAUEcaigCACgCEBEBACEDDAILIAkoAgAgACAEKAIAKAIQEQEARQ0AC0EBIQMLIAJBEGokACADC9MCAQJ/IwBBEGsiAiQAIAAoAgAhAAJAAkAgAUGAAU8NAAJAIAAoAggiAyAAKAIERw0AIABBARAmIABBCGooAgAhAwsgACgCACADaiABOgAAIABBCGoiACAAKAIAQQFqNgIADAELIAJBADYCDAJAAkAgAUGAEE8NACACIAFBP3FBgAFyOgANIAIgAUEGdkEfcUHAAXI6AAxBAiEBDAELAkAgAUH//wNLDQAgAiABQT9xQYABcjoADiACIAFBBnZBP3FBgAFyOgANIAIgAUEMdkEPcUHgAXI6AAxBAyEBDAELIAIgAUE/cUGAAXI6AA8gAiABQRJ2QfABcjoADCACIAFBBnZBP3FBgAFyOgAOIAIgAUEMdkE/cUGAAXI6AA1BBCEBCyAAIAEQJiAAIAAoAggiAyABajYCCCADIAAoAgBqIAJBDGogARA+GgsgAkEQaiQAQQALyQIBBX8jAEGQBGsiBSQAIAVBiAJqQQBBgQIQRBogBSACIAQgBCACSRsiBjYCACAFQQRyIAVBiAJqQYQCED4aAkACQAJAAkAgAkUNAAJAIAZBgAJLDQAgAkF/aiEHIAYgAmshCEEBIQkDQCAIIAZPDQMgBSAIakEEaiABLQAAOgAAIAdFDQIgB0F/aiEHIAFBAWohASAJIAJrIAUoAgAiBmohCCAJQQFqIQkgBkGAAk0NAAsLIAZBgAIQMAALAkAgBEUNAEEAIARrIQYgBUEEciEHA0AgBSgCACIIQYECTw0DIAYgCGoiASAGTw0EIAcgAWoiCCAILQAAIAMtAABzOgAAIANBAWohAyAGQQFqIgYNAAsLIAAgBUGIAhA+GiAFQZAEaiQADwtBuIrAACAIIAYQLwALIAhBgAIQMAALQbiKwAAgASAIEC8AC/cCAQN/IwBB4ABrIgIkACACIAEoAhhB96HAAEEJIAFBHGooAgAoAgwRAAAiAzoAECACIAE2AgggAkEAOgARIAJBADYCDCACIAA2AhRBASEAAkAgAw0AAkAgASgCACIAQQRxDQBBASEAIAFBGGoiAygCAEHvy8AAQQEgAUEcaiIEKAIAKAIMEQAADQEgAygCAEHIsMAAQQAgBCgCACgCDBEAAA0BIAJBFGogARAMIQAMAQsgAkHEAGpBoJzAADYCACACQQA6ACAgAiAANgIoIAIgAUEYaikCADcDGCACIAEtADA6AFggAiABKAIENgIsIAIgASkCCDcDMCACIAEpAig3A1AgAiABKQIgNwNIIAIgASkCEDcDOCACIAJBGGo2AkBBASEAIAJBGGpB78vAAEEBEA8NACACQRhqQefLwABBARAPDQAgAkEUaiACQShqEAwhAAsgAkEQaiAAOgAAIAJBATYCDCACQQhqECEhASACQeAAaiQAIAELyQIBBn8jAEEwayICJABBJyEDAkACQCAAKAIAIgBBkM4ASQ0AQSchAwNAIAJBCWogA2oiBEF8aiAAIABBkM4AbiIFQfCxf2xqIgZB5ABuIgdBAXRB+rDAAGovAAA7AAAgBEF+aiAGIAdBnH9sakEBdEH6sMAAai8AADsAACADQXxqIQMgAEH/wdcvSyEEIAUhACAEDQAMAgsLIAAhBQsCQAJAIAVB5ABIDQAgAkEJaiADQX5qIgNqIAVB//8DcUHkAG4iAEGcf2wgBWpB//8DcUEBdEH6sMAAai8AADsAAAwBCyAFIQALAkACQCAAQQlKDQAgAkEJaiADQX9qIgNqIgUgAEEwajoAAAwBCyACQQlqIANBfmoiA2oiBSAAQQF0QfqwwABqLwAAOwAACyABQciwwABBACAFQScgA2sQByEAIAJBMGokACAAC7gCAQl/AkAgAkUNACABIAJqIgZBf2ohB0EAIQhBACEJIAEhCgNAIAohCwJAAkADQCALLQAAIgxBv39qIQ0gCSAESQ0BIAtBAWohDgJAIA1B/wFxQRlLDQAgCyAMQSBzOgAACyAOIQsgBiAORw0ADAILCyAMQZ9/aiEOAkACQCANQf8BcSINQRpJDQAgDkH/AXFBGUsNAQsCQAJAIAMgCWotAABBMUcNACAOQf8BcUEZSw0BIAogDEEgczoAAAwBCyANQRlLDQAgCiAMQSBzOgAACyAIQQFqIQggCUEBaiEJCyALQQFqIQogByALRw0BCwsgCCAFTw0AIAJFDQADQAJAIAEtAAAiC0G/f2pB/wFxQRlLDQAgASALQSBzOgAACyABQQFqIQEgAkF/aiICDQALCyAAQQA2AgALtwIBAn8gACgCACIEQQA2AgAgBEF4aiIAIAAoAgBBfnE2AgACQAJAAkACQCACIAMoAhQRAwBFDQACQCAEQXxqIgMoAgBBfHEiAkUNACACKAIAIgVBAXENACAAKAIAIgFBfHEiBEUNAiABQQJxDQIgBCAEKAIEQQNxIAJyNgIEIAMoAgAiBEF8cSIBRQ0EIAAoAgBBfHEhBCABKAIAIQUMAwsgACgCACICQXxxIgNFDQAgAkECcQ0AIAMtAABBAXENACAEIAMoAghBfHE2AgAgAyAAQQFyNgIIDwsgBCABKAIANgIAIAEgADYCAA8LIAIhAQsgASAFQQNxIARyNgIAIAMoAgAhBAsgAyAEQQNxNgIAIAAgACgCACIEQQNxNgIAAkAgBEECcUUNACACIAIoAgBBAnI2AgALC68CAQN/IwBBgAFrIgIkACAAKAIAIQACQAJAAkACQAJAIAEoAgAiA0EQcQ0AIANBIHENASAAIAEQGSEADAILIAAoAgAhA0EAIQADQCACIABqQf8AaiADQQ9xIgRBMHIgBEHXAGogBEEKSRs6AAAgAEF/aiEAIANBBHYiAw0ACyAAQYABaiIDQYEBTw0CIAFBprPAAEECIAIgAGpBgAFqQQAgAGsQByEADAELIAAoAgAhA0EAIQADQCACIABqQf8AaiADQQ9xIgRBMHIgBEE3aiAEQQpJGzoAACAAQX9qIQAgA0EEdiIDDQALIABBgAFqIgNBgQFPDQIgAUGms8AAQQIgAiAAakGAAWpBACAAaxAHIQALIAJBgAFqJAAgAA8LIANBgAEQMQALIANBgAEQMQALqAIBA38jAEGAAWsiAiQAAkACQAJAAkACQCABKAIAIgNBEHENACADQSBxDQEgACABEBkhAAwCCyAAKAIAIQNBACEAA0AgAiAAakH/AGogA0EPcSIEQTByIARB1wBqIARBCkkbOgAAIABBf2ohACADQQR2IgMNAAsgAEGAAWoiA0GBAU8NAiABQaazwABBAiACIABqQYABakEAIABrEAchAAwBCyAAKAIAIQNBACEAA0AgAiAAakH/AGogA0EPcSIEQTByIARBN2ogBEEKSRs6AAAgAEF/aiEAIANBBHYiAw0ACyAAQYABaiIDQYEBTw0CIAFBprPAAEECIAIgAGpBgAFqQQAgAGsQByEACyACQYABaiQAIAAPCyADQYABEDEACyADQYABEDEAC/kBAQF/IwBBEGsiAiQAIAJBADYCDAJAAkAgAUH/AEsNACACIAE6AAxBASEBDAELAkAgAUH/D0sNACACIAFBP3FBgAFyOgANIAIgAUEGdkEfcUHAAXI6AAxBAiEBDAELAkAgAUH//wNLDQAgAiABQT9xQYABcjoADiACIAFBBnZBP3FBgAFyOgANIAIgAUEMdkEPcUHgAXI6AAxBAyEBDAELIAIgAUE/cUGAAXI6AA8gAiABQRJ2QfABcjoADCACIAFBBnZBP3FBgAFyOgAOIAIgAUEMdkE/cUGAAXI6AA1BBCEBCyAAIAJBDGogARAPIQEgAkEQaiQAIAEL8wEBBH8jAEEgayIDJAACQAJAIABFDQAgACgCACIEQX9GDQFBASEFIAAgBEEBajYCACADIABBBGogASACEAQCQAJAIAMoAgBBAUcNAAJAIANBCGooAgAiBUUNACADKAIEIAVBARBPC0EBIQUMAQsgA0EIaigCACEBIANBFGooAgAhAiADQRBqKAIAIQYgAygCBCEEAkAgA0EYaigCAA0AIABBuAJqKAIAIAQgA0EMaigCABASIQULAkAgAkUNACAGIAJBARBPCyABRQ0AIAQgAUEBEE8LIAAgACgCAEF/ajYCACADQSBqJAAgBQ8LEFAACxBRAAvnAQEBfyMAQeAAayIEJAAgBCABNgIIIAQgAzYCDCAEIARBCGo2AhAgBCAEQQxqNgIUAkAgASADRw0AIAAgAiABED4aIARB4ABqJAAPCyAEQcgAakEUakEANgIAIARBMGpBFGpBBTYCACAEQTxqQQY2AgAgBEEYakEUakEDNgIAIARByLDAADYCWCAEQgE3AkwgBEHwisAANgJIIARBBjYCNCAEQgM3AhwgBEHYisAANgIYIAQgBEHIAGo2AkAgBCAEQRRqNgI4IAQgBEEQajYCMCAEIARBMGo2AiggBEEYakH4isAAEDsAC9MBAQN/IAAtAAghAQJAIAAoAgQiAkUNACABQf8BcSEDQQEhAQJAIAMNAAJAIAAoAgAiAy0AAEEEcUUNAEEBIQEgAygCGEHny8AAQQEgA0EcaigCACgCDBEAAA0BIABBBGooAgAhAgsCQCACQQFHDQAgAC0ACUUNAEEBIQEgACgCACIDKAIYQebLwABBASADQRxqKAIAKAIMEQAADQELIAAoAgAiASgCGEHwy8AAQQEgAUEcaigCACgCDBEAACEBCyAAQQhqIAE6AAALIAFB/wFxQQBHC74BAQJ/IwBBEGsiAiQAIAFBASABGyEBAkAgAEUNACAAQQNqQQJ2IQACQCABQQRLDQAgAEF/aiIDQf8BSw0AIANBAnRBhIDAAGoiA0UNACACQYCAwAA2AgQgAiADKAIANgIMIAAgASACQQxqIAJBBGpB2IjAABAyIQEgAyACKAIMNgIADAELIAJBACgCgIBANgIIIAAgASACQQhqQciwwABB8IjAABAyIQFBACACKAIINgKAgEALIAJBEGokACABC7kBAQF/IwBBEGsiAyQAAkAgAEUNACADIAA2AgQgAUUNAAJAIAJBBEsNACABQQNqQQJ2QX9qIgBB/wFLDQAgAEECdEGEgMAAaiIARQ0AIANBgIDAADYCCCADIAAoAgA2AgwgA0EEaiADQQxqIANBCGpB2IjAABAbIAAgAygCDDYCAAwBCyADQQAoAoCAQDYCDCADQQRqIANBDGpByLDAAEHwiMAAEBtBACADKAIMNgKAgEALIANBEGokAAuUAQECfyMAQRBrIgQkACAEIAEoAgAiASgCADYCDCACQQJqIgIgAmwiAkGAECACQYAQSxsiBUEEIARBDGpByLDAAEGIicAAEDIhAiABIAQoAgw2AgACQAJAIAJFDQAgAkIANwIEIAIgAiAFQQJ0akECcjYCAEEAIQEMAQtBASEBCyAAIAI2AgQgACABNgIAIARBEGokAAuDAQECfyMAQYABayICJAAgAC0AACEDQQAhAANAIAIgAGpB/wBqIANBAXFBMHI6AAAgAEF/aiEAIANBAXZB/wBxIgMNAAsCQCAAQYABaiIDQYEBTw0AIAFBpMzAAEECIAIgAGpBgAFqQQAgAGsQByEAIAJBgAFqJAAgAA8LIANBgAEQMQALgQEBAn8CQCAAKAIEIgIgACgCCCIDayABTw0AAkACQCADIAFqIgEgA0kNACACQQF0IgMgASABIANJGyIBQQBIDQACQAJAIAJFDQAgACgCACACQQEgARBNIgJFDQEMAwsgAUEBEFUiAg0CCwALEFcACyAAIAI2AgAgAEEEaiABNgIACwt8AQF/AkAgACgCBCIDIAFrIAJPDQACQAJAIAEgAmoiAiABSQ0AIANBAXQiASACIAIgAUkbIgFBAEgNAAJAAkAgA0UNACAAKAIAIANBASABEE0iAkUNAQwDCyABQQEQVSICDQILAAsQVwALIAAgAjYCACAAQQRqIAE2AgALC5EBAAJAAkACQCAAQf8BcSIAQQFGDQAgAEECRg0BIABBA0cNAiABKAIYQZvMwABBCSABQRxqKAIAKAIMEQAADwsgASgCGEGHzMAAQQwgAUEcaigCACgCDBEAAA8LIAEoAhhBk8zAAEEIIAFBHGooAgAoAgwRAAAPCyABKAIYQfXLwABBBSABQRxqKAIAKAIMEQAAC3sBAX8jAEEwayICJAAgAkEUakEPNgIAIAJBDzYCDCACIAA2AgggAiAAQQRqNgIQIAFBHGooAgAhACABKAIYIQEgAkEsakECNgIAIAJCAjcCHCACQaiPwAA2AhggAiACQQhqNgIoIAEgACACQRhqEAkhASACQTBqJAAgAQt5AQN/IAEoAgAhAgJAAkACQCABKAIEIgMgASgCCCIBRw0AIAIhBCADIQEMAQsgAyABSQ0BAkAgAUUNACACIANBASABEE0iBA0BAAtBACEBQQEhBCADRQ0AIAIgA0EBEE8LIAAgATYCBCAAIAQ2AgAPC0HonMAAEDcAC3IBAX9BACEEAkACQEEAIAJBAnQiAiADQQN0QYCAAWoiAyADIAJJG0GHgARqIgJBEHZAACIDQRB0IANBf0YbIgNFDQAgA0IANwIEIAMgAyACQYCAfHFqQQJyNgIADAELQQEhBAsgACADNgIEIAAgBDYCAAtxAQF/AkACQCABIABPDQAgAkUNASABQX9qIQEgAEF/aiEDA0AgAyACaiABIAJqLQAAOgAAIAJBf2oiAg0ADAILCyACRQ0AIAAhAwNAIAMgAS0AADoAACABQQFqIQEgA0EBaiEDIAJBf2oiAg0ACwsgAAtoAQF/IwBBgAVrIgEkAAJAAkAgAEUNACAAKAIADQEgAEEANgIAIAFBwAJqIABBvAIQPhogAUEIaiABQcACakEEckG4AhA+GiAAQbwCQQQQTyABQQhqED0gAUGABWokAA8LEFAACxBRAAt2AQF/IwBBwABrIgAkACAAQTM2AgwgAEG0r8AANgIIIABBNGpBDTYCACAAQSRqQQI2AgAgAEEONgIsIABCAjcCFCAAQYCNwAA2AhAgACAAQThqNgIwIAAgAEEIajYCKCAAIABBKGo2AiAgAEEQakGQjcAAEDsAC2wBAX8jAEEwayIDJAAgAyACNgIEIAMgATYCACADQSxqQQM2AgAgA0EcakECNgIAIANBAzYCJCADQgI3AgwgA0GgjcAANgIIIAMgAzYCKCADIANBBGo2AiAgAyADQSBqNgIYIANBCGogABA7AAtvAQF/IwBBMGsiAiQAIAIgATYCBCACIAA2AgAgAkEsakEDNgIAIAJBHGpBAjYCACACQQM2AiQgAkICNwIMIAJBwI3AADYCCCACIAJBBGo2AiggAiACNgIgIAIgAkEgajYCGCACQQhqQdCNwAAQOwALbwEBfyMAQTBrIgIkACACIAE2AgQgAiAANgIAIAJBLGpBAzYCACACQRxqQQI2AgAgAkEDNgIkIAJCAjcCDCACQeCNwAA2AgggAiACQQRqNgIoIAIgAjYCICACIAJBIGo2AhggAkEIakHwjcAAEDsAC2sBAn8jAEEQayIFJAACQCAAIAEgAiADIAQQDiIGDQAgBUEIaiADIAAgASAEKAIMEQIAQQAhBiAFKAIIDQAgBSgCDCIGIAIoAgA2AgggAiAGNgIAIAAgASACIAMgBBAOIQYLIAVBEGokACAGC2QBAn8jAEEgayICJAAgAUEcaigCACEDIAEoAhghASACQQhqQRBqIABBEGopAgA3AwAgAkEIakEIaiAAQQhqKQIANwMAIAIgACkCADcDCCABIAMgAkEIahAJIQAgAkEgaiQAIAALYwEBfyMAQSBrIgIkACACIAAoAgA2AgQgAkEIakEQaiABQRBqKQIANwMAIAJBCGpBCGogAUEIaikCADcDACACIAEpAgA3AwggAkEEakHojMAAIAJBCGoQCSEBIAJBIGokACABC2MBAX8jAEEgayICJAAgAiAAKAIANgIEIAJBCGpBEGogAUEQaikCADcDACACQQhqQQhqIAFBCGopAgA3AwAgAiABKQIANwMIIAJBBGpB0JzAACACQQhqEAkhASACQSBqJAAgAQtgAQF/IwBBIGsiAiQAIAIgADYCBCACQQhqQRBqIAFBEGopAgA3AwAgAkEIakEIaiABQQhqKQIANwMAIAIgASkCADcDCCACQQRqQdCcwAAgAkEIahAJIQEgAkEgaiQAIAELaAIBfwN+IwBBMGsiASQAIAApAgghAiAAKQIQIQMgACkCACEEIAFBFGpBADYCACABIAQ3AxggAUHIsMAANgIQIAFCATcCBCABIAFBGGo2AgAgASADNwMoIAEgAjcDICABIAFBIGoQOwALawECf0EBIQECQAJAAkACQEEAKALIiEBBAUcNAEEAQQAoAsyIQEEBaiIBNgLMiEAgAUEDSQ0BDAILQQBCgYCAgBA3A8iIQAtBACgC0IhAIgJBf0wNAEEAIAI2AtCIQCABQQJJDQELAAsQYgALUAEBfyMAQRBrIgIkACACIAEoAhhBlq/AAEENIAFBHGooAgAoAgwRAAA6AAggAiABNgIAIAJBADoACSACQQA2AgQgAhAhIQEgAkEQaiQAIAELRAEDfwJAAkAgAkUNAEEAIQMDQCAAIANqLQAAIgQgASADai0AACIFRw0CIANBAWoiAyACSQ0AC0EADwtBAA8LIAQgBWsLSgIBfwF+IwBBIGsiAiQAIAEpAgAhAyACQRRqIAEpAgg3AgAgAiADNwIMIAIgADYCCCACQbCNwAA2AgQgAkHIsMAANgIAIAIQWAALOgEBfwJAIAFBf0wNAAJAAkAgAUUNACABQQEQVSICDQEAC0EBIQILIAAgATYCBCAAIAI2AgAPCxBbAAs6AQF/AkAgAEEQaigCACIBRQ0AIAAoAgwgAUEBEE8LAkAgAEEcaigCACIBRQ0AIAAoAhggAUEBEE8LCzYBAX8CQCACRQ0AIAAhAwNAIAMgAS0AADoAACADQQFqIQMgAUEBaiEBIAJBf2oiAg0ACwsgAAssAAJAIABBfEsNAAJAIABFDQAgACAAQX1JQQJ0EFUiAEUNASAADwtBBA8LAAszAQF/IAAgACgCCCACIAFrIgIQJyAAIAAoAggiAyACajYCCCADIAAoAgBqIAIgASACECALMQEBfyMAQRBrIgEkACAAKAIIEEgaIAEgAEEUaikCADcDCCABIAApAgw3AwAgARA4AAstAQF/AkAgAyACECIiBEUNACAEIAAgAyABIAEgA0sbED4aIAAgASACECMLIAQLLwEBfyAAKAIAIgAgAhAmIAAgACgCCCIDIAJqNgIIIAMgACgCAGogASACED4aQQALLAEBfwJAIAJFDQAgACEDA0AgAyABOgAAIANBAWohAyACQX9qIgINAAsLIAALHwACQCABQXxLDQAgACABQQQgAhBNIgFFDQAgAQ8LAAsmAQF/IAAoAgAiASgCACABKAIEIAAoAgQoAgAgACgCCCgCABAKAAscACABKAIYQabMwABBBSABQRxqKAIAKAIMEQAACxUAAkAgAEUNACAADwtB2I7AABA3AAsSAAJAIAFFDQAgACABQQQQTwsLEAAgASAAKAIAIAAoAgQQCAsQACABIAAoAgAgACgCBBAICxAAIAEgACgCACAAKAIEEAgLDAAgACABIAIgAxBCCw0AIAAoAgAgASACEA8LCgAgACABIAIQVAsMAEGAncAAQRsQUgALDQBBm53AAEHPABBSAAsJACAAIAEQAQALCwAgACgCACABEB4LCgAgACABIAIQIwsIACAAIAEQWgsHACAAEAIACwoAQcCMwAAQNwALBwAgABBBAAsIACAAIAEQGQsIACAAIAEQIgsFABBXAAsFABBXAAsFAEGABAsEAEEBCwQAIAELBABBAAsHAEGIiMAACwMAAAsMAEK25NPM4tPkyAALAgALAgALAgALAgALAgALAgALAgALC/7MgIAAAwBBgIDAAAvUCAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQdiIwAALqBQQAAAABAAAAAQAAAARAAAAEgAAABMAAAAUAAAAAAAAAAEAAAAVAAAAFgAAABcAAAAYAAAAAAAAAAEAAAAVAAAAFgAAABcAAAB9DxAADwAAAKUZEAABAAAA0g8QAAsAAADIDxAACgAAALAPEAAYAAAAyA8QAAoAAAA4EBAACQAAAMgPEAAKAAAA8A8QAEgAAADuCQAADgAAAFAQEABIAAAA6AkAAAoAAABIGBAAAAAAAAAREABfAAAAFAAAABoAAAAAERAAXwAAACAAAAASAAAAYBEQAGAAAAA5AAAARwAAAMAUEABfAAAAIAAAABIAAADAFBAAXwAAABQAAAAaAAAAHxUQAC0AAABMFRAADAAAAFgVEAADAAAAWxUQADQAAABCGRAAGAAAAK0HAAAJAAAAFRcQACEAAAA2FxAAYAAAAEUAAAAJAAAAUBYQAF8AAAAUAAAAGgAAALAWEABlAAAAdwAAABUAAABQFhAAXwAAACAAAAASAAAAkBUQAGAAAABQAQAADQAAAJAVEABgAAAAWAEAACQAAACQFRAAYAAAAFgBAAAzAAAA8BUQAGAAAAA2AAAACQAAAJAVEABgAAAAGgEAACIAAACQFRAAYAAAAM8AAAANAAAAkBUQAGAAAABJAAAAEQAAAKMXEAARAAAATyYQABcAAADrAgAABQAAAAAYEABGAAAAYwEAABMAAAAZAAAABAAAAAQAAAAaAAAAGwAAABwAAABIGBAAAAAAAOglEAACAAAA5xcQABUAAADlAwAABQAAAEgYEAAgAAAAaBgQABIAAAAdAAAAAAAAAAEAAAAeAAAAWhkQAAYAAABgGRAAIgAAAEIZEAAYAAAAagkAAAUAAACCGRAAFgAAAJgZEAANAAAAQhkQABgAAABwCQAABQAAAMMZEAALAAAAyiUQABYAAAClGRAAAQAAAK0ZEAAWAAAA6wcAAAkAAACoJRAADgAAALYlEAAEAAAAuiUQABAAAAClGRAAAQAAAK0ZEAAWAAAA7wcAAAUAAABoJRAAKwAAAJMlEAAVAAAAWQEAABUAAADDGRAACwAAAM4ZEAAmAAAA9BkQAAgAAAD8GRAABgAAAKUZEAABAAAArRkQABYAAAD8BwAABQAAAEgYEAAAAAAAJhoQAAIAAAAQGhAAFgAAAD8EAAARAAAAEBoQABYAAAAzBAAAKAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP//////////////////AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAPgDAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD+/////7+2AAAAAAAAAAAA/wcAAAAAAPj//wAAAQAAAAAAAAAAAAAAwJ+fPQAAAAACAAAA////BwAAAAAAAAAAAADA/wEAAAAAAAD4DyBgHxAASgAAALAhEAAAAgAAsCMQADcAAAAAAQIDBAUGBwgJCAoLDA0ODxAREhMUAhUWFxgZGhscHR4fIAICAgICAgICAgIhAgICAgICAgICAgICAgIiIyQlJgInAigCAgIpKisCLC0uLzACAjECAgIyAgICAgICAgIzAgI0AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI1AjYCNwICAgICAgICOAI5AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI6OzwCAgICPQICPj9AQUJDREVGAgICRwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJIAgICAgICAgICAgJJAgICAgI7AgABAgICAgMCAgICBAIFBgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIHAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgJAHxAAIAAAACcAAAAZAAAAQB8QACAAAAAoAAAAIAAAAEAfEAAgAAAAKgAAABkAAABAHxAAIAAAACsAAAAYAAAAQB8QACAAAAAsAAAAIAAAAB8AAAAMAAAABAAAACAAAAAhAAAAIgAAAEgYEAAAAAAA6iUQAAEAAADoJRAAAgAAACMAAAAEAAAABAAAACQAAAAlAAAAJgAAACsmEAAkAAAATyYQABcAAABBAgAACQAAAABBgJ3AAAvmL251bGwgcG9pbnRlciBwYXNzZWQgdG8gcnVzdHJlY3Vyc2l2ZSB1c2Ugb2YgYW4gb2JqZWN0IGRldGVjdGVkIHdoaWNoIHdvdWxkIGxlYWQgdG8gdW5zYWZlIGFsaWFzaW5nIGluIHJ1c3Sy/7T/tv+4/7r/vP++/////////////////////////////////////////////////////////////////////////////////////////////////////////4D/gv+E/4b/iP+K/4z/jv+Q/5L/lP///5b/mP+a/5z/nv+g/6L/pP+m/6j/qv+t/67/sP8xMDFpbnZhbGlkIGtpbmQ6IGABAAAAAAAAACAAAAAIAAAAAwAAAAAAAAAAAAAACAAAAAMAAABJbnRlcm5hbEdlbmVyYXRlZEJhZExlbjosRXhwZWN0ZWQ6QmFkRGF0YUxlbjpQcmVmaXhNaXNtYXRjaAAAAAAAL3J1c3RjLzE5Zjg5NThmODI3ZTM3OWJlOWIwZmZjOTNmY2E1ODBlYzhmZmFjNzcvc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzQmFkSWRMZW46AAAAAAAAAAAAAAAAAAAAL3J1c3RjLzE5Zjg5NThmODI3ZTM3OWJlOWIwZmZjOTNmY2E1ODBlYzhmZmFjNzcvc3JjL2xpYmNvcmUvc2xpY2UvbW9kLnJzQUJDREVGR0hJSktNTk9QUVJTVFVWV1hZWjM0NTY3ODliYWQgaW5wdXQZGhscHR4f/////////wABAgMEBQYHCAkK/wsMDQ4PEBESExQVFhcYdW5leHBlY3RlZCBlb2ZIY2lkRXJyb3IvaG9tZS9uZW9ucGhvZy8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9yZWVkLXNvbG9tb24tMC4yLjEvc3JjL21hY3Jvcy5ycwAvaG9tZS9uZW9ucGhvZy8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9yZWVkLXNvbG9tb24tMC4yLjEvc3JjL2VuY29kZXIucnMBAgQIECBAgB06dOjNhxMmTJgtWrR16smPAwYMGDBgwJ0nTpwlSpQ1atS1d+7BnyNGjAUKFChQoF26adK5b96hX75hwpkvXrxlyokPHjx48P3n07tr1rF//uHfo1u2ceLZr0OGESJEiA0aNGjQvWfOgR8+fPjtx5M7duzFlzNmzIUXLly4bdqpT54hQoQVKlSoTZopUqRVqkmSOXLk1bdz5tG/Y8aRP3785deze/bx/+Pbq0uWMWLElTdu3KVXrkGCGTJkyI0HDhw4cODdp1OmUaJZsnny+e/DmytWrEWKCRIkSJA9evT19/P768uLCxYsWLB9+unPgxs2bNitR44BAgQIECBAgB06dOjNhxMmTJgtWrR16smPAwYMGDBgwJ0nTpwlSpQ1atS1d+7BnyNGjAUKFChQoF26adK5b96hX75hwpkvXrxlyokPHjx48P3n07tr1rF//uHfo1u2ceLZr0OGESJEiA0aNGjQvWfOgR8+fPjtx5M7duzFlzNmzIUXLly4bdqpT54hQoQVKlSoTZopUqRVqkmSOXLk1bdz5tG/Y8aRP3785deze/bx/+Pbq0uWMWLElTdu3KVXrkGCGTJkyI0HDhw4cODdp1OmUaJZsnny+e/DmytWrEWKCRIkSJA9evT19/P768uLCxYsWLB9+unPgxs2bNitR44BAgAAARkCMhrGA98z7htox0sEZOAONI3vgRzBafjICExxBYplL+EkDyE1k47a8BKCRR21wn1qJ/m5yZoJeE3kcqYGv4tiZt0w/eKYJbMQkSKINtCUzo+W273x0hNcgzhGQB5CtqPDSH5uazooVPqFuj3KXpufChV5K07U5axz86dXB3DA94yAYw1nSt7tMcX+GOOlmXcmuLR8EUSS2SMgiS43P9FblbzPzZCHl7Lc/L5h8lbTqxQqXZ6EPDlTR21Boh8tQ9i3e6R2xBdJ7H8Mb/ZsoTtSKZ1VqvtghrG7zD5ay1lfsJypoFEL9RbrenUs10+u1enm563odNb06qhQWK8vaG9tZS9uZW9ucGhvZy8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9yZWVkLXNvbG9tb24tMC4yLjEvc3JjL21hY3Jvcy5yc2Fzc2VydGlvbiBmYWlsZWQ6IGAobGVmdCA9PSByaWdodClgCiAgbGVmdDogYGAsCiByaWdodDogYGA6IGRlc3RpbmF0aW9uIGFuZCBzb3VyY2Ugc2xpY2VzIGhhdmUgZGlmZmVyZW50IGxlbmd0aHMAL2hvbWUvbmVvbnBob2cvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvcmVlZC1zb2xvbW9uLTAuMi4xL3NyYy9kZWNvZGVyLnJzL2hvbWUvbmVvbnBob2cvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvcmVlZC1zb2xvbW9uLTAuMi4xL3NyYy9nZi9wb2x5LnJzL2hvbWUvbmVvbnBob2cvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvcmVlZC1zb2xvbW9uLTAuMi4xL3NyYy9tYWNyb3MucnMAL2hvbWUvbmVvbnBob2cvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9naXRodWIuY29tLTFlY2M2Mjk5ZGI5ZWM4MjMvcmVlZC1zb2xvbW9uLTAuMi4xL3NyYy9nZi9wb2x5X21hdGgucnNhc3NlcnRpb24gZmFpbGVkOiBtc2cubGVuKCkgPCAyNTYvaG9tZS9uZW9ucGhvZy8uY2FyZ28vcmVnaXN0cnkvc3JjL2dpdGh1Yi5jb20tMWVjYzYyOTlkYjllYzgyMy9yZWVkLXNvbG9tb24tMC4yLjEvc3JjL2RlY29kZXIucnNUb29NYW55RXJyb3JzY2FwYWNpdHkgb3ZlcmZsb3dhIGZvcm1hdHRpbmcgdHJhaXQgaW1wbGVtZW50YXRpb24gcmV0dXJuZWQgYW4gZXJyb3JzcmMvbGliY29yZS9yZXN1bHQucnMAAAAAL3J1c3RjLzE5Zjg5NThmODI3ZTM3OWJlOWIwZmZjOTNmY2E1ODBlYzhmZmFjNzcvc3JjL2xpYmNvcmUvZm10L21vZC5ycwAAaW5kZXggb3V0IG9mIGJvdW5kczogdGhlIGxlbiBpcyAgYnV0IHRoZSBpbmRleCBpcyAwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OXNyYy9saWJjb3JlL3NsaWNlL21vZC5yc2luZGV4ICBvdXQgb2YgcmFuZ2UgZm9yIHNsaWNlIG9mIGxlbmd0aCBzbGljZSBpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IGAweFsuLi5dc3JjL2xpYmNvcmUvc3RyL21vZC5yc2J5dGUgaW5kZXggIGlzIG5vdCBhIGNoYXIgYm91bmRhcnk7IGl0IGlzIGluc2lkZSAgKGJ5dGVzICkgb2YgYAAAAAAAAAAAAAAAAAAAc3JjL2xpYmNvcmUvZm10L21vZC5ycy4uAAEDBQUGBgMHBggICREKHAsZDBQNEg4WDwQQAxISEwkWARcFGAIZAxoHHAIdAR8WIAMrBiwCLQsuATADMQIyAqkCqgSrCPoC+wX9BP4D/wmteHmLjaIwV1iLjJAcHd0OD0tM+/wuLz9cXV+14oSNjpGSqbG6u8XGycre5OX/AAQREikxNDc6Oz1JSl2EjpKpsbS6u8bKzs/k5QAEDQ4REikxNDo7RUZJSl5kZYSRm53Jzs8NESlFSVdkZY2RqbS6u8XJ3+Tl8AQNEUVJZGWAgYSyvL6/1dfw8YOFhomLjJigpKaoqay6vr/Fx87P2ttImL3Nxs7PSU5PV1leX4mOj7G2t7/BxsfXERYXW1z29/7/gA1tcd7fDg8fbm8cHV99fq6vu7z6FhceH0ZHTk9YWlxefn+1xdTV3PDx9XJzj3R1lpfJ/y9fJi4vp6+3v8fP19+aQJeYMI8f/87/Tk9aWwcIDxAnL+7vbm83PT9CRZCR/v9TZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGsDoCrNR4VgOADGQgBBC8ENAQHAwEHBgcRClAPEgdVCAIEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBToDEQcGBRAIVgcCBxUNUARDAy0DAQQRBg8MOgQdJQ0GTCBtBGolgMgFgrADGgaC/QNZBxULFwkUDBQMagYKBhoGWQcrBUYKLAQMBAEDMQssBBoGCwOArAYKBh9BTAQtA3QIPAMPAzwHOAgqBoL/ERgILxEtAyAQIQ+AjASClxkLFYiUBS8FOwcCDhgJgK8xdAyA1hoMBYD/BYC2BSQMm8YK0jAQhI0DNwmBXBSAuAiAuj01BAoGOAhGCAwGdAseA1oEWQmAgxgcChYJRgqAigarpAwXBDGhBIHaJgcMBQWApRGBbRB4KCoGTASAjQSAvgMbAw8NAAYBAQMBBAIICAkCCgULAhABEQQSBRMRFAIVAhcCGgIcBR0IJAFqA2sCvALRAtQM1QnWAtcC2gHgBegC7iDwBPkEDCc7Pk5Pj56enwYHCTY9Plbz0NEEFBg2N1ZXvTXOz+ASh4mOngQNDhESKTE0OkVGSUpOT2RlWly2txschIUJN5CRqAcKOz5maY+Sb1/u71pimpsnKFWdoKGjpKeorbq8xAYLDBUdOj9FUaanzM2gBxkaIiXFxgQgIyUmKDM4OkhKTFBTVVZYWlxeYGNlZmtzeH1/iqSqr7DA0D9xcnteInsFAwQtA2UEAS8ugIIdAzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAsBgJCBNwkWCgiAmDkDYwgJMBYFIQMbBQFAOARLBS8ECgcJB0AgJwQMCTYDOgUaBwQMB1BJNzMNMwcuCAqBJh+AgSgIKoCmTgQeD0MOGQcKBkcJJwl1Cz9BKgY7BQoGUQYBBRADBYCLXyFICAqApl4iRQsKBg0TOAgKNiwEEIDAPGRTDAGBAEgIUx05gQdGCh0DR0k3Aw4ICgY5BwqBNhmBB4OaZnULgMSKvIQvj9GCR6G5gjkHKgQCYCYKRgooBROCsFtlRQsvEBFAAh6X8g6C86UNgR9RgYyJBGsFDQMJBxCTYID2CnMIbhdGgJoUDFcJGYCHgUcDhUIPFYVQK4fVgNcpSwUKBAKDEUSBSzwGAQRVBRs0AoEOLARkDFYKDQNcBD05HQ0sBAkHAg4GgJqD1QsNAwoGdAxZJwwEOAgKBigIHlIMBGcDKQ0KBgMNMGAOhZJzcmMvbGliY29yZS91bmljb2RlL2Jvb2xfdHJpZS5ycwAAwPvvPgAAAAAADgAAAAAAAAAAAAAAAAAA+P/7////BwAAAAAAABT+If4ADAAAAAIAAAAAAABQHiCAAAwAAEAGAAAAAAAAEIY5AgAAACMAviEAAAwAAPwCAAAAAAAA0B4gwAAMAAAABAAAAAAAAEABIIAAAAAAABEAAAAAAADAwT1gAAwAAAACAAAAAAAAkEQwYAAMAAAAAwAAAAAAAFgeIIAADAAAAACEXIAAAAAAAAAAAAAA8geAfwAAAAAAAAAAAAAAAPIbAD8AAAAAAAAAAAADAACgAgAAAAAAAP5/3+D//v///x9AAAAAAAAAAAAAAAAA4P1mAAAAwwEAHgBkIAAgAAAAAAAAAOAAAAAAAAAcAAAAHAAAAAwAAAAMAAAAAAAAALA/QP4PIAAAAAAAOAAAAAAAAGAAAAAAAgAAAAAAAIcBBA4AAIAJAAAAAAAAQH/lH/ifAAAAAAAA/38PAAAAAADQFwQAAAAA+A8AAwAAADw7AAAAAAAAQKMDAAAAAAAA8M8AAAD3//0hEAP/////////+wAQAAAAAAAAAAD/////AQAAAAAAAIADAAAAAAAAAACAAAAAAP////8AAAAAAPwAAAAAAAYAAAAAAAAAAACA9z8AAADAAAAAAAAAAAAAAAMARAgAAGAAAAAwAAAA//8DgAAAAADAPwAAgP8DAAAAAAAHAAAAAADIEwAAAAAgAAAAAAAAAAB+ZgAIEAAAAAAAEAAAAAAAAJ3BAgAAAAAwQAAAAAAAICEAAAAAAEAAAAAA//8AAP//AAAAAAAAAAAAAQAAAAIAAwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAABQAAAAAAAAAABgAAAAAAAAAABwAACAkKAAsMDQ4PAAAQERIAABMUFRYAABcYGRobABwAAAAdAAAAAAAAAB4fIAAAAAAAIQAiACMkJQAAAAAmAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAJygAAAAAAAAAAAAAAAAAAAAAACkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAqAAAAAAAAAAAAAAAAAAAAAAAAKywAAC0AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAuLzAAAAAAAAAAAAAAAAAAAAAAAAAAAAAxAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADIAMwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAANDUAADU1NTYAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAAAAAAAQAAAAAAAAAAAMAHbvAAAAAAAIcAAAAAYAAAAAAAAADwAAAAwP8BAAAAAAACAAAAAAAA/38AAAAAAACAAwAAAAAAeAYHAAAAgO8fAAAAAAAAAAgAAwAAAAAAwH8AHgAAAAAAAAAAAAAAgNNAAAAAgPgHAAADAAAAAAAAWAEAgADAHx8AAAAAAAAAAP9cAABAAAAAAAAAAAAAAPmlDQAAAAAAAAAAAAAAAIA8sAEAADAAAAAAAAAAAAAA+KcBAAAAAAAAAAAAAAAAKL8AAAAA4LwPAAAAAAAAAID/Bv4HAAAAAPh5gAB+DgAAAAAA/H8DAAAAAAAAAAAAAH+/AAD8///8bQAAAAAAAAB+tL8AAAAAAAAAAACjAAAAAAAAAAAAAAAYAAAAAAAAAB8AAAAAAAAAfwAAgAcAAAAAAAAAAGAAAAAAAAAAAKDDB/jnDwAAADwAABwAAAAAAAAA////////f/j//////x8gABAAAPj+/wAAf///+dsHAAAAAH8AAAAAAPAHAAAAAAAAAAAAAP///////////////////////wAAY2FsbGVkIGBPcHRpb246OnVud3JhcCgpYCBvbiBhIGBOb25lYCB2YWx1ZXNyYy9saWJjb3JlL29wdGlvbi5yc2JlZ2luIDw9IGVuZCAoIDw9ICkgd2hlbiBzbGljaW5nIGAgaXMgb3V0IG9mIGJvdW5kcyBvZiBgICAgICB7LAo6ICAgfQp9KClraW5kRW1wdHlQYXJzZUludEVycm9ySW52YWxpZERpZ2l0T3ZlcmZsb3dVbmRlcmZsb3cwYkVycm9yVHJpZWQgdG8gc2hyaW5rIHRvIGEgbGFyZ2VyIGNhcGFjaXR5c3JjL2xpYmFsbG9jL3Jhd192ZWMucnMA9ayAgAAEbmFtZQHqrICAAGsAFV9fd2JpbmRnZW5fc3RyaW5nX25ldwEQX193YmluZGdlbl90aHJvdwISX193YmluZGdlbl9yZXRocm93AzpyZWVkX3NvbG9tb246OmRlY29kZXI6OkRlY29kZXI6OmNvcnJlY3Q6OmhjNzRlMzQ3ZTliNGQxMTRkBDFoY2lkOjpIY2lkRW5jb2Rpbmc6OnByZV9kZWNvZGU6OmgzZGM1Y2M4OTJkNTRmZmZiBQ9lbmNvZGluZ19lbmNvZGUGDGVuY29kaW5nX25ldwc1Y29yZTo6Zm10OjpGb3JtYXR0ZXI6OnBhZF9pbnRlZ3JhbDo6aDlhNmJiNzIyNmU0N2EyZWUILGNvcmU6OmZtdDo6Rm9ybWF0dGVyOjpwYWQ6Omg4NTcyYWNlNTA5YmYyNzk3CSNjb3JlOjpmbXQ6OndyaXRlOjpoMTcwZWVkNzAxMGExNzk4ZgouY29yZTo6c3RyOjpzbGljZV9lcnJvcl9mYWlsOjpoODA1MzQzNGUzYTgwNmE1MQsPZW5jb2RpbmdfZGVjb2RlDDA8JlQgYXMgY29yZTo6Zm10OjpEZWJ1Zz46OmZtdDo6aGM2OWU3MjY4MmJlMjZjMjENLmNvcmU6OnNsaWNlOjptZW1jaHI6Om1lbWNocjo6aDYxNmViYWE5MzIwZDIxZGMOLXdlZV9hbGxvYzo6YWxsb2NfZmlyc3RfZml0OjpoNTE1ZjM4MWNhNDhlM2E4Mw9XPGNvcmU6OmZtdDo6YnVpbGRlcnM6OlBhZEFkYXB0ZXI8J18+IGFzIGNvcmU6OmZtdDo6V3JpdGU+Ojp3cml0ZV9zdHI6Omg2OTRjMmZjMTNkZDliNWZiEEFjb3JlOjpjaGFyOjptZXRob2RzOjo8aW1wbCBjaGFyPjo6ZXNjYXBlX2RlYnVnOjpoY2NkODE2ZWY1NmE4MWRjNRFGPGNvcmU6Om51bTo6UGFyc2VJbnRFcnJvciBhcyBjb3JlOjpmbXQ6OkRlYnVnPjo6Zm10OjpoOThlNDM3OTRkMGRmOTAwYxI/cmVlZF9zb2xvbW9uOjpkZWNvZGVyOjpEZWNvZGVyOjppc19jb3JydXB0ZWQ6OmgyZTRhNTdkNjU5Y2Y5ZmM4EyVhbGxvYzo6Zm10Ojpmb3JtYXQ6Omg5ZTdlZTY4OGMzOGNiNjE2FDJjb3JlOjp1bmljb2RlOjpwcmludGFibGU6OmNoZWNrOjpoYjdjYjVkM2EzMmQ2ZmVkMRUyPGNoYXIgYXMgY29yZTo6Zm10OjpEZWJ1Zz46OmZtdDo6aDU0Nzk0YjU3ZjI0MTEwN2MWOzwmbXV0IFcgYXMgY29yZTo6Zm10OjpXcml0ZT46OndyaXRlX2NoYXI6Omg5Y2QxY2ViZmExYjQ4N2UzF0I8W3U4XSBhcyByZWVkX3NvbG9tb246OmdmOjpwb2x5X21hdGg6OkFkZD46OmFkZDo6aDE0NDY3MDk1MTE4NzA5YjAYRDxoY2lkOjplcnJvcjo6SGNpZEVycm9yIGFzIGNvcmU6OmZtdDo6RGVidWc+OjpmbXQ6OmhjNGQ0OTJjMjk4ZDlkYTY4GUljb3JlOjpmbXQ6Om51bTo6PGltcGwgY29yZTo6Zm10OjpEaXNwbGF5IGZvciB1MzI+OjpmbXQ6OmgzZjI0MzVlNmYzZTRhYzgzGi1oY2lkOjp1dGlsOjpjYXBfZW5jb2RlX2Jpbjo6aGY3NTYzMDVlODE4NWU2YmEbQXdlZV9hbGxvYzo6V2VlQWxsb2M6OmRlYWxsb2NfaW1wbDo6e3tjbG9zdXJlfX06Omg4ODdkZjM4ZDM3OTg4Yjc2HDA8JlQgYXMgY29yZTo6Zm10OjpEZWJ1Zz46OmZtdDo6aDA3Mjg4NTY3OTM3Njg4ZDgdSWNvcmU6OmZtdDo6bnVtOjo8aW1wbCBjb3JlOjpmbXQ6OkRlYnVnIGZvciB1c2l6ZT46OmZtdDo6aDRiYmIyMTY0YzAwZDc2N2QeL2NvcmU6OmZtdDo6V3JpdGU6OndyaXRlX2NoYXI6Omg2MGE0MDM2YmFmNjhjNjNjHxNlbmNvZGluZ19pc19jb3JydXB0IDtjb3JlOjpzbGljZTo6PGltcGwgW1RdPjo6Y29weV9mcm9tX3NsaWNlOjpoZTI3NjIxMTM3MjVjMzAwZiE6Y29yZTo6Zm10OjpidWlsZGVyczo6RGVidWdUdXBsZTo6ZmluaXNoOjpoNzdhNTA5MjQ4MzYxYWM3MCJUPHdlZV9hbGxvYzo6V2VlQWxsb2M8J3N0YXRpYz4gYXMgY29yZTo6YWxsb2M6Okdsb2JhbEFsbG9jPjo6YWxsb2M6Omg2MDgwMWZhMDcwZjVjZWYzI1Y8d2VlX2FsbG9jOjpXZWVBbGxvYzwnc3RhdGljPiBhcyBjb3JlOjphbGxvYzo6R2xvYmFsQWxsb2M+OjpkZWFsbG9jOjpoY2UyYWVkY2MwOTM2OTg2ZCSAATx3ZWVfYWxsb2M6OnNpemVfY2xhc3Nlczo6U2l6ZUNsYXNzQWxsb2NQb2xpY3k8J2EsICdiPiBhcyB3ZWVfYWxsb2M6OkFsbG9jUG9saWN5PCdhPj46Om5ld19jZWxsX2Zvcl9mcmVlX2xpc3Q6Omg1NzkzNzI0YzIwZDIxZTM5JUdjb3JlOjpmbXQ6Om51bTo6PGltcGwgY29yZTo6Zm10OjpCaW5hcnkgZm9yIGk4Pjo6Zm10OjpoZWExYjMzOWI1NTFlOTkxYyYwPGFsbG9jOjp2ZWM6OlZlYzxUPj46OnJlc2VydmU6OmgzOTMzN2RkY2U2MTEwMjJjJzo8YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxULCBBPj46OnJlc2VydmU6Omg4NTUwZDNjOGMxOWU0Y2ExKDA8JlQgYXMgY29yZTo6Zm10OjpEZWJ1Zz46OmZtdDo6aDhkY2ZiOWJhODc5MTczMTMpSjxjb3JlOjpvcHM6OnJhbmdlOjpSYW5nZTxJZHg+IGFzIGNvcmU6OmZtdDo6RGVidWc+OjpmbXQ6Omg3ZTlmNzIwNTNmMmY1ZTA1KoQBd2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjpzbGljZXM6OjxpbXBsIHdhc21fYmluZGdlbjo6Y29udmVydDo6dHJhaXRzOjpJbnRvV2FzbUFiaSBmb3IgYWxsb2M6OnZlYzo6VmVjPFQ+Pjo6aW50b19hYmk6OmgwZTE0OTkwNmM4MjY3NDZhK2Y8d2VlX2FsbG9jOjpMYXJnZUFsbG9jUG9saWN5IGFzIHdlZV9hbGxvYzo6QWxsb2NQb2xpY3k8J2E+Pjo6bmV3X2NlbGxfZm9yX2ZyZWVfbGlzdDo6aGVmNzlhNTU2MTEzZTgwMTMsB21lbW1vdmUtE19fd2JnX2VuY29kaW5nX2ZyZWUuLmNvcmU6OnJlc3VsdDo6dW53cmFwX2ZhaWxlZDo6aDVkY2YxNjlmOTE3ZTAyOGEvNmNvcmU6OnBhbmlja2luZzo6cGFuaWNfYm91bmRzX2NoZWNrOjpoZDFjMDEzNGY1NDQyMjEwZTA0Y29yZTo6c2xpY2U6OnNsaWNlX2luZGV4X2xlbl9mYWlsOjpoNDkwMzA5NWY1ZmZhMTExMjE2Y29yZTo6c2xpY2U6OnNsaWNlX2luZGV4X29yZGVyX2ZhaWw6OmgzYjBkZWExMTRjNzRjYWMxMi93ZWVfYWxsb2M6OmFsbG9jX3dpdGhfcmVmaWxsOjpoMDQyYWM1N2I1NmIwMWZiNTNIPGNvcmU6OmZtdDo6QXJndW1lbnRzPCdfPiBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6OmgxNmRhZTQ2ZTJkM2I1MjNhNDo8Jm11dCBXIGFzIGNvcmU6OmZtdDo6V3JpdGU+Ojp3cml0ZV9mbXQ6Omg4MDlhOWM1MjRjOTVmYWFhNTo8Jm11dCBXIGFzIGNvcmU6OmZtdDo6V3JpdGU+Ojp3cml0ZV9mbXQ6Omg0MTYwZDc2YjQzOGYxOTBlNi5jb3JlOjpmbXQ6OldyaXRlOjp3cml0ZV9mbXQ6OmgzZWQ1NGMyYzA3ZDhjYzdmNyljb3JlOjpwYW5pY2tpbmc6OnBhbmljOjpoYzhjM2RkOTkxMjdjOTE3ZDg3c3RkOjpwYW5pY2tpbmc6OnJ1c3RfcGFuaWNfd2l0aF9ob29rOjpoMTNiOWU4ODU5MDgwOTBhOTlRPHJlZWRfc29sb21vbjo6ZGVjb2Rlcjo6RGVjb2RlckVycm9yIGFzIGNvcmU6OmZtdDo6RGVidWc+OjpmbXQ6OmhkMWI3YTUzZDQ2ZDM0ZTk2OgZtZW1jbXA7LWNvcmU6OnBhbmlja2luZzo6cGFuaWNfZm10OjpoYzU2MjM5OGVhMDgwYzhjYTw+PGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCwgQT4+OjphbGxvY2F0ZV9pbjo6aDJiYTVkZWIwM2JjYTZjYjc9MGNvcmU6OnB0cjo6cmVhbF9kcm9wX2luX3BsYWNlOjpoNTJlODFlYmU0NDU5MDA2NT4GbWVtY3B5PxFfX3diaW5kZ2VuX21hbGxvY0BvPGFsbG9jOjp2ZWM6OlZlYzxUPiBhcyBhbGxvYzo6dmVjOjpTcGVjRXh0ZW5kPCYnYSBULCBjb3JlOjpzbGljZTo6SXRlcjwnYSwgVD4+Pjo6c3BlY19leHRlbmQ6OmhmNmYxNDc3YTIyYjE1MWVjQTVzdGQ6OnBhbmlja2luZzo6Y29udGludWVfcGFuaWNfZm10OjpoNDA4MDUxNWU0NzYzNTQyNUIMX19yZ19yZWFsbG9jQzo8Jm11dCBXIGFzIGNvcmU6OmZtdDo6V3JpdGU+Ojp3cml0ZV9zdHI6Omg1NzgwNTRhODc5ZDNhMjI4RAZtZW1zZXRFEl9fd2JpbmRnZW5fcmVhbGxvY0aAAWNvcmU6OnN0cjo6dHJhaXRzOjo8aW1wbCBjb3JlOjpzbGljZTo6U2xpY2VJbmRleDxzdHI+IGZvciBjb3JlOjpvcHM6OnJhbmdlOjpSYW5nZTx1c2l6ZT4+OjppbmRleDo6e3tjbG9zdXJlfX06Omg0Zjc3NWNiZTVkMzBkODM1Rz48Y29yZTo6Zm10OjpFcnJvciBhcyBjb3JlOjpmbXQ6OkRlYnVnPjo6Zm10OjpoNmI1MDc4ZjhmYzg1MWZhM0g0PGNvcmU6Om9wdGlvbjo6T3B0aW9uPFQ+Pjo6dW53cmFwOjpoOGU4NTAxYTQ3ODQyYjVmOEkPX193YmluZGdlbl9mcmVlSjI8JlQgYXMgY29yZTo6Zm10OjpEaXNwbGF5Pjo6Zm10OjpoYzg2OTUyMmM1MGQ0ZjkwYksyPCZUIGFzIGNvcmU6OmZtdDo6RGlzcGxheT46OmZtdDo6aGM5ZjhjZjFiZWUzZTFhOWVMMjwmVCBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6OmhkNmM1Zjc4MzVkZjA0NDJiTQ5fX3J1c3RfcmVhbGxvY046PCZtdXQgVyBhcyBjb3JlOjpmbXQ6OldyaXRlPjo6d3JpdGVfc3RyOjpoNTViYjk4ZTQzNzg2MDk1NE8OX19ydXN0X2RlYWxsb2NQMXdhc21fYmluZGdlbjo6X19ydDo6dGhyb3dfbnVsbDo6aDBiZjY5YzQ4MjdhZmMwZjdRMndhc21fYmluZGdlbjo6X19ydDo6Ym9ycm93X2ZhaWw6Omg4NWEyYWU3YTVlNzVmMjllUip3YXNtX2JpbmRnZW46OnRocm93X3N0cjo6aGM2YWIwY2UwZDZhODlhZWJTOzwmbXV0IFcgYXMgY29yZTo6Zm10OjpXcml0ZT46OndyaXRlX2NoYXI6OmhlYzM0NGZmNDM2YWJkYzQ0VAxfX3JnX2RlYWxsb2NVDF9fcnVzdF9hbGxvY1Yqd2FzbV9iaW5kZ2VuOjp0aHJvd192YWw6OmgxZjlkYmMzNTk5NWQ4MDE3VzRhbGxvYzo6cmF3X3ZlYzo6Y2FwYWNpdHlfb3ZlcmZsb3c6OmgwNWVkNGQyNGEwM2JjYTZiWBFydXN0X2JlZ2luX3Vud2luZFk0Y29yZTo6Zm10OjpBcmd1bWVudFYxOjpzaG93X3VzaXplOjpoMjM5NzFmZDVmMWRjNDc2MFoKX19yZ19hbGxvY1tLPGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCwgQT4+OjphbGxvY2F0ZV9pbjo6e3tjbG9zdXJlfX06Omg4ZWU5YjExMzEzMzYyZDQ2XEs8YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxULCBBPj46OmFsbG9jYXRlX2luOjp7e2Nsb3N1cmV9fTo6aDEzMjI0YTdhOGZlNDJmOTddXTx3ZWVfYWxsb2M6OkxhcmdlQWxsb2NQb2xpY3kgYXMgd2VlX2FsbG9jOjpBbGxvY1BvbGljeTwnYT4+OjptaW5fY2VsbF9zaXplOjpoOGNjMzVkYmFjNDZmOTNiMl5wPHdlZV9hbGxvYzo6TGFyZ2VBbGxvY1BvbGljeSBhcyB3ZWVfYWxsb2M6OkFsbG9jUG9saWN5PCdhPj46OnNob3VsZF9tZXJnZV9hZGphY2VudF9mcmVlX2NlbGxzOjpoYzQ4NGVmN2QxNTk3ODUzNl93PHdlZV9hbGxvYzo6c2l6ZV9jbGFzc2VzOjpTaXplQ2xhc3NBbGxvY1BvbGljeTwnYSwgJ2I+IGFzIHdlZV9hbGxvYzo6QWxsb2NQb2xpY3k8J2E+Pjo6bWluX2NlbGxfc2l6ZTo6aDA0YzI5MjU5YmI5YWJlNDdgigE8d2VlX2FsbG9jOjpzaXplX2NsYXNzZXM6OlNpemVDbGFzc0FsbG9jUG9saWN5PCdhLCAnYj4gYXMgd2VlX2FsbG9jOjpBbGxvY1BvbGljeTwnYT4+OjpzaG91bGRfbWVyZ2VfYWRqYWNlbnRfZnJlZV9jZWxsczo6aDFlYmIwYTQ2ZGQ3YTRiMWFhHl9fd2JpbmRnZW5fZ2xvYmFsX2FyZ3VtZW50X3B0cmIKcnVzdF9wYW5pY2M1PFQgYXMgY29yZTo6YW55OjpBbnk+OjpnZXRfdHlwZV9pZDo6aDE0NTdkZTVlNTEwOTIwOTZkMGNvcmU6OnB0cjo6cmVhbF9kcm9wX2luX3BsYWNlOjpoNGUxYjI3NDcwNmE1Y2I0M2UwY29yZTo6cHRyOjpyZWFsX2Ryb3BfaW5fcGxhY2U6Omg5N2U5OTBjNDA3MzJiYTE1ZjJjb3JlOjpwdHI6OnJlYWxfZHJvcF9pbl9wbGFjZTo6aDRlMWIyNzQ3MDZhNWNiNDMuN2cwY29yZTo6cHRyOjpyZWFsX2Ryb3BfaW5fcGxhY2U6OmgxNTE2NGExZjM4M2MzNzRkaDBjb3JlOjpwdHI6OnJlYWxfZHJvcF9pbl9wbGFjZTo6aDczYzYwOWIzNDhmNmNmNjdpMGNvcmU6OnB0cjo6cmVhbF9kcm9wX2luX3BsYWNlOjpoMDQ4NTcxY2QwYjNjMzcwNWowY29yZTo6cHRyOjpyZWFsX2Ryb3BfaW5fcGxhY2U6OmgxMWIxMTJmZWM1OTc0NzU4APqAgIAACXByb2R1Y2VycwIIbGFuZ3VhZ2UBBFJ1c3QEMjAxNQxwcm9jZXNzZWQtYnkDBXJ1c3RjJTEuMzMuMC1uaWdodGx5ICgxOWY4OTU4ZjggMjAxOS0wMS0yMykGd2FscnVzBTAuNS4wDHdhc20tYmluZGdlbgYwLjIuNDA=";
-                    if (typeof Buffer === 'undefined') {
-                        bytes = Uint8Array.from(atob(base64), c => c.charCodeAt(0));
-                    } else {
-                        bytes = Buffer.from(base64, 'base64');
-                    }
-                    
-            export const booted = 
-            WebAssembly.instantiate(bytes,{ './bindgen': import_b,  })
-                .then(obj => {
-                    const wasm = obj.instance;
-                    memory = wasm.exports.memory;
-__wbg_encoding_free = wasm.exports.__wbg_encoding_free;
-encoding_new = wasm.exports.encoding_new;
-encoding_encode = wasm.exports.encoding_encode;
-encoding_decode = wasm.exports.encoding_decode;
-encoding_is_corrupt = wasm.exports.encoding_is_corrupt;
-__wbindgen_global_argument_ptr = wasm.exports.__wbindgen_global_argument_ptr;
-__wbindgen_malloc = wasm.exports.__wbindgen_malloc;
-__wbindgen_realloc = wasm.exports.__wbindgen_realloc;
-__wbindgen_free = wasm.exports.__wbindgen_free;
-
-                })
-            ;
-            export let memory;
-export let __wbg_encoding_free;
-export let encoding_new;
-export let encoding_encode;
-export let encoding_decode;
-export let encoding_is_corrupt;
-export let __wbindgen_global_argument_ptr;
-export let __wbindgen_malloc;
-export let __wbindgen_realloc;
-export let __wbindgen_free;
-
-            
\ No newline at end of file
+let wasm;
+export function __wbg_set_wasm(val) {
+    wasm = val;
+}
+
+
+const cachedTextDecoder = (typeof TextDecoder !== 'undefined' ? new TextDecoder('utf-8', { ignoreBOM: true, fatal: true }) : { decode: () => { throw Error('TextDecoder not available') } } );
+
+if (typeof TextDecoder !== 'undefined') { cachedTextDecoder.decode(); };
+
+let cachedUint8Memory0 = null;
+
+function getUint8Memory0() {
+    if (cachedUint8Memory0 === null || cachedUint8Memory0.byteLength === 0) {
+        cachedUint8Memory0 = new Uint8Array(wasm.memory.buffer);
+    }
+    return cachedUint8Memory0;
+}
+
+function getStringFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return cachedTextDecoder.decode(getUint8Memory0().subarray(ptr, ptr + len));
+}
+
+const heap = new Array(128).fill(undefined);
+
+heap.push(undefined, null, true, false);
+
+let heap_next = heap.length;
+
+function addHeapObject(obj) {
+    if (heap_next === heap.length) heap.push(heap.length + 1);
+    const idx = heap_next;
+    heap_next = heap[idx];
+
+    heap[idx] = obj;
+    return idx;
+}
+
+let WASM_VECTOR_LEN = 0;
+
+const cachedTextEncoder = (typeof TextEncoder !== 'undefined' ? new TextEncoder('utf-8') : { encode: () => { throw Error('TextEncoder not available') } } );
+
+const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
+    ? function (arg, view) {
+    return cachedTextEncoder.encodeInto(arg, view);
+}
+    : function (arg, view) {
+    const buf = cachedTextEncoder.encode(arg);
+    view.set(buf);
+    return {
+        read: arg.length,
+        written: buf.length
+    };
+});
+
+function passStringToWasm0(arg, malloc, realloc) {
+
+    if (realloc === undefined) {
+        const buf = cachedTextEncoder.encode(arg);
+        const ptr = malloc(buf.length, 1) >>> 0;
+        getUint8Memory0().subarray(ptr, ptr + buf.length).set(buf);
+        WASM_VECTOR_LEN = buf.length;
+        return ptr;
+    }
+
+    let len = arg.length;
+    let ptr = malloc(len, 1) >>> 0;
+
+    const mem = getUint8Memory0();
+
+    let offset = 0;
+
+    for (; offset < len; offset++) {
+        const code = arg.charCodeAt(offset);
+        if (code > 0x7F) break;
+        mem[ptr + offset] = code;
+    }
+
+    if (offset !== len) {
+        if (offset !== 0) {
+            arg = arg.slice(offset);
+        }
+        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
+        const view = getUint8Memory0().subarray(ptr + offset, ptr + len);
+        const ret = encodeString(arg, view);
+
+        offset += ret.written;
+        ptr = realloc(ptr, len, offset, 1) >>> 0;
+    }
+
+    WASM_VECTOR_LEN = offset;
+    return ptr;
+}
+
+let cachedInt32Memory0 = null;
+
+function getInt32Memory0() {
+    if (cachedInt32Memory0 === null || cachedInt32Memory0.byteLength === 0) {
+        cachedInt32Memory0 = new Int32Array(wasm.memory.buffer);
+    }
+    return cachedInt32Memory0;
+}
+
+function getObject(idx) { return heap[idx]; }
+
+function dropObject(idx) {
+    if (idx < 132) return;
+    heap[idx] = heap_next;
+    heap_next = idx;
+}
+
+function takeObject(idx) {
+    const ret = getObject(idx);
+    dropObject(idx);
+    return ret;
+}
+
+let cachedUint32Memory0 = null;
+
+function getUint32Memory0() {
+    if (cachedUint32Memory0 === null || cachedUint32Memory0.byteLength === 0) {
+        cachedUint32Memory0 = new Uint32Array(wasm.memory.buffer);
+    }
+    return cachedUint32Memory0;
+}
+
+function getArrayU32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint32Memory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8Memory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8Memory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+const EncodingFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_encoding_free(ptr >>> 0));
+/**
+*/
+export class Encoding {
+
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        EncodingFinalization.unregister(this);
+        return ptr;
+    }
+
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_encoding_free(ptr);
+    }
+    /**
+    * @param {string} data
+    * @returns {boolean}
+    */
+    is_corrupt(data) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_is_corrupt(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return r0 !== 0;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @param {string} data
+    * @returns {Uint32Array}
+    */
+    erased_chars(data) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_erased_chars(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var v2 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_free(r0, r1 * 4, 4);
+            return v2;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @param {string} data
+    * @param {number} size
+    * @returns {string}
+    */
+    identicon_svg(data, size) {
+        let deferred3_0;
+        let deferred3_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_identicon_svg(retptr, this.__wbg_ptr, ptr0, len0, size);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            var r3 = getInt32Memory0()[retptr / 4 + 3];
+            var ptr2 = r0;
+            var len2 = r1;
+            if (r3) {
+                ptr2 = 0; len2 = 0;
+                throw takeObject(r2);
+            }
+            deferred3_0 = ptr2;
+            deferred3_1 = len2;
+            return getStringFromWasm0(ptr2, len2);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        }
+    }
+    /**
+    * @param {string} encoding_name
+    */
+    constructor(encoding_name) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(encoding_name, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_new(retptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0 >>> 0;
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @param {string} data
+    * @returns {Uint8Array}
+    */
+    decode(data) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_decode(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            var r3 = getInt32Memory0()[retptr / 4 + 3];
+            if (r3) {
+                throw takeObject(r2);
+            }
+            var v2 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_free(r0, r1 * 1, 1);
+            return v2;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+    * @param {Uint8Array} data
+    * @returns {string}
+    */
+    encode(data) {
+        let deferred3_0;
+        let deferred3_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(data, wasm.__wbindgen_malloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_encode(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            var r3 = getInt32Memory0()[retptr / 4 + 3];
+            var ptr2 = r0;
+            var len2 = r1;
+            if (r3) {
+                ptr2 = 0; len2 = 0;
+                throw takeObject(r2);
+            }
+            deferred3_0 = ptr2;
+            deferred3_1 = len2;
+            return getStringFromWasm0(ptr2, len2);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        }
+    }
+    /**
+    * @param {string} data
+    * @returns {string}
+    */
+    randomart(data) {
+        let deferred3_0;
+        let deferred3_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(data, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.encoding_randomart(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getInt32Memory0()[retptr / 4 + 0];
+            var r1 = getInt32Memory0()[retptr / 4 + 1];
+            var r2 = getInt32Memory0()[retptr / 4 + 2];
+            var r3 = getInt32Memory0()[retptr / 4 + 3];
+            var ptr2 = r0;
+            var len2 = r1;
+            if (r3) {
+                ptr2 = 0; len2 = 0;
+                throw takeObject(r2);
+            }
+            deferred3_0 = ptr2;
+            deferred3_1 = len2;
+            return getStringFromWasm0(ptr2, len2);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_free(deferred3_0, deferred3_1, 1);
+        }
+    }
+}
+
+export function __wbindgen_string_new(arg0, arg1) {
+    const ret = getStringFromWasm0(arg0, arg1);
+    return addHeapObject(ret);
+};
+
+export function __wbindgen_throw(arg0, arg1) {
+    throw new Error(getStringFromWasm0(arg0, arg1));
+};
+
@@ -0,0 +1,46 @@
+import * as import_b from './bindgen_bg.js';
+
+            
+                    let bytes;
+                    const base64 = "AGFzbQEAAAABXA5gAABgAAF/YAF/AGABfwF/YAJ/fwBgAn9/AX9gA39/fwBgA39/fwF/YAR/f39/AGAEf39/fwF/YAV/f39/fwBgBX9/f39/AX9gBn9/f39/fwBgBn9/f39/fwF/AkwCDy4vYmluZGdlbl9iZy5qcxVfX3diaW5kZ2VuX3N0cmluZ19uZXcABQ8uL2JpbmRnZW5fYmcuanMQX193YmluZGdlbl90aHJvdwAEA4QBggEICAgDCAwKCAYKCggFBwYEBgsHCQULAwUHBAYGCAYICAMGDAgGBQYFBQUIDAYEBAQKBQwGBAQFBQkIBgUIAgYIBwcCAg0ICAIIBAcEAgYBBwYHBQICAgIKBQQEBgkGCQsCBQYCBAUFBgUKBQUFAAcHAgADAAAFBAQEBAQEAgQCBQAFBAUBcAEdHQUDAQASBgkBfwFBgIDAAAsHjAINBm1lbW9yeQIAE19fd2JnX2VuY29kaW5nX2ZyZWUARQ9lbmNvZGluZ19kZWNvZGUAIA9lbmNvZGluZ19lbmNvZGUABhVlbmNvZGluZ19lcmFzZWRfY2hhcnMALBZlbmNvZGluZ19pZGVudGljb25fc3ZnAAwTZW5jb2RpbmdfaXNfY29ycnVwdAAhDGVuY29kaW5nX25ldwAQEmVuY29kaW5nX3JhbmRvbWFydAAJH19fd2JpbmRnZW5fYWRkX3RvX3N0YWNrX3BvaW50ZXIAdBFfX3diaW5kZ2VuX21hbGxvYwBaEl9fd2JpbmRnZW5fcmVhbGxvYwBeD19fd2JpbmRnZW5fZnJlZQBpCSQBAEEBCxxBgwE7gQEzJ2M9Z3crbCpuam0ZOXt/ZVEpcGgUVHEKjewCggGyHgIdfwF+IwBB8ABrIgQkACAEQdgAaiACIAMgAS0ANBAEIAQoAlwhBSAEKAJYIQYgBCgCYCEHIAQoAmgiAyAEKAJsEFwgBCgCZCADQQRBEBBKAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgByABKAIoRw0AIARBDGogASgCICIIIAEoAhwgASgCGGoiCWoQTSAEQRhqIAcQTSAEKAIgIQogBCgCHCELIARBADYCYCAEQoCAgIAQNwJYAkACQCAHDQBBASEMQQAhDUEAIQ4MAQtBACEDQQEhDwNAQc8AIQICQAJAAkACQAJAIAUgA2otAAAiEEFQag4DBAECAAsgEEHsAEYNACAQQcwARw0CC0HJACECDAILQdoAIQIMAQsCQCAQQU1qQf8BcUEHTw0AIBAhAgwBCyAQIQIgEEHfAXFBv39qQf8BcUEaSQ0AIAMgCk8NBCALIANqQTE6AABBwQAhAgsCQCADIAQoAlhHDQAgBEHYAGoQSSAEKAJcIQ8LIA8gA2ogAjoAACAEIANBAWoiAjYCYCACIQMgByACRw0ACyAEKAJcIQwgBCgCWCEOIAchDQsgBEEANgIsIARCgICAgBA3AiQgCEUNBkEAIAEoAhQiEWshEiAMIBFqIRMgBCgCHCARaiEUIAEoAiQhFSAEKAIQIRYgBCgCFCEXIAQoAiAhGCARIRlBASEaQQEhG0EAIRwDQCAVIBwiHWwgEWoiAiAVaiIDIAJJDQMgAyANSw0DIB1BAWohHCAYIBkgGCAZSxsgEmohEEEAIQMgBEEANgJgIARCgICAgBA3AlggGCACIBggAksbIR5BASEfIBUhAiATIQogFCEPAkACQANAAkACQCACRQ0AIBBFDQkgDy0AAEExRw0BIARBADYCYAsgBEEAOgA0IAQoAlwhCwwCCwJAAkACQCAKLQAAIgtBv39qQf8BcUEaTw0AQTEhIAwBCyALQZ9/akH/AXFBGUsNAUEwISALIAMhCwJAIAQoAlggA0cNACAEQdgAaiADQQFBAUEBEFkgBCgCYCELIAQoAlwhHwsgHyALaiAgOgAAIAQgA0EBaiIDNgJgCyACQX9qIQIgEEF/aiEQIApBAWohCiAPQQFqIQ8gA0EISQ0ACyADIAQoAlwiCy0AAEErRiICayEPIAsgAmohAkEAIQMCQAJAAkADQCACLQAAIRAgA8BBAEgNAUEBIQogEEFQaiIQQQFLDQIgAkEBaiECIANBAXQgEHIhAyAPQX9qIg9FDQMMAAsLQQJBASAQQf4BcUEwRhshCgsgBCAKOgBAIARBCzYCUCAEIARBwABqNgJMIARBMGpBmIXAACAEQcwAahAfIAQoAjAhAgwCCyAEIAM6ADUgBEEBOgA0C0GAgICAeCECIARBgICAgHg2AjALIAQoAlggC0EBQQEQSiAELQA1IQMgBC0ANCEQAkAgAkGAgICAeEYNACAAIAQvATo7AQ4gACAEKAE2NgEKIAAgAzoACSAAIBA6AAggACACNgIEIABBgICAgHg2AgAMEAsCQAJAAkAgEEEBcUUNAEEAIBsgG0EBcRsgGyADQf8BcSICQf8BRxshG0EAIBogGkEBcRsgGiACGyEaIAQoAiwiAiAEKAIkRg0BDAILIB0gCWoiAyAXTw0HIBYgA2pBMToAAEEAIQMgBCgCLCICIAQoAiRHDQELIARBJGoQSQsgBCgCKCACaiADOgAAIAQgAkEBajYCLCASIBVrIRIgGSAVaiEZIBMgFWohEyAUIBVqIRQgHCAIRg0GDAALCyAEIAc2AjAgBEEGNgJkIAQgAUEoajYCYCAEQQY2AlwgBCAEQTBqNgJYIABBBGpBk4PAACAEQdgAahAfIABBgICAgHg2AgAMDgsgAyAKQay6wAAQUgALIAIgAyANQbCowAAQJQALIB4gGEGcusAAEFIACyADIBdBwKjAABBSAAsCQCAaQQFxDQAgG0EBcUUNAQsgBCgCFCIQIAkgECAJSRshAyAEKAIQIQ8gCSECA0AgECADRg0CIA8gAmpBMToAACACQQFqIQIgA0EBaiEDIAhBf2oiCA0ACwsgDUUNAiAHIQIgDCEDA0ACQCADLQAAIhBBn39qQf8BcUEZSw0AIAMgEEHfAHE6AAALIANBAWohAyACQX9qIgINAAtBACEKIARBADYCYCAEQoCAgIAQNwJYQQEhC0EAIQNBACEQIAwhAgNAAkAgAi0AACIPQaV/akH/AXFB2AFPDQAgBEE0akHyucAAQQkQXQwHCwJAQgEgD0FNaiIPrUL/AYOGQoD/gBCDUA0AIARBNGpB8rnAAEEJEF0MBwsgEEEFdCAPQf8BcS0AvLlAciEQAkACQCADQQNPDQAgA0EFaiEDDAELIBAgA0F9aiIDdiEPAkAgCiAEKAJYRw0AIARB2ABqEEkgBCgCXCELCyALIApqIA86AAAgBCAKQQFqIgo2AmALIAJBAWohAiAHQX9qIgdFDQIMAAsLIAIgEEGgqMAAEFIACyADQQRLDQIMAQtBACEDIARBADYCYCAEQoCAgIAQNwJYQQAhEAsgEEEIIANrdEH+AXFFDQILIARBNGpB5LnAAEEOEF0LIARBATYCMCAEKAJYIAQoAlxBAUEBEEogACAEKAI8NgIMIAAgBCkCNDcCBCAAQYCAgIB4NgIADAELIAQgBCgCYCIDNgI8IAQgBCkCWCIhNwI0QQAhAiAEQQA2AjAgBCADNgJIIAQgITcDQAJAAkACQAJAAkAgASgCCCIZIANLDQAgASgCBCESIAQoAiAhEyAEKAIcIRQgBCgCRCEcIARBADYCYCAEQoCAgIDAADcCWAJAAkAgGUEDdCIfDQBBBCEDQQAhFQwBC0EAIRVBBCEYQQAhIANAAkACQAJAICBBA3YiAyAZTw0AICBBBW4hDwJAIBIgA2otAAAgHCADai0AAHMgIEEHcXRBgAFxDQAgDyATTw0CIBQgD2otAABBMUcNAwsgFUEPcSEHIBggFUHw////AXEiCkECdGohCyAYIRACQANAIApFDQFBACECQQAhAwNAIAIgECADaigCACAPRnIhAiADQQRqIgNBwABHDQALIApBcGohCiAQQcAAaiEQIAJBAXFFDQAMBAsLIAdBAnQhAwJAA0AgA0UNASADQXxqIQMgCygCACECIAtBBGohCyACIA9HDQAMBAsLAkAgFSAEKAJYRw0AIARB2ABqEFUgBCgCXCEYCyAYIBVBAnRqIA82AgAgBCAVQQFqIhU2AmAMAgsgAyAZQbipwAAQUgALIA8gE0HIqcAAEFIACyAgQQFqIiAgH0cNAAsgBCgCICETIAQoAlwhAyAEKAJYIQILIAIgA0EEQQQQSgJAAkAgH0EFbiIDIB8gA0EFbGtBAEdqIgIgE0sNACAEKAIcIQcCQCACQQdLDQAgAkUNBiAHIQMDQCADLQAAQTFGDQggA0EBaiEDIAJBf2oiAg0ADAcLCyAHQQNqQXxxIhAgB0YNASAQIAdrIQMgByAQayEPIAchEANAIBAtAABBMUYNByAQQQFqIRAgD0EBaiIPDQALIAMgAkF4aiIKSw0EDAMLQQAgAiATQdCnwAAQJQALIAJBeGohCkEAIQMMAQtBACAZIANBwKfAABAlAAsDQEGAgoQIIAcgA2oiECgCACIPQbHixIkDc2sgD3JBgIKECCAQQQRqKAIAIhBBseLEiQNzayAQcnFBgIGChHhxQYCBgoR4Rw0BIANBCGoiAyAKTQ0ACwsgAiADRg0AIAIgA2shAiAHIANqIQMDQCADLQAAQTFGDQIgA0EBaiEDIAJBf2oiAg0ACwsgFUUNACAEQdgAaiAcIBkQEiAEKAJYIgNBgICAgHhGDQAgAyAEKAJcQQFBARBKIARB+KrHADYCTCAEQQAoAoSjRzYCWEEEQQEgBEHYAGogBEHMAGpBA0EEEEYhA0EAIAQoAlg2AoSjRwJAIANFDQAgAEEONgIMIAAgAzYCCCAAQoCAgIDoATcCACADQQApAOanQDcABiADQQApAOCnQDcAACAEKAJAIAQoAkRBAUEBEEoMAgtBAUEOEGYACwJAAkACQAJAIAQoAkgiAiAZSQ0AQQAhAyAEQQA2AkggBCgCRCEcIAIgGWshAiAZRQ0BIAJFDQMgAkUNAiAcIBwgGWogAvwKAAAMAgtBACAZIAJB/LbAABAlAAsgAkUNAQsgBCACNgJIIAIhAwsgBCgCKCEUAkACQAJAIAQoAiwiAiAEKAJAIANrTQ0AIARBwABqIAMgAkEBQQEQWSAEKAJEIRwgBCgCSCEDDAELIAJFDQELIAJFDQAgHCADaiAUIAL8CgAAC0EAIQogBEEANgIsIAQgAyACaiISNgJIIAcgE2ohGCAZIAlqQQN0QX9qIRkgASgCFCEPIAQoAhAhCyAEKAIUISAgByEDAkACQANAAkACQCAPDQAgAyECIAohECADIBhHDQEMBAsgDyAYIANrTw0DIA8gCmohECADIA9qIQILIBBBAWohCiACQQFqIQNBACEPIAItAABBMUcNACAfIBBBBWwiAiAfIAJLGyIQIBkgAkEEaiICIBkgAkkbIgJLDQACQCAQIB9rQQN2IhAgIE8NACALIBBqQTE6AAAgAiAfa0EDdiICICBPDQIgCyACakExOgAADAELCyAQICBBgKjAABBSAAsgAiAgQZCowAAQUgALIARBADYCVCAEQoCAgIAQNwJMAkACQCAgRQ0AQQAhAwNAAkAgCyADai0AAEExRw0AIAMgEk8NAyAcIANqQQA6AAACQCAEKAJUIgIgBCgCTEcNACAEQcwAahBJCyAEKAJQIAJqIAM6AAAgBCACQQFqNgJUCyAgIANBAWoiA0cNAAsgBCgCECELIAQoAhwhByAEKAIoIRQLIAAgBCkDQDcCACAAIBU2AhggBCAEKAJINgJgIAQgBCkCTDcCZCAAIAQpA2A3AgggBCAEKAJUNgJsIAAgBCkDaDcCECAEKAIkIBRBAUEBEEogDiAMQQFBARBKIAQoAhggB0EBQQEQSiAEKAIMIAtBAUEBEEoMAgsgAyASQfCnwAAQUgALIAQoAiQgBCgCKEEBQQEQSiAOIAxBAUEBEEogBCgCGCAEKAIcQQFBARBKIAQoAgwgBCgCEEEBQQEQSgsgBiAFQQFBARBKIARB8ABqJAALpxoCGX8EfiMAQcAWayIEJAAgBEHACmogASACIAMQAiAEKALMCiEFIAQpAsQKIR0CQAJAAkAgBCgCwAoiBkGAgICAeEYNACAEKALECiEHIAQoAtAKIQggBCkC0AohHiAEIAQoAtgKIgk2AgQgASgCHCEKIAEoAiAhCyAEIAkgHUIgiCIfpyIMaiINNgIIAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgDSAJSQ0AAkACQAJAAkACQAJAAkBBACALIApqIg4gASgCMGsiCiAKIA5LGyIPIB5CIIgiIKciEEkNACAdpyERICBQRQ0DIAEoArgCIRJBACELAkADQCALIgpB/wFuIRMgEiAKRg0BIApBAWohCyARIAwgE0GBfmxBvK/AAGogCmotAAAQQkH/AXFFDQALIAogEkkNBAsgASgCGCIKIAxLDQVBACELIApBAEgNBCAKDQFBACEKQQEhEwwCCyAEQfiqxwA2AsAIIARBACgChKNHNgLACkEEQQEgBEHACmogBEHACGpBA0EEEEYhCkEAIAQoAsAKNgKEo0cgCkUNBSAKQQApAPK4QDcABSAKQQApAO24QDcAACAEQQ02AhggBCAKNgIUIARBDTYCEAwUC0EBIQtBASAKEDgiE0UNAiAKRQ0AIBMgESAK/AoAAAsgBCAONgI4IARCADcCMCAEIAo2AhwgBCATNgIYIAQgCjYCFCAEIAk2AjwgBEIANwIoIARCgICAgMAANwIgIAQgDjYCEAwMCyAfICBUDQRBACEKAkAgHUIAUw0AQQEhCwJAAkAgH1BFDQBBACEUQQEhFQwBC0EBIQpBASAMEDgiFUUNAQJAIAxFDQAgFSARIAz8CgAACyAMIRQLIB1C//////8fVg0KIAEoArgCIhIgDE8NCiAepyILIBBqIRZBACEKIB+nQf8BcSERAkADQCAQIApGDQEgCyAKaiETIApBAWohCiATLQAAIBFJDQALQQIhCwwLC0EAIQogBEHCAGpBAEH/AfwLAAJAIBJFDQADQCAEQcIAaiAKaiAVIAwgCkG8r8AAai0AABBCOgAAIBIgCkEBaiIKRw0ACwtBACEKA0ACQCASIApHDQBBACEKDA0LIARBwgBqIApqIRMgCkEBaiEKIBMtAABFDQALQQAhFyAEQcECakEAQf8B/AsAIARBwARqQQFqQQBB/wH8CwAgBEEBOgDABAJAA0AgCyAWRg0BIAstAAAiCkH/AUYNCiALQQFqIQsgBEHBAmogCmoiEy0AAA0AIBNBAToAACAXQQFqIRhBACEXIBhFDQAgDCAKQX9zakH/AXAtALyvQEG8s8AAaiEZIBghCgNAQQEhE0EAIRFBACEaAkAgCkEBRg0AIApBf2ohEyAKQYACSw0LIBMhGgsCQCAEQcAEaiAaai0AACIaRQ0AIBotALyzQCAZLQAAai0AvK9AIRELIApBgAJPDQkgBEHABGogCmoiGiAaLQAAIBFzOgAAIApBAUYhESATIQogEUUNAAsgGCEXDAALCyAXIBJLDQkgBEHABmogBEHABGpBgAL8CgAAIARBwAZqQQFqIRggFyEaIBchGwNAIBogEiAaIBJLGyEZA0ACQCAaIgsgGUcNAEH/ASEKAkADQCAEQcAEaiAKai0AAA0BIApBf2oiCg0AC0EAIQoLIAogG0cNDCAbQQF0IBdrIBJLDQxBACERIARBwApqQQBB/Af8CwAgDEG7r8AAaiEKIAxBf2ohCyAbQQFqIRZBACETAkADQAJAIARBwARqIBYgCiALQf8BbkGBfmxqLQAALQC8s0BB/wFzLQC8r0AQQ0H/AXENACARQf4BSw0CIARBwApqIBFBAnRqIBM2AgAgEUEBaiERCyALQX9qIQsgCkF/aiEKIAwgE0EBaiITRw0ACyARIBtHDQ1BACEXIARBwhJqQQBB/wH8CwAgBEHCEmogEmohHCAEQcISaiEYA0AgGyAXIhkgGyAZSRshCyAZQQFqIRcgGCIRQQFqIRhBACEKAkACQANAIBkgCmsiFiASTw0BQQAhEwJAIARBwARqIApqLQAAIhpFDQAgBEHCAGogFmotAAAiFkUNACAaLQC8s0AgFi0AvLNAai0AvK9AIRMLIBEgES0AACATczoAACAKIAtPDQIgCiAKIAtJaiIKIAtLDQIMAAsLIBYgEkGctsAAEFIACyAYIBxHDQALQQAhCiAEQcEUakEAQf8B/AsAAkACQAJAAkAgG0UNACAEQcAEakEBaiELQQAhCgNAAkAgCkEBcQ0AIARBwRRqIApqIAsgCmotAAA6AAALIBsgCkEBaiIKRw0ACyAbQYACTw0CIBtBAnQhFiAEQcAKaiETQQAhCgNAIARBwRRqIBsgDCATKAIAIgtBf3NqQf8BcC0AvK9ALQC8s0AiGkH/AXNBvK/AAGotAAAiERBDQf8BcSIZRQ0SAkACQAJAIARBwhJqIBIgERBDQf8BcSIRRQ0AIBogES0AvLNAIBktALyzQGtBu7HAAGotAAAtALyzQGotALyvQCIRDQELQQAhEQwBCyALQf4BSw0FIAogBEHBAmogC2otAABBAXNqIQoLIAsgDE8NAiATQQRqIRMgFSALaiILIAstAAAgEXM6AAAgFkF8aiIWDQALCyASIBUgDBBMRQ0SDBALIAsgDEGMtsAAEFIAC0EAIBtB/wFB7LXAABAlAAsgC0H/AUH8tcAAEFIACyARQf8BQay2wAAQUgALIAtBAWohGkEAIQpBACEWAkACQANAIAsgCmsiEyASTw0BAkAgBEHABGogCmotAAAiEUUNACAEQcIAaiATai0AACITRQ0AIBEtALyzQCATLQC8s0BqLQC8r0AgFnMhFgsgCyAKTQ0CIAogCyAKS2oiCiALTQ0ADAILCyATIBJBvLbAABBSAAsgGCAEQcAGakH/AfwKAAAgBEEAOgDABiAWQf8BcSIWRQ0ACyAEQcAIaiAEQcAEakGAAvwKAABBACEKA0BBACETAkAgBEHABmogCmotAAAiEUUNACAWQbyzwABqLQAAIBEtALyzQGotALyvQCETCyAEQcAIaiAKaiIRIBEtAAAgE3M6AAACQCAKQf8BRiITDQBB/wEgCkEBaiATGyIKQf8BTQ0BCwsCQCAbQQF0IAsgF2oiGUsNAEEAIQogFkG8s8AAai0AAEH/AXMtALyvQEG8s8AAaiERA0BBACELAkAgBEHABGogCmotAAAiE0UNACATLQC8s0AgES0AAGotALyvQCELCyAEQcAGaiAKaiALOgAAAkAgCkH/AUYiCw0AQf8BIApBAWogCxsiCkH/AU0NAQsLIBkgG2tBAWohGwsgBEHABGogBEHACGpBgAL8CgAADAALCyAKIAwQZgALIAsgChBmAAtBACAKIAxBmKnAABAlAAtBAUENEGYACyAEQQY2AswKIARBBjYCxAogBCAEQQhqNgLICiAEIARBBGo2AsAKIARBEGpBy4LAACAEQcAKahAfDA0LIAQgCSAQajYCwAYgBCANNgLACCAEQQY2AswKIARBBjYCxAogBCAEQcAIajYCyAogBCAEQcAGajYCwAogBEEQakHLgsAAIARBwApqEB8MDAsgCkGAAkHstsAAEFIACyATQYACQdy2wAAQUgALQf8BQf8BQcy2wAAQUgALQQAhCwsgBCALOgDACCAEQQc2AsQKIAQgBEHACGo2AsAKIARBEGpBmIXAACAEQcAKahAfDAYLIApBAXQgEGoiCyAPSw0DIAogEGoiEyAMSw0BIAEoAhgiEyAMSw0EIARBFGogFSATEE8gBCAKNgIwIAQgEDYCLCAEIAk2AjwgBEKAgICAwAA3AiAgBCALNgI0IARBADYCKCAEQQAgDiALayIKIAogDksbIgo2AjggBCAKNgIQIBQgFUEBQQEQSgsgBCgCHCETIAQoAhghCiAEKQIQIR0gBCgCICELIAQoAiQhESAEQcAKaiABLQA0IAIgAxANIAsgEUEEQQQQSiAEKALECiERIAQoAsAKIQMgASgCLCILRQ0BIARBwApqIAogEyALEQYAIAQoAsAKQYCAgIB4Rg0BIB1CIIghHiAEKALICiEBIAQpA8AKIR1BASELIB6nIApBAUEBEEogAyARQQRBBBBKIAEhCgwGCyAEIBMgCWo2AsAGIAQgDTYCwAggBEEGNgLMCiAEQQY2AsQKIAQgBEHACGo2AsgKIAQgBEHABmo2AsAKIARBEGpBy4LAACAEQcAKahBADAMLQQAhCwwECyAEQRBqED8MAQtBACATIAxBqKnAABAlAAsgFCAVQQFBARBKC0EBIQsgBCgCGCEKIAQpAhAhHQsgBSAIQQFBARBKIAYgB0EBQQEQSiALRQ0BIAohBQsgACAFNgIMIAAgHTcCBEEBIQoMAQsgACATNgIMIAAgCjYCCCAAIB1CIIg+AgQgAyARQQRBBBBKQQAhCgsgACAKNgIAIARBwBZqJAAL5xYBEH8jAEHgAWsiBCQAAkACQAJAAkACQAJAAkACQAJAAkACQCADRQ0AIAJFDQECQAJAIAEsAAAiBUF/TA0AIAFBAWohAyAFQf8BcSEGDAELIAEtAAFBP3EhAyAFQR9xIQcCQCAFQV9LDQAgB0EGdCADciEGIAFBAmohAwwBCyADQQZ0IAEtAAJBP3FyIQMCQCAFQXBPDQAgAyAHQQx0ciEGIAFBA2ohAwwBCyADQQZ0IAEtAANBP3FyIAdBEnRBgIDwAHFyIgZBgIDEAEYNAiABQQRqIQMLQQAhBwJAIAEgAmoiBSADayICQQJ2IAJBA3FBAEdqIgJB/v///wNNDQAMCwsgAkEDIAJBA0sbQQFqIghBAnQiAkH8////B0sNCgJAAkAgAg0AQQQhCUEAIQgMAQtBBCEHQQQgAhA4IglFDQsLIAkgBjYCACAEQQE2AjwgBCAJNgI4IAQgCDYCNAJAAkAgAyAFRw0AQQEhBgwBC0EEIQJBASEGAkADQAJAAkAgAywAACIBQX9MDQAgA0EBaiEDIAFB/wFxIQEMAQsgAy0AAUE/cSEHIAFBH3EhCgJAIAFBX0sNACAKQQZ0IAdyIQEgA0ECaiEDDAELIAdBBnQgAy0AAkE/cXIhBwJAIAFBcE8NACAHIApBDHRyIQEgA0EDaiEDDAELIAdBBnQgAy0AA0E/cXIgCkESdEGAgPAAcXIiAUGAgMQARg0CIANBBGohAwsCQCAGIAQoAjRHDQAgBEE0aiAGIAUgA2siB0ECdiAHQQNxQQBHakEBakEEQQQQWSAEKAI4IQkLIAkgAmogATYCACAEIAZBAWoiBjYCPCACQQRqIQIgAyAFRw0ACwsgBCgCOCEJIAQoAjQhCAtBACEDIARBADYCECAEQoCAgIDAADcCCCAGQQJ0IgFBfGpBAnZBAWohByABIQUgCSECAkADQCACKAIAEBhFDQEgA0EBaiEDIAJBBGohAiAFQXxqIgUNAAsgByEDC0EAIQVBACECAkAgAUUNACAJQXxqIQcgAUF8akECdkEBaiEKQQAhAgNAIAcgAWooAgAQGEUNASACQQFqIQIgAUF8aiIBDQALIAohAgsgAw0CQQQhCgwDCwJAAkAgAg0AQQEhA0EAIQEMAQtBASACEDgiA0UNBAJAIAJFDQAgAyABIAL8CgAACyACIQELIABBADYCFCAAQoCAgIDAADcCDCAAIAI2AgggACADNgIEIAAgATYCAAwIC0EAIQsgBEEANgIQIARCgICAgMAANwIIQQEhAUEEIQlBACEIQQAhBkEAIQNBBCEKQQAhBUEAIQIMBgsgAyAGSw0CIARBNGogCSAJIANBAnRqEB0CQCAEKAIQIgEgBCgCCEcNACAEQQhqEFcLIAQoAgwiCiABQQR0aiIFIAQpAjQ3AgAgBSAEKAI8NgIIIAVBADYCDCAEIAFBAWoiBTYCEAtBACEHIAYgAmsiASADIAEgA0sbIgsgA2siAkEASA0DIAMgAUkNAkEAIQJBASEBDAQLQQEgAhBmAAtBACADIAZBjK3AABAlAAtBASEHQQEgAhA4IgENAQsgByACEGYACyAEQQA2AhwgBCABNgIYIAQgAjYCFCAEIAM2AjAgBCALNgIsIARBADYCKCAEIAk2AiAgBCAJIAZBAnRqIgw2AiQgBEHCAGohDSAEQTRqQSJqIQ4gBEGuAWpBAmohDyAEQYwBakECaiEQA0ACQAJAIAMNAEEAIQMCQCAEKAIsIgINAAwCCyAEIAJBf2o2AiwgBCgCICICIAQoAiRGDQEgBCACQQRqNgIgIAQgBCgCKCIHQQFqNgIoIAIhAwwBCyAEQQA2AjAgBCAEQSBqIAMQLiAEKAIEIQMgBCgCACEHCwJAAkACQCADRQ0AIAMoAgAiAkGAAU8NASAEKAIcIgMhAQJAIAQoAhQgA0cNACAEQRRqIANBAUEBQQEQWSAEKAIcIQELIAQoAhggAWogAjoAACAEIANBAWo2AhwMAgsCQCALIAZPDQAgBEE0aiAJIAtBAnRqIAwQHQJAIAQoAhAiAyAEKAIIRw0AIARBCGoQVwsgBCgCDCADQQR0aiICIAQpAjQ3AgAgAiAEKAI8NgIIIAIgCzYCDCAEIANBAWo2AhALIAAgBCgCHDYCCCAAIAQpAhQ3AgAgACAEKQIINwIMIAAgBCgCEDYCFCAIIAlBBEEEEEoMAwsCQAJAAkAgAkHfwABKDQAgAkH1v39qQQVJDQIgAkGtAUcNAQwCCyACQaC/f2pBBUkNASACQf/9A0YNAQtBACEDA0AgBEHAAWogA2oiAUEAOgAAIAFBBGpBADYCACADQQhqIgNBIEcNAAsgECAEKQLYATcBGCAQIAQpAtABNwEQIBAgBCkCyAE3AQggECAEKQLAATcBAEEAIQMDQCAEQcABaiADakEANgIAIANBBGoiA0EQRw0ACyAPIAQpAsgBNwEIIA8gBCkCwAE3AQAgBEEAOwFUIA4gBEGMAWpBIvwKAAAgDSAEKQGuATcBACANIAQpAbYBNwEIIA0gBC8BvgE7ARAgBEEBOgCEASAEQgA3AnwgBCACNgJ4IARBADsBQCAEQQA2AjQgBEEAOgCIASAEQYCAxAA2AjwgBEE0ahAFIQEgBEE0ahAFIQMCQAJAIAFBgIDEAEYNACADQYCAxABHDQAgAUFQakEKSQ0BIAFB3///AHFBv39qQRpJDQFBeCEDA0AgA0UNASADQYytwABqIREgA0EEaiEDIBEoAgAgAUcNAAwCCwtBACEDA0ACQCADQdgCRw0AQYCAxAAhAQwCCyADQayqwABqIQEgA0EIaiEDIAEoAgAgAkcNAAsgASgCBCEBCwJAIAQoAlxBgIDEAEcNACAEKAJgIAQoAmRBBEEIEEoLAkAgBCgCREGAgMQARw0AIAQoAkggBCgCTEEEQQQQSgsCQAJAAkAgAUGAgMQARg0AAkAgBSAEKAIIRw0AIARBCGoQVwsgBCgCDCIKIAVBBHRqIgMgBzYCDCADIAE2AgggAyACNgIEIANBgYCAgHg2AgBBASECIAQgBUEBaiIFNgIQIAQoAhwhAwJAIAFBgAFJIhENAEECIQIgAUGAEEkNAEEDQQQgAUGAgARJGyECCyADIQcCQCACIAQoAhQgA2tNDQAgBEEUaiADIAJBAUEBEFkgBCgCHCEHCyAEKAIYIAdqIQcgEQ0BIAFBP3FBgH9yIREgAUEGdiESAkAgAUGAEE8NACAHIBE6AAEgByASQcABcjoAAAwDCyABQQx2IRMgEkE/cUGAf3IhEgJAIAFB//8DSw0AIAcgEToAAiAHIBI6AAEgByATQeABcjoAAAwDCyAHIBE6AAMgByASOgACIAcgE0E/cUGAf3I6AAEgByABQRJ2QXByOgAADAILIAQoAhwiASEDAkBBAkEDQQQgAkGAgARJGyACQYAQSRsiByAEKAIUIAFrTQ0AIARBFGogASAHQQFBARBZIAQoAhwhAwsgBCgCGCADaiEDIAJBP3FBgH9yIREgAkEGdiESAkACQCACQf8PSw0AIAMgEkHAAXI6AABBASECDAELIAJBDHYhEyASQT9xQYB/ciESAkAgAkH//wNLDQAgAyASOgABIAMgE0HgAXI6AABBAiECDAELIAMgEjoAAiADIBNBP3FBgH9yOgABIAMgAkESdkFwcjoAAEEDIQILIAMgAmogEToAACAEIAEgB2o2AhwMAwsgByABOgAACyAEIAIgA2o2AhwMAQsCQCAFIAQoAghHDQAgBEEIahBXIAQoAgwhCgsgCiAFQQR0aiIDIAc2AgggAyACNgIEIANBgICAgHg2AgAgBCAFQQFqIgU2AhALIAQoAjAhAwwACwsgBEHgAWokAA8LIAcgAhBmAAu6EwINfwF+IwBBMGsiASQAIABBJGohAiAAQSBqIQMgAEEUaiEEIABBEGohBSAAQQxqIQYgACgCBCEHIAAoAgAhCAJAA0AgACgCHCEJIAAvAQwhCiAAKAIYIQsgACgCECIMQYCAxABGIQ0CQANAAkACQAJAAkACQAJAAkACQAJAAkAgCEEBRg0AAkAgCA4DAwACAwsACwJAAkAgDQ0AIApB//8DcSIIQQVJDQFBACAIQQRBnK3AABAlAAsgByAJTw0IIAshBQwLCyAHIAhJDQpBACEKIAhFDQhBACEKIAZBADsBAAwICyAMQYCAxABGDQIgCkH//wNxIghBBUkNAUEAIAhBBEGcrcAAECUACwNAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCAAKAJMIggNAAJAIAAoAkQiCkGBgMQARg0AIABBgIDEADYCRCAKQYCAxABGDQAgAC0AUEUNBCAKQYABSQ0FIApBgKh9aiIIQaTXAEkNAyAKIAogCkG5893xeWwgCkGmsoWKA2wiCHOtQokefkIgiKdBAXQvAay/Q2pBufPd8XlsIAhzrUKJHn5CIIinQQN0KQLA+0MiDqdHDQIgDkIgiKdB//8DcSIIQY0tTw0IQYwtIAhrIgcgDkIwiKciCkkNCSAIQQJ0QYjsxQBqIQgMFAsgACgCKEGAgMQARw0FIAAoAjQiCEUNBiAAKAIwIQoMCgsgACgCKCEHDAoLIAFBCGogChAwIAEoAggiCEUNAiABKAIMIQoMEQsgAyAIQf//A3EiCkHMBG4iB0GAInIQESADIAggB0HMBGxrQf//A3FBHG5B4SJqEBEgCkEccCIIRQ0RIAhBpyNqIQoMAQsgCkGAAUkNAAJAIApBgKh9aiIIQaTXAEkNACABQRBqIAoQMCABKAIQIghFDQEgASgCFCIKRQ0RIApBAnQhCgNAIAMgCCgCABARIAhBBGohCCAKQXxqIgoNAAwSCwsgAyAIQf//A3EiCkHMBG4iB0GAInIQESADIAggB0HMBGxrQf//A3FBHG5B4SJqEBEgCkEccCIIRQ0QIAhBpyNqIQoLIAMgChARDA8LIAMvAQAiCA0DCyAAQgI3AgBBAiEIQQAhByAAKAIIIgpBgIDEAEYNFyAAQYCAxAA2AggMGAsgCEGMLUGMLUG4oMcAECUAC0EAIAogB0HIoMcAECUACyAIQQVPDQIgAiEKCwJAIAhBAkkNAAJAIAhBFUkNACAKIAgQNgwBCyAKIAgQNwsCQAJAIAAoAigiB0GAgMQARw0AIAAoAjQhCAwBCyADLwEAIQgLIAAgCDYCTAsgACgCSCEKAkACQCAHQYCAxABHIgsNACAAKAI0IQkgACgCMCENDAELIAMvAQAiCUEFTw0CIAIhDQsgCiAJTw0CIA0gCkEDdGooAgQhDAJAIApBAWoiCiAIRg0AIAAgCjYCSAwHCwJAAkAgCw0AIAAoAjQhCgwBCyADLwEAIQoLIAogCGshCwJAIAogCEYNAEEAIQhBBCEKA0AgCCAAKAJMaiEHAkACQCAAKAIoQYCAxABGDQAgAy8BACINQQVPDQkgByANTw0HIAIhCQwBCyAHIAAoAjQiDU8NBiAAKAIwIQkLIAggDU8NBiAJIAdBA3RqIgctAAAhDSAJIApqIgkgBygCBDYCACAJQXxqIA06AAAgCkEIaiEKIAsgCEEBaiIIRw0ACyAAKAIoIQcLAkACQCAHQYCAxABHDQAgCyAAKAI0Sw0BIAAgCzYCNAwBCyALIAMvAQBPDQAgAyALOwEACyAAQgA3AkgMBgtBACAIQQRBrK/AABAlAAtBACAJQQRBnK3AABAlAAsgCiAJQeypwAAQUgALIAcgDUH8qcAAEFIACyAIIA1BjKrAABBSAAtBACANQQRBnK3AABAlAAsgDCAMQbnz3fF5bCAMQaayhYoDbCIIc61CyAd+QiCIp0EBdC8BqINBakG5893xeWwgCHOtQsgHfkIgiKdBAnQoAriSQSIIQQAgDCAIQQh2RhshCAJAAkACQAJAAkAgACgCCCIKQYCAxABGDQAgAC0AVEUNASAALQBVIAhB/wFxIgdPDQMgCiAMEA4iCkGAgMQARg0CIAAgCjYCCAwGCwJAIAhB/wFxDQAgACAMNgIIDAYLIAwhCgwQCwJAIAogDBAOIgdBgIDEAEYNACAAIAc2AggMBQsCQCAIQf8BcQ0AIAAgDDYCCAwQCwJAIAUoAgBBgIDEAEcNAAJAIAAoAhwiCiAAKAIURw0AIAQQVQsgACgCGCAKQQJ0aiAMNgIAIAAgCkEBajYCHAwDCwJAIAYvAQAiCkEDSw0AIAYgCkEBajsBACAFIApBAnRqIAw2AgAMAwsgAUEcaiAGIAwQIwJAIAUoAgBBgIDEAEcNACAAKAIUIAAoAhhBBEEEEEoLIAYgASgCLDYCECAGIAEpAiQ3AgggBiABKQIcNwIADAILAkAgBSgCAEGAgMQARw0AAkAgACgCHCIKIAAoAhRHDQAgBBBVCyAAKAIYIApBAnRqIAw2AgAgACAKQQFqNgIcDAILAkAgBi8BACIKQQNLDQAgBiAKQQFqOwEAIAUgCkECdGogDDYCAAwCCyABQRxqIAYgDBAjAkAgBSgCAEGAgMQARw0AIAAoAhQgACgCGEEEQQQQSgsgBiABKAIsNgIQIAYgASkCJDcCCCAGIAEpAhw3AgAMAQsCQCAHRQ0AAkAgBSgCAEGAgMQARw0AAkAgACgCHCIKIAAoAhRHDQAgBBBVCyAAKAIYIApBAnRqIAw2AgAgACAKQQFqNgIcDAILAkAgBi8BACIKQQNLDQAgBiAKQQFqOwEAIAUgCkECdGogDDYCAAwCCyABQRxqIAYgDBAjAkAgBSgCAEGAgMQARw0AIAAoAhQgACgCGEEEQQQQSgsgBiABKAIsNgIQIAYgASkCJDcCCCAGIAEpAhw3AgAMAQsgAEEAOgBUIAAgDDYCCCAAQgE3AgAMDQsgACAIOgBVIABBAToAVAwBCyAKRQ0AIApBAnQhCgNAIAMgCCgCABARIAhBBGohCCAKQXxqIgoNAAwBCwsLIAUhCyAHIAhJDQEgCkH//wNxRQ0DIAZBADsBAAwDCyAHIAlPDQELIAsgB0ECdGooAgAhCiAAIAdBAWo2AgQgAEECNgIADAcLIABBADYCHAsgACgCCCEKIABBgIDEADYCCAwFC0EAIQkgAEEANgIcC0EAIQggAEEANgIADAALCwsgBSAHQQJ0aigCACEKIAAgB0EBajYCBCAAQQE2AgALIAFBMGokACAKC+wRAQ9/IwBB4ABrIgQkACAEQRhqIAEQWyAEKAIcIQUgBCgCGCEGIAQgAzYCWCAEIAI2AlQgBCADNgJQIARBEGogBEHQAGoQSyAEKAIQIQcCQAJAAkACQAJAIAQoAhQiCCAGKAIYRw0AAkAgBigCLCIDRQ0AIARB0ABqIAcgCCADEQYAIAQoAlAiA0GAgICAeEYNACAEIAQpAlQ3AiwgBCADNgIoDAQLAkACQAJAIAgNAEEAIQEgBEEANgI8IARCgICAgBA3AjRBASEJDAELQQEgCBA4IglFDQEgBEEANgI8IAQgCTYCOCAEIAg2AjQCQCAIRQ0AIAkgByAI/AoAAAsgBCAINgI8IAghAQsCQAJAIAYoArgCIgIgCGoiAyABSw0AIAQgAzYCPAwBCwJAIAMgAWsiAiABIAFrTQ0AIARBNGogASACQQFBARBZIAQoAjwhASAEKAI4IQkLIAkgAWohAwJAIAJBAkkNAAJAIAJBf2oiAkUNACADQQAgAvwLAAsgCSACIAFqIgFqIQMLIANBADoAACAEIAFBAWoiAzYCPCAGKAK4AiECCyADQf8BSw0CIAMgAk0NAgJAIAJFDQAgCSADIAJraiEKAkAgAkUNACAKQQAgAvwLAAtBACACayELIApBAWohDCACQX9qIQ0gBkE5aiEOIAkgA2oiD0F/aiEQA0AgCS0AACEDIAotAAAhAQJAIA1FDQAgCiAMIA38CgAACyAQQQA6AAACQCADIAFGDQAgAyABc0G8s8AAaiERIAshAyAOIQEDQAJAAkAgAS0AACICDQBBACECDAELIAItALyzQCARLQAAai0AvK9AIQILIA8gA2oiEiASLQAAIAJzOgAAIAFBAWohASADQQFqIgMNAAsLIAlBAWoiCSAKRw0ACyAEKAI8IgIgBigCICILayEBAkAgAiALSQ0AIAQoAjghD0EAIRICQAJAAkAgCw0AQQEhDEEAIQ4MAQtBASALEDgiDEUNAQJAIAtFDQAgDCAPIAFqIAv8CgAACyALIQ4LAkAgBigCCCIDQQBIDQACQAJAIAMNAEEAIQMgBEEANgJIIARCgICAgBA3AkBBASEKDAELIAYoAgQhEUEBIRJBASADEDgiCkUNASAEQQA2AkggBCAKNgJEIAQgAzYCQAJAIANFDQAgCiARIAP8CgAACyAEIAM2AkgLAkAgASADIANrTQ0AIARBwABqIAMgAUEBQQEQWSAEKAJEIQogBCgCSCEDCwJAIAIgC0YNACABRQ0AIAogA2ogDyAB/AoAAAtBACENIARBADYCWCAEQoCAgIAQNwJQAkACQCADIAFqIgMNAEEBIRFBACEPDAELIAogA2ohDUEAIRJBASEJQQAhAkEAIREDQCACQQ1qIQMgEUEIdCAKLQAAIhByIREDQCARIANBdmoiAnZBH3EtAPu5QCEPAkAgEiIBIAQoAlBHDQAgBEHQAGoQSSAEKAJUIQkLIAkgAWogDzoAACAEIAFBAWoiEjYCWCADQXtqIQMgAkEFSw0ACyAKQQFqIgogDUcNAAsgEEEKIANrdEEfcS0A+7lAIQMCQCASIAQoAlAiDUcNACAEQdAAahBJIAQoAlAhDQsgBCgCVCIRIBJqIAM6AAAgAUECaiEPCwJAAkACQAJAAkAgDyAGKAIoRw0AIAYoAhQiECAPSw0CIARB0ABqIBEgECAGKAIQIBBBAxAkAkAgBCgCUCIDQYCAgIB4Rg0AIAQgBCkCVDcCLCAEIAM2AigMAgsCQAJAIA4NAEEAIQMMAQsgBigCJCEDIBAhASAMIQIDQCADIAFqIhIgA0kNBSASIA9LDQUgBEEINgIoIAQgAjYCJCAEQdAAakGQqcAAIARBJGoQHyAEKAJQIQkgBEHQAGogESABaiADIAQoAlQiASAEKAJYQQgQJAJAIAQoAlAiCkGAgICAeEYNACAEIAQpAlQ3AiwgBCAKNgIoIAkgAUEBQQEQSgwECyAJIAFBAUEBEEogAkEBaiECIBIhASALQX9qIgsNAAsgAyAObCEDCyAPIAMgEGoiA0kNBAJAIA8gA0YNACAPIANrIQEgESADaiEDA0ACQCADLQAAIgJBv39qQf8BcUEZSw0AIAMgAkEgcjoAAAsgA0EBaiEDIAFBf2oiAQ0ACwsgBCAPNgIwIAQgETYCLCAEIA02AiggBCgCQCAEKAJEQQFBARBKIA4gDEEBQQEQSiAEKAI0IAQoAjhBAUEBEEogBCgCMCECIAQoAiwhAyAEKAIoIQEMDgsgBCAPNgJMIARBBjYCXCAEIAZBKGo2AlggBEEGNgJUIAQgBEHMAGo2AlAgBEEoakHsgsAAIARB0ABqEB8LIA0gEUEBQQEQSiAEKAJAIAQoAkRBAUEBEEogDiAMQQFBARBKDAoLQQAgECAPQeCowAAQJQALIAEgEiAPQYCpwAAQJQALIAMgDyAPQfCowAAQJQALIBIgAxBmAAtBASALEGYACyABIAIgAkHQqMAAECUAC0EAQQBB3LXAABBSAAtBASAIEGYACyAEIAg2AkAgBEEGNgJcIAQgBkEYajYCWCAEQQY2AlQgBCAEQcAAajYCUCAEQShqQauDwAAgBEHQAGoQHwwCCyAEQQE6AEAgBEEHNgJUIAQgBEHAAGo2AlAgBEEoakGYhcAAIARB0ABqEB8LIAQoAjQgBCgCOEEBQQEQSgsgBCAEKAIwNgJIIAQgBCkCKDcDQCAEQQU2AjggBCAEQcAAajYCNCAEQdAAakGYhcAAIARBNGoQHyAEKAJUIgEgBCgCWBAAIQMgBCgCUCABQQFBARBIIAQoAkAgBCgCREEBQQEQSEGAgICAeCEBCwJAIAhFDQAgB0EBIAgQHAsgBSAFKAIAQX9qNgIAAkACQCABQYCAgIB4Rw0AQQEhAUEAIQJBACESDAELIAQgAjYCWCAEIAM2AlQgBCABNgJQIARBCGogBEHQAGoQSyAEKAIMIRIgBCgCCCECQQAhA0EAIQELIAAgATYCDCAAIAM2AgggACASNgIEIAAgAjYCACAEQeAAaiQAC/ANAhB/AX4jAEEQayIGJAACQAJAAkACQCABQSFPDQAgASEHDAELIAJBeGohCAJAA0ACQCAEDQAgACABIAIgA0EBEAsMBAsgACABQQN2IglBOGxqIQogACAJQQV0aiEHAkACQCABQcAASQ0AIAAgByAKIAkQOiELDAELIAAgCiAHIAAtAAAiCSAHLQAAIgxJIg0gDCAKLQAAIg5JcxsgDSAJIA5JcxshCwsgBEF/aiEEIAYgCygCBDYCBCAGIAstAAAiDToAACALIABrQQN2IQ8CQAJAIAVFDQAgBS0AACANQf8BcU8NAQsgAyABSQ0FIAIgAUEDdCIQaiEKQQAhByAAIQkgDyERA0ACQCAJIABBACARQX1qIgwgDCARSxtBA3RqIhJPDQAgCy0AACETA0AgAiAKQXhqIAktAAAgDUH/AXFJIg4bIAdBA3RqIAkpAgA3AgAgAiAKQXBqIAlBCGoiFC0AACATQf8BcSIMSSIVGyAHIA5qIgdBA3RqIBQpAgA3AgAgAiAKQWhqIAlBEGoiDi0AACAMSSIUGyAHIBVqIgdBA3RqIA4pAgA3AgAgAiAKQWBqIgogCUEYaiIOLQAAIAxJIgwbIAcgFGoiB0EDdGogDikCADcCACAHIAxqIQcgCUEgaiIJIBJJDQALIBMhDQsCQCAJIAAgEUEDdGoiDk8NACALLQAAIQ0DQCACIApBeGoiCiAJLQAAIA1B/wFxSSIMGyAHQQN0aiAJKQIANwIAIAcgDGohByAJQQhqIgkgDkkNAAsLAkAgESABRg0AIApBeGoiCiAHQQN0aiAJKQIANwIAIAlBCGohCSABIREMAQsLAkAgB0EDdCINRQ0AIAAgAiAN/AoAAAsgASAHayEOAkAgASAHRg0AIAggEGohCSAAIA1qIQogDiEMA0AgCiAJKQIANwIAIAlBeGohCSAKQQhqIQogDEF/aiIMDQALCyAHRQ0AIAEgB0kNAiAAIA1qIA4gAiADIAQgBhAHIAchASAHQSFJDQMMAQsgAyABSQ0EIAIgAUEDdCISaiEKQQAhDCAAIQkDQAJAIAkgAEEAIA9BfWoiByAHIA9LG0EDdGoiFU8NACALLQAAQf8BcSEHA0AgAiAKQXhqIAcgCS0AAE8iDRsgDEEDdGogCSkCADcCACACIApBcGogByAJQQhqIg4tAABPIhQbIAwgDWoiDEEDdGogDikCADcCACACIApBaGogByAJQRBqIg0tAABPIg4bIAwgFGoiDEEDdGogDSkCADcCACACIApBYGoiCiAHIAlBGGoiDS0AAE8iFBsgDCAOaiIMQQN0aiANKQIANwIAIAwgFGohDCAJQSBqIgkgFUkNAAsLAkAgCSAAIA9BA3RqIg1PDQAgCy0AAEH/AXEhDgNAIAIgCkF4aiIKIA4gCS0AAE8iBxsgDEEDdGogCSkCADcCACAMIAdqIQwgCUEIaiIJIA1JDQALCwJAIA8gAUYNACACIAxBA3RqIAkpAgA3AgAgCUEIaiEJIAxBAWohDCAKQXhqIQogASEPDAELCwJAIAxBA3QiDkUNACAAIAIgDvwKAAALIAEgDEYNAyAIIBJqIQkgACAOaiEKIAEgDGsiByENA0AgCiAJKQIANwIAIAlBeGohCSAKQQhqIQogDUF/aiINDQALAkAgASAMSQ0AIAAgDmohAEEAIQUgByEBIAdBIUkNAwwBCwsgDCABIAFBrLnAABAlAAtBkrnAAEETQZy5wAAQXwwCCyAHQQJJDQAgAyAHQRBqSQ0BIAdBAXYhBAJAAkACQCAHQQ9LDQAgB0EHTQ0BIAAgAhAvIAAgBEEDdCIJaiACIAlqEC9BBCEPDAILIAAgAiACIAdBA3RqIgkQZCAAIARBA3QiCmogAiAKaiAJQcAAahBkQQghDwwBCyACIAApAgA3AgAgAiAEQQN0IglqIAAgCWopAgA3AgBBASEPC0EAIQkgBkEANgIIIA9BA3QhDSAGIAQ2AgwgByAEayEDIAZBCGohBQNAIAkhCwJAIA8gAyAEIAUgCUECdGooAgAiCRsiEU8NACAAIAlBA3QiCWohAUEIIRUgAiAJaiITIRIgDyEUA0AgEyAUQQN0IglqIgogASAJaikCACIWNwIAAkAgCkF4ai0AACAWp0H/AXEiDk0NACAVIQogEiEJAkADQCAJIA1qIgwgDEF4aikCADcCAAJAIA0gCkcNACATIQkMAgsgCkEIaiEKIAlBeGohCSAMQXBqLQAAIA5LDQALIAkgDWohCQsgCSAWQv+BgIBwgzcCAAsgFUF4aiEVIBJBCGohEiAUQQFqIhQgEUcNAAsLQQEhCSALQQFxRQ0ACyACIAcgABAmCyAGQRBqJAAPCwALkAsCA38BfiMAQdAAayIFJAAgBSADNgIIIAUgAjYCBAJAAkAgAUGBAkkNAEGAAiEGAkADQCAAIAZqLAAAQb9/Sg0BIAZBf2oiBg0AC0EAIQYLIAUgADYCDCAFIAY2AhBBBUEAIAYgAUkiBxshBkHIlsAAQQEgBxshBwwBCyAFIAE2AhAgBSAANgIMQQAhBkEBIQcLIAUgBjYCGCAFIAc2AhQCQAJAAkACQAJAAkACQAJAIAIgAUsNACADIAFLDQEgAiADSw0CIAJFDQQgAiABTw0EIAAgAmosAABBv39KDQQgAiEGAkADQCAAIAZqLAAAQb9/Sg0BIAZBf2oiBg0AC0EAIQYLA0AgACACaiwAAEG/f0oNBCABIAJBAWoiAkcNAAsgASECDAMLIAVBEK1CIIYiCCAFQRRqrYQ3AzggBSAIIAVBDGqthDcDMCAFQQatQiCGIAVBBGqthDcDKEG5gMAAIAVBKGogBBBfAAsgBUEQrUIghiIIIAVBFGqthDcDOCAFIAggBUEMaq2ENwMwIAVBBq1CIIYgBUEIaq2ENwMoQeiAwAAgBUEoaiAEEF8ACyAFQRCtQiCGIgggBUEUaq2ENwNAIAUgCCAFQQxqrYQ3AzggBUEGrUIghiIIIAVBCGqthDcDMCAFIAggBUEEaq2ENwMoQY+AwAAgBUEoaiAEEF8ACyAFIAY2AhwgBSACNgIgAkAgBiACSw0AAkAgBkUNAAJAIAYgAUkNACAGIAFGDQEMAgsgACAGaiwAAEFASA0BCwJAAkAgAiABSQ0AIAIgAUcNAgwBCyAAIAJqLAAAQb9/TA0BCyAGIAJGDQICQAJAIAAgBmoiACwAACIGQX9MDQAgBkH/AXEhBgwBCyAALQABQT9xIQMgBkEfcSECAkAgBkFfSw0AIAJBBnQgA3IhBgwBCyADQQZ0IAAtAAJBP3FyIQMCQCAGQXBPDQAgAyACQQx0ciEGDAELIANBBnQgAC0AA0E/cXIgAkESdEGAgPAAcXIhBgsgBSAGNgIkIAVBEK1CIIYiCCAFQRRqrYQ3A0ggBSAIIAVBDGqthDcDQCAFQRGtQiCGIAVBHGqthDcDOCAFQRKtQiCGIAVBJGqthDcDMCAFQQatQiCGIAVBBGqthDcDKEGVgcAAIAVBKGogBBBfAAsgACABIAYgAiAEEGsACyADRQ0CIAMgAU8NAiAAIANqLAAAQb9/Sg0CIAMhBgJAA0AgACAGaiwAAEG/f0oNASAGQX9qIgYNAAtBACEGCwJAA0AgACADaiwAAEG/f0oNASABIANBAWoiA0cNAAsgASEDCyAFIAY2AhwgBSADNgIgIAYgA0sNAQJAIAZFDQACQCAGIAFJDQAgBiABRg0BDAMLIAAgBmosAABBQEgNAgsCQAJAIAMgAUkNACADIAFHDQMMAQsgACADaiwAAEG/f0wNAgsgBiADRg0AAkACQCAAIAZqIgAsAAAiBkF/TA0AIAZB/wFxIQYMAQsgAC0AAUE/cSEDIAZBH3EhAgJAIAZBX0sNACACQQZ0IANyIQYMAQsgA0EGdCAALQACQT9xciEDAkAgBkFwTw0AIAMgAkEMdHIhBgwBCyADQQZ0IAAtAANBP3FyIAJBEnRBgIDwAHFyIQYLIAUgBjYCJCAFQRCtQiCGIgggBUEUaq2ENwNIIAUgCCAFQQxqrYQ3A0AgBUERrUIghiAFQRxqrYQ3AzggBUESrUIghiAFQSRqrYQ3AzAgBUEGrUIghiAFQQhqrYQ3AyhB5oHAACAFQShqIAQQXwALIAQQfgALIAAgASAGIAMgBBBrAAsgBUEQrUIghiIIIAVBFGqthDcDOCAFIAggBUEMaq2ENwMwIAVBBq1CIIYgBUEIaq2ENwMoQeiAwAAgBUEoaiAEEF8AC9sLARJ/IwBB4AFrIgQkACAEQRhqIAEQWyAEKAIcIQUgBCgCGCEBIAQgAzYCNCAEIAI2AjAgBCADNgIsIARBEGogBEEsahBLIAQoAhQhBiAEKAIQIQcgBEEsaiABKAIEIAEoAggQEgJAAkACQAJAAkACQCAEKAIsIghBgICAgHhGDQAgBCgCNCEDIAQoAjAhCQwBCyAEQfiqxwA2AsgBIARBACgC+KJHNgLUAUEBQQEgBEHUAWogBEHIAWpBA0EEEEYhCUEAIAQoAtQBNgL4okcgCUUNASAJQejGpaMGNgAAQQQhA0EEIQgLIARBLGogASAHIAYQAyAEKAI4IQIgBCgCNCEKIAQoAjAhCyAEKAIsDQEgBCADNgIoIAQgCTYCJEEAIQEDQCAEQSxqIAFqIgNBADoAECADQgA3AAggA0IANwAAIAFBEWoiAUGZAUcNAAtBBCEMQQghDQJAIAJFDQAgCiACaiEOQQghDUEEIQwgCiEPA0AgDy0AAEH/AXEhAkEAIQEDQAJAAkAgAiABQQF0Qf4BcXYiA0EBcQ0AIA0gDUEAR2shDQwBCyANQQ8gDUEPSRtBAWohDQsCQAJAIANBAnFFDQAgDEEHIAxBB0kbQQFqIQwMAQsgDCAMQQBHayEMCyAEQSxqIAxBEWxqIA1qIgMgAy0AAEEBaiIDQf8BIANB/wFJGzoAACABQQFqIgFBBEcNAAsgD0EBaiIPIA5HDQALCyAEQQw2AswBIAQgBEEkajYCyAEgBEHUAWpB3I3AACAEQcgBahAfIARByAFqIAQoAtgBIgEgBCgC3AEQNSAEKALUASABQQFBARBKQQAhECAEQSxqIREgBCgC0AEhAUEAIRIDQCABIQMCQCAEKALIASABRw0AIARByAFqIAFBAUEBQQEQWSAEKALQASEDCyAEKALMASITIANqQfwAOgAAIAQgAUEBaiIDNgLQAUEAIQIgEEEERiEUIBAgDEYhFQNAAkACQCACQQhHDQAgFEUNAEHTACEBDAELAkAgDSACRw0AIBVFDQBBxQAhAQwBCyARIAJqLQAAIgFBDiABQQ5JGy0AvbhAIQELIAMhDwJAQQFBAiABQYABSRsiDiAEKALIASADa00NACAEQcgBaiADIA5BAUEBEFkgBCgC0AEhDyAEKALMASETCyATIA9qIQ8CQCABQf8ATQ0AIA8gAUE/cUGAAXI6AAEgAUEGdkFAciEBCyAPIAE6AAAgBCAOIANqIgM2AtABIAJBAWoiAkERRw0ACwJAIAQoAsgBIANrQQFLDQAgBEHIAWogA0ECQQFBARBZIAQoAtABIQMLIBBBAWohECAEKALMASICIANqQfwUOwAAIAQgA0ECaiIBNgLQASARQRFqIREgEkERaiISQZkBRg0DDAALC0EBQQQQZgALIAggCUEBQQEQSiAEIAI2AtwBIAQgCjYC2AEgBCALNgLUASAEQQU2AswBIAQgBEHUAWo2AsgBIARBLGpBmIXAACAEQcgBahAfIAQoAjAiASAEKAI0EAAhAiAEKAIsIAFBAUEBEEggBCgC1AEgBCgC2AFBAUEBEEhBgICAgHghAwwBCyAEQdQBakEBQQAQNSAEKALYASEMAkACQAJAIAQoAtwBIg0gBCgCyAEiAyABa00NACAEQcgBaiABIA1BAUEBEFkgBCgCyAEhAyAEKALMASECIAQoAtABIQEMAQsgDUUNAQsgDUUNACACIAFqIAwgDfwKAAALIAQoAtQBIAxBAUEBEEogCCAJQQFBARBKIAsgCkEBQQEQSiABIA1qIQELAkAgBkUNACAHQQEgBhAcCyAFIAUoAgBBf2o2AgACQAJAIANBgICAgHhHDQBBASEBQQAhA0EAIQ0MAQsgBCABNgI0IAQgAjYCMCAEIAM2AiwgBEEIaiAEQSxqEEsgBCgCDCENIAQoAgghA0EAIQJBACEBCyAAIAE2AgwgACACNgIIIAAgDTYCBCAAIAM2AgAgBEHgAWokAAuVDAEGfyMAQSBrIgMkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAEOKAIBAQEBAQEBAQMFAQEEAQEBAQEBAQEBAQEBAQEBAQEBAQEJAQEBAQcACyABQdwARg0FCwJAIAJBAXFFDQAgAUH/BUsNBwsgAUEgSQ0LIAFB/wBJDQoMCQsgAEIANwECIABB3OAAOwEADAcLIABCADcBAiAAQdzoATsBAAwGCyAAQgA3AQIgAEHc5AE7AQAMBQsgAEIANwECIABB3NwBOwEADAQLIABCADcBAiAAQdy4ATsBAAwDCyACQYACcUUNBCAAQgA3AQIgAEHczgA7AQAMAgsgARAiRQ0CIANBADoADiADQQA7AQwgAyABQRR2LQCMlkA6AA8gAyABQQR2QQ9xLQCMlkA6ABMgAyABQQh2QQ9xLQCMlkA6ABIgAyABQQx2QQ9xLQCMlkA6ABEgAyABQRB2QQ9xLQCMlkA6ABAgA0EMaiABQQFyZ0ECdiICaiIEQfsAOgAAIARBf2pB9QA6AAAgA0EMaiACQX5qIgJqQdwAOgAAIAAgAykBDDcAACADQf0AOgAVIAMgAUEPcS0AjJZAOgAUIAAgAy8BFDsACAwFCyACQf///wdxQYCABEkNAiAAQgA3AQIgAEHcxAA7AQALQQIhAUEAIQIMBAsCQAJAAkACQCABQYCABEkNACABQYCACEkNASABQf7//wBxIgJBrp0LRg0FIAFB4P//AHFB4M0KRg0FIAJBnvAKRg0FIAFBkKh0akFwSw0FIAFBgJB0akHdbEsNBSABQYCAdGpBnXRLDQUgAUGw2XNqQXpLDQUgAUGA/kdqQfnmVEsNBSABQfCDOEkNBAwFC0EAIQUgAUEIdkH/AXEhBkEAIQIDQCACQQJqIQcgBSACLQDpnkAiBGohCAJAIAItAOieQCICIAZGDQAgAiAGSw0DIAghBSAHIQIgB0HMAEcNAQwDCwJAAkACQCAIIAVJDQAgCEGcAksNACAERQ0CIAVBtJ/AAGohAgwBCyAFIAhBnAJB9KPAABAlAAsDQCACLQAAIAFB/wFxRg0HIAJBAWohAiAEQX9qIgQNAAsLIAghBSAHIQIgB0HMAEcNAAwCCwtBACEFIAFBCHZB/wFxIQZBACECAkADQCACQQJqIQcgBSACLQDBmEAiBGohCAJAIAItAMCYQCICIAZGDQAgAiAGSw0CIAghBSAHIQIgB0HcAEcNAQwCCwJAAkACQCAIIAVJDQAgCEHUAUsNACAERQ0CIAVBnJnAAGohAgwBCyAFIAhB1AFB9KPAABAlAAsDQCACLQAAIAFB/wFxRg0HIAJBAWohAiAEQX9qIgQNAAsLIAghBSAHIQIgB0HcAEcNAAsLIAFB//8DcSEFQQEhBEEAIQIDQCACQQFqIQcCQAJAIAIsAPCaQCIIQQBIDQAgByECDAELAkAgB0H4A0YNACAIQf8AcUEIdCACQfGawABqLQAAciEIIAJBAmohAgwBC0GEpMAAEH4ACyAFIAhrIgVBAEgNAiAEQQFzIQQgAkH4A0cNAAwCCwtBASEEIAEhBUEAIQIDQCACQQFqIQcCQAJAIAIsANChQCIIQQBIDQAgByECDAELAkAgB0GkAkYNACAIQf8AcUEIdCACQdGhwABqLQAAciEIIAJBAmohAgwBC0GEpMAAEH4ACyAFIAhrIgVBAEgNASAEQQFzIQQgAkGkAkcNAAsLIARBAXFFDQELIAAgATYCAEGBASEBQYABIQIMAgsgA0EAOgAYIANBADsBFiADIAFBFHYtAIyWQDoAGSADIAFBBHZBD3EtAIyWQDoAHSADIAFBCHZBD3EtAIyWQDoAHCADIAFBDHZBD3EtAIyWQDoAGyADIAFBEHZBD3EtAIyWQDoAGiADQRZqIAFBAXJnQQJ2IgJqIgRB+wA6AAAgBEF/akH1ADoAACADQRZqIAJBfmoiAmpB3AA6AAAgACADKQEWNwAAIANB/QA6AB8gAyABQQ9xLQCMlkA6AB4gACADLwEeOwAIC0EKIQELIAAgAToADSAAIAI6AAwgA0EgaiQAC94JAhN/An4jAEHQAmsiBSQAQoCAgICAgICAwAAgAa0iGIAiGSAYfkKAgICAgICAgMAAUq0hGAJAAkAgAUGBIEkNAEEBIAFBAXJnQR9zIgZBAXYgBkEBcWoiBnQgASAGdmpBAXYhBwwBCyABIAFBAXZrIgZBwAAgBkHAAEkbIQcLIBkgGHwhGSAAQXhqIQggAEEQaiEJQQEhBkEAIQpBACELA0BBACEMQQEhDQJAIAEgCksiDkUNACAAIApBA3QiD2ohEAJAAkAgASAKayIRIAdJDQACQAJAIBFBAk8NACARIRIMAQsCQAJAAkACQCAQLQAIIhMgEC0AAEkiFA0AQQIhEiARQQJGDQQgCSAPaiEVQQIhEgNAIBNB/wFxIRYgFS0AACITIBZJDQMgFUEIaiEVIBEgEkEBaiISRw0ADAILC0ECIRJBASEVIBFBAkYNAiAJIA9qIRVBAiESA0AgE0H/AXEhFiAVLQAAIhMgFk8NAiAVQQhqIRUgESASQQFqIhJHDQALCyARIRILIBIgB0kNAiAURQ0BIBJBAXYiFUUNAQsgCCASQQN0IA9qaiERA0AgESkCACEYIBEgEC0AADoAACAQQQRqKAIAIRMgECAYNwIAIBFBBGogEzYCACARQXhqIREgEEEIaiEQIBVBf2oiFQ0ACwsgEkEBdEEBciENDAELAkAgBA0AIBEgByARIAdJG0EBdCENDAELIBAgEUEgIBFBIEkbIhEgAiADQQBBABAHIBFBAXRBAXIhDQsgGSANQQF2IApBAXRqrX4gCiAGQQF2a60gCq18IBl+hXmnIQwLAkACQCALQQJJDQAgCCAKQQN0IhBqIRcgACAQaiEUA0AgBUGOAmogC0F/aiIVai0AACAMSQ0BAkACQAJAAkACQCAFQQRqIBVBAnRqKAIAIhJBAXYiECAGQQF2IhFqIg8gA0sNACASIAZyQQFxRQ0BCyAAIAogD2tBA3RqIQsgEkEBcUUNAQwCCyAPQQF0IQYMAgsgCyAQIAIgAyAQQQFyZ0EBdEE+c0EAEAcLAkAgBkEBcQ0AIAsgEEEDdGogESACIAMgEUEBcmdBAXRBPnNBABAHCwJAIBBFDQAgEUUNACADIBEgECARIBBJIhIbIhFJDQAgCyAQQQN0aiEGAkAgEUEDdCIQRQ0AIAIgBiALIBIbIBD8CgAACyACIBBqIRECQAJAIBINACACIRADQCALIAYgECAGLQAAIhIgEC0AACITSSIWGykCADcCACALQQhqIQsgECASIBNPQQN0aiIQIBFGDQIgBiAWQQN0aiIGIBRHDQAMAgsLIBchEAJAA0AgECAGQXhqIgYgEUF4aiIRIBEtAAAiEiAGLQAAIhNJIhYbKQIANwIAIBEgFkEDdGohESAGIBIgE09BA3RqIgYgC0YNASAQQXhqIRAgESACRw0ACwsgBiELIAIhEAsgESAQayIGRQ0AIAsgECAG/AoAAAsgD0EBdEEBciEGC0EBIRAgFSELIBVBAUsNAAwCCwsgCyEQCyAFQY4CaiAQaiAMOgAAIAVBBGogEEECdGogBjYCAAJAIA5FDQAgEEEBaiELIA1BAXYgCmohCiANIQYMAQsLAkAgBkEBcQ0AIAAgASACIAMgAUEBcmdBAXRBPnNBABAHCyAFQdACaiQAC4sKAQp/IwBBsAFrIgUkACAFQRhqIAEQWyAFKAIcIQYgBSgCGCEBIAUgAzYCRCAFIAI2AkAgBSADNgI8IAVBEGogBUE8ahBLIAVBPGogASAFKAIQIgcgBSgCFCIIEAMgBSgCSCEJIAUoAkQhCiAFKAJAIQsCQAJAIAUoAjwNACAFIAQ2AiRBACEMIAVBADsBMCAFQgA3AyhBACEBQQAhAwJAIAlFDQBBACEDIAVBKGohASAKIQIDQCABIANBCm5BdmxqIgQgBC0AACACLQAAczoAACABQQFqIQEgA0EBaiEDIAJBAWohAiAJQX9qIgkNAAsgBS0AMUG0AWxBCHYhASAFLQAwQegCbEEIdiEDCyAFIAM2AjQgBSADIAFqIgNB2gBqIgEgA0HyfWogAUHoAkkbNgI4IAVBBjYCnAEgBSAFQTRqNgKYASAFQTxqQQxqQZiOwAAgBUGYAWoQHyAFQQY2ApwBIAUgBUE0ajYCmAEgBUHUAGpBqY7AACAFQZgBahAfIAVBBjYCnAEgBSAFQThqNgKYASAFQeAAakG6jsAAIAVBmAFqEB8gBUGAgICAeDYCPCAFQQY2AqQBIAVBjLfAADYCoAEgBUEGNgKcASAFIAVBJGo2ApgBIAVB7ABqQZC3wAAgBUGYAWoQHwNAIAUgDDYCeEEAIQ0gBUEoaiAMai0AAEH/AXEhDgNAAkAgBUE8aiAOIA1BAXRB/gFxdkEDcUEMbGoiAygCAEGAgICAeEYNACAFIAM2AnwgBSANNgKAASAFQQcgDWs2AoQBQQAhAiAFKAJ0IQEDQCAFIAVBgAFqIAJqNgKIASAFQQk2AqwBIAVBBjYCpAEgBUEKNgKcASAFIAVB/ABqNgKoASAFIAVB+ABqNgKgASAFIAVBiAFqNgKYASAFQYwBakHijcAAIAVBmAFqEB8gBSgCjAEhBCAFKAKQASEJAkACQAJAIAUoApQBIgMgBSgCbCABa00NACAFQewAaiABIANBAUEBEFkgBSgCdCEBDAELIANFDQELIANFDQAgBSgCcCABaiAJIAP8CgAACyAFIAEgA2oiATYCdCAEIAlBAUEBEEogAkEEaiICQQhHDQALCyANQQFqIg1BBEcNAAsgDEEBaiIMQQhHDQALAkAgBSgCbCIEIAUoAnQiDWtBBUsNACAFQewAaiANQQZBAUEBEFkgBSgCbCEEIAUoAnQhDQtBACEDIAUoAnAiCSANaiIBQQAoALe4QDYAACABQQAvALu4QDsABANAAkAgBUE8aiADaiIBKAIAIgJBgICAgHhGDQAgAiABQQRqKAIAQQFBARBKCyADQQxqIgNBMEcNAAsgCyAKQQFBARBKIA1BBmohAwwBCyAFIAk2AqABIAUgCjYCnAEgBSALNgKYASAFQQU2ApABIAUgBUGYAWo2AowBIAVBPGpBmIXAACAFQYwBahAfIAUoAkAiAyAFKAJEEAAhCSAFKAI8IANBAUEBEEggBSgCmAEgBSgCnAFBAUEBEEhBgICAgHghBAsCQCAIRQ0AIAdBASAIEBwLIAYgBigCAEF/ajYCAAJAAkAgBEGAgICAeEcNAEEBIQNBACEBQQAhAgwBCyAFIAM2AkQgBSAJNgJAIAUgBDYCPCAFQQhqIAVBPGoQSyAFKAIMIQIgBSgCCCEBQQAhCUEAIQMLIAAgAzYCDCAAIAk2AgggACACNgIEIAAgATYCACAFQbABaiQAC6EJAQh/IwBB4ABrIgQkACAEQRBqIAIgAyABQQFxEAQgBCgCJCEBIAQoAiAhBQJAAkAgA0EQSQ0AIAIgAxAWIQYMAQtBACEGIANFDQADQCAGIAIsAABBv39KaiEGIAJBAWohAiADQX9qIgMNAAsLQQAhAgJAAkACQCAGQQBIDQACQCAGDQBBACEHQQEhCAwDC0EBIQJBASAGEDgiCEUNACAGRQ0BIAhBACAG/AsADAELIAIgBhBmAAsgBiEHCwJAIAFFDQAgBSABQQR0aiEJIAggBmohCgJAA0ACQAJAAkAgBSgCACICQR91IAJBgYCAgHhqcQ4DAAECAAsgBSgCBCEDIAUoAgwhCwJAAkAgBSgCCCIBQRBJDQAgAyABEBYhAgwBC0EAIQIgAUUNAANAIAIgAywAAEG/f0pqIQIgA0EBaiEDIAFBf2oiAQ0ACwsgCCEDIAJFDQEDQAJAAkAgCw0AIAMgCkYNBAwBCyALIAogA2tPDQMgAyALaiEDCyADQQE6AAAgA0EBaiEDQQAhCyACQX9qIgINAAwCCwsgBSgCCCICIAZPDQIgCCACakEBOgAACyAFQRBqIgUgCUcNAAwCCwsgAiAGQcy4wAAQUgALQQAhAgJAAkACQAJAAkADQCAGIAIiA0YNASADQQFqIQIgCCADai0AAA0ACyAEQfiqxwA2AjQgBEEAKAKEo0c2AlBBBEEEIARB0ABqIARBNGpBA0EEEEYhC0EAIAQoAlA2AoSjRyALRQ0DIAsgAzYCACAEQQE2AlggBCALNgJUIARBBDYCUEEBIQUDQCAGIAIgBiACSxshAQNAIAEgAiIDRg0DIANBAWohAiAIIANqLQAADQALAkAgBSAEKAJQRw0AIARB0ABqIAVBAUEEQQQQWSAEKAJUIQsLIAsgBUECdGogAzYCACAEIAVBAWoiBTYCWAwACwsgBEEANgIwIARCgICAgMAANwMoDAELIAQgBCgCWDYCMCAEIAQpAlA3AygLIAcgCEEBQQEQSiAEKAIYIQIgBCgCFCEFIARBADYCPCAEIAUgAmo2AjggBCAFNgI0IAQgBEEoajYCQCAEQQhqIARBNGoQGwJAAkAgBCgCCEEBRw0AIAQoAgwhAiAEQfiqxwA2AkQgBEEAKAKEo0c2AlBBBEEEIARB0ABqIARBxABqQQNBBBBGIQtBACAEKAJQNgKEo0cgC0UNAyALIAI2AgAgBEEBNgJMIAQgCzYCSCAEQQQ2AkQgBCAEKQI8NwNYIAQgBCkCNDcDUEEEIQNBASECAkADQCAEIARB0ABqEBsgBCgCAEEBRw0BIAQoAgQhAQJAIAIgBCgCREcNACAEQcQAaiACQQFBBEEEEFkgBCgCSCELCyALIANqIAE2AgAgBCACQQFqIgI2AkwgA0EEaiEDDAALCyAAIAQoAkw2AgggACAEKQJENwIADAELIABBADYCCCAAQoCAgIDAADcCAAsgBCgCKCAEKAIsQQRBBBBKIAQoAhAgBUEBQQEQSiAEKAIgIgIgBCgCJBBcIAQoAhwgAkEEQRAQSiAEQeAAaiQADwtBBEEQEGYAC0EEQRAQZgALxAgBAn8CQAJAAkAgAEGAXmpBE0kNACAAQYCofWoiAkH//wNxQRxwIQMgAkGj1wBLDQEgAUHYXGpBGksNASADDQEgACABakHZXGoPCyABQZ9dakEVSQ0BCwJAAkAgASAAckGAgARJDQBBgIDEACECAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAEHBpwRKDQACQCAAQbCiBEoNAAJAIABBmKEESg0AIABB0osERg0DIABB2osERw0iQeSLBEGAgMQAIAFBhwZGGw8LIABB5957ag4DAyEEBQsCQCAAQYGnBEoNACAAQc/de2oOAgYHCAsCQCAAQf7Ye2oOAwkhCgALIABBi6cERg0KIABBkKcERw0gQZGnBEGAgMQAIAFByacERhsPCwJAIABBncIFSg0AAkAgAEG3qwRKDQAgAEHCpwRGDQwgAEG5qQRHDSEgAUHG1ntqDgQaISEbGQsgAEHI1HtqDgIMDQ4LAkAgAEHivXpqDgUPICAQEQALIABBnaV6ag4HEh8fHxMfFBELQcmLBEGAgMQAIAFBhwZGGw8LQZqhBEGAgMQAIAFBuqEERhsPC0GcoQRBgIDEACABQbqhBEYbDwsgAEGloQRHDRtBq6EEQYCAxAAgAUG6oQRGGw8LQa6iBEGAgMQAIAFBp6IERhsPC0GvogRBgIDEACABQaeiBEYbDwsgAEHHpgRHDRhBy6YEQcymBEGAgMQAIAFB16YERhsgAUG+pgRGGw8LQYOnBEGAgMQAIAFByacERhsPC0GFpwRBgIDEACABQbunBEYbDwtBjqcEQYCAxAAgAUHCpwRGGw8LIAFBuKcERg0KIAFBwqcERg0LIAFByacERw0UQcinBA8LQbqrBEGAgMQAIAFBr6sERhsPC0G7qwRBgIDEACABQa+rBEYbDwsgAEG1sgRHDRFBuLIEQYCAxAAgAUGwsgRGGw8LIAFB4r16ag4DCwwNDgtBpsIFQajCBUGAgMQAIAFBoMIFRhsgAUGfwgVGGw8LQafCBUGAgMQAIAFBn8IFRhsPCyAAQanCBUcNDUGkwgVBgIDEACABQZ/CBUYbDwtB6doFQYCAxAAgAUHn2gVGGw8LQejaBUGAgMQAIAFB59oFRhsPC0Hq2gVBgIDEACABQefaBUYbDwtBx6cEDwtBxacEDwsgAUGwqQRHDQdBvKkEDwtBu6kEDwtBvqkEDwtBocIFDwtBo8IFDwtBpcIFDwsgAUGpwgVHDQFBosIFDwsgAEEQdCABciIAIABBufPd8XlsIABBprKFigNsIgFzrUKgB35CIIinQQF0LwHoukBqQbnz3fF5bCABc61CoAd+QiCIp0EDdCIBKAKsyUBBgIDEACAAIAEoAqjJQEYbIQILIAIPCyAAQcwEbCABQRxsakHk8t5+agvuBgEMfyMAQRBrIgMkACAAKAIIIQQgACgCBCEFQQEhBgJAIAFBIiACKAIQIgcRBQANAAJAAkAgBA0AQQAhBEEAIQAMAQtBACEIQQAhCSAEIQogBSELA0AgCyAKaiEMQQAhAAJAAkADQCALIABqIg0tAAAiDkGBf2pB/wFxQaEBSQ0BIA5BIkYNASAOQdwARg0BIAogAEEBaiIARw0ACyAJIApqIQkMAQsCQAJAIA0sAAAiDkF/TA0AIA1BAWohCyAOQf8BcSEODAELIA0tAAFBP3EhCiAOQR9xIQsCQCAOQV9LDQAgC0EGdCAKciEOIA1BAmohCwwBCyAKQQZ0IA0tAAJBP3FyIQoCQCAOQXBPDQAgCiALQQx0ciEOIA1BA2ohCwwBCyAKQQZ0IA0tAANBP3FyIAtBEnRBgIDwAHFyIQ4gDUEEaiELCyAAIAlqIQAgAyAOQYGABBAKAkAgAy0ADSINIAMtAAwiCmsiCUH/AXFBAUYNAAJAAkACQCAAIAhJDQACQCAIRQ0AAkAgCCAESQ0AIAggBEcNAgwBCyAFIAhqLAAAQb9/TA0BCwJAIABFDQACQCAAIARJDQAgACAERg0BDAILIAUgAGosAABBv39MDQELIAEgBSAIaiAAIAhrIAIoAgwiCBEHAEUNAQwCCyAFIAQgCCAAQZCmwAAQawALAkACQCANQYEBSQ0AIAEgAygCACAHEQUADQIMAQsgASADIApqIAkgCBEHAA0BCwJAAkAgDkGAAU8NAEEBIQ0MAQsCQCAOQYAQTw0AQQIhDQwBC0EDQQQgDkGAgARJGyENCyANIABqIQgMAQtBASEGDAQLAkACQCAOQYABTw0AQQEhDgwBCwJAIA5BgBBPDQBBAiEODAELQQNBBCAOQYCABEkbIQ4LIA4gAGohCSAMIAtrIgoNAQsLAkAgCCAJSw0AQQAhAAJAIAhFDQACQCAIIARJDQAgBCEAIAggBEcNAgwBCyAIIQAgBSAIaiwAAEG/f0wNAQsCQCAJDQBBACEEDAILAkACQCAJIARJDQAgCSAERg0DDAELIAUgCWosAABBv39MDQAgCSEEDAILIAAhCAsgBSAEIAggCUGgpsAAEGsACyABIAUgAGogBCAAayACKAIMEQcADQAgAUEiIAcRBQAhBgsgA0EQaiQAIAYL/QcCD38BfiMAQaAHayIDJAAgAyACNgLMAiADIAE2AsgCIAMgAjYCxAIgA0EIaiADQcQCahBLIANBoAVqIAMoAggiBCADKAIMIgUQKCADKAKsBSEGIAMoAqgFIQcgAygCpAUhCAJAAkACQCADKAKgBSIJQYCAgIB4Rw0AIAMgBjYC0AIgAyAHNgLMAiADIAg2AsgCDAELIAMgAykCxAU3A4gFIAMgAykCzAU3A5AFIAMgAygC1AU2ApgFIAMpArQFIRIgAygCsAUhCiADIAMoAsAFIgsgAygCvAUiDGoiDTYCgAUCQCANQf4BSw0AQQAhDiADQaAFakEBakEAQf8B/AsAIANBAToAoAUCQCANRQ0AA0AgDiICLQC8r0BBvLPAAGohDyACQQFqIg4hAgJAAkADQEEBIQFBACEQQQAhEQJAIAJBAUYNACACQX9qIQEgAkGAAksNAiABIRELAkAgA0GgBWogEWotAAAiEUUNACARLQC8s0AgDy0AAGotALyvQCEQCwJAIAJBgAJPDQAgA0GgBWogAmoiESARLQAAIBBzOgAAIAJBAUYhECABIQIgEA0DDAELCyACQYACQcy1wAAQUgALIAFBgAJBvLXAABBSAAsgDiANRw0ACwsgA0H8AmogA0GgBWpBgAL8CgAAIAMgDTYC/AQgAyALNgLkAiADIAw2AuACIAMgEjcC2AIgAyAKNgLUAiADIAY2AtACIAMgBzYCzAIgAyAINgLIAiADIAMpA4gFNwLoAiADIAMpA5AFNwLwAiADIAMoApgFNgL4AiAJQYCAgIB4Rg0BIAMoAsgCIRAgA0EQaiADQcwCakG0AvwKAABBACECDAILIANBBjYCpAUgAyADQYAFajYCoAUgA0HIAmpBtYLAACADQaAFahAfIAkgCEEBQQEQSiAGIApBAUEBEEoLIAMgAygC0AI2ApAFIAMgAykCyAI3A4gFIANBBTYChAUgAyADQYgFajYCgAUgA0GgBWpBmIXAACADQYAFahAfQQEhAiADKAKkBSIBIAMoAqgFEAAhECADKAKgBSABQQFBARBIIAMoAogFIAMoAowFQQFBARBIQYCAgIB4IQkLAkAgBUUNACAEQQEgBRAcC0EBIQECQAJAAkAgAkUNACAQIQIMAQsgA0H4qscANgKgBUEAIQEgA0EAKAK0pUc2AsQCQdAAQQQgA0HEAmogA0GgBWpBA0EEEEYhAkEAIAMoAsQCNgK0pUcgAkUNASACIBA2AgggAiAJNgIEIAJBADYCACACQQxqIANBEGpBtAL8CgAACyAAIAE2AgggACACQQAgARs2AgQgAEEAIAIgARs2AgAgA0GgB2okAA8LQQRBwAIQeAALrAUBBH8jAEEwayICJAACQAJAAkACQAJAIAEgASABQbnz3fF5bCABQaayhYoDbCIDc61CyAd+QiCIp0EBdC8BqINBakG5893xeWwgA3OtQsgHfkIgiKdBAnQoAriSQSIDQQh2Rw0AIANB/wFxDQELIAAoAiwhBAJAAkAgACgCCEGAgMQARw0AIAAoAhQhAyAAKAIQIQUMAQsgAC8BACIDQQVPDQMgAEEEaiEFCyADIARJDQMCQCADIARrIgNBAkkNACAFIARBA3RqIQQCQCADQRVJDQAgBCADEDYMAQsgBCADEDcLAkACQCAAKAIIQYCAxABHDQACQCAAKAIUIgMgACgCDEcNACAAQQxqEFgLIAAoAhAgA0EDdGoiBCABNgIEIARBADoAACAAIANBAWo2AhQMAQsCQCAALwEAIgNBA0sNACAAIANBAWo7AQAgACADQQN0aiIDIAE2AgggA0EAOgAEDAELIAJBDGogAEEAIAEQHgJAIAAoAghBgIDEAEcNACAAKAIMIAAoAhBBBEEIEEoLIAAgAkEMakEk/AoAAAsCQAJAIAAoAghBgIDEAEcNACAAKAIUIQEMAQsgAC8BACEBCyAAIAE2AiwMAQsCQCAAKAIIQYCAxABHDQACQCAAKAIUIgQgACgCDEcNACAAQQxqEFgLIAAoAhAgBEEDdGoiBSABNgIEIAUgAzoAACAAIARBAWo2AhQMAQsCQCAALwEAIgRBA0sNACAAIARBAWo7AQAgACAEQQN0aiIAIAE2AgggACADOgAEDAELIAJBDGogACADIAEQHgJAIAAoAghBgIDEAEcNACAAKAIMIAAoAhBBBEEIEEoLIAAgAkEMakEk/AoAAAsgAkEwaiQADwtBACADQQRBrK/AABAlAAsgBCADIANBnKrAABAlAAv4BAENfyMAQeAAayIDJABBACEEIANBADYCECADQoCAgIDAADcCCEEEIQVBACEGQQAhBwNAIAZBM2ohCCAHQbCmwABqIQlBACEKQQAhCwNAIAMgCzYCFAJAIAkgC2otAABB/wFGDQAgAyAINgIkIANBBjYCNCADQQ02AiwgAyADQRRqNgIwIAMgA0EkajYCKCADQRhqQYmAwAAgA0EoahAfAkAgBCADKAIIRw0AIANBCGoQViADKAIMIQULIAUgBEEMbGoiCyADKAIgNgIIIAsgAykCGDcCACADIARBAWoiBDYCEAtBASELIApBAXEhDEEBIQogDEUNAAsgBkEBaiEGIAdBAmoiB0GQAUcNAAsgAygCDCINIARBDGxqIQUgAygCCCEOIA0hCwJAAkAgBEUNACANIQsDQCALKAIAIQQgA0EoaiALQQRqKAIAIgogC0EIaigCACIIECgCQAJAAkACQCADKAIoIgxBgICAgHhGDQAgAygCOCEGIAMoAjQhByADKAIsIQkgAygCMCACRw0BIAkgASACEFMhDyAMIAlBAUEBEEogByAGQQFBARBKIA8NAiAEQYCAgIB4Rg0DIAtBDGohCyAAIAg2AgggACAKNgIEIAAgBDYCAAwGCyADKAIsIAMoAjBBAUEBEEoMAQsgDCAJQQFBARBKIAcgBkEBQQEQSgsgBCAKQQFBARBKCyALQQxqIgsgBUcNAAsgBSELCyAAQYCAgIB4NgIACyAFIAtrQQxuIQQCQCAFIAtGDQADQCALKAIAIAtBBGooAgBBAUEBEEogC0EMaiELIARBf2oiBA0ACwsgDiANQQRBDBBKIANB4ABqJAAL1gQBCH8CQCACKAIAIgVFDQAgAUF/aiEGQQAgAWshByAAQQJ0IQgDQAJAAkAgBSgCCCIBQQFxDQAgBUEIaiEJDAELA0AgBSABQX5xNgIIAkACQCAFKAIEIgpBfHEiCQ0AQQAhCwwBC0EAIAkgCS0AAEEBcRshCwsCQCAFKAIAIgFBAnENACABQXxxIgxFDQAgDCAMKAIEQQNxIAlyNgIEIAUoAgQiCkF8cSEJIAUoAgAhAQsCQCAJRQ0AIAkgCSgCAEEDcSABQXxxcjYCACAFKAIEIQogBSgCACEBCyAFIApBA3E2AgQgBSABQQNxNgIAAkAgAUECcUUNACALIAsoAgBBAnI2AgALIAIgCzYCACALIQUgCygCCCIBQQFxDQALIAtBCGohCSALIQULAkAgBSgCAEF8cSILIAlrIAhJDQACQAJAIAkgAyAAIAQRBQBBAnRqQQhqIAsgCGsgB3EiAU0NACAJKAIAIQEgBiAJcQ0CIAIgAUF8cTYCACAFKAIAIQkgBSEBDAELQQAhCyABQQA2AgAgAUF4aiIBQgA3AgAgASAFKAIAQXxxNgIAAkAgBSgCACIKQQJxDQAgCkF8cSIKRQ0AIAogCigCBEEDcSABcjYCBCABKAIEQQNxIQsLIAEgCyAFcjYCBCAJIAkoAgBBfnE2AgAgBSAFKAIAIglBA3EgAXIiCzYCAAJAIAlBAnENACABKAIAIQkMAQsgBSALQX1xNgIAIAEoAgBBAnIhCQsgASAJQQFyNgIAIAFBCGoPCyACIAE2AgAgASEFIAENAAsLQQALzgQBC38gACgCBCEDIAAoAgAhBCAAKAIIIQVBACEGQQAhB0EAIQhBACEJAkADQCAJQQFxDQECQAJAIAIgCEkNAANAIAEgCGohCQJAAkACQAJAAkACQCACIAhrIgpBB0sNACACIAhHDQEgAiEIDAcLIAlBA2pBfHEiACAJRg0BIAAgCWshC0EAIQADQCAJIABqLQAAQQpGDQUgCyAAQQFqIgBHDQALIAsgCkF4aiIMSw0DDAILQQAhAANAIAkgAGotAABBCkYNBCAKIABBAWoiAEcNAAsgAiEIDAULIApBeGohDEEAIQsLA0BBgIKECCAJIAtqIgAoAgAiDUGKlKjQAHNrIA1yQYCChAggAEEEaigCACIAQYqUqNAAc2sgAHJxQYCBgoR4cUGAgYKEeEcNASALQQhqIgsgDE0NAAsLAkAgCiALRw0AIAIhCAwDCyAJIAtqIQ0gAiALayAIayEKQQAhAAJAA0AgDSAAai0AAEEKRg0BIAogAEEBaiIARw0ACyACIQgMAwsgACALaiEACyAIIABqIgtBAWohCAJAIAsgAk8NACAJIABqLQAAQQpHDQBBACEJIAghDSAIIQAMAwsgAiAITw0ACwsgAiAHRg0CQQEhCSAHIQ0gAiEACwJAAkAgBS0AAEUNACAEQfalwABBBCADKAIMEQcADQELIAAgB2shCkEAIQsCQCAAIAdGDQAgASAAakF/ai0AAEEKRiELCyABIAdqIQAgBSALOgAAIA0hByAEIAAgCiADKAIMEQcARQ0BCwtBASEGCyAGC8AEAQh/IwBBEGsiBCQAAkACQAJAIANBAXENACACLQAAIgUNAUEAIQUMAgsgACACIANBAXYgASgCDBEHACEFDAELIAEoAgwhBkEAIQcDQCACQQFqIQgCQAJAAkACQAJAIAXAQX9KDQAgBUH/AXEiCUGAAUYNASAJQcABRw0DIAQgATYCBCAEIAA2AgAgBEKggICABjcCCCADIAdBA3RqIgUoAgAgBCAFKAIEEQUARQ0CQQEhBQwGCwJAIAAgCCAFQf8BcSIFIAYRBwANACAIIAVqIQIMBAtBASEFDAULAkAgACACQQNqIgUgAi8AASICIAYRBwANACAFIAJqIQIMAwtBASEFDAQLIAdBAWohByAIIQIMAQtBoICAgAYhCgJAIAVBAXFFDQAgAkEFaiEIIAIoAAEhCgtBACEJAkACQCAFQQJxDQBBACELIAghAgwBCyAIQQJqIQIgCC8AACELCwJAAkAgBUEEcQ0AIAIhCAwBCyACQQJqIQggAi8AACEJCwJAAkAgBUEIcQ0AIAghAgwBCyAIQQJqIQIgCC8AACEHCwJAIAVBEHFFDQAgAyALQf//A3FBA3RqLwEEIQsLAkAgBUEgcUUNACADIAlB//8DcUEDdGovAQQhCQsgBCAJOwEOIAQgCzsBDCAEIAo2AgggBCABNgIEIAQgADYCAAJAIAMgB0EDdGoiBSgCACAEIAUoAgQRBQBFDQBBASEFDAMLIAdBAWohBwsgAi0AACIFDQALQQAhBQsgBEEQaiQAIAULjgQBCH8gASAAIABBA2pBfHEiAmsiA2oiBEEDcSEFQQAhAUEAIQYCQCAAIAJGDQBBACEGA0AgBiAALAAAQb9/SmohBiAAQQFqIQAgA0EBaiIDDQALCwJAIAVFDQAgAiAEQfz///8HcWohAEEAIQEDQCABIAAsAABBv39KaiEBIABBAWohACAFQX9qIgUNAAsLIARBAnYhAyABIAZqIQcCQANAIAIhBiADRQ0BIANBwAEgA0HAAUkbIgRBA3EhCAJAAkAgBEECdCIJQfAHcSIADQBBACEBDAELIAYgAGohAkEAIQEgBiEAA0AgAEEMaigCACIFQX9zQQd2IAVBBnZyQYGChAhxIABBCGooAgAiBUF/c0EHdiAFQQZ2ckGBgoQIcSAAQQRqKAIAIgVBf3NBB3YgBUEGdnJBgYKECHEgACgCACIFQX9zQQd2IAVBBnZyQYGChAhxIAFqampqIQEgAEEQaiIAIAJHDQALCyADIARrIQMgBiAJaiECIAFBCHZB/4H8B3EgAUH/gfwHcWpBgYAEbEEQdiAHaiEHIAhFDQALIAhBAnQhBSAGIARB/AFxQQJ0aiEAQQAhAQNAIAAoAgAiAkF/c0EHdiACQQZ2ckGBgoQIcSABaiEBIABBBGohACAFQXxqIgUNAAsgAUEIdkH/gfwHcSABQf+B/AdxakGBgARsQRB2IAdqIQcLIAcLwgQCCH8BfiAAKAIIIgVBgICAAXEiBkEVdiAEaiEHAkACQCAFQYCAgARxDQBBACEBDAELQQAhCAJAIAJFDQAgASEJIAIhCgNAIAggCSwAAEG/f0pqIQggCUEBaiEJIApBf2oiCg0ACwsgCCAHaiEHC0ErQYCAxAAgBhshCwJAAkAgByAALwEMIgZPDQACQAJAAkAgBUGAgIAIcQ0AIAYgB2shDEEAIQlBACEGAkACQAJAIAVBHXZBA3EOBAIAAQACCyAMIQYMAQsgDEH+/wNxQQF2IQYLIAVB////AHEhBSAAKAIEIQcgACgCACEKA0AgCUH//wNxIAZB//8DcU8NAkEBIQggCUEBaiEJIAogBSAHKAIQEQUARQ0ADAULCyAAIAApAggiDadBgICA/3lxQbCAgIACcjYCCEEBIQggACgCACIKIAAoAgQiBSALIAEgAhBhDQNBACEJIAYgB2tB//8DcSEHA0AgCUH//wNxIAdPDQJBASEIIAlBAWohCSAKQTAgBSgCEBEFAEUNAAwECwtBASEIIAogByALIAEgAhBhDQIgCiADIAQgBygCDBEHAA0CIAwgBmtB//8DcSEAQQAhCQNAAkAgCUH//wNxIABJDQBBAA8LQQEhCCAJQQFqIQkgCiAFIAcoAhARBQBFDQAMAwsLQQEhCCAKIAMgBCAFKAIMEQcADQEgACANNwIIQQAPC0EBIQggACgCACIJIAAoAgQiCiALIAEgAhBhDQAgCSADIAQgCigCDBEHACEICyAIC5EEAQF/AkACQCAAQXdqIgFBF0sNAEGfgIAEIAF2QQFxDQELAkACQAJAAkACQCAAQYABSQ0AAkACQAJAAkACQAJAAkAgAEEIdiIBQR9KDQAgAUUNBiABQRZHDQcgAEGKwABKDQJBASEBIABB1X5qDgMKCwoBCyABQSBGDQQgAUEwRw0GIABB38AASg0DIABB9b9/aiIBQRNNDQIMCAsgAEG7AUYNCkEBIQEgAEGALUYNCAwJCyAAQfW/f2oiAUETSw0FQQEgAXRBn8A7cQ0JDAULQQEgAXRBn8A7cQ0IDAULIABBoL9/akEFSQ0HIABBgOAARg0HQQEhASAAQf/9A0YNBQwGCyAAQf8BcS0ArK1AQQJxDQYMAQtBASEBIABB/wFxLQCsrUBBAXENAwsCQCAAQYrAAEoNAAJAIABB3wBKDQBBASEBAkAgAEFEag4DBQYFAAsgAEEiRg0GQQEhASAAQSdGDQQMBQtBASEBAkAgAEHVfmoOAwQFBAALIABB4ABGDQVBASEBIABBuwFGDQMMBAsCQCAAQfW/f2oiAUETSw0AQQEgAXRBn8A7cQ0FCyAAQaC/f2pBBUkNBEEBIQEgAEH//QNGDQIMAwsgAEGgv39qQQVJDQNBASEBIABB//0DRg0BDAILQQEhAQJAIABB1X5qDgMBAgEAC0EBIQEgAEG7AUcNAQsgAQ8LQQAPC0EBC/kDAQN/IwBBEGsiAiQAAkACQAJAAkAgASgCCCIDQYCAgBBxDQAgA0GAgIAgcQ0BIAAgARAnRQ0CQQEhAwwDCyAAKAIAIQRBCSEDA0AgAkEIaiADakF+aiAEQQ9xLQCMlkA6AAAgA0F/aiEDIARBBHYiBA0ACyABQf6lwABBAiACQQhqIANqQX9qQQkgA2sQF0UNAUEBIQMMAgsgACgCACEEQQkhAwNAIAJBCGogA2pBfmogBEEPcS0AgKZAOgAAIANBf2ohAyAEQQR2IgQNAAsgAUH+pcAAQQIgAkEIaiADakF/akEJIANrEBdFDQBBASEDDAELAkAgASgCAEH0pcAAQQIgASgCBCgCDBEHAEUNAEEBIQMMAQsgAEEEaiEDAkACQCABKAIIIgRBgICAEHENACAEQYCAgCBxDQEgAyABECchAwwCCyADKAIAIQRBCSEDA0AgAkEIaiADakF+aiAEQQ9xLQCMlkA6AAAgA0F/aiEDIARBBHYiBA0ACyABQf6lwABBAiACQQhqIANqQX9qQQkgA2sQFyEDDAELIAMoAgAhBEEJIQMDQCACQQhqIANqQX5qIARBD3EtAICmQDoAACADQX9qIQMgBEEEdiIEDQALIAFB/qXAAEECIAJBCGogA2pBf2pBCSADaxAXIQMLIAJBEGokACADC4IEAQd/AkACQCAAKAIIIgNBgICAwAFxRQ0AAkACQAJAAkACQCADQYCAgIABcUUNACAALwEOIgQNAUEAIQIMAgsCQCACQRBJDQAgASACEBYhBQwEC0EAIQUgAkUNAyABIQYgAiEHA0AgBSAGLAAAQb9/SmohBSAGQQFqIQYgB0F/aiIHDQAMBAsLIAEgAmohCEEAIQIgASEFIAQhBwNAIAUiBiAIRg0CAkACQCAGLAAAIgVBf0wNACAGQQFqIQUMAQsCQCAFQWBPDQAgBkECaiEFDAELIAZBBEEDIAVBb0sbaiEFCyAFIAZrIAJqIQIgB0F/aiIHDQALC0EAIQcLIAQgB2shBQsgBSAALwEMIgZPDQAgBiAFayEJQQAhBkEAIQQCQAJAAkAgA0EddkEDcQ4EAgABAgILIAkhBAwBCyAJQf7/A3FBAXYhBAsgA0H///8AcSEIIAAoAgQhByAAKAIAIQACQANAIAZB//8DcSAEQf//A3FPDQFBASEFIAZBAWohBiAAIAggBygCEBEFAA0DDAALC0EBIQUgACABIAIgBygCDBEHAA0BIAkgBGtB//8DcSECQQAhBgNAAkAgBkH//wNxIAJJDQBBAA8LQQEhBSAGQQFqIQYgACAIIAcoAhARBQANAgwACwsgACgCACABIAIgACgCBCgCDBEHACEFCyAFC7ADAQl/IAEoAghBf2ohAiABKAIAIQMgASgCBCEEAkACQANAQQAhBQJAIAMgBEcNAAwCCyABIANBAWoiBjYCAAJAAkAgAy0AACIHwEF/Sg0AIAEgA0ECaiIGNgIAIAMtAAFBP3EhCCAHQR9xIQkCQAJAIAdB3wFLDQAgCUEGdCAIciEHDAELIAEgA0EDaiIGNgIAIAhBBnQgAy0AAkE/cXIhCAJAIAdB8AFPDQAgCCAJQQx0ciEHDAELIAEgA0EEaiIGNgIAIAhBBnQgAy0AA0E/cXIgCUESdEGAgPAAcXIiB0GAgMQARg0EC0EBIQUgBiEDIAdB/wBLDQELQQAhBQJAAkAgB0FQaiIDQRxLDQBBASADdEGHgICAAXENAQsgB0HsAEYNACAHQUZqQf8BcUH5AUkgB0HfAHFBpX9qQf8BcUHmAUlxIQULIAYhAwsgASACQQJqNgIIIAJBAWohAiAFRQ0AIAdBgIDEAEYNAAsgAiABKAIMIgMoAggiB08NASADKAIEIAJBAnRqKAIAIQpBASEFCyAAIAo2AgQgACAFNgIADwsgAiAHQdypwAAQUgALlAMBBX8CQAJAAkACQAJAAkAgAEUNACACRQ0AAkACQCABQQRLDQAgAkEDakECdkF/aiICQf8BTQ0BCyAAQQA2AgAgAEF4aiICIAIoAgAiAUF+cTYCAEEAKAL4qkchAwJAIABBfGoiBCgCAEF8cSIFRQ0AIAUoAgAiBkEBcQ0AIAFBfHEhACABQQJxDQMgAEUNAyAAIAAoAgRBA3EgBXI2AgQgAigCACEAIAQoAgAiAUF8cSIHRQ0FIABBfHEhACAHKAIAIQYMBAsCQAJAIAFBAnENACABQXxxIgFFDQAgAS0AAEEBcUUNAQsgACADNgIADAcLIAAgASgCCEF8cTYCACABIAJBAXI2AggMBQsgACACQQJ0IgIoAviiRzYCACACIABBeGoiADYC+KJHIAAgACgCAEF+cTYCAAsPCyAFIQcLIAcgBkEDcSAAcjYCACAEKAIAIQEgAigCACEACyAEIAFBA3E2AgAgAiAAQQNxNgIAIABBAnFFDQAgBSAFKAIAQQJyNgIACyADIQILQQAgAjYC+KpHC5YDAQh/IwBBEGsiAyQAIANBADYCDCADQoCAgIAQNwIEIAIgAWtBAnYhBAJAIAIgAUYiAg0AIANBBGpBACAEQQFBARBZCwJAIAINACADKAIMIQUDQEEBIQYCQCABKAIAIgJBgAFJIgcNAEECIQYgAkGAEEkNAEEDQQQgAkGAgARJGyEGCyAFIQgCQCAGIAMoAgQgBWtNDQAgA0EEaiAFIAZBAUEBEFkgAygCDCEICyADKAIIIAhqIQgCQAJAIAcNACACQT9xQYB/ciEHIAJBBnYhCQJAIAJBgBBPDQAgCCAHOgABIAggCUHAAXI6AAAMAgsgAkEMdiEKIAlBP3FBgH9yIQkCQCACQf//A0sNACAIIAc6AAIgCCAJOgABIAggCkHgAXI6AAAMAgsgCCAHOgADIAggCToAAiAIIApBP3FBgH9yOgABIAggAkESdkFwcjoAAAwBCyAIIAI6AAALIAMgBiAFaiIFNgIMIAFBBGohASAEQX9qIgQNAAsLIAAgAygCDDYCCCAAIAMpAgQ3AgAgA0EQaiQAC40DAgd/AX4jAEEgayIEJABBBCEFAkACQAJAAkAgAS8BACIGDQBBACEHIARBADYCHCAEQoCAgIDAADcCFAwBC0EEIAZBBHQiBxA4IgVFDQEgBEEANgIcIAQgBTYCGCAEIAZBAXQiBzYCFCAGQQVPDQILQQAhCAJAIAcgBk8NACAEQRRqQQAgBkEEQQgQWSAEKAIYIQUgBCgCHCEICwJAIAZFDQAgAUEEaiEHIAggBmohCSAFIAhBA3RqIQgDQCAHLQAAIQUgB0EAOgAAIAggBToAACAHQQRqIgUoAgAhCiAFQQA2AgAgCEEEaiAKNgIAIAdBCGohByAIQQhqIQggBkF/aiIGDQALIAkhCAsgAUEAOwEAIAQgBCkCFCILNwMIIAQgCDYCEAJAIAggC6dHDQAgBEEIahBYCyAAIAQpAwg3AgwgAEGAgMQANgIIIAAgCEEBajYCFCAEKAIMIAhBA3RqIgcgAzYCBCAHIAI6AAAgBEEgaiQADwtBBCAHEGYAC0EAIAZBBEGsr8AAECUAC+sCAQZ/IwBBEGsiAyQAAkACQAJAAkACQAJAAkACQCACQQFxRQ0AIAJBAXYhBAwBCyABLQAAIgRFDQFBACEFIAEhBkEAIQcDQCAGQQFqIQYCQAJAIATAQX9KDQACQCAEQf8BcUGAAUcNACAFIAYvAAAiBGohBSAGIARqQQJqIQYMAgsgBiAEQQNxQQh4IghBBXRBgICAgARxIAhBB3RyQR12aiAEQQF2QQJxaiAEQQJ2QQJxaiEGIAVFIAdyIQcMAQsgBiAEQf8BcSIEaiEGIAUgBGohBQsgBi0AACIEDQALQQAhBCAHIAVBEElxDQAgBUEBdCIEQX9MDQQLIAQNAQtBASEGQQAhBAwBC0EBIAQQOCIGRQ0CCyADQQA2AgggAyAGNgIEIAMgBDYCACADQfiOwAAgASACEBVFDQIgA0EPahBOAAsQbwALQQEgBBBmAAsgACADKAIINgIIIAAgAykCADcCACADQRBqJAALhwMBBH8jAEHQAGsiBCQAIARBGGogARBbIAQoAhwhASAEKAIYIQUgBCADNgIoIAQgAjYCJCAEIAM2AiAgBEEQaiAEQSBqEEsgBEEgaiAFIAQoAhAiBiAEKAIUIgIQAwJAAkAgBCgCIEEBRw0AIAQgBCgCLDYCOCAEIAQpAiQ3AzAgBEEFNgJMIAQgBEEwajYCSCAEQTxqQZiFwAAgBEHIAGoQHyAEKAJAIgUgBCgCRBAAIQMgBCgCPCAFQQFBARBIIAQoAjAgBCgCNEEBQQEQSEGAgICAeCEFDAELIAQoAiwhByAEKAIoIQMgBCgCJCEFCwJAIAJFDQAgBkEBIAIQHAsgASABKAIAQX9qNgIAAkACQCAFQYCAgIB4Rw0AQQEhAUEAIQJBACEFDAELIAQgBzYCKCAEIAM2AiQgBCAFNgIgIARBCGogBEEgahBLQQAhAyAEKAIIIQIgBCgCDCEFQQAhAQsgACABNgIMIAAgAzYCCCAAIAU2AgQgACACNgIAIARB0ABqJAAL8AIBC38jAEEwayIEJAAgBEEIaiABEFsgBCgCDCEFIAQoAgghASAEIAM2AhwgBCACNgIYIAQgAzYCFCAEIARBFGoQSyAEQRRqIAEgBCgCACIGIAQoAgQiBxACAkACQCAEKAIUIghBgICAgHhGDQAgBCgCLCEJIAQoAiQhCiAEKAIgIQsgBCgCGCEMQQEhAwJAIAQoAigNACAEKAIcIQ0gASgCuAIhDkEAIQNBvK/AACEBAkADQCADQf8BbiECAkAgDiADRw0AIA4hAwwCCyABIAJBgX5saiECIAFBAWohASADQQFqIQMgDCANIAItAAAQQkH/AXFFDQALIANBf2ohAwsgAyAOSSEDCyALIApBAUEBEEogCCAMQQFBARBKIAlBAEcgA3IhAwwBC0EBIQMgBCgCGCAEKAIcQQFBARBKCwJAIAdFDQAgBkEBIAcQHAsgAEIANwIEIAAgAzYCACAFIAUoAgBBf2o2AgAgBEEwaiQAC8sCAQV/QQAhAUEAQRAgAEGrnQRJGyICIAJBCHIiAiACQQJ0KAKUpEBBC3QgAEELdCICSxsiAyADQQRyIgMgA0ECdCgClKRAQQt0IAJLGyIDIANBAnIiAyADQQJ0KAKUpEBBC3QgAksbIgMgA0EBaiIDIANBAnQoApSkQEELdCACSxsiAyADQQFqIgMgA0ECdCgClKRAQQt0IAJLGyIDQQJ0KAKUpEBBC3QiBCACRiAEIAJJaiADaiIDQQJ0IgJBlKTAAGohBSACKAKUpEBBFXYhAkH/BSEEAkACQCADQR9LDQAgBSgCBEEVdiEEIANFDQELIAVBfGooAgBB////AHEhAQsCQCAEIAJBf3NqRQ0AIAAgAWshAyAEQX9qIQRBACEAA0AgACACQY2QwABqLQAAaiIAIANLDQEgBCACQQFqIgJHDQALCyACQQFxC+MCAgZ/AX4jAEEgayIDJAACQAJAAkACQCABLwEAIgQNAEEAIQUgA0EANgIcIANCgICAgMAANwIUQQQhBgwBC0EEIARBA3QiBRA4IgZFDQEgA0EANgIcIAMgBjYCGCADIARBAXQiBTYCFCAEQQVPDQILQQAhBwJAIAUgBE8NACADQRRqQQAgBEEEQQQQWSADKAIYIQYgAygCHCEHCwJAIARFDQAgAUEEaiEFIAcgBGohCCAGIAdBAnRqIQcDQCAHIAUoAgA2AgAgBUEANgIAIAVBBGohBSAHQQRqIQcgBEF/aiIEDQALIAghBwsgAUEAOwEAIAMgAykCFCIJNwMIIAMgBzYCEAJAIAcgCadHDQAgA0EIahBVCyAAIAMpAwg3AgggAEGAgMQANgIEIAAgB0EBajYCECADKAIMIAdBAnRqIAI2AgAgA0EgaiQADwtBBCAFEGYAC0EAIARBBEGsr8AAECUAC7ICAQh/AkAgAkUNACABIAJqIQZBACEHQQAhCCABIQkDQCAIIARJIQogCSILIQwCQAJAAkACQAJAA0AgDEEBaiEJAkAgCkUNACALLQAAIgxB3wFxQb9/akH/AXFBGk8NBSAMwCEMIAMgCGotAABBMUcNAiAMQZ9/akH/AXFBGUsNBCAMQd8AcSEMDAMLAkAgDC0AACINQb9/akH/AXFBGUsNACAMIA1BIHI6AAALIAkhDCAJIAZHDQAMBQsLIAxB2gBKDQEgDEEgciEMCyALIAw6AAALIAdBAWohByAIQQFqIQgLIAkgBkcNAQsLIAcgBU8NAANAAkAgAS0AACIMQb9/akH/AXFBGUsNACABIAxBIHI6AAALIAFBAWohASACQX9qIgINAAsLIABBgICAgHg2AgALpQICAX8BfiMAQSBrIgQkAAJAAkACQCAAIAJLDQAgASACSw0BQQatQiCGIQUgACABTQ0CIAQgADYCCCAEIAE2AgwgBCAFIARBDGqthDcDGCAEIAUgBEEIaq2ENwMQQcWDwAAgBEEQaiADEF8ACyAEIAA2AgggBCACNgIMIARBBq1CIIYiBSAEQQxqrYQ3AxggBCAFIARBCGqthDcDEEGkhMAAIARBEGogAxBfAAsgBCABNgIIIAQgAjYCDCAEQQatQiCGIgUgBEEMaq2ENwMYIAQgBSAEQQhqrYQ3AxBB3YTAACAEQRBqIAMQXwALIAQgATYCCCAEIAI2AgwgBCAFIARBDGqthDcDGCAEIAUgBEEIaq2ENwMQQd2EwAAgBEEQaiADEF8AC4sCAQt/IAIgAUEDdEF4aiIDaiEEIAAgA2ohBSAAIAFBAXYiBkEDdGoiA0F4aiEHA0AgAiADIAAgAy0AACIIIAAtAAAiCUkiChspAgA3AgAgBCAHIAUgBS0AACILIActAAAiDEkiDRspAgA3AgAgBEF4aiEEIAJBCGohAiAHQXhBACANG2ohByAFQXhBACALIAxPG2ohBSAAIAggCU9BA3RqIQAgAyAKQQN0aiEDIAZBf2oiBg0ACyAHQQhqIQcCQCABQQFxRQ0AIAIgACADIAAgB0kiBBspAgA3AgAgAyAAIAdPQQN0aiEDIAAgBEEDdGohAAsCQCAAIAdHDQAgAyAFQQhqRw0ADwsQcwALpQIBB38jAEEQayICJABBCiEDIAAoAgAiBCEFAkAgBEHoB0kNAEEKIQMgBCEFA0AgAkEGaiADaiIGQXxqIAUiACAAQZDOAG4iBUGQzgBsayIHQf//A3FB5ABuIghBAXQvAPiWQDsAACAGQX5qIAcgCEHkAGxrQf//A3FBAXQvAPiWQDsAACADQXxqIQMgAEH/rOIESw0ACwsCQAJAIAVBCUsNACAFIQAMAQsgAkEGaiADQX5qIgNqIAUgBUH//wNxQeQAbiIAQeQAbGtB//8DcUEBdC8A+JZAOwAACwJAAkAgBEUNACAARQ0BCyACQQZqIANBf2oiA2ogAEEBdC0A+ZZAOgAACyABQQFBACACQQZqIANqQQogA2sQFyEDIAJBEGokACADC8gCAQF/IwBBEGsiAyQAIAMgATYCACADIAI2AgQCQAJAIAJBBEcNACABLQAAQegARw0AIAEtAAFB4wBHDQAgAS0AAyICQf4BcUEwRw0AIAEtAAIiAUGFf2pB/wFxQbgBSQ0AAkAgAUEBdCACQTBHckHKpcAAai0AACIBQf8BRw0AIANBDDYCDCADIAM2AgggAEEEakHIjcAAIANBCGoQQCAAQYCAgIB4NgIADAILEFAiAkEkOgACIAIgAToAASACQTg6AAAgAEEMakHYqcAAQQMQTyAAQQE6ADQgAEEANgIwIABCPzcCKCAAQoSAgIDwATcCICAAQqCAgIDAADcCGCAAQQM2AgggACACNgIEIABBAzYCAAwBCyADQQw2AgwgAyADNgIIIABBBGpByI3AACADQQhqEB8gAEGAgICAeDYCAAsgA0EQaiQAC6ECAQZ/IAAoAgghAgJAAkAgAUGAAU8NAEEBIQMMAQsCQCABQYAQTw0AQQIhAwwBC0EDQQQgAUGAgARJGyEDCyACIQQCQCADIAAoAgAgAmtNDQAgACACIAMQPCAAKAIIIQQLIAAoAgQgBGohBAJAAkAgAUGAAUkNACABQT9xQYB/ciEFIAFBBnYhBgJAIAFBgBBPDQAgBCAFOgABIAQgBkHAAXI6AAAMAgsgAUEMdiEHIAZBP3FBgH9yIQYCQCABQf//A0sNACAEIAU6AAIgBCAGOgABIAQgB0HgAXI6AAAMAgsgBCAFOgADIAQgBjoAAiAEIAdBP3FBgH9yOgABIAQgAUESdkFwcjoAAAwBCyAEIAE6AAALIAAgAyACajYCCEEAC5ECAQR/IwBBEGsiAiQAIAAoAgAhAAJAAkAgAS0AC0EYcQ0AIAEoAgAgACABKAIEKAIQEQUAIQAMAQsgAkEANgIMAkACQCAAQYABSQ0AIABBP3FBgH9yIQMgAEEGdiEEAkAgAEGAEE8NACACIAM6AA0gAiAEQcABcjoADEECIQAMAgsgAEEMdiEFIARBP3FBgH9yIQQCQCAAQf//A0sNACACIAM6AA4gAiAEOgANIAIgBUHgAXI6AAxBAyEADAILIAIgAzoADyACIAQ6AA4gAiAFQT9xQYB/cjoADSACIABBEnZBcHI6AAxBBCEADAELIAIgADoADEEBIQALIAEgAkEMaiAAEBohAAsgAkEQaiQAIAALtQIBBX8jAEEQayICJABBASEDAkAgASgCACIEQdy4wABBDSABKAIEIgUoAgwiBhEHAA0AAkAgAS0ACkGAAXENACAEQZyWwABBAyAGEQcADQEgBEHpuMAAQQQgBhEHAA0BIARBn5bAAEECIAYRBwANASAEIAAtAABBAnQiASgCpKJHIAEoApCiRyAGEQcADQEgBEGrlsAAQQIgBhEHACEDDAELIARBoZbAAEEDIAYRBwANACACIAU2AgQgAiAENgIAQQEhAyACQQE6AA8gAiACQQ9qNgIIIAJB6bjAAEEEEBQNACACQZ+WwABBAhAUDQAgAiAALQAAQQJ0IgEoAsyiRyABKAK4okcQFA0AQQEhAyACQaSWwABBAhAUDQAgBEGplsAAQQEgBhEHACEDCyACQRBqJAAgAwv9AQEEfyMAQTBrIgQkACAEQRBqIAEQWyAEKAIUIQEgBCgCECEFIAQgAzYCLCAEIAI2AiggBCADNgIkIARBCGogBEEkahBLIARBJGogBUE0ai0AACAEKAIIIgYgBCgCDCICEA0gBCAEKAIsIgM2AiAgBCAEKAIoIgU2AhwgBCAEKAIkIgc2AhgCQCACRQ0AIAZBASACEBwLIAEgASgCAEF/ajYCAAJAAkAgByADTQ0AIAQgBEEYaiADQQRBBBAyIAQoAgAiA0GBgICAeEcNASAEKAIcIQUgBCgCICEDCyAAIAM2AgQgACAFNgIAIARBMGokAA8LIAMgBCgCBBBmAAv3AQIDfwF+IwBBEGsiBiQAQQEhB0EEIQgCQAJAIAWtIAOtfiIJQiCIpw0AIAmnIgNBgICAgHggBGtLDQACQAJAIAENAEEAIQggBkEMaiEFDAELIAYgBDYCDCABIAVsIQggBkEIaiEFCyAFIAg2AgACQAJAAkACQAJAIAYoAgxFDQACQCAGKAIIIggNACADDQIgBCEIDAMLIAIgCCAEIAMQYCEIDAILIAMNACAEIQgMAgsgBCADEDghCAsgCA0AIAAgBDYCBAwBCyAAIAg2AgRBACEHC0EIIQgMAQtBACEDCyAAIAhqIAM2AgAgACAHNgIAIAZBEGokAAveAQEDfwJAAkACQAJAAkAgASgCDCIDIAJLDQAgAw0BDAILIAEgAyACQX9zajYCDCABIAEoAgAiBCACQQJ0aiIDQQRqIAEoAgQiBSACIAUgBGtBAnYiBEkbNgIAIAIgBE8NASABIAEoAgggAmoiAkEBajYCCAwDCyABIAEoAgAiBCADQX9qIgJBAnRqQQRqIAEoAgQiBSACIAUgBGtBAnYiBEkbNgIAAkAgAiAETw0AIAEgASgCCCADajYCCAtBACEDIAFBADYCDAwBC0EAIQMLCyAAIAM2AgQgACACNgIAC8ABAQl/IAAgAC0ACCICIAAtAAAiA0lBA3RqIgQgAEEYQRAgAC0AGCAALQAQSSIFG2oiBiAAIAIgA09BA3RqIgIgAEEQQRggBRtqIgAtAAAgAi0AAEkiAxsgBi0AACAELQAASSIFGyIHLQAAIQggACACIAYgBRsgAxsiCS0AACEKIAEgBiAEIAUbKQIANwIAIAEgCSAHIAogCEkiBhspAgA3AgggASAHIAkgBhspAgA3AhAgASACIAAgAxspAgA3AhgLxAECAn8BfgJAAkACQAJAIAEgASABQbnz3fF5bCABQaayhYoDbCICc61CoRB+QiCIp0EBdC8B2LBBakG5893xeWwgAnOtQqEQfkIgiKdBA3QpApzRQSIEp0YNAEEAIQIMAQsgBEIgiKdB//8DcSICQfsaTw0BQfoaIAJrIgMgBEIwiKciAUkNAiACQQJ0QaTTwgBqIQILIAAgATYCBCAAIAI2AgAPCyACQfoaQfoaQYy/wwAQJQALQQAgASADQZy/wwAQJQALtQEBA38CQAJAIAENAEEBIQJBACEBDAELQQAhAwJAIAFBAEgNAEEBIQNBASABEDgiAkUNACACQS06AABBASEDAkAgAUEBdiIERQ0AQQEhAwNAAkAgA0UNACACIANqIAIgA/wKAAALIANBAXQhAyAEQQF2IgQNAAsLIAEgA0YNASABIANrIgRFDQEgAiADaiACIAT8CgAADAELIAMgARBmAAsgACABNgIIIAAgAjYCBCAAIAE2AgALuwEBBH8jAEEQayIFJAACQAJAIAEoAgAiBg0AQQAhBiAFQQxqIQcMAQsgBSADNgIMIAYgBGwhBiABKAIEIQggBUEIaiEHCyAHIAY2AgACQAJAIAUoAgwiBkUNACAFKAIIIQcCQAJAIAINACAHRQ0BIAggBiAHEBwMAQsgCCAHIAYgBCACbCIEEGAiA0UNAgsgASACNgIAIAEgAzYCBAtBgYCAgHghBgsgACAENgIEIAAgBjYCACAFQRBqJAALywEBBX8jAEEQayICJABBASEDAkAgASgCACIEQYCAwABBCSABKAIEIgUoAgwiBhEHAA0AAkACQCABLQAKQYABcQ0AQQEhAyAEQaaWwABBASAGEQcADQIgACAEIAUQD0UNAQwCCyAEQaeWwABBAiAGEQcADQEgAiAFNgIEIAIgBDYCAEEBIQMgAkEBOgAPIAIgAkEPajYCCCAAIAJBsJbAABAPDQEgAkGklsAAQQIQFA0BCyAEQaqWwABBASAGEQcAIQMLIAJBEGokACADC7MBAQF/IwBBEGsiBiQAAkACQAJAIAMgAmoiAiADTw0AQQAhAwwBCyAGQQRqIAEoAgAiAyABKAIEIAIgA0EBdCIDIAIgA0sbIgNBCEEEIAVBAUYbIgIgAyACSxsiAyAEIAUQLQJAIAYoAgRBAUcNACAGKAIMIQIgBigCCCEDDAILIAYoAgghAiABIAM2AgAgASACNgIEQYGAgIB4IQMLCyAAIAI2AgQgACADNgIAIAZBEGokAAuuAQEBfyMAQcAAayIDJAAgAyABNgIIIAMgAjYCDCADQRBqQQBBESACayICIAJBEUsbIgJBAXYiARAxIANBHGogAiABaxAxIANBDjYCPCADQQw2AjQgA0EONgIsIAMgA0EcajYCOCADIANBCGo2AjAgAyADQRBqNgIoIABBy47AACADQShqEB8gAygCHCADKAIgQQFBARBKIAMoAhAgAygCFEEBQQEQSiADQcAAaiQAC6wBAQV/IwBBgCBrIgIkAAJAAkACQCABQcCEPSABQcCEPUkbIgMgASABQQF2ayIEIAMgBEsbIgNBgQRJDQAgA0EDdCEFQQAhBiAEQf////8BSw0CIAVB/P///wdLDQJBBCEGQQQgBRA4IgRFDQIgACABIAQgAyABQcEASRALIAMgBEEEQQgQSgwBCyAAIAEgAkGABCABQcEASRALCyACQYAgaiQADwsgBiAFEGYAC6MBAgV/An4gAEEIaiECIAAgAUEDdGohA0EAIQQDQAJAIAItAAAiBSACQXhqLQAATw0AIAWtIQcgAjUCBCEIIAQhAQJAA0AgACABaiIGQQhqIAYpAgA3AgACQCABDQAgACEBDAILIAUgAUF4aiIBIABqIgYtAABJDQALIAZBCGohAQsgASAIQiCGIAeENwIACyAEQQhqIQQgAkEIaiICIANHDQALC68BAQJ/IwBBEGsiAiQAAkAgAUUNACABQQNqQQJ2IQECQAJAIABBBEsNACABQX9qIgNBgAJJDQELIAJBACgC+KpHNgIIIAEgACACQQhqQZCixwBBAUECEEYhAEEAIAIoAgg2AviqRwwBCyACQfiqxwA2AgQgAiADQQJ0IgMoAviiRzYCDCABIAAgAkEMaiACQQRqQQNBBBBGIQAgAyACKAIMNgL4okcLIAJBEGokACAAC5gBAQR/IwBBEGsiAiQAQQEhAwJAIAEoAgAiBEEnIAEoAgQiBSgCECIBEQUADQAgAiAAKAIAQYECEAoCQAJAIAItAA0iA0GBAUkNACAEIAIoAgAgAREFAEUNAUEBIQMMAgsgBCACIAItAAwiAGogAyAAayAFKAIMEQcARQ0AQQEhAwwBCyAEQScgAREFACEDCyACQRBqJAAgAwt/AQN/AkAgA0EISQ0AIAAgACADQQN2IgNBBXQiBGogACADQThsIgVqIAMQOiEAIAEgASAEaiABIAVqIAMQOiEBIAIgAiAEaiACIAVqIAMQOiECCyAAIAIgASAALQAAIgMgAS0AACIESSIFIAQgAi0AACIGSXMbIAUgAyAGSXMbC4sBAQN/IwBBEGsiBCQAIAQgASgCACIFKAIANgIMQQEhBiACQQJqIgEgAWwiAUGAECABQYAQSxsiAkEEIARBDGpBAUEBQQIQRiEBIAUgBCgCDDYCAAJAIAFFDQAgAUIANwIEIAEgASACQQJ0akECcjYCAEEAIQYLIAAgATYCBCAAIAY2AgAgBEEQaiQAC4cBAQF/IwBBEGsiAyQAAkAgAiABaiIBIAJPDQBBAEEAEGYACyADQQRqIAAoAgAiAiAAKAIEIAEgAkEBdCICIAEgAksbIgJBCCACQQhLGyICED4CQCADKAIEQQFHDQAgAygCCCADKAIMEGYACyADKAIIIQEgACACNgIAIAAgATYCBCADQRBqJAALcwEEfyMAQRBrIgIkACACQRBqIQNBISAALQAAIgBBAXZnayIEIQUDQCADQX9qIgMgAEEBcUH6pcAAai0AADoAACAAQf4BcUEBdiEAIAVBf2oiBUH/AXENAAsgAUH8pcAAQQIgAyAEEBchACACQRBqJAAgAAt7AQF/QQAhBAJAAkAgA0EATg0AQQEhAUEEIQIMAQsCQAJAIAFFDQAgAiABQQEgAxBgIQQMAQtBASADEDghBAsCQAJAIAQNAEEBIQEgAEEBNgIEDAELIAAgBDYCBEEAIQELQQghAiADIQQLIAAgAmogBDYCACAAIAE2AgALhwEBAn8jAEEQayIBJAAgAUH4qscANgIIIAFBACgChKNHNgIMQQRBASABQQxqIAFBCGpBA0EEEEYhAkEAIAEoAgw2AoSjRwJAIAINAEEBQQ0QZgALIABBDTYCCCAAIAI2AgQgAEENNgIAIAJBACkA8rhANwAFIAJBACkA7bhANwAAIAFBEGokAAtyAQF/QQEhAwJAAkAgAkEBcUUNAAJAAkAgAkEBdiICDQBBACEBDAELQQEgAhA4IgNFDQICQCACRQ0AIAMgASAC/AoAAAsgAiEBCyAAIAI2AgggACADNgIEIAAgATYCAA8LIAAgASACEB8PC0EBIAIQZgALbgACQAJAIANBA3RBgIABaiIDIAJBAnQiAiADIAJLG0GHgARqIgNBEHZAACICQX9HDQBBASEDQQAhAgwBCyACQRB0IgJCADcCBCACIAIgA0GAgHxxakECcjYCAEEAIQMLIAAgAjYCBCAAIAM2AgALdAEEfwJAIAENAEEADwtBACEDIAJB/wFxIgRBvLPAAGohBQNAIAAhBkEAIQICQCAERQ0AQQAhAiADQf8BcSIARQ0AIAAtALyzQCAFLQAAai0AvK9AIQILIAZBAWohACAGLQAAIAJzIQMgAUF/aiIBDQALIAMLcwEDfwJAIAENAEEADwsgAEF/aiEDQQAhACACQf8BcSIEQbyzwABqIQUDQEEAIQICQCAERQ0AQQAhAiAAQf8BcSIARQ0AIAAtALyzQCAFLQAAai0AvK9AIQILIAMgAWotAAAgAnMhACABQX9qIgENAAsgAAt4AQN/IwBBEGsiASQAAkAgACgCACICKAIEIgNBAXFFDQAgAigCACECIAEgA0EBdjYCBCABIAI2AgAgAUETIAAoAggiAC0ACCAALQAJEEcACyABQYCAgIB4NgIAIAEgADYCDCABQRQgACgCCCIALQAIIAAtAAkQRwALdAEEfwJAAkAgAEUNACAAKAIADQEgAEEAKAK0pUc2AgAgACgCFCEBIAAoAhAhAiAAKAIIIQMgACgCBCEEQQAgAEF4aiIANgK0pUcgACAAKAIAQX5xNgIAIAQgA0EBQQEQSCACIAFBAUEBEEgPCxB1AAsQdgALawECfyMAQRBrIgYkAAJAIAAgASACIAMgBRATIgcNACAGQQhqIAMgACABIAQRCABBACEHIAYoAghBAUYNACAGKAIMIgcgAigCADYCCCACIAc2AgAgACABIAIgAyAFEBMhBwsgBkEQaiQAIAcLeAECfyMAQRBrIgQkAEEAQQAoAoirRyIFQQFqNgKIq0cCQCAFQQBIDQACQAJAQQAtAICrRw0AQQBBACgC/KpHQQFqNgL8qkdBACgChKtHQX9KDQEMAgsgBEEIaiAAIAERBAAAC0EAQQA6AICrRyACRQ0AEIIBAAsAC2YBAX8jAEEQayIEJAACQAJAIAANAEEAIQAgBEEMaiEDDAELIAQgAjYCDCAAIANsIQAgBEEIaiEDCyADIAA2AgACQCAEKAIMIgBFDQAgBCgCCCIDRQ0AIAEgACADEBwLIARBEGokAAtnAQN/IwBBEGsiASQAIAFBBGogACgCACICIAAoAgQgAkEBdCICQQggAkEISxsiAhA+AkAgASgCBEEBRw0AIAEoAgggASgCDBBmAAsgASgCCCEDIAAgAjYCACAAIAM2AgQgAUEQaiQAC2YBAX8jAEEQayIEJAACQAJAIAANAEEAIQAgBEEMaiEDDAELIAQgAjYCDCAAIANsIQAgBEEIaiEDCyADIAA2AgACQCAEKAIMIgBFDQAgBCgCCCIDRQ0AIAEgACADEBwLIARBEGokAAtrAQJ/IwBBEGsiAiQAAkACQCABKAIAIAEoAggiA00NACACQQhqIAEgA0EBQQEQMiACKAIIIgNBgYCAgHhHDQEgASgCCCEDCyAAIAM2AgQgACABKAIENgIAIAJBEGokAA8LIAMgAigCDBBmAAtkAQN/QQAhA0G8r8AAIQQCQANAIANB/wFuIQUCQCAAIANHDQAgACEDDAILIAQgBUGBfmxqIQUgBEEBaiEEIANBAWohAyABIAIgBS0AABBCQf8BcUUNAAsgA0F/aiEDCyADIABJC2cBA39BACECAkAgAUEASA0AAkACQCABDQBBACEDQQEhBAwBC0EBIQIgASEDQQEgARA4IgRFDQELIAAgBDYCBCAAIAM2AgACQCABRQ0AIARBMCAB/AsACyAAIAE2AggPCyACIAEQZgALYwEBfyMAQSBrIgEkACABQdYANgIEIAFBoI/AADYCACABQZCPwAA2AgwgASAANgIIIAFBD61CIIYgAUEIaq2ENwMYIAFBEK1CIIYgAa2ENwMQQZSFwAAgAUEQakH4j8AAEF8AC2MBAX8CQAJAIAJFDQBBASACEDgiA0UNASAAQQA2AgggACADNgIEIAAgAjYCAAJAIAJFDQAgAyABIAL8CgAACyAAIAI2AggPCyAAQQA2AgggAEKAgICAEDcCAA8LQQEgAhBmAAtcAQJ/IwBBEGsiACQAIABB+KrHADYCCCAAQQAoAviiRzYCDEEBQQEgAEEMaiAAQQhqQQNBBBBGIQFBACAAKAIMNgL4okcCQCABDQBBAUEDEHgACyAAQRBqJAAgAQtWAQF/AkACQAJAIAIgACgCACAAKAIIIgNrTQ0AIAAgAyACEDwgACgCCCEDDAELIAJFDQELIAJFDQAgACgCBCADaiABIAL8CgAACyAAIAMgAmo2AghBAAtOAgF/AX4jAEEgayIDJAAgAyABNgIMIAMgADYCCCADQQatQiCGIgQgA0EIaq2ENwMYIAMgBCADQQxqrYQ3AxBB7YPAACADQRBqIAIQXwALSgEDf0EAIQMCQCACRQ0AAkADQCAALQAAIgQgAS0AACIFRw0BIABBAWohACABQQFqIQEgAkF/aiICRQ0CDAALCyAEIAVrIQMLIAMLTwECfyAAKAIEIQIgACgCACEDAkAgACgCCCIALQAARQ0AIANB9qXAAEEEIAIoAgwRBwBFDQBBAQ8LIAAgAUEKRjoAACADIAEgAigCEBEFAAtFAQF/IwBBEGsiASQAIAFBCGogACAAKAIAQQFBBEEEEDQCQCABKAIIIgBBgYCAgHhGDQAgACABKAIMEGYACyABQRBqJAALRQEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEBQQRBDBA0AkAgASgCCCIAQYGAgIB4Rg0AIAAgASgCDBBmAAsgAUEQaiQAC0UBAX8jAEEQayIBJAAgAUEIaiAAIAAoAgBBAUEEQRAQNAJAIAEoAggiAEGBgICAeEYNACAAIAEoAgwQZgALIAFBEGokAAtFAQF/IwBBEGsiASQAIAFBCGogACAAKAIAQQFBBEEIEDQCQCABKAIIIgBBgYCAgHhGDQAgACABKAIMEGYACyABQRBqJAALQgEBfyMAQRBrIgUkACAFQQhqIAAgASACIAMgBBA0AkAgBSgCCCIEQYGAgIB4Rg0AIAQgBSgCDBBmAAsgBUEQaiQACzwBAX8CQCABaUEBRw0AQQAgASAAQYCAgIB4IAFrSxsiAkUNAAJAIABFDQAgAiAAEDgiAUUNAQsgAQ8LAAs9AQF/AkACQCABRQ0AIAEoAgAiAkF/Rg0BIAEgAkEBajYCACAAIAE2AgQgACABQQRqNgIADwsQdQALEHYACz4BAX8CQCABRQ0AA0ACQCAAKAIAIgJBAEgNACACIABBBGooAgBBAUEBEEoLIABBEGohACABQX9qIgENAAsLC0EBAX8CQEEBIAIQOCIDRQ0AAkAgAkUNACADIAEgAvwKAAALIAAgAjYCCCAAIAM2AgQgACACNgIADwtBASACEGYACzYAAkAgA2lBAUcNAEEAIAMgAUGAgICAeCADa0sbIgNFDQAgACABIAMgAhBgIgNFDQAgAw8LAAs7AQF/IwBBIGsiAyQAIAMgATYCECADIAA2AgwgA0EBOwEcIAMgAjYCGCADIANBDGo2AhQgA0EUahBiAAs4AQF/AkAgAiADEDgiBEUNAAJAIAMgASADIAFJGyIDRQ0AIAQgACAD/AoAAAsgACACIAEQHAsgBAs5AAJAIAJBgIDEAEYNACAAIAIgASgCEBEFAEUNAEEBDwsCQCADDQBBAA8LIAAgAyAEIAEoAgwRBwALLQIBfwF+IwBBEGsiASQAIAApAgAhAiABIAA2AgwgASACNwIEIAFBBGoQgAEACygAIAEoAgAgAC0AAEECdCIAKALsokcgACgC4KJHIAEoAgQoAgwRBwALHAAgACACEC8gAEEgaiACQSBqEC8gAkEIIAEQJgscAQF/AkAgACgCACIBRQ0AIAAoAgRBASABEBwLCxQAAkAgAEUNACAAIAEQeAALEG8ACxUAIAEgACgCACIAKAIEIAAoAggQGgsZACABKAIAQYiQwABBBSABKAIEKAIMEQcACxIAAkAgAUUNACAAIAIgARAcCwsUACAAKAIAIAEgACgCBCgCDBEFAAsPACAAIAEgAiADIAQQCAALEAAgASAAKAIAIAAoAgQQGgsQACABIAAoAgAgACgCBBAaCxAAIAEgACgCBCAAKAIIEBoLEQBB1I7AAEEjQeiOwAAQXwALDwAgAEH4jsAAIAEgAhAVCw8AIABBsJbAACABIAIQFQsPAEHNlsAAQdcAIAAQXwALEgBBmKXAAEGZAUHkpcAAEF8ACwsAIAAjAGokACMACwwAQdigxwBBGxB9AAsNAEHzoMcAQc8AEH0ACwsAIAAoAgAgARAnCwkAIAEgABB5AAsJACABIAAQfAALDABBAEEBOgCMq0cACwwAIAAgASkCADcDAAsJACAAIAEQegALCQAgACABEAEACwcAIAAQcgALCQAgAEEANgIACwcAIAAQRAALBAAgAQsDAAALBQBBgAQLC4OjBwEAQYCAwAAL+KIHSGNpZEVycm9yAmhjwMAADWJlZ2luID4gZW5kICjAAyA+IMAQKSB3aGVuIHNsaWNpbmcgYMABYMAAEXN0YXJ0IGJ5dGUgaW5kZXggwBYgaXMgb3V0IG9mIGJvdW5kcyBvZiBgwAFgwAAPZW5kIGJ5dGUgaW5kZXggwBYgaXMgb3V0IG9mIGJvdW5kcyBvZiBgwAFgwAARc3RhcnQgYnl0ZSBpbmRleCDAJiBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUgwAggKGJ5dGVzIMAGKSBvZiBgwAFgwAAPZW5kIGJ5dGUgaW5kZXggwCYgaXMgbm90IGEgY2hhciBib3VuZGFyeTsgaXQgaXMgaW5zaWRlIMAIIChieXRlcyDABikgb2YgYMABYMAAE0JhZFBhcml0eUJ5dGVDb3VudDrAABNUb29NYW55Q29ycmVjdGlvbnM6wAksQWxsb3dlZDrAABhJbnRlcm5hbEdlbmVyYXRlZEJhZExlbjrACixFeHBlY3RlZDrAAAlCYWRJZExlbjrACixFeHBlY3RlZDrAAAtCYWREYXRhTGVuOsAKLEV4cGVjdGVkOsAAFnNsaWNlIGluZGV4IHN0YXJ0cyBhdCDADSBidXQgZW5kcyBhdCDAACBpbmRleCBvdXQgb2YgYm91bmRzOiB0aGUgbGVuIGlzIMASIGJ1dCB0aGUgaW5kZXggaXMgwAAScmFuZ2Ugc3RhcnQgaW5kZXggwCIgb3V0IG9mIHJhbmdlIGZvciBzbGljZSBvZiBsZW5ndGggwAAQcmFuZ2UgZW5kIGluZGV4IMAiIG91dCBvZiByYW5nZSBmb3Igc2xpY2Ugb2YgbGVuZ3RoIMAAwAI6IMAAL3J1c3RjLzU5ODA3NjE2ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9jb3JlL3NyYy9zbGljZS9pbmRleC5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2NvcmUvc3JjL3NsaWNlL3NvcnQvc2hhcmVkL3NtYWxsc29ydC5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2NvcmUvc3JjL3NsaWNlL3NvcnQvc3RhYmxlL3F1aWNrc29ydC5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2FsbG9jL3NyYy9mbXQucnMAaGNpZC9zcmMvZmF1bHRzLnJzAGhjaWQvc3JjL3JzLnJzAC9yb290Ly5jYXJnby9yZWdpc3RyeS9zcmMvaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2YvdW5pY29kZS1ub3JtYWxpemF0aW9uLTAuMS4yNS9zcmMvbG9va3Vwcy5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L3N0ZC9zcmMvc3lzL3RocmVhZF9sb2NhbC9ub190aHJlYWRzLnJzAGhjaWQvc3JjL3V0aWwucnMAaGNpZC9zcmMvbm9ybWFsaXplLnJzAC9yb290Ly5jYXJnby9yZWdpc3RyeS9zcmMvaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2YvdW5pY29kZS1ub3JtYWxpemF0aW9uLTAuMS4yNS9zcmMvZGVjb21wb3NlLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYnJhcnkvY29yZS9zcmMvdW5pY29kZS9wcmludGFibGUucnMAL3J1c3RjLzU5ODA3NjE2ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9jb3JlL3NyYy9mbXQvbW9kLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYnJhcnkvYWxsb2Mvc3JjL3Jhd192ZWMvbW9kLnJzAC9yb290Ly5jYXJnby9yZWdpc3RyeS9zcmMvaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2YvdGlueXZlYy0xLjEzLjMvc3JjL2FycmF5dmVjLnJzAGhjaWQvc3JjL2xpYi5ycwAPaW52YWxpZCBraW5kOiBgwAFgAAFbwAFdAAk8cmVjdCB4PSLABSIgeT0iwB0iIHdpZHRoPSIxIiBoZWlnaHQ9IjEiIGZpbGw9IsADIi8+AARoc2wowAksNjUlLDU1JSkABGhzbCjACSw2NSUsMzUlKQAEaHNsKMAJLDY1JSw1MCUpAAErwMDAAisKAGNhcGFjaXR5IG92ZXJmbG93AAAADQYQAFAAAAAcAAAABQAAABUAAAAMAAAABAAAABYAAAAXAAAAGAAAAAAAAAAAAAAAAQAAABkAAABhIGZvcm1hdHRpbmcgdHJhaXQgaW1wbGVtZW50YXRpb24gcmV0dXJuZWQgYW4gZXJyb3Igd2hlbiB0aGUgdW5kZXJseWluZyBzdHJlYW0gZGlkIG5vdAAAqgMQAEgAAACPAgAADgAAAEVycm9yAHAABwAtAQEBAgECAQFICzAVEAFlBwIGAgIBBCMBHhtbCzoJCQEYBAEJAQMBBSsDOwkqGAEgNwEBAQQIBAEDBwoCHQE6AQEBAgQIAQkBCgIaAQICOQEEAgQCAgMDAR4CAwELAjkBBAUBAgQBFAIWBgEBOgEBAgEECAEHAwoCHgE7AQEBDAEJASgBAwE3AQEDBQMBBAcCCwIdAToBAgIBAQMDAQQHAgsCHAI5AgEBAgQIAQkBCgIdAUgBBAECAwEBCAFRAQIHDAhiAQIJCwdJAhsBAQEBATcOAQUBAgULASQJAWYEAQYBAgICGQIEAxAEDQECAgYBDwEAAwAEHAMdAh4CQAIBBwgBAgsJAS0DAQF1AiIBdgMEAgkBBgPbAgIBOgEBBwEBAQECCAYKAgEwLgIMFAQwCgQDJgkMAiAEAgY4AQECAwEBBTgIAgKYAwENAQcEAQYBAwLGQAABwyEAA40BYCAABmkCAAQBCiACUAIAAQMBBAEZAgUBlwIaEg0BJggZCwEBLAMwAQIEAgICASQBQwYCAgICDAEIAS8BMwEBAwICBQIBASoCCAHuAQIBBAEAAQAQEBAAAgAB4gGVBQADAQIFBCgDBAGlAgAEQQUAAk0GRgsxBHsBNg8pAQICCgMxBAICBwE9AyQFAQg+AQwCNAkBAQgEAgFfAwIEBgECAZ0BAwgVAjkCAQEBAQwBCQEOBwMFQwECBgEBAgEBAwQDAQEOAlUIAgMBARcBUQECBgEBAgEBAgEC6wECBAYCAQIbAlUIAgEBAmoBAQECCGUBAQECBAEFAAkBAvUBCgQEAZAEAgIEASAKKAYCBAgBCQYCAy4NAQLGAQEDAQHJBwEGAQFSFgIHAQIBAnoGAwEBAgEHAQFIAgMBAQEAAgsCNAUFAxcBAAEGDwAMAwMABTsHAAE/BFEBCwIAAgAuAhcABQMGCAgCBx4ElAMANwQyCAEOARYFAQ8ABwERAgcBAgEFZAGgBwABPQQABP4C8wECAQcCBQEAB20HAGCA8AAwMTIzNDU2Nzg5YWJjZGVmIHsgOiAgewosCigoCn0pIH0AAAAAAAAADAAAAAQAAAAaAAAAGwAAABwAAABbLi4uXWNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKWAgb24gYSBgTm9uZWAgdmFsdWUwMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5MjAyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3ODc5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OQAGAQEDAQQCBQcHAggICQIKBQsCDgQQARECEgUTHBQBFQIXAhkNHAUdCB8BJAFqBGsCbgKvA7ECvALPAtEC1AzVCdYC1wLaAeAF4QLmAecE6ALuIPAE+AL6BfsBDCc7Pk5Pj56en3uLk5aisrqGsQYHCTY9Plbz0NEEFBg2N1ZXf6qur7014BKHiY6eBA0OERIpMTQ6RUZJSk5PZGWKjI2PtsHDxMbL1ly2txscBwgKCxQXNjk6qKnY2Qk3kJGoBwo7PmZpj5IRb1+/7u9aYrm69Pz/U1Samy4vJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZGiIlPj/f5+zv/8XGBCAjJSYoMzg6SEpMUFNVVlhaXF5gY2Vma3N4fX+KpKqvsMDQrq9ub8fd3pNeInsFAwQtA2YDAS8ugIIdAzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAtOAzQMgTcJFgoIGDtFOQNjCAkwFgUhAxsFGyY4BEsFLwQKBwkHQCAnBAwJNgM6BRoHBAwHUEk3Mw0zBy4ICgYmAx0IAoDQUhAGCAkhLggqFhomHBQXCU4EJAlEDRkHCgZICCcJdQtCPioGOwUKBlEGAQUQAwULWQgCHWIeSAgKgKZeIkULCgYNEzoGCgYUHCwEF4C5PGRTDEgJCkZFG0gIUw1JBwpWCFgiDgoGRgodA0dJNwMOCAoGOQcKBiwECoD2GQc7Ax1VAQ8yDYObZnULgMSKTGMNhDAQFgqPmwWCR5q5OobGgjkHKgRcBiYKRgooBROBsDqAxlsFNCxLBDkHEUAFCwcJnNYpIGFzof2BMw8BHQYOBAiBjIkEawUNAwkHEI9ggP0DgbQGFw8RD0cJdDyA9gpzCHAVRnoUDBQMVwkZgIeBRwOFQg8VhFAfBgaA1SsFPiEBcC0DGgQCgUAfEToFAYHQKoDWKwQBgMA2CAKA4ID3KUwECgQCgxFETD2AwjwGAQRVBRs0AoEOLARkDFYKgK44HQ0sBAkHAg4GgJqD2QMRAw0DgNoGDAQBDwwEOAgKBigILAQCDgkngVgIHQMLAzsEHgQKB4D7hAUAAQMFBQYGAgcGCAcJEQocCxkMGQ0QDgwPBBADEhITCRYBFwQYARkDGgkbARwCHxYgAysCLQsuATAEMQIyAakCqgSrCPoC+wX+A/8JrXh5i42iMFdYi4yQHN0OD0tM+/wuLz9cXV/ihI2OkZKpsbq7xcbJyt7k5f8ABBESKTE0Nzo7PUlKXYSOkqmxtLq7xsrOz+TlAAQNDhESKTE0OjtFRklKXmRlhJGbncnOzw0RKTo7RUlXW15fZGWNkam0urvFyd/k5fANEUVJZGWAhLK8vr/V1/Dxg4WLpKa+v8XHz9rbSJi9zcbOz0lOT1dZXl+Jjo+xtre/wcbH1xEWF1tc9vf+/4Btcd7fDh9ubxwdX31+rq/e3027vBYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dSYuL6evt7/Hz9ffmgBAl5gwjx/O/05PWlsHCA8QJy/u725vNz0/QkVTZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGsDoCrBSAHgRwDGQgBBC8ENAQHAwEHBgcRClAPEgdVBwMEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBU4HGwdXBwIFGAxQBEMDLQMBBBEGDww6BB0lXyBtBGolgMgFgrADGgaC/QNZBxYJGAkUDBQMagYKBhoGWQcrBUYKLAQMBAEDMQssBBoGCwOArAYKBkwUgPQIPAMPAz4FOAgrBYL/ERgILxEtAyIOIQ+AjASCmhYLFYiUBS8FOwcCDhgJgL4idAyA1hqBEAWA4QnyngM3CYFcFIC4CIDdFDwDCgY4CEYIDAZ0Cx4DWgRZCYCDGBwKFglMBICKBqukDBcEMaEEgdomBwwFBYKzICoGTASAjQSAvgMbAw8NawUQAFUAAAAKAAAAKwAAAGsFEABVAAAAGgAAADYAAAAAAwAAgwQgAJEFYABdE6AAEhcgHwwgYB/vLGArKjDgK2+moCwCqCAtHvsgLgD+YDae/6A2/QEhNwEKYTckDSE4qw6hOS8YITrzHiFLQDShUx5h4VTwamFVT2/hVZ28YVYAz2FXZdGhVwDaIVgA4KFZruIhW+zk4VzQ6GFdIADuXvABf191c2VyLXByb3ZpZGVkIGNvbXBhcmlzb24gZnVuY3Rpb24gZG9lcyBub3QgY29ycmVjdGx5IGltcGxlbWVudCBhIHRvdGFsIG9yZGVy6gIQAF8AAABcAwAABQAAAC4uICAgIDAxMGIweDAxMjM0NTY3ODlBQkNERUbBBRAASwAAAH4LAAAmAAAAwQUQAEsAAACHCwAAGgAAALL/tP+2/7j/uv+8/77/////////////////////////////////////////////////////////////////////////////////////////////////////////gP+C/4T/hv+I/4r/jP+O/5D/kv+U////lv+Y/5r/nP+e/6D/ov+k/6b/qP+q/63/rv+w/7gGEAAPAAAAfAMAABsAAAC4BhAADwAAAH4DAAAqAAAAUHJlZml4TWlzbWF0Y2gAALgGEAAPAAAAoQMAABUAAAC4BhAADwAAAJcDAAAiAAAAuAYQAA8AAACYAwAAIgAAALgGEAAPAAAAagMAAB4AAAC4BhAADwAAAFEDAAAXAAAAuAYQAA8AAABVAwAAIgAAALgGEAAPAAAA3AEAABgAAAC4BhAADwAAAPMBAAAYAAAAuAYQAA8AAAADAgAAGAAAALgGEAAPAAAA+wEAACIAAADDIAAAaQgAALgGEAAPAAAAvwIAABUAAAC4BhAADwAAALgCAAAgAAAAuAYQAA8AAADAAAAAGAAAALgGEAAPAAAAwQAAABgAAAAxMDEAuAYQAA8AAAABAwAAIQAAAAIFEABoAAAAmQAAACIAAAACBRAAaAAAAGAAAAApAAAAAgUQAGgAAABgAAAAGAAAAAIFEABoAAAAVwAAABQAAAAQBAAAQQAAABIEAABCAAAAFQQAAEUAAAAaBAAASwAAABwEAABNAAAAHQQAAEgAAAAeBAAATwAAACAEAABQAAAAIQQAAEMAAAAiBAAAVAAAACMEAABZAAAAJQQAAFgAAAAFBAAAUwAAAAYEAABJAAAACAQAAEoAAAAwBAAAYQAAADUEAABlAAAAPgQAAG8AAABABAAAcAAAAEEEAABjAAAAQwQAAHkAAABFBAAAeAAAAFUEAABzAAAAVgQAAGkAAABYBAAAagAAAAEFAABkAAAAkQMAAEEAAACSAwAAQgAAAJUDAABFAAAAlgMAAFoAAACXAwAASAAAAJkDAABJAAAAmgMAAEsAAACcAwAATQAAAJ0DAABOAAAAnwMAAE8AAAChAwAAUAAAAKQDAABUAAAApQMAAFkAAACnAwAAWAAAAL8DAABvAAAAvQMAAHYAAAC6AwAAawAAAD8AAABfAAAA7AQQABUAAACJAAAAGwAAAF4GEABZAAAAnwAAABoAAAACAgICAgICAgICAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgIAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAXgYQAFkAAACmAAAAIgAAAAECBAgQIECAHTp06M2HEyZMmC1atHXqyY8DBgwYMGDAnSdOnCVKlDVq1LV37sGfI0aMBQoUKFCgXbpp0rlv3qFfvmHCmS9evGXKiQ8ePHjw/efTu2vWsX/+4d+jW7Zx4tmvQ4YRIkSIDRo0aNC9Z86BHz58+O3Hkzt27MWXM2bMhRcuXLht2qlPniFChBUqVKhNmilSpFWqSZI5cuTVt3Pm0b9jxpE/fvzl17N79vH/49urS5YxYsSVN27cpVeuQYIZMmTIjQcOHDhw4N2nU6ZRolmyefL578ObK1asRYoJEiRIkD169PX38/vry4sLFixYsH366c+DGzZs2K1HjgECBAgQIECAHTp06M2HEyZMmC1atHXqyY8DBgwYMGDAnSdOnCVKlDVq1LV37sGfI0aMBQoUKFCgXbpp0rlv3qFfvmHCmS9evGXKiQ8ePHjw/efTu2vWsX/+4d+jW7Zx4tmvQ4YRIkSIDRo0aNC9Z86BHz58+O3Hkzt27MWXM2bMhRcuXLht2qlPniFChBUqVKhNmilSpFWqSZI5cuTVt3Pm0b9jxpE/fvzl17N79vH/49urS5YxYsSVN27cpVeuQYIZMmTIjQcOHDhw4N2nU6ZRolmyefL578ObK1asRYoJEiRIkD169PX38/vry4sLFixYsH366c+DGzZs2K1HjgECAAABGQIyGsYD3zPuG2jHSwRk4A40je+BHMFp+MgITHEFimUv4SQPITWTjtrwEoJFHbXCfWon+bnJmgl4TeRypga/i2Jm3TD94pglsxCRIog20JTOj5bbvfHSE1yDOEZAHkK2o8NIfm5rOihU+oW6Pcpem58KFXkrTtTlrHPzp1cHcMD3jIBjDWdK3u0xxf4Y46WZdya4tHwRRJLZIyCJLjc/0VuVvM/NkIeXstz8vmHyVtOrFCpdnoQ8OVNHbUGiHy1D2Ld7pHbEF0nsfwxv9myhO1IpnVWq+2CGsbvMPlrLWV+wnKmgUQv1Fut6dSzXT67V6ebnreh01vTqqFBYrwYEEAAOAAAAYgAAACUAAAAGBBAADgAAAGIAAAARAAAABgQQAA4AAAB6AAAAHAAAAAYEEAAOAAAA/wAAAB0AAAAGBBAADgAAAAcBAAAjAAAABgQQAA4AAAAKAQAADQAAAAYEEAAOAAAA8AAAACYAAAAGBBAADgAAAOMAAAARAAAABgQQAA4AAAC+AAAAKQAAAAYEEAAOAAAAqQAAABAAAAAGBBAADgAAALEAAAAiAAAABgQQAA4AAACxAAAAEQAAAJoCEABPAAAA/AMAADMAAAAIAAAALzxzdmcgeG1sbnM9Imh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnIiB3aWR0aD0iwAoiIGhlaWdodD0iyAAADyIgdmlld0JveD0iMCAwIMABIMgBACwiIHNoYXBlLXJlbmRlcmluZz0iY3Jpc3BFZGdlcyI+PHJlY3Qgd2lkdGg9IsgBAAoiIGhlaWdodD0iyAEAEiIgZmlsbD0iI2YwZjBmMCIvPgA8L3N2Zz4gLm8rPSpCT1hAJSYjL17zAxAAEgAAAKQAAABHAAAAUGFyc2VJbnRFcnJvcmtpbmRUb29NYW55RXJyb3JzQmFkQ29kZXdvcmRMZW5CYWRFcmFzdXJlbWlkID4gbGVuAEoDEABfAAAATQAAAB8AAABKAxAAXwAAAEcAAAAXAAAAGRobHB0eH/////////8AAQIDBAUGBwgJCv8LDA0ODxAREhMUFRYXGHVuZXhwZWN0ZWQgZW9mYmFkIGlucHV0QUJDREVGR0hJSktNTk9QUVJTVFVWV1hZWjM0NTY3ODkA2wQQABAAAAAQAAAAGQAAANsEEAAQAAAASAAAAB4AAABFbXB0eUludmFsaWREaWdpdFBvc092ZXJmbG93TmVnT3ZlcmZsb3daZXJvAAAAAAAAAAAAXQIDAF0AAAACAAkABACHAAYANQEBAAAAAAAEAAMA4QB4AAAAAgApAAgAjwAkAAAAAAAAADEAAAAAAG0AAAAzAAEATAAAAAEAAAAAAAgAfAAGAEwARQAqAAIAAAAAAAAACgAAAAAAWQAAABAAAwAJAA4ABgABAAwAEAAAAAAAAAA9AAAAAAAqAAAAAAABACAACgAAAAEADgACABAAIgAAAGEAAAAEABUAAAAEAAMAAAAAAAMAAAANAAEAAQAkAAMABAAAAAAAGABYAAAAAAAKABkAEAAAAAIAAAAAABIAFQAAAAwABwADAAAAGgAHAAwAAQAFAAQAIQABAAIAAAAAAAEACgAAAAoAAAAEAAkABQANAAUAAAADAAYAJgALAAAABAAGAAAAEwAAAAAABAABAAAAAAAAACQAAQAdAAEAAAAAABAAAAABAAEAAQAAAAAACgAAAA0AAAAQAAAABgAFAAAAAAAJAAMAAQAAAAAAAAAAAAIAAQACAAAAAAAWAAEAAgAAAAEAAAAGAAEAJQAFAAAACgANAAIAAAABAAAAAAASAAAAAAABAAAAAQAAAAEAAAAEAAkAAAABAAQAAAAEAAEABgALAAMABgAHAAAAAAAAAA0ADwAOAAAAAQABAAQADwAQAAAABQAAABkAAQAOAAAACAABAAMAAAAAAAUAAQAAAAAAEQAAAAQABQAAAAEAAAAAAAAAAAACAAEAAAAAAAIAAQABAAUACAAAAAAADAAKAAAABgAJAAMAAQAGAAEAAAAbAAEADgAAAAgABQADABUAAAACAAcACwADAAAACgADAAAABgAAAAoAAAABAAMACAAAAAkAAAABAAIAAAAFAAAAAQAAAAAAAAAXAAAAAAACAAAABQAWAAAAEAADAAQAEQABAAMAAAAAAAAABAABAAAABQAAAAIAAQAAAAIAAAACAAIAAAAAAAAAAAACAAAAEgABAAQACgALAAQAAAACAAAAAwAAAAQABQAAAAAAEQABAAYABgAAAAIAAAABAAAABAAAAAAAAQABAAcAAAADAAoAAAABAAoAAgAAAAEAAAAAAA0ABQABAAEAAAAAAAUABgAIAAQABQAAAAsAAAAEAAsAAAAAAAEABQAAAAsAAAAGAAAAAAAAAAAAAQAAAAQAAAAGAAAAAAAHABMABAAZAAMAAQAAAAEABgAGAAAAAAAAAAAADAACAAMAAAAEAAAAAQAAAAEABQABAAEAAgABAAAAAAAAAAAACQAAAAAABwABAAkABgAEAAEABQAAAAEAAgAIAAAACQAAAAAAAQADAAsAAAABAAAAAAAFAAAAAAAAAAAAAAADAAAAAgABAAIAAAAAAAAABAABAAAABgACAAUACAASAAAAAAAAAAMAAQAAAAAAAAACAAMAAwAAAAAAAQAAAAEAAAADAAAAAAACAAEABAANAAAAAAALAAAAAQAAAAAADAAAAAAAAAAFAAgAAgAAAAIAAQAGAAEAAQABAAAAAgAAAAUAAQABAAAAAgAAAAAAAAAAAA4AEAAAAAUABAACAAEAAAAAAAMAAQAAAAIAAQAEAAAAAAADAAAAAQABAAMAAQAFAAIAAwAEAAAAAwADAAYAAAAAAAEAAAACAA4AAQACAAEABAACAAUAAgABAAEAAAABAAAABQAFAAYABgAFAAAAAgABAAAAAgAEAAEAAAAFAAAABgACAAgAAAAAAAcAAQACAAIABQAEABEAAAADAAAAAQAAAAEABAAAAAMAAgAAAAEAAwAAAAsABgAAAAYAAAAEAAEAAgAAAAEAAAACAAEAAgAAAAUAAQAAAAAAAAABAAMAAgAGAAAAAAACAAQABgAAAAAAAAAAAAwADAAAAAAAAAAEAAQAAAAIAAMAAQAAAAEAAAADAAAAAAAFAAAABQABAAAAAwAEAAEAAgADAAAAAQAHAAAAAAAHAAAAAgACAAAAAAABAAEAAQABAAsAAAAEAAAAAQABAAAAAgABAAEABQAAAAAAAwAGAAAAAAAEAAIAAQADAAMACgAHAAAABAABAAYAAAABAAAAAAACAAEAAQAAAAAAAAAAAAAAAgABAAEAAQAEAAAAAwAAAAEAAAAAAAEAAAABAAAABAAAAAEAAAAGAAAAAQAAAAQAAAABAAIABgAAAAIAAAADAAcAAAADAAAAAQABAAAAAAADAAUAAgAAAAEAAQACAAIABAABAAAAAAABAAAAAwACAAAAAQAAAAIABAACAAAAAQAFAAAAAAACAAEAAQAAAAAAAQAAAAUAAgAAAAgAAwABAAEAAQADAAQAAAACAAAAAQACAAAAAAAAAAAAAAABAAAABgAAAAAAAAADAAAAAAACAAIAAAABAAMAAQADAAEAAgAAAAEAAgAAAAAAAQAAAAMAAQACAAAAAgABAAAAAgAEAAAABQAFAAAABQACAAAAAQADAAEAAAACAAYAAgABAAAAAAAAAAMAVAZKBiYGAAAJA2kAyR4AAAYDZQAVAQAACwMjBPIEAAAMA0cA5gEAAAEDVwCCHgAAAQPFAPoBAAA4A9IhzyEAAAcDQwAKAQAABwN6AHwBAAAMA0kAzwEAAAcDcgBZHgAAmTBLMEwwAABFA2EfoR8AAAcDQQAmAgAAIwNrADMeAAAEA5kD2R8AAAEDSR9NHwAAQgPKA9cfAAAEA1UAagEAAJow2zDdMAAARQNuH64fAAAxA0IABh4AAAgDHgTmBAAAJQNBAAAeAAAxA1IAXh4AAEUD9h/3HwAAOANyInQiAAA4A0giSSIAAAEDvx/OHwAAmTDGMMcwAAAjA3YAfx4AAAcDcABXHgAAAQNRH1UfAAAoA3UAcwEAAJkwWzBcMAAACgNBAMUAAAAjA6EB4x4AABEDVQAWAgAABwNTAGAeAAAMA9wA2QEAAAADTwDSAAAABwNvAC8CAAABA0cA9AEAAAkDYQCjHgAAAQMIHwwfAAABA7kDrwMAAAEDMwRTBAAARQMgH5AfAAAHA2IAAx4AAEUDAx+DHwAACANXAIQeAAAHA1oAewEAAJkwwTDCMAAACQOhAd8eAABVBicGJQYAABQDvwNBHwAAAQNnAPUBAAABA58DjAMAAAED6gC/HgAADANjAA0BAABFAwAfgB8AAAEDEwQDBAAAIwNLADIeAAABA3kA/QAAAAEDdwCDHgAARQMOH44fAAAIA0gAJh4AAJkwpjD0MAAAAAMCAbAeAAABA2wAOgEAAAkDRQC6HgAAAQM5Hz0fAAARA2kACwIAAAQDIwTuBAAADANhAM4BAAAAAxAfEh8AAAADOARdBAAAIwNIACQeAAA1GxEbEhsAAAEDQwAGAQAAIwNUAGweAAADA0kAKAEAAAIDoR6tHgAACAM1BFEEAAAHA2EAJwIAAAADAB8CHwAABwNtAEEeAAADA3YAfR4AACcDcwBfAQAABANHACAeAAAGA0cAHgEAAEUDbR+tHwAAAwNvAPUAAAAxA2sANR4AADEDcgBfHgAAAAOwAeseAAA1GwsbDBsAAC0DVQB2HgAACgNhAOUAAACZMEYwlDAAADUbOhs7GwAACgNVAG4BAAA8CSgJKQkAABMDyQNgHwAAMANlABseAAADA1kA+B4AADADRQAaHgAAMQNkAA8eAAAIA7kDygMAAJkwnTCeMAAACwNDBPMEAAA+C0cLSwsAAA8DbwANAgAAIwNuAEceAAAIA2kA7wAAAC0DZQAZHgAABwNaAWQeAAABAxgfHB8AAAEDCR8NHwAARQMnH5cfAAACA3oAkR4AAAEDWQDdAAAAMQNMADoeAABFA2QfpB8AAAIDuR7HHgAARQMkH5QfAAAAAxMBFR4AAA8DaQAJAgAAMQN0AG8eAAABA0gfTB8AADEDegCVHgAARQMpH5kfAAABA9QA0B4AAAQDxADeAQAAAQOXA4kDAAAMA3IAWQEAAAEDER8VHwAACQP0ANUeAAAnA0UAKAIAABsDbwChAQAABAPGAOIBAAAIA5kDqgMAAEUDzgP0HwAAAQNtAD8eAAAJAwMBsx4AAAIDbwD0AAAADANzAGEBAAABA3UA+gAAAAEDaR9tHwAAAQOpA48DAAA4Az0AYCIAAEUDxh/HHwAAAAN3AIEeAAAGAygCHB4AADEDbAA7HgAAmTCtMK4wAAACA7gexh4AAAIDVQDbAAAAzw3ZDdwNAAAEA6UD6R8AABQDqQNpHwAAJwN0AGMBAAAmA1MAGAIAAAsDVQBwAQAAmjBvMHEwAAAnA2sANwEAADADdQB1HgAAAQNzAFsBAABCAzAfNh8AAAcDRwAgAQAA1QzGDMcMAAAAA2kfax8AAAEDAwGvHgAAEQN1ABcCAAA8CTAJMQkAAJkwYTBiMAAAIwN3AIkeAAABA/UATR4AAAAD/ADcAQAADwNJAAgCAAACA2kA7gAAAEUDIx+THwAAAAP0ANMeAAAIA0sE+QQAAEUDLh+eHwAAAANlAOgAAAAIA0kAzwAAAAEDKB8sHwAAAAMYHxofAABTBicGIgYAAAEDIB8kHwAAOAMDIgQiAAAEA3kAMwIAAAADOR87HwAAJQNhAAEeAAAJA3kA9x4AAAMDrwHuHgAAAQNvAPMAAAAjA2QADR4AAJkwTzBQMAAARQMBH4EfAAAJA8oAwh4AAAgD0gPUAwAAAQNQH1QfAAAAAwEfAx8AAEUDqQP8HwAACAMnBPQEAAACA1MAXAEAAAEDUgBUAQAABgNFABQBAAAjA1MAYh4AAAYDZwAfAQAAOAN2IngiAAAAAzUEUAQAAJkwtTC2MAAACwNvAFEBAAAEAxgE4gQAAAcDYwALAQAAAQPSA9MDAAA4A3wi4CIAAAQDYQABAQAAAAOoAO0fAAAxA0QADh4AABEDZQAHAgAAAQP4AP8BAAAMA3UA1AEAACcDUwBeAQAAmTBXMFgwAABFA7cDwx8AAAwDaQDQAQAAJwNuAEYBAAAHA3gAix4AAEIDAB8GHwAAAwOwAe8eAAAnA0cAIgEAAAkDwgCoHgAALQNsAD0eAACZME0wTjAAAAMDaQApAQAAIwOvAfAeAAACA08A1AAAAAEDAgGuHgAACANHBPUEAAA4A6girSIAAAADxQN6HwAAEwORAwgfAAAtA2QAEx4AAAEDzwAuHgAADwNhAAECAAAGA6Eetx4AACMDeQD1HgAAOAOQIZohAABFAyUflR8AAAQDTwBMAQAABgNPAE4BAACZMLMwtDAAACcDRAAQHgAA1wuSC5QLAAAIA1UA3AAAACMDZQC5HgAAmTB7MHwwAAAMA08A0QEAAAQDZwAhHgAADANqAPABAAAjA1UA5B4AAAEDoQHbHgAAIwNZAPQeAAAAA0EAwAAAAJkwuTC6MAAACAMjBPAEAAABA1MAWgEAAJow2DDaMAAACQN1AOceAAAwA1UAdB4AACcDUgBWAQAACAM3BN8EAAAAA8oAwB4AADgDlCGuIQAABgOxA7AfAABCA2EfZx8AADgDPCJBIgAAmTDSMNMwAABFA2sfqx8AAAcDSQAwAQAAIwNBAKAeAAAJA1UA5h4AAAsDdQBxAQAAmTBTMFQwAACZMF0wXjAAACMDTAA2HgAABAMmAuABAAAPA3UEdwQAACgDSQAuAQAAAAMhHyMfAAABA2EfZR8AAEIDIR8nHwAAAgNnAB0BAAABA04AQwEAAAMDTgDRAAAAAAOZA9ofAAABA78DzAMAAAADFQQABAAAFAPJA2EfAAA4A6IirCIAAJkw2zDcMAAAFAOlA1kfAAAPA1IAEAIAACMDTgBGHgAARQMNH40fAAACA80e2R4AAAwD/ADaAQAABAPWACoCAAABAwAfBB8AAJkwzzDQMAAABgM1BNcEAAA4AwsiDCIAAAADWQDyHgAACAMXBN4EAAAMA24ASAEAAAwDTAA9AQAAAQNpAXkeAAAIAxAE0gQAAAEDoAHaHgAADwNBAAACAAABA3IAVQEAAAMDRQC8HgAANRsNGw4bAAABAxIBFh4AAAIDSgA0AQAAAAO/A3gfAACZMFkwWjAAAAsDTwBQAQAAKANhAAUBAACZMNgw2TAAAEUDKx+bHwAAAQNAH0QfAAAPA2UABQIAAEUDrgPEHwAAAQNoAXgeAAAmA3QAGwIAAEIDuQPWHwAARQNvH68fAAABA6UDjgMAAEUDCR+JHwAAAANVANkAAAAMA0EAzQEAACcDQwDHAAAABwNGAB4eAAABA7UDrQMAAD4NRw1LDQAAEQNhAAMCAAAIA9UATh4AAAADyQN8HwAABgNDBF4EAAABA/4f3h8AAAEDmQOKAwAABwN5AI8eAAAEA1oeXB4AAA8DdQAVAgAAvgnHCcsJAAA1GwcbCBsAADADaQAtHgAAmjB7MH0wAACZMMgwyTAAADgDsyLrIgAAAQOwAekeAAAAA3kA8x4AAAcDVwCGHgAAKANpAC8BAAAjA1oAkh4AAEIDxQPmHwAACQNPAM4eAABCAykfLx8AAAADoAHcHgAARQMqH5ofAAABA00BUx4AADgD0CHNIQAAKANVAHIBAAAxA2gAlh4AAAwDaAAfAgAAAQNMADkBAAA4A4YiiCIAABQDnwNJHwAABgOlA+gfAABWC0cLSAsAAJkwdTB2MAAABANbHl0eAABFA2ofqh8AACcDVABiAQAAAwMCAbQeAAATA58DSB8AAEIDCR8PHwAABgMQBNAEAAAAA00BUR4AAAQDQwTvBAAAOAOSIZshAAA4A2EiYiIAAAQD9QAtAgAAEwO5AzAfAAAIA28A9gAAAAcDcwBhHgAAGwN1ALABAACZMLcwuDAAAAcDYh5oHgAABgNBAAIBAAAUA5cDKR8AAAIDYwAJAQAAAgNqADUBAAABA+UA+wEAACYDcwAZAgAARQN0H8IfAAAJA2UAux4AAAwDWgB9AQAAOAMlIiYiAAATA6kDaB8AACcDcgBXAQAAAAMJHwsfAAAIAzYE3QQAAEUDCB+IHwAAMQNiAAceAAA4A3MidSIAACMDVgB+HgAAIwNPAMweAAAEAy4CMAIAAFQG1QbABgAAEwOxAwAfAAADA08A1QAAAAEDAR8FHwAABANlABMBAAAjA0UAuB4AAL4LxgvKCwAAOANlInEiAAAUA5EDCR8AAAADEgEUHgAABwNOAEQeAAAIA2gAJx4AABEDTwAOAgAAAwNVAGgBAAAUA6ED7B8AAAEDsQOsAwAADwNVABQCAAA4Az4AbyIAAAcDYQFnHgAARQMEH4QfAAAAA0UAyAAAADUbCRsKGwAAmTD9MP4wAAABA2gfbB8AAAMDZQC9HgAABAO5A9EfAADXCccJzAkAAAgDKwT4BAAAJwNOAEUBAABFA2Mfox8AAAYDOAQ5BAAAFAOxAwEfAAAmA1QAGgIAAAcDdABrHgAAAQPJA84DAAAUA5kDOR8AAJkwsTCyMAAAAgN1APsAAADWDMYMyAwAAJkwvTC+MAAAAANuAPkBAABCAzkfPx8AAAQDJwLhAQAAOAMIIgkiAAAGAxgEGQQAAAwDRAAOAQAACgN1AG8BAABUBicGIwYAAAYDdQBtAQAAAAO3A3QfAABFA2Yfph8AAEUDaR+pHwAABwNbAWUeAABCAygfLh8AAEUDcB+yHwAARQMPH48fAAAxA1QAbh4AADgDeyKBIgAAOAOHIokiAABCAwEfBx8AAAgDpQOrAwAARQNlH6UfAAAAA9QA0h4AAAQD9gArAgAA1Qy/DMAMAABCAzgfPh8AADgDsiLqIgAADwNyABECAACZML8wwDAAAAgD6QTrBAAAOAODIoUiAAAAA8sD4h8AAAEDygC+HgAABANZADICAAABA2kA7QAAAJkwXzBgMAAABwNEAAoeAAACA8we2B4AAEUDIR+RHwAAEQNBAAICAAARA3IAEwIAAAADpQPqHwAAAANZH1sfAAAEA+QA3wEAAAADUB9SHwAAOANNIm0iAAABA24ARAEAAC0DdQB3HgAAAQP0ANEeAABCA8kD9h8AAAEDtwOuAwAABwNuAEUeAAAEA0EAAAEAAAEDGR8dHwAABAOxA7EfAACaMHgwejAAACMDVwCIHgAACANDBPEEAAAtA0UAGB4AAAgDFQQBBAAAKANPAOoBAAAIA9kE2wQAAAgDBgQHBAAAAwP0ANceAAAGA7kD0B8AAAcDZQAXAQAAQgNoH24fAAAEAzgE4wQAAAADQR9DHwAAAAP+H90fAAABA08A0wAAACcDZAARHgAABgMpAh0eAAABA3oAegEAACMDYgAFHgAARQN8H/IfAAAjA7AB8R4AAAEDYB9kHwAAmTBmMGcwAAA4A2QicCIAAAADMR8zHwAAIwNhAKEeAAAAA0gfSh8AAAEDVQDaAAAAVgxGDEgMAAAAA8oD0h8AAAwDtwHuAQAAIwNyAFseAABCAyAfJh8AAAgDMATTBAAAOAOCIoQiAAABA1kfXR8AAEIDCB8OHwAAIwNEAAweAAAuECUQJhAAAAQDkQO5HwAAMQNLADQeAAAAA2kA7AAAAC4DSAAqHgAAAQNjAAcBAABCA2kfbx8AABMDtQMQHwAAmjDPMNEwAAAMA04ARwEAAFcLRwtMCwAAAQORA4YDAAAoA2UAGQEAAAADvx/NHwAAQgO3A8YfAAAHA0UAFgEAAAEDOB88HwAAmjB1MHcwAAABA0sAMB4AAAADAwGxHgAAAwPiAKseAAACA0EAwgAAAAgDZQDrAAAABwNYAIoeAAAMA1QAZAEAABMDxQNQHwAAFAO1AxEfAAA1Gz4bQBsAAAEDEB8UHwAAmjByMHQwAABFAygfmB8AADgDdyJ5IgAAOAN6IoAiAAACA0cAHAEAAAgDRQDLAAAAJANVAHIeAABFAywfnB8AACcDSwA2AQAAAgNJAM4AAAACA2gAJQEAAAcDUgBYHgAAAQNhAOEAAAAMA0sA6AEAAAkDSQDIHgAADANsAD4BAAAHA00AQB4AAAQDNh44HgAABAPFA+EfAAAKA3kAmR4AABMDlwMoHwAALQNUAHAeAAAAA0kfSx8AADUbQhtDGwAAEwO3AyAfAAABA+8ALx4AAAED5wAJHgAACANqAXoeAAADA8oAxB4AAAADsQNwHwAAVAbSBtMGAACZMNUw1jAAAEUDLx+fHwAAAAO1A3IfAAAkA3UAcx4AAAYDFQTWBAAADANlABsBAAACA1cAdAEAAAYDoB62HgAARQMHH4cfAAABA6gAhQMAAAIDeQB3AQAALQNuAEseAAAMA28A0gEAAEUDyQPzHwAAAgNDAAgBAAADA3kA+R4AACMDdABtHgAACQPiAKkeAAAAAzAfMh8AAAEDawAxHgAAOAOSIuMiAAAUA5UDGR8AAAEDKR8tHwAAKANFABgBAABFA7Yftx8AAAQD1QAsAgAABgMwBNEEAAAIA/UATx4AAAIDoB6sHgAAAANoH2ofAAAAA5cDyh8AAAEDRQDJAAAABANpACsBAADXC8YLzAsAAAQDbwBNAQAACANPANYAAAC+C8cLywsAAAwDVQDTAQAARQOsA7QfAABCA78fzx8AAJow0jDUMAAABwNmAB8eAAAAA04A+AEAAAgDeQD/AAAALgNoACseAAAMA2QADwEAAAcDTwAuAgAAQgNZH18fAACZMK8wsDAAAAQD5gDjAQAAAQPFA80DAAAIA3cAhR4AABQDuQMxHwAAIwN1AOUeAAAJA1kA9h4AAAwDkgLvAQAABgNVAGwBAAABA+YA/QEAAAgDawF7HgAADwNPAAwCAAATA78DQB8AAAED2AD+AQAAAwPCAKoeAAAAA1EfUx8AAAkDsAHtHgAALQNOAEoeAAAJA28Azx4AAAADGAQNBAAAOAOpIq4iAABFA2gfqB8AADUbPBs9GwAAAANAH0IfAABFAwsfix8AAAYDxQPgHwAALQNEABIeAADVDMoMywwAAAMD6gDFHgAABgMjBA4EAAABA8cACB4AAAwDegB+AQAABwNnACEBAAARA0UABgIAAAADbwDyAAAAmTC7MLwwAAAIAxgE5AQAAJkwUTBSMAAAIwNsADceAAAUA7cDIR8AAAoDdwCYHgAAIwNpAMseAAADA2EA4wAAAJkw8TD5MAAARQOxA7MfAAA+DUYNSg0AAAADuQN2HwAAOAPUIc4hAABFA2Ifoh8AAAIDdwB1AQAABAP8ANYBAAABAxMBFx4AAJkwaDBpMAAAQgNgH2YfAAAjA2gAJR4AABMDlQMYHwAAAAPqAMEeAABCA8sD5x8AADgDQyJEIgAAmTB4MHkwAAATA5kDOB8AADADSQAsHgAABwN3AIceAAABA2UA6QAAACcDaAApHgAAOAO1Iu0iAABCA7EDth8AAAYDkQO4HwAAAQNNAD4eAAAJA0EAoh4AADUbBRsGGwAAOAM8AG4iAAAIA1kAeAEAAAMDAwG1HgAAJwNIACgeAAAjA20AQx4AAJkwZDBlMAAACANWBFcEAAACA1kAdgEAAFQGwQbCBgAAAQM6BFwEAAAAA+IApx4AAAEDlQOIAwAAJwNjAOcAAAAHA38Bmx4AAFQGSAYkBgAAmTCrMKwwAAAjA6AB4h4AAAgD2ATaBAAAMQNaAJQeAAAMA2cA5wEAAAADnwP4HwAAJwNlACkCAAAjA0kAyh4AAAADER8THwAAQgNRH1cfAAAjA00AQh4AAAEDMB80HwAAJwNsADwBAABFAwUfhR8AAAADoQHdHgAACAMWBNwEAAAHA2QACx4AAC0DTAA8HgAAFAPBA+UfAAAEA+oB7AEAAAADCB8KHwAAAgNlAOoAAAAAA5EDuh8AAAEDcABVHgAARQMCH4IfAAAJAwIBsh4AAAMDdQBpAQAARQMMH4wfAAABA8sDsAMAAAgDdACXHgAAAAOVA8gfAAA4A30i4SIAACMDegCTHgAAEQNvAA8CAAAoA28A6wEAAAADIB8iHwAAAAMZHxsfAAADA1YAfB4AAAwDawDpAQAACAPoBOoEAAAAA6kD+h8AAEIDqADBHwAABgM2BMIEAAABA9wA1wEAAAQDdQBrAQAAOANFIkciAAAEA0kAKgEAAAgDOATlBAAAVw1GDUwNAAAAA3UA+QAAADgDtCLsIgAACAN1APwAAAAUA8UDUR8AAAcDYAFmHgAABgMWBMEEAAAbA1UArwEAADgDqyKvIgAAAANMAVAeAABCAzEfNx8AAEUDJh+WHwAAOAORIuIiAAABA1AAVB4AACcDTAA7AQAAAQNaAHkBAAAHA0IAAh4AABMDwQPkHwAAAQNBH0UfAAAHA1AAVh4AAAwDQwAMAQAAIwNzAGMeAACZMFUwVjAAAJkwbzBwMAAACANNBO0EAABFA2cfpx8AAAEDrwHoHgAAAAMpHysfAAA1Gz8bQRsAAAIDRQDKAAAABgNvAE8BAAABA/wA2AEAAJkw8jD6MAAARQMtH50fAAABA0wBUh4AAAgDYQDkAAAARQNgH6AfAABFAwofih8AAAYDYQADAQAAAANgH2IfAAAAA68B6h4AAAgDPgTnBAAAOAMjIiQiAAABA8oDkAMAAJow1TDXMAAARQMiH5IfAAABA9UATB4AAEUDbB+sHwAABwNUAGoeAAAEAzceOR4AAMoN2Q3aDQAAAwOhAeEeAAAMA0gAHgIAAAkD6gDDHgAADANSAFgBAAADA9QA1h4AAAwDRQAaAQAAmTDvMPcwAAACA0gAJAEAACMDUgBaHgAAAAPCAKYeAAAtA3QAcR4AAAgDLQTsBAAA3w3ZDd4NAAAIA0EAxAAAAA8DdAR2BAAAAQPGAPwBAAABAzEfNR8AAAADYR9jHwAAwgzGDMoMAAABA8IApB4AAAgDxQPLAwAAAQPiAKUeAABCA1AfVh8AAAADYQDgAAAAEQNJAAoCAAAnA2cAIwEAAAcDWQCOHgAARQOXA8wfAAAJA9QA1B4AAAMDoAHgHgAAQgP+H98fAAABAxoEDAQAAAwDdABlAQAAAAPcANsBAAAIA3gAjR4AAAQD3ADVAQAAAgNhAOIAAAACA1oAkB4AADwJMwk0CQAAGwNPAKABAAARA1IAEgIAAAMDbgDxAAAADwNFAAQCAAAJA68B7B4AAAQDRQASAQAAAQMhHyUfAAAEAy8CMQIAAAYDmQPYHwAAMQNOAEgeAAAAAzgfOh8AAAYDSQAsAQAABgNpAC0BAABFAwYfhh8AAAEDSQDNAAAAAANXAIAeAACZMPAw+DAAAAwDUwBgAQAAAANJAMwAAACZMHIwczAAAAADKB8qHwAABwNIACIeAAAJA6AB3h4AAAQD6wHtAQAABwNjHmkeAAAjA28AzR4AAAMDQQDDAAAARQORA7wfAAAoA0EABAEAAAcDaAAjHgAAMQNuAEkeAADKDdwN3Q0AAAgDWACMHgAAAQNBAMEAAACZMMQwxTAAACMDQgAEHgAAAgNzAF0BAAAAAAkA1wMGAAIAvwGJAK4AHwA0AAAABgCrAAAAnwA1ACIAAAAAAGYAAACNADgAGgABAIAAlAAGAFAAXwAAAF4ACwBkAAAAAAAmAAIAAAAEAAAAHAAAAAMACAACABwAJAAhAAMAFwAHAAAAAQACAAwAFAAAAAcABQA+AAEAAAAPAAAAAAAAAAAABgAHAAAAAQAEAAcAAAAGAAAAAAAPAAwAOwAAAAAAEgAAAAMAAwACABwAAAAAAAAABwABAAAAAgADAAIAAQAAAAoAAAATAAQACgAAAAEAAAAFAAAAAAAJAAAAAAAAAAIAAAAQAAIABwAwAAgAAQAAACUAAAABAAoAAQAAAAQAAAAQAFkAEAAGAAAAAAAAAAoAEQAAAAAAAAABAAIACQACAAAABAAAAAEACAAAAAAAAgAQAAAAAgAAABEAAAADAAAAAAAAAAEAAAAAAAEAAQARAAIACQAAABQACQABAAYAAgAKAAQAAwAHAAAABAAoAAcAAAAFAAAAAwAJAAAAAAABAAAACwAAAAMAAAABAAwAAwAAAAAABgABAAcAFwABAA4ADAABAAgAAQACAAAABwAAAA0AAAABAAAAAAAKAAYAFwAGAAAAAAADAAAAAAAVAAEAAAADAAAAAAAOAAAAFwAAAAIAAAAAAA8AAwAAAAAAAQAEAAAAAQAAAAoABQAIAAIAAAACAAAACAABAAAABAANAAYAAAAEAAAAAAAAAAAADAAKAAAAAwAEAAQAAAAAAAIAAAAAAAoAEQABAAMACAABAAgAAAABAAEACgAAAAUAAwARAAEAAgABAAQAFgAYAAQABAABAAAAAwAFAAsAAAABAAAAGgAAABAAAAAFAAAACgACAAAAEQABAAcAEgABAAUAAAABAAAAAAADAAQAAAACAAAABAAOAAMAAAABAAAAAAABAAcACAAAAAAAAAAAAAUAAwAAAAAACQABAAAAAAAKAAEAAAAKAAEAAQABAAAACwABAAQAAgAGAAAABAABAAEAAwAAAAAAAQAAAAMAAQACAAMAEQAdAAUAAAAAAAAAAAAAAAAAAAAFAAAAAAAAAAEAAAAAAAAAAAAAAAAAAQAAAAIAAQACAAAAAAADAAMAAwABAAEACAADAAAACQAAAAQAAAABAAIAAAABAAAAAgAKAAMABwABAAkABAAAAAIAAgAFAAYAAAACAAAAEAABAAIABQAAAAAAAwABAAAAAAAAAAAABgAAAAAAAAAHAAkAAAAGAAAAAgABAAUAAwADAAIAAAABAAoABAABAAUAAwAAAAEAAQANAAAAAwAEAAMAAgABAAIAAAADAAAAAAABAAAABAAEAAAAAAADAAEADgACAAMAAAAAAAQACAACAAQAAQAAAAAAAQAAAAkAAQAAAAYAAgAAAAIAAQACAAAACAAAAAUAAQABAAAAAAAEAAgAAAADAAEAAAAKAAUAAAABAAMAAAACAAMABAAEAAMAAAANAAUAAAAAAAMABwABAA8AAQACAAkAAgADAAUAAAAAAAIAAAACAAAAAAAAAAQAAQAAAAAAAwAGAAMAAwACAAAAAwAAAAEAAAAAAAUAAwACAAgAAgADAAAAAQAAAAEAAQAGAAEACwAAAAIAAQABAAAAAAAAAAMAAwAGAAAAAgACAAAAAAABAAEAAAAFAAAAAAAAAAIAAAADAAIAAAAAAAAAAAAAAAIAAgACAAgAAQAAAAAAAgADAAMAAAABAAIAAAABAAIAAAAFAAIAAAACAAMAAAABAAEAAAAFAAoAAwACAAAABgACAAMAAwACAAEAAgAAAAAAAAAAAAAAAQABAAIAAgAAAAAAAQABAAAAAAABAAgABwAAAAEAAwAAAAIAAgACAAEAAgAAAAEAAwAAAAMAAgAEAAAAAQAAAAMABAABAAAAAgAEAAAAAgAAAAAAAQADAAIAAQAAAAAAAQAEAAIAAQAEAAIAAwABAAMAAAABAAcAAwABAAEABwAAAAAAAQAAAAAAAwAAAAYAAAACAAIAAQACAAQAAQABAAEAAwAAAAMAAAAAAAgAAAAGAAAABQADAAEAAgAAAAAAAAABAAAAAAAAAAIAAwADAAAAAAAAAAAAAAALAAAAAAAAAAQACQABAAUAAQAAAAEAAAAHAAEAAwABAAEAAQAAAAUAAAAIAAEAAgAAAAAAAQAAAAEAAwABAAQAAgAHAAAAAQAEAAIABQAEAAAAAgACAAIAAAAIAAUAAwABAAAAAAAAAAEAAgACAAMAAwADAAAAAwABAAIAAAAAAAAAAQAAAAAAAwACAAAAAQABAAEAAAAAAAMAAQAAAAAAAgAAAAAAAQAAAAEAAwABAAEAAQAAAAAAAQADAAAAAgABAAEAAQACAAAAAwAIAAAAAQABAAgABAACAAIAAgABAAIAAgAAAAAAAQAAAAAAAAAAAAAAAAAAAAAACAAAAAAAAAAAAAEAAQAAAAAAAwAAAAEAAQABAAEABAAAAAIAAAAAAAEAAQABAAAABAAAAAUAAAABAAIAAgABAAAAAAADAAEAAQAAAAEAAgAFAAAAAgABAAEABAAAAAAAAgAAAAAAAQADAAYA5koDAObQGgDm0hoA5onRAeZ0EwEJTQoA5jZrAebLHQDmkwUA3OACAeZmEwHmJv4A5pgIAAlFHQEZwgUA3JsFAObwpgDm9y0A5uuoAHa4DgAfTwYA5kEHANx70QHcLf4A3NHoAebeHQDmI+AB5ssaABS7BQAB4xwA5s4IAOZ7GgDmbRsA5uaoAOY6BwAHwxQB5iwIAObWBgDcIAMA5uSoANzYHAAHNhIB4C4wAOZH6QHm7AcACcARAeYq4AHm6AYA5jAHAObgGgDmFeAB5lEDAOZ8GgDmJQ0B5v0IAOYPCgHmnAgA5vwtAObiqABrSA4A5swaAOjt5AHm/h0A3E0PAd6tBQDcXwYA5r6qAObEBQAHPAwAIBoGAObxLQDm5C0A5pwFAAfKEQHc1xwA5kgPAdw6CgHmt6oACToQAObkBgDoWAMA5ocEAOaxGgDqXQMACc4TAeY9AwAMsgUA5tUgAOYZCAAHNxwAAfNqAcrQHQDm1B0A5uMdAAfmGwDm3R0ACTQXANxEBwDm2x0A5lIDAHrLDgDm0xoA3DoDAAc3EAABNwMA5gkDAAlNDADmbhsAAeogAOarDgHm7wcAgXEPAAFo0QHc1ugB3MYPAOZnAwDkKzAA5uAcAOau4gHm2hwA5scaAAc7EwHcVAMA5uEtAOaYBQDm1RoA2G7RAQl/LQDc6CAACc8TAebhHQDcFgMAyicDAOZF6QHmSOkBeskOAOrNHQAJTQ0AAegcAAm5EAEJ0hcA3JYFAOaq0QEOtAUA5oQEANz5HQDm7gcA5lcDANzU6AHm+S0A5j4DANzpCADm+BwA3NPoAQmEDwDcFwMA5oMEAObt4gHmbAMA5mMDAObbIAAhUQYA5uEIAObhBgDmJP4A5tQaAObkHQDcUgkA8EUDAOZMDwHmSQ8B5lkGAOZvGwDm6x0A5vEsAObXBgDcNAcA4C8wAOZqEwEB5SAA5vsIAOZoEwHmROkB5hjgAQkVFwAHQh0B5u4tAOY/AwABadEBgnIPAOZAAwAImjAAgnsPAOaGDwDq6xoA5tsaAAk6DgAJRhAB3BgDAMooAwDcLP4A6PYdAAc8CQAJRB0BCcIUAebsBgDm6RoAHEwGAOZE0gHmHeAB5hvgAeZ4GgDcqgUA5hIDANzCHQDm5i0A5iEIANwuAwAgUAYA5ssIAObGHQDmtBoAB7wMAOZeEwDmngUA5vMtAOboGgDm5QoB5msTAQlwEAHmM2sB5iMIAObzHQDmFAMA3EYPAQdDGQHcHwMACUcaAebIGgAJQR8B5p0IANzeHADcjRAA5hYGABjBBQAHvAoA3NkcAAk9GQHmQwMAAdkgANwvAwDcRgcA3PoOAeboHQDmeqYA6l4DAAmXHQEB9GoB5sEdAGc4DgDcwxoA5tkIAOb0LQDc/w4BDbMFANwp/gDcHgMA5swIAOn8HQAdTQYA5m0NAdxcBgDYcdEB5jIHANzdHADcgw8B5pcFAOasBQDcMgMA5ucIAAE5CgHc7RwA5msNAQezqQDmQQMAI3AGANxPDwEJLKgA5hgIAObvLADm2wYA5tYdANwsAwAJOw0A2GXRAYJ9DwDctxoA5pkFAObyLQDm6h0ABzwLAOarBQDcTQMA5vsdAGtKDgDc7iAA5u/mAdxJAwDm1yAA3NIIAObXCAAVvAUA5jThAebnqADmZxMB5loGAAkGqADm5xoA3M8dAOYv/gDmxx0AGh77AOaVBQDmaQMA5vCoANzjCADm7C0A5ugIABK4BQAB8moB5kAHAObu5QHmJw0B3MQaANy6GgAJPA0A3KUFAObxqADmzxoA5gDgAeZ5pgDqYQMA3EcPAebm5gEJNBEB5gbgAeYmDQEB4hwA5swdAOaP4AEJqhsA5u/kAQk5EADmegMB3DEHAOb0HQDmawMA5kwDAHa5DgDc+ggA3JkIAObNCADcthoA5h7gAebGGgDm4AgA3MUFANx/GgDm3AYA5u2oANzTCADmKOAB5sgdANzRCADo7OQB3DwHAOZqDQHm3x0A3O8gANzmCgHmHQgABzwKAAm6DgDm76gAgoAPAObcHQDmJQgA5jJrAebQIADmJwgA2BsDAAHSIADcLakA5gPgAeYLAwDcpwUA5uktAOZyEwHc/x0ACTUSAeZsEwHm5S0AATgDAOZdEwDmJOAB5v4JAAk/CgEJzQwA5lMGAAlCHwHmVAkACc0KAOSpGADk9x0A5lcGANwYGgDmrA4B5skaAOYCEQHm3RcA5noaAObaGgDmXQYA5nimAObUIADcKQMA5tYaAB3yCADoLDAA3P0OAebwLADmOAoB5ukgANzVHADmMuEBesgOANxIBwDmBgMA5iX+AOZvpgDmAQMA5qEFAObrCAAJfxAB5hTgAeZFBwDm4KgAB8AVAeb6LQDmwB0A5gARAeT4HQAKsAUA5hUGAOZCAwDmFxoA5p8IAOZ2GgDcKP4A5iD+AOY1BwDmBOAB5uUdANxZAwDm9R0A5v8tAIJ8DwDmDwMACasbAOZpEwHc0OgB4m3RAQk+GQHYOQ8A5gzgAdbOHQDmh9EB5sGqAOb9LQDmCuABBzQbAObqLQDmhA8B5t0IAObwBwDm4y0A5gADAAHaIADc7QYA3Cr+AAc6GAHcgNEB5mUDAOYN4AEImTAA5h8IABzxCAABNgMA5p0FAAntqwDmAREB5hQGAOYHAwAJPxwB5s0aANzmGgDm5BoA5uPmARC2BQDm0RwA5qzRAeYf4AHms6oA5uMaAHrKDgDcJgMA3CQDAOar0QHmIf4A3JoIAOYMAwDmIv4A5hLgARtLBgDmZAMACRQXAAm/FQHm/wgA3HzRAQmZGgHmJggA3N8cAOlcAwDmHggA5iQNAQk/FgHmIAgA5nMbAOYP4AEBZ9EBCWAaANx+0QHmXxMACTkYAVtWDADmagMACU0LAAbwbwHcIwMA5vcIANxVBgDme6YA3DMDAAkrFwHm5yAA6BoDAObvHQDmdKYA5kbpAdwn/gDmPQcA5joZAOYC4AHc/R0A5kkHAAlTqQDmnwUAG/AIANzPCADmUAMA5mwNAeb4CADmdqYAZzkOAObWIADcf9EB5u8tAObZGgDm6wYA5lsGAOZC0gHmoAUA3OMGAObVCADm7uYB5lgGAAHkHADmsKoAAdMgAObiBgDcOQcA5u0dANxTAwDcVQMA5vkcAOZeBgDc0ugB5vstAOYOAwDcVgYA5tsIANzuCADmdwMB5kMHAOb8CADmVAYA5uwIAObKCADm9BwA5tcdAOYpCADckQUA5nEbAObuqADcDQoB3EIHAOZKDwHmEwYA3E4PAeY0awHm9eYB3O/lAebtLQDm8h0AB0YUAQn2qgDmgg8A6mADAOY/BwDm5QgA5i7+AObxpgDcGQ8A5gIDAObrLQDmaAMA5tgaAAHrIADc7wgA3EcDAOYrCADmhgQA5nUaANzqBgDm2AYA5obRAebbHADm6qgA5uQIAB8ZBgDmnggA5v4tANyjBQDmEeAB3NYcAObaHQDmyR0A3LkaAOZeFAHmbgMA5tEgAOY2BwDmaxsAC7EFAOazGgAJxKgA3DUPAObpqADcpgUA5twIABG3BQDmsqoA5q3RAebu4gEH6RIB3MAaAObxHQDctKoA5r+qANzV6AHmcxMBATUDAOZ9pgDmFggA3DcPAOYW4AHcHAMA6BUDAOblGgAB2CAA5vYtABe/BQDmGwgA5ucGANhm0QHmxRoACc0LAOSuBQDmd6YA5jMHANwrqQDcWggA5hIGAAe8CQDmgg8B5sQdAOYXCAAJ8xsABvFvAeaFBAAHuhAB5kYDAOb+CADcMQMA5tUdAOYO4AHcJQMACTMRAebpHQDmvBoA5t4IAObcIADmEOAB5gQDAObXGgDmnqYA5nWmANz2CADc7uQB5vQIANw4BwAB1BwA5gjgAeaI0QHmCgMA3O0gAOY24QHm7+IB2vodAObhqADm6ggA5jDhAeYp4AHcPgcA5gXgAdwZAwDcOQMAHhgGAOYcCADmIOAB5jPhAeYc4AHcK/4AAZ68AeYJ4AHm9S0A5ggDAOYm4AHm8QcA3C0DAAm2FgHmsBoA3CypANwwAwDctRoACcoNAMoiAwDmMGsBB7cWAebRGgDcWgMA5uctAAnqEgHmeAMB3P0HANoqMADm3wYA5g0DAObTHQDmLQgA5gUDANz5CADmbwMA5hEDAObcGgAJTRMB5sIaANzsIADm2QYA5kcHAObiHQDmcBMB3P4OAeYT4AHcTgMA3DsDAAnyGwDcWQgA5vgtAObzCADm0R0A5hMDANzKGgDmzhoAD7UFAIJ6DwDm6hoA5iIIANztCADpXwMAAeYcAOZTCQDmFwYA2HLRARO5BQDm4B0AIlIGANxsGwDmKggA5koHAOYj/gDcPAMAAeccANxLDwHm5agA5jFrAQlNCQBrSw4A5uwdAObzBwDcpAUA3B0DAOaDDwDcgdEB5oXRAeb1CADcmwgAJBEHAObgLQDcKwMAEscFAOZRCQDm3wgAAfFqAdyiBQDmEQYA5toGAOaoBQAB5RwA5riqANw3BwDcOxkA3CoDAAkvYQHmF+AB5nkaAObDHQDm6C0A5ncaAOZ8pgDmeQMB5sEaANz9AQHmRAMA5vAgAAlEGwDeORkA3BgPAOavBQDmhw8AAeYgAFRVDADmQ9IB5iHgAebjqAAJNBoB5tIdANz7DgHcVgMA2HDRAebuHQDm2ggA5ksDAObhIADm4RoA5gMDANy9GgDcvxoA6WIDAOaUBQABNAMA3PIHANyK0QHm1AgA5rsaAOapBQDm2B0A3IUPAeYL4AHm6KgAhHQPAObmHQDcgtEB5tgIAOZmAwDmn6YA5tYIAObQHADmEAMA5sUdAOYQBgAB8GoB5ifgAeaXCADc0AgA2G/RAdy4GgDmSekBCUIUAebiGgDmkgUA5uItAAnAqQDm0hwAE7oFAAnQEwEHPBMB3poFAOZ2AwHmNeEB5uyoANxQDwHcOwcA5jHhAdzmCADm7OIB3IvRAd4tMADc3BwA5lsDAOYB4AHm7QcA5vAtAB5OBgDm2R0A5nAbANzKHQDmchsA5uAGAObnHQDKIQMA5jVrAQngGQHcSAMAa0kOAOZtAwDmcRMBB3MRAebwHQDm6wcA3N0aANx90QEJzQkAFr0FAOZpDQHcWwgA5rIaAAdK6QFxBQMAAAAAAD0AYAEAAH8C9wCxAQAAAgAEADMANgEXAAEAAACTAe4BAAAGAAAAVAA0AAIAVABIAggABgADAAAAAAAAAGQAAQAAAAAAAgAFAAAAAAAeADUACQB9AAQAEgBYAAAAAAAAAAIATAAAAAAABgBoAAAABgAAAAoABgABAPYAAAAAAAIARgAAAAEAAAADAAAAQAEAAAoA3wABAAAAAwAEAAgABQAEABQAMABbAAAAGACoAAAASAAEAAAABQAAAEIAAABoAAAAAAAtAAAAAAAAADIABgAAAB8AYQAAAA0AAAATAAUAJwACAAIAAgACABMANwAQAAAAGQAGAAAALABfAF8AAAABAAAABQAAAAAABQAGACIAPAAQAAAAAAAAABIAAgAEAFEAAAAAAAIAMgAGAAEAAAARAAUABAB1ADIACgAAABYADgABAA8AJwAAAAUAAQAKAAcAEAAEAAEAAAAHAA8AAgAFAF4AAABIABcAAAAAAAQAAAACADwAHAALAAAABAAjAAEADAAOAAUAHAAAACAAAQADAAAABwAIAAQAAAAAAC0ACAAAAAAAMwATAAIAAAADAAAABgA8ACAAAAAJAAAAJQASAAkAAAAAADoAIgAAAAIAAQAeAAkAAgACAAMAAAAAAAAAAAAAAAAACwABAAEAGwAEAAcADgADAAAAAwAHAAAAAgACACYAAAAIAAMABAACAAIACAAJAAAAAQAGAAEAAwACAAEACAAAAAQACQAMABMAAAAAAAUAAAAKAAAAFAAAABQAAAABAAAAAAAAABwAFgAAAAAAAgAEAAAAAAAAAAoAAAAAAAcAAgAAAAAABAABAAoAAAAAAAkABQApAAAAAAABACAAAwAGAAIAAQAOABMAAQAkAAAAAQABAAMABAACAAIACgAGAAgAAAAKAAAABAAAAA0ACwAAABMACQAAAAAAEgAAAAYAAAAAAAIAAAAAAAwAAAAAAAAAAAAAAAIAAAATAAIAAAACAAAAAAAAAAAACAAAAAAAAQABAAQAEAAAAAsACwACAAEAAAABAAEAAgACAAEAAQACAAQACAAAAAAAAQApAAEADgAAAAAADgAAAAsAAAAXAAUAAAAVAAgABgAIAAAAAgAMAAAAAAAAAAgAAAAAAAAAAAAMAAAAAAAEAAAAAAAAAA8AAAAAAAEABwAAABEAAAANAAgADgALAAAAAgABAAAABAAFAAMAAwABAAMACAABABoAAQAFAAEABQAGAAAAAQAAAAAAAAAQAA8AAgAEAAYAAgACAAwAFwADAAAAAwAAAAEAAAAAAAAAAAAAAAAAAAAAACIAAAAAAAAAAAAAAAAAHAAUAAEAAAACAAQADwAAAAcAAQAAAAIAAQAAAAAAAAAGAAkAAwAsAAkABQAAAAAACwAIAB0AAAABAAAAAAAAAAEACAADAAUAAAAEAAAAAAADAAoAAwAAAAUAAgAEAAoAAAAAAAMAAgAAAAIAAAAOAAAAAAAFAAAACQAXAAMABAAAAAEABAAAAAAABwAVAAAAAAAAAAAAAAAAAAAACQADAAEAAQABAAYACgAAAAAABgAAAAEAAAAAAAIAAAAAAAEAAQACAAAAAAAAAAMAAwASAAEABgAEAAcAAAABAAAALgACAAEACwAIAAAABQAAAAIABAAJAAAAAAAIAAUAAwABAAYAAAAAAAMAAAADAAYABgAAAAMABwAAAAYAAQAAAAAABgAEAAAAAAABAAAAAgAAAAUAAAAiABQABgACAAAAAAAAAAAADAAAAAoAAQACAAcAAAACAAEAAQAEAAAAAAASAAIAAQAAAAAAAAADAAMAAAAFAAAAAAADAAAAAwAAAAYAAAAFAAEAAAAIAAQAAAAJAAEACgAOAAAABQAOAAcAAAAAAAgAAAAAAAAAAAAEAAAAAQABAAAAAAACAAAAAQAAABAAFgABAAEAAAAAAAAAEQAAAAAAAAABAAAAAAAAAAIAAAABAAYAAgAaAAAACwAEAAAAAAADAAYAAQAJAA8AAgAFAAEAGgAAAAAAAAABAAIAAgAPAAAAAAAKAAIAAwACAAcABgAAAAcABAAMAAAAAAABAAcAAAAAAAAAAAAAAAQAAAAAAAAAAgAAAAAAAAAKAAYAAAACAAAAAAAAAA0AAAADAAEACgAAAAwAAAABAAAABwADAAIAAQACAAEABwADAAIAAQAEAAEAAAAAAAgABgAAAAAAAQANAAQAAQACAAEABAAAAAAAAAAHAAIAAAAAAAAAAAATAAAAAAAAAAAAAwARAAEAAQADAAYAAAAGAAMAAQAAAAAAAAALAAAABAAGAAAAAAAFAAAAAgAAAAEAAQAFAAEABwAFAAAAAQABAAAAAQABAAcACQABAAwABAACAAEAAAAFAAAAAAABAAIAAQAIAAUACwACAAAABwABAAQAFgAAAAAAAAAAAAAAAAAAAAAAAAAWAAAAAAAOAAAAAAACAAQAAAAAAAIAAAAEAAYAAQAAAAAAAAAMAAcACQAEAAMAAQABAAkADQABAAIAAwAAAAQABAAEAAIAAAABAAsAAAASAAAAAwADAAEAAQABAA4AAAABAAEABAABAAIAAAAAAAAAAQAAAAEAAAAAAAAAAAATAAAAAAAAAAAAAwAAAAAAAAAJAAAAAQAHAAAAAgAAAAwAAQADAAAAAQAAAAUAAQAAAAEAAwACAAkAAQAAAAgAAQAFAAIAAAAEAAAAAQAFAAAACAADAAAABAAAAAAABgADAAMAAgAAAAIAAAACAAIACAAAAAoABAASAAYAAgADAAIAAAABAAAAAAAAAAcAAQAGAAAAAQAFAAsAAAANABIABQACAAQAAAAFAAsAAAAHAAQAAQADAAgAAwACAAUAAQAAAAIAAgAAAAAAAQACAAAAAQAAAAAAAQAIAAMAAAAAAAAAAAACAAgAAAACAAAAAQAOAAAAAAADAAIABgAAAAEACAACAAUAAAABAAEABwAGAAEAAAAAAAAAAgAAAAAAAgABAAEABQAAAAMACQACAAAAAQAAAAkAAQABAAIAAQACAAMAAgAAAAAAAAAGAAEAAAABAAYAAAAAAAAAAAABAAAAAAABAAEAAAAAAAEAAAAAAAAAAQAUAAAAAAAAAAEAAAAGAAEAAAAAABIAAAAEABIAAwAEAAIAAAAAAAAABQAGAAAAAQABAAAAAAAGAAIABAAEAAIAAAAAAAIAAQABAAAAAQALAAAABgAAAAAAAAACAAEACQABAAIAEAABAAIAAgABAAAAAQAPAAkAAQABAAAAAAABAAEAAQAAAAAAAAACAAAAAgAAAAAAAAADAAEABwAPAAUABQAAAAAAAAABAAQAAAACAAQAAwABAAEAAgAEAAEAAgABAAIAAgAJAAAAAQAAAAUACwABAAAAAgALAAAABAAAAAEAAQABAAAACQACAAUAAAAEAAMAAgABAAEAAgAAAAYACgAAAAIAAQAJAAEAAQABAAgAAQACAAEAAQAAAAEAAwABAAEAAQADAAEABQABAAMAAQAAAAAABgAAAAQAAQADAAEAAwAAAAEAAAAAAAAAAAAAAAAAAAAAAAsAAAAAAAAAAAAAAAMABgAAAAAAAgADAAMAAgACAAAABAACAAAAAQABAAoAAwAAAAMABAABAAEAAwAAAAAABAAEAAEAAgAFAAwAAQAAAAcAAgABAAIABQABAAAABAABAAAAAAABAAAAAAALAA0AAgADAAAAAAAAAAAABQAFAAAAAAAAAAAAAAAAAAAAAAAAAAYAAgACAAIAAQABAAEAAAAAAAAAAQAAAAEACwABAAEAAQACAAUAAQABAAMABAABAAIAAgAIAAAAAQAEAAMAAwABAAgAAQAIAAEAAAAEAAgAAAACAAMAAwAAAAAAAgABAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAKAAAAAQAAAAAAAAABAAMACQAAAAIAAQAKAAAAAQAAAAAAAwAAAAEAAQAAAAkABwABAAIACAABAAUAAwACAAEAAQACAAIAAQABAAEABQAAAAAAAQAAAAoAAwAAAAAAAQAAAAwAAAAHAAMAAQAAAAAAAAAAAAAAAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAMAAQAAAAEABAAPAAAAAQAAAAEAAAAAAAAACAAAAAIABgABAAAABAACAAAAAQAEAAAAAgACAAIAAQAAAAAAAgABAAAAAwABAAAAAQAAAAEAAQACAAIAAgAKAAEAAQABAAEAAgACAAAAAQAAAAAAAAAAAAEAAAACAAgAAQACAAYAAQAAAAIAAQAAAAAAAAABAAIAAQAEAAQAAQAAAAUAAQABAAEAAAACAAEAAAAAAAAAAgAAAAEAAAAAAAEABAAAAAkAAgAMAAgAAAABAAAABAAAAAAAAQADAAQAAgAAAAEAAwAEAAAAAQAAAAcAAgABAAQAAAAEAAAAAAAAAAAAAAAJAAEAAQACAAAABAABAAEAAQAAAAEAAQABAAEAAAAAAAEAAQADAAEAAQABAAIAAAADAAEAAAACAAAABAABAAAAAAAAAAEABwAAAAAAAAAAAAQAAAABAAcAAAAAAAAAAQAEAAEAAAAEAAIAAgABAAAAAQABAAEAAgABAAAABwAAAAAABgAAAAMAAgACAAEAAAAAAAIAAAABAAMABQABAAEAAAAAAAAAAAAAAAEABgABAAEAAwAJAAAAAQABAAAABgAAAAMAAQACAAAAAQACAAUABQACAAAAAQAEAAAAAQABAAEAAQAAAAEAAAAAAAAAAAACAAAAAAABAAIAAQAAAAAAAgAAAAEABgAGAAIAAgAAAAMAAgADAAEAAQACAAEAAgALAAAAAQAAAAEAAgACAAQAAAAAAAAAAAAAAAIAAAAAAAAAAwAAAAAAAQAGAAAAAgABAAAAAwAEAAAAAwAAAAEABAABAAMAAgAAAAAAAgABAAAAAQABAAEABQAGAAQAAAAAAAAAAgABAAAAAQABAAQAAQABAAkAAAACAAAAAgADAAUAAgAAAAAAAAAAAAAAAgAAAAAAAAAAAAEAAAABAAMAAAAAAAAAAAABAAIABwABAAAAAAAAAAEAAAAAAAoAAQAAAAEAEAACAAgAAQABAAIAAQACAAEAAAADAAIABAAAAAIAAgAAAAEAAgABAAEAAQABAAAAAAAAAAYAAgAFAAAAAAAEAAQAAQAFAAAABQAAAAAAAAAAAAAAAAABAAAAAQAAAAUAAAAAAAAABQACAAEAAwAJAAEAAwAAAAAAAgAAAAEAAQAEAAIAAAAIAAIAAQAGAAAAAgACAAMAAAADAAEAAQACAAIAAAADAAEAAAAAAAAAAgACAAAAAgAFAAEAAAAAAAAAAgACAAEAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAEAAAAHAAEAAAACAAIAAAABAAAAAgAAAAYAAgABAAgAAAACAAIAAQAEAAEAAAABAAEAAgAJAAEAAQABAAQAAQAFAAIAAQABAAQAAwAAAAAAAQAAAAEAAQAAAAEAAAABAAEAAAAEAAQAAAA7+QIAlwwBAGn4AgDFCwEAPvkAAB8JAQAuHwAACgYDAAr5AgBmDAEAy/oAAJ4KAQCH+gAAWgoBAFv5AgC3DAEAZfkAAEYJAQD7+AIAVwwBADr4AgCWCwEAWwkAAMoCAgCvHwAAdQcEACz5AgCIDAEAiB4AAIIEAgDLCQAA1AICAAofAACyBQMAKQIAAPIBAgCL+gAAXgoBAP34AgBZDAEArfkCAAkNAQB6HgAAZAQDAJT5AAB1CQEA7/kCAEsNAQDO+AIAKgwBAK8DAAAzAgIAEQIAANIBAgDI+QAAqQkBAJX6AABoCgEAwPkCABwNAQCpHgAAvwQDAPUAAABaAAIAM/gCAI8LAQBK+QAAKwkBAGz5AABNCQEAPvoAABMKAQBe+gAAMwoBAHIeAABSBAIAHvoAAPoJAQBfAQAABgECAGQfAACHBgMABx8AAKsFAwCHHgAAgAQCAIb6AABZCgEAL/kAABAJAQDd+AIAOQwBABf6AgBzDQEAcw8AACQDAgDQHgAAJgUDAMr5AACrCQEAyBMBABELAgA/+AIAmwsBAJn5AgD1DAEA9vkCAFINAQDCHwAAlwcDALz6AACPCgEA2h8AAMcHAgC5+QIAFQ0BAPX5AADWCQEAcx4AAFQEAgCd+gAAcAoBACkeAACsAwIAX/oAADQKAQBJ+AIApQsBAEMPAAAYAwIAoh4AAKwEAgCc+QAAfQkBACkBAAC0AAIAhR8AANcGBADA+AIAHAwBAJMeAACYBAIAgCIAAEUIAgAh+AIAfQsBAFD6AAAlCgEAdzAAAJUIAgD9AQAAqgECADACAAAEAgMAbQEAAB4BAgCi+QAAgwkBAKgeAAC8BAMAYdEBAEALAwCU+gAAZwoBABofAADYBQMAoQEAAEQBAgAi+QIAfgwBAPkfAAACCAIAPfkAAB4JAQCsMAAAowgCAOn5AADKCQEAiR4AAIQEAgA1HwAAHQYDAC/6AAAECgEACvkAAOsIAQD8+QAA3QkBAJH4AgDtCwEAfTAAAJ0IAgClHwAATwcEAHMBAAAqAQIASfsAAOcKAgDK+gAAnQoBAHv5AABcCQEA/fkCAFkNAQAL+QIAZwwBAKP5AACECQEAKh4AAK4DAgC7HwAAkAcCAKD5AgD8DAEAJPkAAAUJAQB7HgAAZwQDAMv4AgAnDAEA9vgCAFIMAQDxBAAAoAICANAfAAC0BwIA9R4AAI4FAgBGAQAA3gACAC4eAAC2AwMAXvkAAD8JAQAW+QAA9wgBAET6AAAZCgEAFvoAAPIJAQAcHwAA3gUDAOQFAQD1CgIATfkCAKkMAQC5+gAAjAoBAAf5AADoCAEAXjAAAH8IAgANHgAAbgMCAEofAABQBgMA+fkAANoJAQDH+QIAIw0BAEv5AAAsCQEAfvgCANoLAQBMDQAADQMCAOz5AADNCQEArwEAAEYBAgAd+QIAeQwBAOgeAABqBQMArSIAAFMIAgA0+AIAkAsBAMgeAAAWBQIAUh8AAGAGAwBP+AIAqwsBACceAACoAwIASB8AAEwGAgDrHwAA6gcCAMT5AAClCQEA5R4AAGQFAgC7HgAA8gQCALX5AACWCQEAjR8AAPUGBACvHgAA0QQDAHj6AABLCgEAdfkCANEMAQC6+QIAFg0BACIeAACeAwIA6h4AAHAFAwAq+wAAsQoCAFH5AgCtDAEAMwoAAN4CAgDc+AIAOAwBAOcAAABAAAIA9/gCAFMMAQDr+QIARw0BAHQBAAAsAQIAHQEAAKAAAgBm+QAARwkBADb7AADLCgIAivgCAOYLAQBQBAAAWAICAFwwAAB9CAIA9vkAANcJAQCn+QAAiAkBAIL6AABVCgEA1/oAAKoKAQBBGwAATgMCAF0eAAAhBAMAEh8AAMgFAwAIAQAAegACAKweAADIBAMA8PkCAEwNAQAAAQAAagACAKL6AAB1CgEA0wMAAEICAgBz+QAAVAkBAAcEAABMAgIAcDAAAIsIAgB5HgAAYQQDAGT5AABFCQEAUg8AABwDAgDv+QAA0AkBAFgeAAAWBAIASfkCAKUMAQBxAQAAJgECACofAAD+BQMAiAMAABoCAgBXAQAA9gACAD0fAAAzBgMAi/kAAGwJAQC+MAAAtQgCABb4AgByCwEAlR4AAJwEAgCR+QAAcgkBALX4AgARDAEAEPgCAGwLAQC4+gAAiwoBAOwBAACOAQMACR4AAGUDAwC7+AIAFwwBAOH5AgA9DQEATvkCAKoMAQCuHwAAcQcEAP0fAAAKCAEAAPoAAOEJAQDMCwAA+gICAEv6AAAgCgEAw/oAAJYKAQDOIQAAGQgCAHEiAAA7CAIAywAAABQAAgBRHwAAXgYCAJn6AABsCgEAuDAAAK8IAgB+HgAAbgQCAHX6AABICgEAKGEBAC8LAwB4+QAAWQkBAOYBAACCAQIAjh4AAI4EAgDm+QIAQg0BAOP4AgA/DAEAXQQAAGICAgDeAQAAcgEDAGMfAACEBgMASB4AAO4DAgDQ+AIALAwBACL6AAD8CQEAz/oAAKIKAQCjHgAArgQCAOMiAABfCAIA1PoAAKcKAQBMMAAAbQgCAI36AABgCgEA1gQAAHYCAgAAHwAAmAUCADb6AAALCgEAcvoAAEUKAQCj+QIA/wwBABsfAADbBQMAXR8AAHcGAwCr+QIABw0BABX6AADxCQEAvvkAAJ8JAQC/0QEAVgsDAA0BAACEAAIAtB8AAIIHAwBC+QAAIwkBADQJAADCAgIArvoAAIEKAQCK+QIA5gwBADwfAAAwBgMA0fkCAC0NAQDZHwAAxQcCADgfAAAmBgIAvDAAALMIAgD8AAAAZAACAAUfAAClBQMA5B4AAGIFAgAM+QAA7QgBALMeAADdBAMA4PgCADwMAQBrAQAAGgECABIeAAB4AwIAn/kAAIAJAQCzHwAAgAcCAGDRAQA9CwMAzwAAABwAAgDNAAAAGAACADX4AgCRCwEAAvkAAOMIAQBMCwAA7gICAJz5AgD4DAEAQfoAABYKAQDF+QAApgkBAMf5AACoCQEA/jAAAN8IAgDgHgAAVgUDAIL4AgDeCwEAc/kCAM8MAQAN+gAA7gkBAFz6AAAxCgEAAPkCAFwMAQD9+QAA3gkBAGIfAACBBgMAufgCABUMAQD1AQAAnAECAPMeAACKBQIATPgCAKgLAQDVAQAAWgEDALz5AACdCQEA5R8AAN0HAgBbHwAAdAYDAHEwAACNCAIAu/kCABcNAQCqHwAAYQcEAJQfAAAPBwQATh4AAPwDAwDuAQAAlAECAO35AADOCQEAtPkCABANAQAP+gIAaw0BAN4NAAAWAwIAFfkAAPYIAQCHHwAA3wYEALL5AACTCQEABhsAADwDAgBL+AIApwsBAD35AgCZDAEAEwEAAIwAAgBpHwAAlQYCAMb4AgAiDAEA/wAAAGgAAgB3HgAAXAQCABL6AgBuDQEAQQMAAA8CAQAn+AIAgwsBAEMbAABQAwIAuvgCABYMAQCH+QAAaAkBABIbAABGAwIA6iIAAGEIAgB9+QIA2QwBAGhtAQAyCwIATPsAAO0KAgBKHgAA8gMCAB4eAACWAwIASAsAAOoCAgDcCQAA2AICAN0AAAAyAAIAcfkCAM0MAQCf+gAAcgoBAF4EAABkAgIAox8AAEcHBADfBAAAhAICANwEAAB+AgIAUAEAAOwAAgDaAAAALAACAOcBAACEAQIALvkCAIoMAQAY+QAA+QgBAM4fAACwBwIAKiMAAGoIAQCBIgAARwgCAA8eAAByAwIA4wAAADoAAgBI+wAA5QoCAAv6AgBnDQEAHfsAAK0KAgCQHgAAkgQCAKoDAAApAgIAgfkAAGIJAQAwHgAAvAMCAL0eAAD2BAIACx4AAGoDAgBiHgAALAQCAF34AgC5CwEAAQQAAEgCAgB/+AIA2wsBACIfAADoBQMApPkCAAANAQBV+AIAsQsBAI/5AgDrDAEA0PkAALEJAQDNIQAAFwgCAHr4AgDWCwEAuR8AAIwHAgDI+AIAJAwBAGX6AAA6CgEApB4AALAEAwBMAQAA5AACAPAEAACeAgIAsB8AAHkHAgCtHwAAbQcEAE/5AgCrDAEAdR8AALMGAgAF+QAA5ggBAHH6AABECgEAzgAAABoAAgCe+AIA+gsBAAP5AgBfDAEAOfkAABoJAQCUCwAA9AICACb5AgCCDAEAIPoAAPsJAQCrHgAAxQQDAO8EAACcAgIATR8AAFkGAwBLEwEAAQsCACgfAAD6BQIAVfkCALEMAQC8HwAAkgcCAEIeAADiAwIANfkAABYJAQC7FAEAEwsCAOD5AgA8DQEAXgEAAAQBAgB7AQAAOgECADseAADUAwIAah4AAEIEAgAUAgAA2AECAAobAABAAwIAJfgCAIELAQB4HgAAXgQDAOgBAACGAQIA7SIAAGcIAgCHAwAAGQIBAM75AgAqDQEAhPkCAOAMAQDrIgAAYwgCAMsfAACqBwIAxfoAAJgKAQAG+AIAYgsBAGD5AABBCQEAs/kAAJQJAQAC+gAA4wkBALkeAADuBAIAPh8AADYGAwCxHgAA1wQDABX6AgBxDQEAMfsAAMEKAgANHwAAuwUDAKv5AACMCQEAOfsAAM8KAgBaMAAAewgCALD5AACRCQEAGvkAAPsIAQD4+QIAVA0BAHf5AgDTDAEAzwEAAE4BAgAgHgAAmgMCAKT5AACFCQEAdgEAADABAgAd+gIAeQ0BAPQEAACmAgIAEPoCAGwNAQBu+QAATwkBAL/5AACgCQEAnh8AADUHBABN+AIAqQsBAA76AgBqDQEAB/oAAOgJAQDnBAAAkAICAOMfAADYBwMAzAMAADwCAgCmHwAAUwcEAA8CAADOAQIAXfoAADIKAQAHAQAAeAACAE/6AAAkCgEAZPoAADkKAQC80QEATgsCAJr5AAB7CQEA1fkCADENAQCnHgAAuQQDAPn4AgBVDAEA8PkAANEJAQB2BAAAZgICAOoAAABGAAIAb/gCAMsLAQCS+QIA7gwBAA4eAABwAwIAXfkAAD4JAQCJ+gAAXAoBAC8CAAACAgIAywwAAAYDAwBB+AIAnQsBAOYEAACOAgIAXx4AACYEAgDCBAAAbAICAOwEAACWAgIADhsAAEQDAgCQAwAAJgIDAAUBAAB0AAIAGQQAAFQCAgBZ+QAAOgkBAPgwAADZCAIAJR8AAPEFAwDAHgAA/gQDACQBAACuAAIAAQEAAGwAAgDHAAAADAACAPz5AgBYDQEALvkAAA8JAQAQ+QIAbAwBAIQiAABJCAIA4x4AAF8FAwA0HgAAxAMCAK0DAAAvAgIALPoAAAEKAQB3+AIA0wsBAJv5AAB8CQEA6AAAAEIAAgAoHgAAqgMCAKEeAACqBAIASvkCAKYMAQBe0QEAOQsCADv4AgCXCwEAZNEBAEkLAwBU+AIAsAsBAJL4AgDuCwEAu9EBAEwLAgAPAQAAiAACAGMeAAAuBAIA0x8AALsHAwC2HwAAhQcCAET5AAAlCQEAK/kCAIcMAQD/AQAArgECAIYDAAAXAgIAX/kCALsMAQAy+gAABwoBABf6AADzCQEAFx4AAIUDAwAxHwAAEgYCAID5AgDcDAEALfoAAAIKAQCO+AIA6gsBAKwfAABpBwQAPPoAABEKAQAJHwAAsAUCANsfAADJBwIAyvkCACYNAQAvHwAADQYDACMGAACwAgIA+fkCAFUNAQC0+gAAhwoBAL8eAAD7BAMAhfkAAGYJAQBuHwAAowYDAGX5AgDBDAEAKfgCAIULAQAkHgAAogMCAKL4AgD+CwEAzPgCACgMAQDcKgAAawgCAPMEAACkAgIAnR8AADEHBAAkHwAA7gUDAK/5AgALDQEAd/oAAEoKAQCT+gAAZgoBAGEeAAAqBAIAIQEAAKgAAgDMHwAArAcCALb5AgASDQEAY/kCAL8MAQDbHgAARwUDAKsfAABlBwQAWh4AABoEAgAO+AIAagsBAAr6AADrCQEAlPkCAPAMAQAb+QIAdwwBAO4eAAB8BQMAZx8AAJAGAwC5+QAAmgkBAGP6AAA4CgEAcvkCAM4MAQB5MAAAlwgCAOr5AADLCQEAOPsAAM0KAgCe+gAAcQoBAAIeAABWAwIAzh4AACIFAgB1HgAAWAQCAEb6AAAbCgEAQ/sAAN0KAgAK+AIAZgsBABUBAACQAAIAWfkCALUMAQDRMAAAwwgCALL5AgAODQEAjfkCAOkMAQB++gAAUQoBAHcBAAAyAQIAZ/gCAMMLAQCo+gAAewoBAH35AABeCQEAuPkAAJkJAQBo+QAASQkBAEsNAAALAwIAWvoAAC8KAQAEAQAAcgACAEwfAABWBgMAjh8AAPkGBADJBQEA8woCAC0fAAAHBgMAK/oAAAAKAQDF+AIAIQwBAHMfAACvBgIAc/oAAEYKAQBOAQAA6AACAJj5AAB5CQEATfkAAC4JAQBm+QIAwgwBAB75AgB6DAEAOQQAAFYCAgCYHgAAogQCAIr6AABdCgEA6QAAAEQAAgDpHwAA5gcCAHABAAAkAQIAmhABAPcKAgCDEwEABQsCAHAiAAA5CAIAgvkCAN4MAQCv+QAAkAkBALP6AACGCgEA4vkAAMMJAQDL+QIAJw0BAEf5AAAoCQEAeA8AACoDAgAfAgAA6gECACj5AgCEDAEA0gQAAHICAgBF+AIAoQsBACMfAADrBQMAhSIAAEsIAgAD+QAA5AgBAEr4AgCmCwEAPx8AADkGAwAHHgAAYAMCACP4AgB/CwEA0fgCAC0MAQARHgAAdgMCANIfAAC4BwMAfAEAADwBAgDkAAAAPAACAGL5AABDCQEA9AEAAJoBAgB6AQAAOAECAEj5AAApCQEA/gEAAKwBAgAP+QIAawwBADj4AgCUCwEAAPgCAFwLAQArIQAADwgCAD74AgCaCwEAkPoAAGMKAQBdCQAAzgICAGz5AgDIDAEATfoAACIKAQAG+QAA5wgBADcfAAAjBgMArPkAAI0JAQDhHwAA0wcCAMUeAAANBQMAyx4AABwFAgBH+QIAowwBAG0fAACgBgMATB4AAPYDAwDmHwAA3wcCADz5AgCYDAEAxPkCACANAQDtAQAAkQEDAPkwAADbCAIAmPgCAPQLAQAM+gAA7QkBAOAAAAA0AAIAhvkCAOIMAQBhHwAAfwYCAHwfAADBBgIAoR8AAEAHAwB8MAAAmwgCAO4fAADwBwIA5vgCAEIMAQBRBAAAWgICABYeAACCAwMAeQEAADYBAgAUAQAAjgACADIfAAAUBgMAPvsAANcKAgBJ+QAAKgkBADf5AgCTDAEAMvkAABMJAQCbIQAAEwgCAE8eAAD/AwMAUR4AAAUEAwCPHgAAkAQCANQwAADHCAIADfoCAGkNAQDhHgAAWQUDACYQAAA6AwIAwvkAAKMJAQAtAQAAvAACAGT4AgDACwEAjfgCAOkLAQDM+QIAKA0BACRhAQAlCwIAmPoAAGsKAQAB+QIAXQwBANP5AgAvDQEAlh4AAJ4EAgBg+gAANQoBAGL6AAA3CgEA/QAAAGYAAgAA+gIAXA0BAAYfAACoBQMAKAEAALIAAgALHwAAtQUDAJr5AgD2DAEAcPkCAMwMAQAT+gIAbw0BAA8fAADBBQMA8QAAAFIAAgDT+AIALwwBABn5AAD6CAEAKSMAAGkIAQAy+wAAwwoCAEj6AAAdCgEAU/kAADQJAQBz+AIAzwsBAC4BAAC+AAIAY/kAAEQJAQBTHwAAYwYDAE75AAAvCQEAWgkAAMgCAgAp+QIAhQwBACwBAAC6AAIAGQEAAJgAAgCX+QIA8wwBAM36AACgCgEAGx4AAI4DAgDWHgAAOAUDAEX5AAAmCQEAqB8AAFsHAwA6+gAADwoBAEz5AgCoDAEAxvkAAKcJAQBO+wAA8QoCACAfAADkBQIA0/oAAKYKAQB4IgAAQQgCAIYeAAB+BAIALfkAAA4JAQBb+gAAMAoBAEn6AAAeCgEAx/oAAJoKAQAd+QAA/ggBAFL4AgCuCwEAPB4AANYDAgAlHgAApAMCADj5AAAZCQEAGfkCAHUMAQAFAgAAugECAEP4AgCfCwEAM/sAAMUKAgC4HwAAigcCANf5AAC4CQEASvsAAOkKAgBHAQAA4AACAKr6AAB9CgEAWPgCALQLAQCKHgAAhgQCAOEBAAB7AQMAUPkCAKwMAQAZ+gIAdQ0BAGz6AABBCgEAkx8AAAsHBAB0+gAARwoBAAkBAAB8AAIAWR8AAHIGAgDV+QAAtgkBAHD6AABDCgEAqfoAAHwKAQD0+QIAUA0BAEj5AgCkDAEA2/kAALwJAQArHgAAsAMCAPP5AgBPDQEAbwEAACIBAgCXHwAAGwcEAKIfAABDBwQAVvkCALIMAQCBDwAALAMCAN8eAABTBQMAQR4AAOADAgBf0QEAOwsCAGYeAAA2BAMA5/gCAEMMAQC9+gAAkAoBACr5AAALCQEAuvoAAI0KAQDVHgAANQUDACkfAAD8BQIAsB4AANQEAwD2HwAA+wcCAGn6AAA+CgEAMQIAAAcCAwAaAQAAmgACABb5AgByDAEA0gAAACAAAgAmHgAApgMCAOj5AgBEDQEAMwIAAAwCAgA2+QAAFwkBAOwfAADsBwIAAgIAALQBAgCt+QAAjgkBACf5AgCDDAEAVvgCALILAQAxCQAAwAICAHAeAABOBAIANQEAAMYAAgAzHgAAwgMCAIIfAADLBgQAJx8AAPcFAwAJ+AIAZQsBACD5AAABCQEANvkCAJIMAQBAAwAADgIBAKsDAAArAgIAbPgCAMgLAQDTHgAALwUDAAMBAABwAAIAqPkAAIkJAQAcAQAAngACACz5AAANCQEAyQAAABAAAgC0+QAAlQkBADn5AgCVDAEAE/kAAPQIAQBS+gAAJwoBABACAADQAQIA8wAAAFYAAgAE+AIAYAsBAH34AgDZCwEAyfoAAJwKAQAcHgAAkAMDAB4BAACiAAIAbAEAABwBAgBZCgAA4gICACb6AAD+CQEARfoAABoKAQASAQAAigACAC74AgCKCwEAM/oAAAgKAQAvEQEA/woCAAYCAAC8AQIA3DAAANEIAgDLAwAAOgICAIb5AABnCQEALfsAALgKAwCpHwAAXgcDAE4wAABvCAIA5vkAAMcJAQC7FQEAGwsCAJETAQALCwIAPRsAAEoDAgDaDQAADwMCAJL5AABzCQEAXQEAAAIBAgB3BAAAaAICALH5AACSCQEADQQAAFACAgD3MAAA1wgCAHf5AABYCQEAxPoAAJcKAQA7+gAAEAoBAIv4AgDnCwEAeSIAAEMIAgAB+gAA4gkBAHkfAAC7BgIAYjAAAIMIAgDW+QIAMg0BAO0EAACYAgIABfoCAGENAQCrEAEA+woCAPH5AADSCQEAVB4AAA4EAgC2+AIAEgwBACX6AAD9CQEA0wEAAFYBAgAtHgAAtAMCACIGAACuAgIAqfkAAIoJAQDHDAAAAAMCAHofAAC9BgIAV/oAACwKAQCo+QIABA0BAOABAAB4AQMABPkCAGAMAQCnHwAAVwcEAMMeAAAHBQMAg/kCAN8MAQDMCQAA1gICANQAAAAkAAIA2foAAKwKAQAEHwAAogUDALD4AgAMDAEA1/gCADMMAQD7+QAA3AkBAPv5AgBXDQEAFwIAAN4BAgDB+QIAHQ0BAIv5AgDnDAEAVh8AAGwGAwDi+QIAPg0BACUGAAC0AgIAejAAAJkIAgAY+AIAdAsBAIMeAAB4BAIAtx8AAIcHAwDKHgAAGgUCAPL4AgBODAEAkPkCAOwMAQDf+QIAOw0BAFn4AgC1CwEACfoCAGUNAQCD+AIA3wsBANcfAADABwMAcvgCAM4LAQBe+AIAugsBALX5AgARDQEA9PkAANUJAQATAgAA1gECAPQeAACMBQIA2vkCADYNAQA2HgAAyAMCANMGAAC8AgIA//kCAFsNAQB8+QIA2AwBAPwfAAAICAIArfgCAAkMAQD8+AIAWAwBADEeAAC+AwIArAMAAC0CAgCAHgAAcgQCANIBAABUAQIAAfkAAOIIAQDf+QAAwAkBAIP6AABWCgEARvkCAKIMAQBj+AIAvwsBAIsfAADtBgQAfPoAAE8KAQBRAQAA7gACAMEfAACVBwIA+PgCAFQMAQCz+QIADw0BAKb4AgACDAEArgMAADECAgDJ+QIAJQ0BANj5AgA0DQEAHPgCAHgLAQC6HgAA8AQCAFP4AgCvCwEAGAEAAJYAAgC3+AIAEwwBALj5AgAUDQEAP/kCAJsMAQDg+QAAwQkBAIUDAAAVAgIAiPgCAOQLAQCd+QAAfgkBAFseAAAcBAIAxAAAAAgAAgAiYQEAIQsCAFgwAAB5CAIAaB4AADwEAwAqIQAADggBACH5AgB9DAEAYCIAAC8IAgAf+wAArwoCAAEgAAAMCAEAiB8AAOMGAwCcHwAALQcEADcBAADKAAIAMPoAAAUKAQBJHgAA8AMCAOH5AADCCQEAj/oAAGIKAQA4GQEAHQsCANH5AACyCQEACwIAAMYBAgCc+gAAbwoBAIL5AABjCQEAe/kCANcMAQAABAAARgICAED6AAAVCgEAbfkAAE4JAQBBIgAAJwgCACT5AgCADAEAcgEAACgBAgAfHgAAmAMCAJP5AgDvDAEA+wEAAKUBAwB1DwAAJgMCAGIBAAAMAQIA4gEAAH4BAgB0AwAAEwIBAJf5AAB4CQEA3gQAAIICAgDZAAAAKgACAOr5AgBGDQEAq/gCAAcMAQB6+gAATQoBAPr4AgBWDAEADvkAAO8IAQCD+QAAZAkBAGoBAAAYAQIAaPoAAD0KAQD7AAAAYgACABb6AgByDQEA+h8AAAQIAgACHwAAnAUDAAL6AgBeDQEAOh4AANIDAgBpHgAAPwQDAK36AACACgEA+AQAAKoCAgDTBAAAdAICAPEeAACFBQMAjhMBAAkLAgCl+AIAAQwBADf4AgCTCwEACh4AAGgDAgCW+gAAaQoBAE0BAADmAAIAYtEBAEMLAwAj+QAABAkBALf6AACKCgEAER8AAMYFAgDuAAAATgACAMIwAAC5CAIAMfoAAAYKAQDWAQAAXQEDAE37AADvCgIA7AAAAEoAAgBp+QIAxQwBACH5AAACCQEAdg8AACgDAgC1HgAA4wQDAFUBAADyAAIAYfoAADYKAQAOAgAAzAECAFQfAABmBgMA2fgCADUMAQCh+gAAdAoBAIX5AgDhDAEAb/kAAFAJAQB0+QIA0AwBAMQeAAAKBQMARB4AAOYDAgDw+AIATAwBAIH6AABUCgEA3vkAAL8JAQCl+QIAAQ0BAOofAADoBwIAv/oAAJIKAQBXBAAAXgICACEeAACcAwIAsPoAAIMKAQBVHgAAEAQCAPgeAACUBQIAG/kAAPwIAQBQHgAAAgQDAOsAAABIAAIACvoCAGYNAQBP+QAAMAkBAOQEAACKAgIAOwEAANAAAgBpAQAAFgECAAH6AgBdDQEA5fkAAMYJAQAa+gAA9gkBAKz4AgAIDAEAah8AAJcGAwDIDAAAAgMCABH5AADyCAEAKPkAAAkJAQBsHgAARgQCACYiAAAlCAIA6fgCAEUMAQD6+QAA2wkBAOkeAABtBQMAgB8AAMUGAwATHwAAywUDAIz5AgDoDAEAa/kCAMcMAQD7HwAABggCACD5AgB8DAEAUfkAADIJAQAuEQEA/QoCANwAAAAwAAIAHPoCAHgNAQBA+QIAnAwBAE74AgCqCwEAA/oAAOQJAQAU+QAA9QgBADH5AAASCQEAivkAAGsJAQBpDwAAIgMCAL35AACeCQEAsjAAAKkIAgC+HwAAlAcBAML4AgAeDAEA2PoAAKsKAQDeHwAAzQcCAI8fAAD9BgQAEfoCAG0NAQBeHgAAJAQCAMb5AgAiDQEATwEAAOoAAgDWMAAAyQgCAGj5AgDEDAEAUvkAADMJAQDo+AIARAwBAFAfAABcBgIAVh4AABIEAgDM+QAArQkBANQBAABYAQIAvvoAAJEKAQAjAQAArAACADz7AADVCgIAlvkAAHcJAQCNHgAAjAQCANv4AgA3DAEAFQIAANoBAgAJ+QAA6ggBACNhAQAjCwIAa/oAAEAKAQBHIgAAKwgCANAwAADBCAIAvtEBAFMLAwDRBAAAcAICAHn6AABMCgEAfx4AAHAEAgAS+gAA8AkBAKwiAABRCAIA1PkAALUJAQDaHgAARAUDAA35AADuCAEAIvkAAAMJAQCMHwAA8QYEABMeAAB6AwIA9DAAANUIAgCI+gAAWwoBAMoAAAASAAIADB4AAGwDAgC+FAEAFwsCAGT5AgDADAEA2TAAAM0IAgAH+QIAYwwBAGEBAAAKAQIA0PoAAKMKAQAD+gIAXw0BANYfAAC+BwIAtPgCABAMAQCT+AIA7wsBAEUeAADoAwIAJ2EBACwLAwDtAAAATAACADX6AAAKCgEA/AEAAKgBAgBgHgAAKAQCAH0eAABsBAIATPoAACEKAQAAAgAAsAECAD/5AAAgCQEAEfkCAG0MAQBoAQAAFAECAJP5AAB0CQEANgEAAMgAAgCqHgAAwgQDAGn5AABKCQEA1fgCADEMAQCZHgAApAQCAG8eAABMBAIAG/oAAPcJAQCbHgAApgQCAAj4AgBkCwEAQx8AAEMGAwCA+QAAYQkBAFj6AAAtCgEAqPgCAAQMAQBSMAAAcwgCAOn5AgBFDQEARAMAABECAgA4+gAADQoBADX5AgCRDAEAFB8AAM4FAwA5HwAAKAYCAHEeAABQBAIAa/kAAEwJAQDz+QAA1AkBALb6AACJCgEA2B8AAMMHAgCV+QAAdgkBAPkeAACWBQIAL/gCAIsLAQC/+QIAGw0BAC76AAADCgEAFPgCAHALAQDR+gAApAoBAB0eAACTAwMAjwMAACQCAgCh+AIA/QsBACgCAADwAQIAHvgCAHoLAQAR+AIAbQsBAM0BAABKAQIAxx8AAKEHAwAqAgAA9AEDAGsfAACaBgMAJAYAALICAgDIHwAApAcCAML6AACVCgEA5/kAAMgJAQD++AIAWgwBAMkwAAC/CAIACPkCAGQMAQDn+QIAQw0BALv6AACOCgEAG/oCAHcNAQA5AQAAzAACAB36AAD5CQEA8h4AAIgFAgCY+QIA9AwBAB/5AgB7DAEAm/oAAG4KAQCA+gAAUwoBAFz5AgC4DAEA9fkCAFENAQAa+QIAdgwBAHAfAACpBgIAa/gCAMcLAQBa+AIAtgsBAD4BAADWAAIAUPgCAKwLAQDbBAAAfAICAHH4AgDNCwEAcPkAAFEJAQDdBAAAgAICABoeAACMAwIADvkCAGoMAQBlHwAAigYDACn5AAAKCQEA3R4AAE0FAwCJHwAA5gYDANcEAAB4AgIALx4AALkDAwBg+AIAvAsBAFUfAABpBgMApPoAAHcKAQBZ+gAALgoBAEv7AADrCgIAv/gCABsMAQDnHgAAaAUCAPkEAACsAgIAlfkCAPEMAQCvIgAAVwgCALb5AACXCQEAvB4AAPQEAgD1BAAAqAICACb4AgCCCwEAFgEAAJIAAgBTBAAAXAICAEf7AADjCgIACB4AAGIDAwDh+AIAPQwBANwBAABvAQMABfoAAOYJAQAI+QAA6QgBADn4AgCVCwEAX/kAAEAJAQAqAQAAtgACAA4EAABSAgIA0wAAACIAAgBpMAAAiQgCAET5AgCgDAEAD/kAAPAIAQCh+QAAggkBANABAABQAQIAmx8AACkHBAAE+gIAYA0BAHQfAACxBgIAHR8AAOEFAwD4+QAA2QkBAOP5AgA/DQEA4h8AANUHAwCIIgAATQgCAML5AgAeDQEA0zAAAMUIAgDAMAAAtwgCAG8iAAA3CAIAyPkCACQNAQDt+AIASQwBAFz4AgC4CwEADB8AALgFAwDDHwAAmgcCAPIfAADzBwMA7PgCAEgMAQCF+AIA4QsBABr6AgB2DQEAFgIAANwBAgDH+AIAIwwBAHEfAACrBgIACQIAAMIBAgCZ+QAAegkBANEfAAC2BwIAlDAAAJ8IAgB4+AIA1AsBAFweAAAeBAMA7R8AAO4HAgD3+QAA2AkBAN75AgA6DQEAJfkAAAYJAQDaAQAAaQEDANUAAAAmAAIACPoAAOkJAQDJHgAAGAUCABcBAACUAAIAOh8AACoGAwD++QIAWg0BACwfAAAEBgMATPkAAC0JAQBSHgAACAQDAEkiAAAtCAIAHgIAAOgBAgBEHwAARgYDAIj5AABpCQEASx4AAPQDAgDgHwAA0QcCAGwfAACdBgMAZvoAADsKAQAr+AIAhwsBAGceAAA5BAMAdvkCANIMAQBD+gAAGAoBADUeAADGAwIAPfgCAJkLAQDe+AIAOgwBABP4AgBvCwEAdQEAAC4BAgB5+QAAWgkBAEL5AgCeDAEA/vkAAN8JAQCj+gAAdgoBAHT5AABVCQEAqvkAAIsJAQDB+QAAogkBAAAgAAALCAEABfkCAGEMAQCcEAEA+QoCAH/6AABSCgEAkPgCAOwLAQBzMAAAjwgCAOj5AADJCQEAh/gCAOMLAQD6AAAAYAACAAP4AgBfCwEAz/kCACsNAQCn+AIAAwwBACD4AgB8CwEAMPsAAL8KAgDvAAAAUAACAMDRAQBZCwMANx4AAMoDAgAy+QIAjgwBAK4hAAAVCAIAxvoAAJkKAQArHwAAAQYDAD/6AAAUCgEAVPoAACkKAQDbAQAAbAEDACYfAAD0BQMAD/gCAGsLAQDwAQAAmAECAAv6AADsCQEAV/gCALMLAQAQ+gAA7wkBABT6AgBwDQEA9PgCAFAMAQCwAwAANQIDANL5AACzCQEAQvgCAJ4LAQCaHwAAJQcEAO0eAAB5BQMA2B4AAD4FAwBEAQAA2gACANQDAABEAgIAcPgCAMwLAQBc+QAAPQkBAJL6AABlCgEAYvkCAL4MAQDk+AIAQAwBABUeAAB/AwMA4CIAAFkIAgDRAQAAUgECAGUBAAASAQIA0voAAKUKAQAs+wAAtQoDADkeAADPAwMArvkCAAoNAQDIAAAADgACALz4AgAYDAEA6x4AAHMFAwBq+QIAxgwBAPoBAACiAQMAzfgCACkMAQCk+AIAAAwBAB75AAD/CAEAxxMBAA8LAgDvHwAA8gcBAJn4AgD1CwEA2PgCADQMAQBHHgAA7AMCAK4iAABVCAIAiQMAABwCAgCO+gAAYQoBAMwAAAAWAAIAHfgCAHkLAQBB+QAAIgkBAHX5AABWCQEAWgoAAOQCAgCiDwAAMgMCALEfAAB7BwIABgEAAHYAAgBU+QIAsAwBAOsEAACUAgIAch8AAK0GAgCu+AIACgwBANQeAAAyBQMAgR4AAHQEAgBC+gAAFwoBACYGAAC2AgIAQx4AAOQDAgB3HwAAtwYCAOoBAACKAQIA0/kAALQJAQAZ+AIAdQsBAL74AgAaDAEA2R4AAEEFAwAw+QAAEQkBAJH6AABkCgEAXwkAANICAgAw+QIAjAwBAG34AgDJCwEA6gQAAJICAgDmHgAAZgUCAAEfAACaBQIA4iIAAF0IAgDdHwAAywcCABj6AgB0DQEAdjAAAJMIAgAG+QIAYgwBAMP5AgAfDQEACB8AAK4FAgA7GwAASAMCACsCAAD3AQMAhh8AANsGBADDAAAABgACAI4DAAAiAgIA0AQAAG4CAgDHMAAAvQgCAFsKAADmAgIA+QAAAF4AAgB9AQAAPgECAJQeAACaBAIAPR4AANgDAgCx+gAAhAoBANr4AgA2DAEAbx8AAKYGAwA3+QAAGAkBAO74AgBKDAEAwfgCAB0MAQAT+QIAbwwBABL5AADzCAEAePkCANQMAQB/+QIA2wwBAO8BAACWAQIAZR4AADMEAwAvAQAAwAACAMYfAACfBwIAwPoAAJMKAQAKAgAAxAECAMAAAAAAAAIACSIAAB8IAgDOAwAAQAICAA0CAADKAQIAnvkCAPoMAQCnDwAANAMCAI75AABvCQEABfgCAGELAQDBHgAAAQUDAFkBAAD6AAIAzR8AAK4HAgBAHwAAPAYCADP5AgCPDAEAsDAAAKcIAgAwHwAAEAYCAJMPAAAuAwIATBMBAAMLAgAG+gIAYg0BAKL5AgD+DAEADPgCAGgLAQDZ+QIANQ0BABkeAACKAwIAxQAAAAoAAgCfHwAAOQcEAF35AgC5DAEA3A0AABEDAgCBHwAAyAYDAHYeAABaBAIAV/kAADgJAQA0AQAAxAACAJceAACgBAIAsh4AANoEAwCa+AIA9gsBACj4AgCECwEAXAEAAAABAgBn+QAASAkBACIBAACqAAIAZ/kCAMMMAQBjAQAADgECACr6AAD/CQEABwIAAL4BAgDYAQAAYwEDAHgfAAC5BgIAUfoAACYKAQCI+QIA5AwBABj5AgB0DAEA1zAAAMsIAgAt+QIAiQwBAKH5AgD9DAEAQh8AAEAGAwBgAQAACAECAMAMAAD+AgIAujAAALEIAgC+HgAA+AQDABAeAAB0AwIA3h4AAFAFAwBXHwAAbwYDAED5AAAhCQEABh4AAF4DAgAKAQAAfgACAEYeAADqAwIAAPkAAOEIAQDuBAAAmgICAIz4AgDoCwEAifgCAOULAQC5DwAAOAMCAAz5AgBoDAEA8fgCAE0MAQBIDAAA/AICAGcwAACHCAIAF/kCAHMMAQDz+AIATwwBAHr5AgDWDAEAYfkAAEIJAQB8+QAAXQkBAH4DAAAUAgEAJPgCAIALAQDaBAAAegICAJ34AgD5CwEAYPkCALwMAQAsAgAA+gEDAG/5AgDLDAEAMx8AABcGAwDV+gAAqAoBAPf5AgBTDQEAJWEBACcLAgCq+QIABg0BAOwiAABlCAIABAIAALgBAgA7HwAALQYDACX5AgCBDAEAuPgCABQMAQCLHgAAiAQCAED4AgCcCwEAMfkCAI0MAQAYAgAA4AECAOsBAACMAQIApvkAAIcJAQB2+QAAVwkBAKAeAACoBAIAbvgCAMoLAQCX+gAAagoBAAQiAAAdCAIApfkAAIYJAQCZHwAAIgcDAIIeAAB2BAIAfR8AAMMGAgDpAQAAiAECAIT4AgDgCwEADAIAAMgBAgBpbQEANAsCALz5AgAYDQEAR/gCAKMLAQDE+AIAIAwBAMsLAAD4AgIAMPgCAIwLAQADHgAAWAMCABgeAACIAwIAtjAAAK0IAgDU+QIAMA0BALf5AACYCQEAzgEAAEwBAgA0+QAAFQkBALABAABIAQIAHwEAAKQAAgB0MAAAkQgCAJz4AgD4CwEAJwIAAO4BAgB5+QIA1QwBAPL5AgBODQEApB8AAEsHBABi+AIAvgsBAOX4AgBBDAEAbgEAACABAgCM+QAAbQkBAF75AgC6DAEAlvkCAPIMAQCEHwAA0wYEAEX5AgChDAEAKQkAAL4CAgCb+AIA9wsBACQiAAAjCAIACBsAAD4DAgDW+QAAtwkBAGAfAAB9BgIAU/kCAK8MAQBuHgAASgQCAGH4AgC9CwEAXQsAAPICAgA4+QIAlAwBAL35AgAZDQEA5PkCAEANAQBD+QIAnwwBAD8eAADcAwIAYDAAAIEIAgAv+QIAiwwBAID4AgDcCwEAYiIAADEIAgDr+QAAzAkBANz5AAC9CQEARR8AAEkGAwBt+gAAQgoBAFMeAAALBAMAj/gCAOsLAQBW+QAANwkBAIT6AABXCgEA1wEAAGABAwDFEwEADQsCAOUAAAA+AAIAkB8AAAEHAwCm+QIAAg0BAA4fAAC+BQMAXgkAANACAgBNHgAA+QMDANYAAAAoAAIAlh8AABcHBACmHgAAtgQDAHT4AgDQCwEAxfkCACENAQCB+QIA3QwBACv5AAAMCQEABPoAAOUJAQCH+QIA4wwBAGQeAAAwBAMAK/sAALMKAgCr+gAAfgoBABX5AgBxDAEAG/gCAHcLAQBYCQAAxAICAAf6AgBjDQEALgIAAAACAgDM+gAAnwoBAJX4AgDxCwEA4wEAAIABAgCFEwEABwsCADT6AAAJCgEAp/oAAHoKAQCSHwAABwcEAMn4AgAlDAEAGPoAAPQJAQDa+QAAuwkBAEv5AgCnDAEADCIAACEIAgCe+QAAfwkBAFcPAAAeAwIAJgIAAOwBAgC4HgAA7AQCAMoMAAAEAwIA3/gCADsMAQAMBAAATgICACb5AAAHCQEAhR4AAHwEAgBbAQAA/gACAHv6AABOCgEAZ/oAADwKAQBG+QAAJwkBAM35AgApDQEAVDAAAHUIAgBo+AIAxAsBAH36AABQCgEApR4AALMEAwAy+AIAjgsBAKD6AABzCgEA6B8AAOQHAgCx+AIADQwBABkCAADiAQIA3fkAAL4JAQDfAQAAdQEDAFb6AAArCgEA7PkCAEgNAQD1+AIAUQwBAFAwAABxCAIAY9EBAEYLAwC0HgAA4AQDAFr5AAA7CQEA3Q0AABMDAwB2+gAASQoBAAMCAAC2AQIAQBsAAEwDAgDiBAAAhgICAJEeAACUBAIAWvkCALYMAQDk+QAAxQkBAGptAQA2CwMAifkAAGoJAQADHwAAnwUDAFQBAADwAAIA2wAAAC4AAgAj+QIAfwwBADICAAAKAgIASAEAAOIAAgB8+AIA2AsBAKD5AACBCQEAzvkAAK8JAQDCBgAAugICAEH7AADbCgIAqvgCAAYMAQAc+QIAeAwBAJD5AABxCQEA4h4AAFwFAwBoHwAAkwYCACEfAADmBQIAt/kCABMNAQD4HwAAAAgCAAn6AADqCQEAtDAAAKsIAgA0+wAAxwoCAMIAAAAEAAIA4wQAAIgCAgBZHgAAGAQCAAwbAABCAwIAlPgCAPALAQBXHgAAFAQCABoCAADkAQIAy/kAAKwJAQBuIgAANQgCALwUAQAVCwIAlvgCAPILAQB0IgAAPQgCANb6AACpCgEAdSIAAD8IAgAMAQAAggACALH5AgANDQEAO/sAANMKAgDx+QIATQ0BADYKAADgAgIAygsAAPYCAgDMHgAAHgUCAFj5AgC0DAEANfsAAMkKAgCgAQAAQgECABn6AAD1CQEAPQEAANQAAgD2HgAAkAUCAIwDAAAgAgIAgfgCAN0LAQDZ+QAAugkBAM0eAAAgBQIAfB4AAGoEAgDd+QIAOQ0BACZhAQApCwMA7fkCAEkNAQBS+QIArgwBAB/5AAAACQEAAvkCAF4MAQB6+QAAWwkBAP/4AgBbDAEAN/oAAAwKAQBE+wAA3woCAO75AgBKDQEAXgoAAOgCAgBLHwAAUwYDAPL5AADTCQEAbR4AAEgEAgBkAQAAEAECAM0DAAA+AgIA+jAAAN0IAgCy+gAAhQoBAEAeAADeAwIAVgEAAPQAAgBT+gAAKAoBAJ/5AgD7DAEAbfkCAMkMAQCn+QIAAw0BABkfAADWBQIAuvkAAJsJAQDwHgAAggUDANL4AgAuDAEAXA8AACADAgC90QEAUAsDALIfAAB9BwMAZTAAAIUIAgDb+QIANw0BAIoDAAAeAgIAkR8AAAQHAwA6+wAA0QoCAL75AgAaDQEAuh8AAI4HAgD0AAAAWAACAEsLAADsAgIAEPkAAPEIAQBb+QAAPAkBAHb4AgDSCwEAH/gCAHsLAQC9+AIAGQwBAFT5AAA1CQEAIvgCAH4LAQABAgAAsgECAIQeAAB6BAIACPoCAGQNAQDP+QAAsAkBAKn5AgAFDQEAdh8AALUGAgBq+QAASwkBAOP5AADECQEARPgCAKALAQAu+wAAuwoCALX6AACICgEAwQAAAAIAAgB4AQAANAECANb4AgAyDAEAQwMAABACAQAV+AIAcQsBAF/4AgC7CwEAO/kAABwJAQD4AQAAngECALYeAADmBAMA5x8AAOEHAwBH+gAAHAoBAJv5AgD3DAEAwQQAAGoCAgBJHwAATgYCAKn4AgAFDAEAMh4AAMADAgDyAAAAVAACAJr6AABtCgEAW/gCALcLAQDN+QAArgkBAK4wAAClCAIAex8AAL8GAgCSHgAAlgQCALL4AgAODAEAfvkAAF8JAQC3HgAA6QQDAAb6AADnCQEAzx8AALIHAgDq+AIARgwBAH75AgDaDAEA4vgCAD4MAQAYHwAA1AUCAI35AABuCQEAVjAAAHcIAgA6+QIAlgwBAM76AAChCgEAjB4AAIoEAgBYAQAA+AACAFX6AAAqCgEAxB8AAJwHAwC6FQEAGQsCAH4BAABAAQIADgEAAIYAAgBQ+QAAMQkBAIb4AgDiCwEA9x4AAJIFAgDc+QIAOA0BAN8JAADcAgIAih8AAOkGBADB+gAAlAoBADT5AgCQDAEA7B4AAHYFAwDvHgAAfwUDAEEfAAA+BgIAQ/kAACQJAQDHHgAAEwUDAGYfAACNBgMAEB8AAMQFAgDP+AIAKwwBAKAfAAA9BwMACfkCAGUMAQCsDwAANgMCAOUEAACMAgIAkfkCAO0MAQBY+QAAOQkBAMofAACoBwIAygMAADgCAgAX+AIAcwsBAE0PAAAaAwIAQwEAANgAAgA8AQAA0gACAPkBAACgAQIA0vkCAC4NAQBm+AIAwgsBAND5AgAsDQEAAR4AAFQDAgAN+QIAaQwBAFoBAAD8AAIAJQEAALAAAgCE+QAAZQkBABz5AAD9CAEAjPoAAF8KAQAUHgAAfAMDAG0iAAAzCAIAXAQAAGACAgACAQAAbgACAK/6AACCCgEANvgCAJILAQDABgAAuAICAP/5AADgCQEAf/kAAGAJAQDK+AIAJgwBACr5AgCGDAEAKwEAALgAAgCeMAAAoQgCAE76AAAjCgEAyPoAAJsKAQDfHwAAzwcCAG75AgDKDAEAnQ8AADADAgAC+AIAXgsBAAUeAABcAwIADfgCAGkLAQBB+QIAnQwBABsCAADmAQIALPgCAIgLAQBG+wAA4QoCAHH5AABSCQEAw/kAAKQJAQA8+AIAmAsBABr4AgB2CwEAu/kAAJwJAQCs+gAAfwoBAF8fAAB6BgMAavoAAD8KAQCz+AIADwwBAMn5AACqCQEA7vkAAM8JAQBrHgAARAQCACweAACyAwIAUfgCAK0LAQAS+AIAbgsBABUfAADRBQMASvoAAB8KAQDaMAAAzwgCAMYeAAAQBQMACAIAAMABAgAL+QAA7AgBAAAeAABSAwIAQPsAANkKAgAt+AIAiQsBANkBAABmAQMApfoAAHgKAQD2AAAAXAACAOEiAABbCAIAYfkCAL0MAQDPIQAAGwgCANj5AAC5CQEAPfoAABIKAQAv+wAAvQoCAIX6AABYCgEAXAkAAMwCAgD3HwAA/QcDAK4eAADOBAMAjvkCAOoMAQCDHwAAzwYEAMUwAAC7CAIAAwQAAEoCAgAgAQAApgACAPIEAACiAgIAj/kAAHAJAQCw+QIADA0BAPMfAAD2BwIASg0AAAkDAgA6AQAAzgACAHQeAABWBAIAMfgCAI0LAQAL+AIAZwsBAKP4AgD/CwEAVfkAADYJAQCf+AIA+wsBABsBAACcAAIAPPkAAB0JAQDdCQAA2gICAKz5AgAIDQEAM/kAABQJAQDD+AIAHwwBACFhAQAfCwIAKvgCAIYLAQA++QIAmgwBAC0CAAD9AQMA0h4AACwFAwCd+QIA+QwBADYfAAAgBgMA1/kCADMNAQCtHgAAywQDABT5AgBwDAEA5B8AANsHAgBcCwAA8AICAJUfAAATBwQAWQkAAMYCAgDA+QAAoQkBANceAAA7BQMAZfgCAMELAQAB+AIAXQsBAD4eAADaAwIASPgCAKQLAQDv+AIASwwBAHX4AgDRCwEABB4AAFoDAgAc+gAA+AkBAAf4AgBjCwEAzx4AACQFAgDRHgAAKQUDACf5AAAICQEA1PgCADAMAQA5+gAADgoBAIn5AgDlDAEADPoCAGgNAQBq+AIAxgsBANweAABKBQMAF/kAAPgIAQCg+AIA/AsBAJf4AgDzCwEAOB4AAMwDAwDiAAAAOAACAMkfAACmBwIANB8AABoGAwCJIgAATwgCAPr5AgBWDQEARCIAACkIAgCm+gAAeQoBAEb4AgCiCwEAJiEAAA0IAQALAQAAgAACAEUBAADcAAIAMAEAAMIAAgDl+QIAQQ0BABL5AgBuDAEArvkAAI8JAQBX+QIAswwBAAT5AADlCAEAmB8AAB8HAwA6+QAAGwkBAK/4AgALDAEAefgCANULAQD0HwAA+AcDACMeAACgAwIAEgIAANQBAgDdMAAA0wgCAHv4AgDXCwEA6/gCAEcMAQDCHgAABAUDAJohAAARCAIA4QAAADYAAgDRAAAAHgACAHL5AABTCQEAQQAAAAADAABBAAAAAQMAAEEAAAACAwAAQQAAAAMDAABBAAAACAMAAEEAAAAKAwAAQwAAACcDAABFAAAAAAMAAEUAAAABAwAARQAAAAIDAABFAAAACAMAAEkAAAAAAwAASQAAAAEDAABJAAAAAgMAAEkAAAAIAwAATgAAAAMDAABPAAAAAAMAAE8AAAABAwAATwAAAAIDAABPAAAAAwMAAE8AAAAIAwAAVQAAAAADAABVAAAAAQMAAFUAAAACAwAAVQAAAAgDAABZAAAAAQMAAGEAAAAAAwAAYQAAAAEDAABhAAAAAgMAAGEAAAADAwAAYQAAAAgDAABhAAAACgMAAGMAAAAnAwAAZQAAAAADAABlAAAAAQMAAGUAAAACAwAAZQAAAAgDAABpAAAAAAMAAGkAAAABAwAAaQAAAAIDAABpAAAACAMAAG4AAAADAwAAbwAAAAADAABvAAAAAQMAAG8AAAACAwAAbwAAAAMDAABvAAAACAMAAHUAAAAAAwAAdQAAAAEDAAB1AAAAAgMAAHUAAAAIAwAAeQAAAAEDAAB5AAAACAMAAEEAAAAEAwAAYQAAAAQDAABBAAAABgMAAGEAAAAGAwAAQQAAACgDAABhAAAAKAMAAEMAAAABAwAAYwAAAAEDAABDAAAAAgMAAGMAAAACAwAAQwAAAAcDAABjAAAABwMAAEMAAAAMAwAAYwAAAAwDAABEAAAADAMAAGQAAAAMAwAARQAAAAQDAABlAAAABAMAAEUAAAAGAwAAZQAAAAYDAABFAAAABwMAAGUAAAAHAwAARQAAACgDAABlAAAAKAMAAEUAAAAMAwAAZQAAAAwDAABHAAAAAgMAAGcAAAACAwAARwAAAAYDAABnAAAABgMAAEcAAAAHAwAAZwAAAAcDAABHAAAAJwMAAGcAAAAnAwAASAAAAAIDAABoAAAAAgMAAEkAAAADAwAAaQAAAAMDAABJAAAABAMAAGkAAAAEAwAASQAAAAYDAABpAAAABgMAAEkAAAAoAwAAaQAAACgDAABJAAAABwMAAEoAAAACAwAAagAAAAIDAABLAAAAJwMAAGsAAAAnAwAATAAAAAEDAABsAAAAAQMAAEwAAAAnAwAAbAAAACcDAABMAAAADAMAAGwAAAAMAwAATgAAAAEDAABuAAAAAQMAAE4AAAAnAwAAbgAAACcDAABOAAAADAMAAG4AAAAMAwAATwAAAAQDAABvAAAABAMAAE8AAAAGAwAAbwAAAAYDAABPAAAACwMAAG8AAAALAwAAUgAAAAEDAAByAAAAAQMAAFIAAAAnAwAAcgAAACcDAABSAAAADAMAAHIAAAAMAwAAUwAAAAEDAABzAAAAAQMAAFMAAAACAwAAcwAAAAIDAABTAAAAJwMAAHMAAAAnAwAAUwAAAAwDAABzAAAADAMAAFQAAAAnAwAAdAAAACcDAABUAAAADAMAAHQAAAAMAwAAVQAAAAMDAAB1AAAAAwMAAFUAAAAEAwAAdQAAAAQDAABVAAAABgMAAHUAAAAGAwAAVQAAAAoDAAB1AAAACgMAAFUAAAALAwAAdQAAAAsDAABVAAAAKAMAAHUAAAAoAwAAVwAAAAIDAAB3AAAAAgMAAFkAAAACAwAAeQAAAAIDAABZAAAACAMAAFoAAAABAwAAegAAAAEDAABaAAAABwMAAHoAAAAHAwAAWgAAAAwDAAB6AAAADAMAAE8AAAAbAwAAbwAAABsDAABVAAAAGwMAAHUAAAAbAwAAQQAAAAwDAABhAAAADAMAAEkAAAAMAwAAaQAAAAwDAABPAAAADAMAAG8AAAAMAwAAVQAAAAwDAAB1AAAADAMAAFUAAAAIAwAABAMAAHUAAAAIAwAABAMAAFUAAAAIAwAAAQMAAHUAAAAIAwAAAQMAAFUAAAAIAwAADAMAAHUAAAAIAwAADAMAAFUAAAAIAwAAAAMAAHUAAAAIAwAAAAMAAEEAAAAIAwAABAMAAGEAAAAIAwAABAMAAEEAAAAHAwAABAMAAGEAAAAHAwAABAMAAMYAAAAEAwAA5gAAAAQDAABHAAAADAMAAGcAAAAMAwAASwAAAAwDAABrAAAADAMAAE8AAAAoAwAAbwAAACgDAABPAAAAKAMAAAQDAABvAAAAKAMAAAQDAAC3AQAADAMAAJICAAAMAwAAagAAAAwDAABHAAAAAQMAAGcAAAABAwAATgAAAAADAABuAAAAAAMAAEEAAAAKAwAAAQMAAGEAAAAKAwAAAQMAAMYAAAABAwAA5gAAAAEDAADYAAAAAQMAAPgAAAABAwAAQQAAAA8DAABhAAAADwMAAEEAAAARAwAAYQAAABEDAABFAAAADwMAAGUAAAAPAwAARQAAABEDAABlAAAAEQMAAEkAAAAPAwAAaQAAAA8DAABJAAAAEQMAAGkAAAARAwAATwAAAA8DAABvAAAADwMAAE8AAAARAwAAbwAAABEDAABSAAAADwMAAHIAAAAPAwAAUgAAABEDAAByAAAAEQMAAFUAAAAPAwAAdQAAAA8DAABVAAAAEQMAAHUAAAARAwAAUwAAACYDAABzAAAAJgMAAFQAAAAmAwAAdAAAACYDAABIAAAADAMAAGgAAAAMAwAAQQAAAAcDAABhAAAABwMAAEUAAAAnAwAAZQAAACcDAABPAAAACAMAAAQDAABvAAAACAMAAAQDAABPAAAAAwMAAAQDAABvAAAAAwMAAAQDAABPAAAABwMAAG8AAAAHAwAATwAAAAcDAAAEAwAAbwAAAAcDAAAEAwAAWQAAAAQDAAB5AAAABAMAAAADAAABAwAAEwMAAAgDAAABAwAAuQIAADsAAACoAAAAAQMAAJEDAAABAwAAtwAAAJUDAAABAwAAlwMAAAEDAACZAwAAAQMAAJ8DAAABAwAApQMAAAEDAACpAwAAAQMAALkDAAAIAwAAAQMAAJkDAAAIAwAApQMAAAgDAACxAwAAAQMAALUDAAABAwAAtwMAAAEDAAC5AwAAAQMAAMUDAAAIAwAAAQMAALkDAAAIAwAAxQMAAAgDAAC/AwAAAQMAAMUDAAABAwAAyQMAAAEDAADSAwAAAQMAANIDAAAIAwAAFQQAAAADAAAVBAAACAMAABMEAAABAwAABgQAAAgDAAAaBAAAAQMAABgEAAAAAwAAIwQAAAYDAAAYBAAABgMAADgEAAAGAwAANQQAAAADAAA1BAAACAMAADMEAAABAwAAVgQAAAgDAAA6BAAAAQMAADgEAAAAAwAAQwQAAAYDAAB0BAAADwMAAHUEAAAPAwAAFgQAAAYDAAA2BAAABgMAABAEAAAGAwAAMAQAAAYDAAAQBAAACAMAADAEAAAIAwAAFQQAAAYDAAA1BAAABgMAANgEAAAIAwAA2QQAAAgDAAAWBAAACAMAADYEAAAIAwAAFwQAAAgDAAA3BAAACAMAABgEAAAEAwAAOAQAAAQDAAAYBAAACAMAADgEAAAIAwAAHgQAAAgDAAA+BAAACAMAAOgEAAAIAwAA6QQAAAgDAAAtBAAACAMAAE0EAAAIAwAAIwQAAAQDAABDBAAABAMAACMEAAAIAwAAQwQAAAgDAAAjBAAACwMAAEMEAAALAwAAJwQAAAgDAABHBAAACAMAACsEAAAIAwAASwQAAAgDAAAnBgAAUwYAACcGAABUBgAASAYAAFQGAAAnBgAAVQYAAEoGAABUBgAA1QYAAFQGAADBBgAAVAYAANIGAABUBgAAKAkAADwJAAAwCQAAPAkAADMJAAA8CQAAFQkAADwJAAAWCQAAPAkAABcJAAA8CQAAHAkAADwJAAAhCQAAPAkAACIJAAA8CQAAKwkAADwJAAAvCQAAPAkAAMcJAAC+CQAAxwkAANcJAAChCQAAvAkAAKIJAAC8CQAArwkAALwJAAAyCgAAPAoAADgKAAA8CgAAFgoAADwKAAAXCgAAPAoAABwKAAA8CgAAKwoAADwKAABHCwAAVgsAAEcLAAA+CwAARwsAAFcLAAAhCwAAPAsAACILAAA8CwAAkgsAANcLAADGCwAAvgsAAMcLAAC+CwAAxgsAANcLAABGDAAAVgwAAL8MAADVDAAAxgwAANUMAADGDAAA1gwAAMYMAADCDAAAxgwAAMIMAADVDAAARg0AAD4NAABHDQAAPg0AAEYNAABXDQAA2Q0AAMoNAADZDQAAzw0AANkNAADPDQAAyg0AANkNAADfDQAAQg8AALcPAABMDwAAtw8AAFEPAAC3DwAAVg8AALcPAABbDwAAtw8AAEAPAAC1DwAAcQ8AAHIPAABxDwAAdA8AALIPAACADwAAsw8AAIAPAABxDwAAgA8AAJIPAAC3DwAAnA8AALcPAAChDwAAtw8AAKYPAAC3DwAAqw8AALcPAACQDwAAtQ8AACUQAAAuEAAABRsAADUbAAAHGwAANRsAAAkbAAA1GwAACxsAADUbAAANGwAANRsAABEbAAA1GwAAOhsAADUbAAA8GwAANRsAAD4bAAA1GwAAPxsAADUbAABCGwAANRsAAEEAAAAlAwAAYQAAACUDAABCAAAABwMAAGIAAAAHAwAAQgAAACMDAABiAAAAIwMAAEIAAAAxAwAAYgAAADEDAABDAAAAJwMAAAEDAABjAAAAJwMAAAEDAABEAAAABwMAAGQAAAAHAwAARAAAACMDAABkAAAAIwMAAEQAAAAxAwAAZAAAADEDAABEAAAAJwMAAGQAAAAnAwAARAAAAC0DAABkAAAALQMAAEUAAAAEAwAAAAMAAGUAAAAEAwAAAAMAAEUAAAAEAwAAAQMAAGUAAAAEAwAAAQMAAEUAAAAtAwAAZQAAAC0DAABFAAAAMAMAAGUAAAAwAwAARQAAACcDAAAGAwAAZQAAACcDAAAGAwAARgAAAAcDAABmAAAABwMAAEcAAAAEAwAAZwAAAAQDAABIAAAABwMAAGgAAAAHAwAASAAAACMDAABoAAAAIwMAAEgAAAAIAwAAaAAAAAgDAABIAAAAJwMAAGgAAAAnAwAASAAAAC4DAABoAAAALgMAAEkAAAAwAwAAaQAAADADAABJAAAACAMAAAEDAABpAAAACAMAAAEDAABLAAAAAQMAAGsAAAABAwAASwAAACMDAABrAAAAIwMAAEsAAAAxAwAAawAAADEDAABMAAAAIwMAAGwAAAAjAwAATAAAACMDAAAEAwAAbAAAACMDAAAEAwAATAAAADEDAABsAAAAMQMAAEwAAAAtAwAAbAAAAC0DAABNAAAAAQMAAG0AAAABAwAATQAAAAcDAABtAAAABwMAAE0AAAAjAwAAbQAAACMDAABOAAAABwMAAG4AAAAHAwAATgAAACMDAABuAAAAIwMAAE4AAAAxAwAAbgAAADEDAABOAAAALQMAAG4AAAAtAwAATwAAAAMDAAABAwAAbwAAAAMDAAABAwAATwAAAAMDAAAIAwAAbwAAAAMDAAAIAwAATwAAAAQDAAAAAwAAbwAAAAQDAAAAAwAATwAAAAQDAAABAwAAbwAAAAQDAAABAwAAUAAAAAEDAABwAAAAAQMAAFAAAAAHAwAAcAAAAAcDAABSAAAABwMAAHIAAAAHAwAAUgAAACMDAAByAAAAIwMAAFIAAAAjAwAABAMAAHIAAAAjAwAABAMAAFIAAAAxAwAAcgAAADEDAABTAAAABwMAAHMAAAAHAwAAUwAAACMDAABzAAAAIwMAAFMAAAABAwAABwMAAHMAAAABAwAABwMAAFMAAAAMAwAABwMAAHMAAAAMAwAABwMAAFMAAAAjAwAABwMAAHMAAAAjAwAABwMAAFQAAAAHAwAAdAAAAAcDAABUAAAAIwMAAHQAAAAjAwAAVAAAADEDAAB0AAAAMQMAAFQAAAAtAwAAdAAAAC0DAABVAAAAJAMAAHUAAAAkAwAAVQAAADADAAB1AAAAMAMAAFUAAAAtAwAAdQAAAC0DAABVAAAAAwMAAAEDAAB1AAAAAwMAAAEDAABVAAAABAMAAAgDAAB1AAAABAMAAAgDAABWAAAAAwMAAHYAAAADAwAAVgAAACMDAAB2AAAAIwMAAFcAAAAAAwAAdwAAAAADAABXAAAAAQMAAHcAAAABAwAAVwAAAAgDAAB3AAAACAMAAFcAAAAHAwAAdwAAAAcDAABXAAAAIwMAAHcAAAAjAwAAWAAAAAcDAAB4AAAABwMAAFgAAAAIAwAAeAAAAAgDAABZAAAABwMAAHkAAAAHAwAAWgAAAAIDAAB6AAAAAgMAAFoAAAAjAwAAegAAACMDAABaAAAAMQMAAHoAAAAxAwAAaAAAADEDAAB0AAAACAMAAHcAAAAKAwAAeQAAAAoDAAB/AQAABwMAAEEAAAAjAwAAYQAAACMDAABBAAAACQMAAGEAAAAJAwAAQQAAAAIDAAABAwAAYQAAAAIDAAABAwAAQQAAAAIDAAAAAwAAYQAAAAIDAAAAAwAAQQAAAAIDAAAJAwAAYQAAAAIDAAAJAwAAQQAAAAIDAAADAwAAYQAAAAIDAAADAwAAQQAAACMDAAACAwAAYQAAACMDAAACAwAAQQAAAAYDAAABAwAAYQAAAAYDAAABAwAAQQAAAAYDAAAAAwAAYQAAAAYDAAAAAwAAQQAAAAYDAAAJAwAAYQAAAAYDAAAJAwAAQQAAAAYDAAADAwAAYQAAAAYDAAADAwAAQQAAACMDAAAGAwAAYQAAACMDAAAGAwAARQAAACMDAABlAAAAIwMAAEUAAAAJAwAAZQAAAAkDAABFAAAAAwMAAGUAAAADAwAARQAAAAIDAAABAwAAZQAAAAIDAAABAwAARQAAAAIDAAAAAwAAZQAAAAIDAAAAAwAARQAAAAIDAAAJAwAAZQAAAAIDAAAJAwAARQAAAAIDAAADAwAAZQAAAAIDAAADAwAARQAAACMDAAACAwAAZQAAACMDAAACAwAASQAAAAkDAABpAAAACQMAAEkAAAAjAwAAaQAAACMDAABPAAAAIwMAAG8AAAAjAwAATwAAAAkDAABvAAAACQMAAE8AAAACAwAAAQMAAG8AAAACAwAAAQMAAE8AAAACAwAAAAMAAG8AAAACAwAAAAMAAE8AAAACAwAACQMAAG8AAAACAwAACQMAAE8AAAACAwAAAwMAAG8AAAACAwAAAwMAAE8AAAAjAwAAAgMAAG8AAAAjAwAAAgMAAE8AAAAbAwAAAQMAAG8AAAAbAwAAAQMAAE8AAAAbAwAAAAMAAG8AAAAbAwAAAAMAAE8AAAAbAwAACQMAAG8AAAAbAwAACQMAAE8AAAAbAwAAAwMAAG8AAAAbAwAAAwMAAE8AAAAbAwAAIwMAAG8AAAAbAwAAIwMAAFUAAAAjAwAAdQAAACMDAABVAAAACQMAAHUAAAAJAwAAVQAAABsDAAABAwAAdQAAABsDAAABAwAAVQAAABsDAAAAAwAAdQAAABsDAAAAAwAAVQAAABsDAAAJAwAAdQAAABsDAAAJAwAAVQAAABsDAAADAwAAdQAAABsDAAADAwAAVQAAABsDAAAjAwAAdQAAABsDAAAjAwAAWQAAAAADAAB5AAAAAAMAAFkAAAAjAwAAeQAAACMDAABZAAAACQMAAHkAAAAJAwAAWQAAAAMDAAB5AAAAAwMAALEDAAATAwAAsQMAABQDAACxAwAAEwMAAAADAACxAwAAFAMAAAADAACxAwAAEwMAAAEDAACxAwAAFAMAAAEDAACxAwAAEwMAAEIDAACxAwAAFAMAAEIDAACRAwAAEwMAAJEDAAAUAwAAkQMAABMDAAAAAwAAkQMAABQDAAAAAwAAkQMAABMDAAABAwAAkQMAABQDAAABAwAAkQMAABMDAABCAwAAkQMAABQDAABCAwAAtQMAABMDAAC1AwAAFAMAALUDAAATAwAAAAMAALUDAAAUAwAAAAMAALUDAAATAwAAAQMAALUDAAAUAwAAAQMAAJUDAAATAwAAlQMAABQDAACVAwAAEwMAAAADAACVAwAAFAMAAAADAACVAwAAEwMAAAEDAACVAwAAFAMAAAEDAAC3AwAAEwMAALcDAAAUAwAAtwMAABMDAAAAAwAAtwMAABQDAAAAAwAAtwMAABMDAAABAwAAtwMAABQDAAABAwAAtwMAABMDAABCAwAAtwMAABQDAABCAwAAlwMAABMDAACXAwAAFAMAAJcDAAATAwAAAAMAAJcDAAAUAwAAAAMAAJcDAAATAwAAAQMAAJcDAAAUAwAAAQMAAJcDAAATAwAAQgMAAJcDAAAUAwAAQgMAALkDAAATAwAAuQMAABQDAAC5AwAAEwMAAAADAAC5AwAAFAMAAAADAAC5AwAAEwMAAAEDAAC5AwAAFAMAAAEDAAC5AwAAEwMAAEIDAAC5AwAAFAMAAEIDAACZAwAAEwMAAJkDAAAUAwAAmQMAABMDAAAAAwAAmQMAABQDAAAAAwAAmQMAABMDAAABAwAAmQMAABQDAAABAwAAmQMAABMDAABCAwAAmQMAABQDAABCAwAAvwMAABMDAAC/AwAAFAMAAL8DAAATAwAAAAMAAL8DAAAUAwAAAAMAAL8DAAATAwAAAQMAAL8DAAAUAwAAAQMAAJ8DAAATAwAAnwMAABQDAACfAwAAEwMAAAADAACfAwAAFAMAAAADAACfAwAAEwMAAAEDAACfAwAAFAMAAAEDAADFAwAAEwMAAMUDAAAUAwAAxQMAABMDAAAAAwAAxQMAABQDAAAAAwAAxQMAABMDAAABAwAAxQMAABQDAAABAwAAxQMAABMDAABCAwAAxQMAABQDAABCAwAApQMAABQDAAClAwAAFAMAAAADAAClAwAAFAMAAAEDAAClAwAAFAMAAEIDAADJAwAAEwMAAMkDAAAUAwAAyQMAABMDAAAAAwAAyQMAABQDAAAAAwAAyQMAABMDAAABAwAAyQMAABQDAAABAwAAyQMAABMDAABCAwAAyQMAABQDAABCAwAAqQMAABMDAACpAwAAFAMAAKkDAAATAwAAAAMAAKkDAAAUAwAAAAMAAKkDAAATAwAAAQMAAKkDAAAUAwAAAQMAAKkDAAATAwAAQgMAAKkDAAAUAwAAQgMAALEDAAAAAwAAsQMAAAEDAAC1AwAAAAMAALUDAAABAwAAtwMAAAADAAC3AwAAAQMAALkDAAAAAwAAuQMAAAEDAAC/AwAAAAMAAL8DAAABAwAAxQMAAAADAADFAwAAAQMAAMkDAAAAAwAAyQMAAAEDAACxAwAAEwMAAEUDAACxAwAAFAMAAEUDAACxAwAAEwMAAAADAABFAwAAsQMAABQDAAAAAwAARQMAALEDAAATAwAAAQMAAEUDAACxAwAAFAMAAAEDAABFAwAAsQMAABMDAABCAwAARQMAALEDAAAUAwAAQgMAAEUDAACRAwAAEwMAAEUDAACRAwAAFAMAAEUDAACRAwAAEwMAAAADAABFAwAAkQMAABQDAAAAAwAARQMAAJEDAAATAwAAAQMAAEUDAACRAwAAFAMAAAEDAABFAwAAkQMAABMDAABCAwAARQMAAJEDAAAUAwAAQgMAAEUDAAC3AwAAEwMAAEUDAAC3AwAAFAMAAEUDAAC3AwAAEwMAAAADAABFAwAAtwMAABQDAAAAAwAARQMAALcDAAATAwAAAQMAAEUDAAC3AwAAFAMAAAEDAABFAwAAtwMAABMDAABCAwAARQMAALcDAAAUAwAAQgMAAEUDAACXAwAAEwMAAEUDAACXAwAAFAMAAEUDAACXAwAAEwMAAAADAABFAwAAlwMAABQDAAAAAwAARQMAAJcDAAATAwAAAQMAAEUDAACXAwAAFAMAAAEDAABFAwAAlwMAABMDAABCAwAARQMAAJcDAAAUAwAAQgMAAEUDAADJAwAAEwMAAEUDAADJAwAAFAMAAEUDAADJAwAAEwMAAAADAABFAwAAyQMAABQDAAAAAwAARQMAAMkDAAATAwAAAQMAAEUDAADJAwAAFAMAAAEDAABFAwAAyQMAABMDAABCAwAARQMAAMkDAAAUAwAAQgMAAEUDAACpAwAAEwMAAEUDAACpAwAAFAMAAEUDAACpAwAAEwMAAAADAABFAwAAqQMAABQDAAAAAwAARQMAAKkDAAATAwAAAQMAAEUDAACpAwAAFAMAAAEDAABFAwAAqQMAABMDAABCAwAARQMAAKkDAAAUAwAAQgMAAEUDAACxAwAABgMAALEDAAAEAwAAsQMAAAADAABFAwAAsQMAAEUDAACxAwAAAQMAAEUDAACxAwAAQgMAALEDAABCAwAARQMAAJEDAAAGAwAAkQMAAAQDAACRAwAAAAMAAJEDAAABAwAAkQMAAEUDAAC5AwAAqAAAAEIDAAC3AwAAAAMAAEUDAAC3AwAARQMAALcDAAABAwAARQMAALcDAABCAwAAtwMAAEIDAABFAwAAlQMAAAADAACVAwAAAQMAAJcDAAAAAwAAlwMAAAEDAACXAwAARQMAAL8fAAAAAwAAvx8AAAEDAAC/HwAAQgMAALkDAAAGAwAAuQMAAAQDAAC5AwAACAMAAAADAAC5AwAACAMAAAEDAAC5AwAAQgMAALkDAAAIAwAAQgMAAJkDAAAGAwAAmQMAAAQDAACZAwAAAAMAAJkDAAABAwAA/h8AAAADAAD+HwAAAQMAAP4fAABCAwAAxQMAAAYDAADFAwAABAMAAMUDAAAIAwAAAAMAAMUDAAAIAwAAAQMAAMEDAAATAwAAwQMAABQDAADFAwAAQgMAAMUDAAAIAwAAQgMAAKUDAAAGAwAApQMAAAQDAAClAwAAAAMAAKUDAAABAwAAoQMAABQDAACoAAAAAAMAAKgAAAABAwAAYAAAAMkDAAAAAwAARQMAAMkDAABFAwAAyQMAAAEDAABFAwAAyQMAAEIDAADJAwAAQgMAAEUDAACfAwAAAAMAAJ8DAAABAwAAqQMAAAADAACpAwAAAQMAAKkDAABFAwAAtAAAAAIgAAADIAAAqQMAAEsAAABBAAAACgMAAJAhAAA4AwAAkiEAADgDAACUIQAAOAMAANAhAAA4AwAA1CEAADgDAADSIQAAOAMAAAMiAAA4AwAACCIAADgDAAALIgAAOAMAACMiAAA4AwAAJSIAADgDAAA8IgAAOAMAAEMiAAA4AwAARSIAADgDAABIIgAAOAMAAD0AAAA4AwAAYSIAADgDAABNIgAAOAMAADwAAAA4AwAAPgAAADgDAABkIgAAOAMAAGUiAAA4AwAAciIAADgDAABzIgAAOAMAAHYiAAA4AwAAdyIAADgDAAB6IgAAOAMAAHsiAAA4AwAAgiIAADgDAACDIgAAOAMAAIYiAAA4AwAAhyIAADgDAACiIgAAOAMAAKgiAAA4AwAAqSIAADgDAACrIgAAOAMAAHwiAAA4AwAAfSIAADgDAACRIgAAOAMAAJIiAAA4AwAAsiIAADgDAACzIgAAOAMAALQiAAA4AwAAtSIAADgDAAAIMAAACTAAAN0qAAA4AwAASzAAAJkwAABNMAAAmTAAAE8wAACZMAAAUTAAAJkwAABTMAAAmTAAAFUwAACZMAAAVzAAAJkwAABZMAAAmTAAAFswAACZMAAAXTAAAJkwAABfMAAAmTAAAGEwAACZMAAAZDAAAJkwAABmMAAAmTAAAGgwAACZMAAAbzAAAJkwAABvMAAAmjAAAHIwAACZMAAAcjAAAJowAAB1MAAAmTAAAHUwAACaMAAAeDAAAJkwAAB4MAAAmjAAAHswAACZMAAAezAAAJowAABGMAAAmTAAAJ0wAACZMAAAqzAAAJkwAACtMAAAmTAAAK8wAACZMAAAsTAAAJkwAACzMAAAmTAAALUwAACZMAAAtzAAAJkwAAC5MAAAmTAAALswAACZMAAAvTAAAJkwAAC/MAAAmTAAAMEwAACZMAAAxDAAAJkwAADGMAAAmTAAAMgwAACZMAAAzzAAAJkwAADPMAAAmjAAANIwAACZMAAA0jAAAJowAADVMAAAmTAAANUwAACaMAAA2DAAAJkwAADYMAAAmjAAANswAACZMAAA2zAAAJowAACmMAAAmTAAAO8wAACZMAAA8DAAAJkwAADxMAAAmTAAAPIwAACZMAAA/TAAAJkwAABIjAAA9GYAAMqOAADIjAAA0W4AADJOAADlUwAAnJ8AAJyfAABRWQAA0ZEAAIdVAABIWQAA9mEAAGl2AACFfwAAP4YAALqHAAD4iAAAj5AAAAJqAAAbbQAA2XAAAN5zAAA9hAAAapEAAPGZAACCTgAAdVMAAARrAAAbcgAALYYAAB6eAABQXQAA628AAM2FAABkiQAAyWIAANiBAAAfiAAAyl4AABdnAABqbQAA/HIAAM6QAACGTwAAt1EAAN5SAADEZAAA02oAABByAADndgAAAYAAAAaGAABchgAA740AADKXAABvmwAA+p0AAIx4AAB/eQAAoH0AAMmDAAAEkwAAf54AANaKAADfWAAABF8AAGB8AAB+gAAAYnIAAMp4AADCjAAA95YAANhYAABiXAAAE2oAANptAAAPbwAAL30AADd+AABLlgAA0lIAAIuAAADcUQAAzFEAABx6AAC+fQAA8YMAAHWWAACAiwAAz2IAAAJqAAD+igAAOU4AAOdbAAASYAAAh3MAAHB1AAAXUwAA+3gAAL9PAACpXwAADU4AAMxsAAB4ZQAAIn0AAMNTAABeWAAAAXcAAEmEAACqigAAumsAALCPAACIbAAA/mIAAOWCAACgYwAAZXUAAK5OAABpUQAAyVEAAIFoAADnfAAAb4IAANKKAADPkQAA9VIAAEJUAABzWQAA7F4AAMVlAAD+bwAAKnkAAK2VAABqmgAAl54AAM6eAACbUgAAxmYAAHdrAABijwAAdF4AAJBhAAAAYgAAmmQAACNvAABJcQAAiXQAAMp5AAD0fQAAb4AAACaPAADuhAAAI5AAAEqTAAAXUgAAo1IAAL1UAADIcAAAwogAAKqKAADJXgAA9V8AAHtjAACuawAAPnwAAHVzAADkTgAA+VYAAOdbAAC6XQAAHGAAALJzAABpdAAAmn8AAEaAAAA0kgAA9pYAAEiXAAAYmAAAi08AAK55AAC0kQAAuJYAAOFgAACGTgAA2lAAAO5bAAA/XAAAmWUAAAJqAADOcQAAQnYAAPyEAAB8kAAAjZ8AAIhmAAAulgAAiVIAAHtnAADzZwAAQW0AAJxuAAAJdAAAWXUAAGt4AAAQfQAAXpgAAG1RAAAuYgAAeJYAACtQAAAZXQAA6m0AACqPAACLXwAARGEAABdoAACHcwAAhpYAAClSAAAPVAAAZVwAABNmAABOZwAAqGgAAOVsAAAGdAAA4nUAAHl/AADPiAAA4YgAAMyRAADilgAAP1MAALpuAAAdVAAA0HEAAJh0AAD6hQAAo5YAAFecAACfngAAl2cAAMttAADogQAAy3oAACB7AACSfAAAwHIAAJlwAABYiwAAwE4AADaDAAA6UgAAB1IAAKZeAADTYgAA1nwAAIVbAAAebQAAtGYAADuPAABMiAAATZYAAIuJAADTXgAAQFEAAMBVAABaWAAAdGYAAN5RAAAqcwAAynYAADx5AABeeQAAZXkAAI95AABWlwAAvnwAAL1/AAAShgAA+IoAADiQAAD9kAAA75gAAPyYAAAomQAAtJ0AAN6QAAC3lgAArk8AAOdQAABNUQAAyVIAAORSAABRUwAAnVUAAAZWAABoVgAAQFgAAKhYAABkXAAAblwAAJRgAABoYQAAjmEAAPJhAABPZQAA4mUAAJFmAACFaAAAd20AABpuAAAibwAAbnEAACtyAAAidAAAkXgAAD55AABJeQAASHkAAFB5AABWeQAAXXkAAI15AACOeQAAQHoAAIF6AADAewAA9H0AAAl+AABBfgAAcn8AAAWAAADtgQAAeYIAAHmCAABXhAAAEIkAAJaJAAABiwAAOYsAANOMAAAIjQAAto8AADiQAADjlgAA/5cAADuYAAB1YAAA7kICABiCAAAmTgAAtVEAAGhRAACATwAARVEAAIBRAADHUgAA+lIAAJ1VAABVVQAAmVUAAOJVAABaWAAAs1gAAERZAABUWQAAYloAAChbAADSXgAA2V4AAGlfAACtXwAA2GAAAE5hAAAIYQAAjmEAAGBhAADyYQAANGIAAMRjAAAcZAAAUmQAAFZlAAB0ZgAAF2cAABtnAABWZwAAeWsAALprAABBbQAA224AAMtuAAAibwAAHnAAAG5xAACndwAANXIAAK9yAAAqcwAAcXQAAAZ1AAA7dQAAHXYAAB92AADKdgAA23YAAPR2AABKdwAAQHcAAMx4AACxegAAwHsAAHt8AABbfQAA9H0AAD5/AAAFgAAAUoMAAO+DAAB5hwAAQYkAAIaJAACWiQAAv4oAAPiKAADLigAAAYsAAP6KAADtigAAOYsAAIqLAAAIjQAAOI8AAHKQAACZkQAAdpIAAHyWAADjlgAAVpcAANuXAAD/lwAAC5gAADuYAAASmwAAnJ8AAEooAgBEKAIA1TMCAJ07AAAYQAAAOUAAAElSAgDQXAIA034CAEOfAACOnwAA2QUAALQFAADyBQAAtwUAAOkFAADBBQAA6QUAAMIFAADpBQAAvAUAAMEFAADpBQAAvAUAAMIFAADQBQAAtwUAANAFAAC4BQAA0AUAALwFAADRBQAAvAUAANIFAAC8BQAA0wUAALwFAADUBQAAvAUAANUFAAC8BQAA1gUAALwFAADYBQAAvAUAANkFAAC8BQAA2gUAALwFAADbBQAAvAUAANwFAAC8BQAA3gUAALwFAADgBQAAvAUAAOEFAAC8BQAA4wUAALwFAADkBQAAvAUAAOYFAAC8BQAA5wUAALwFAADoBQAAvAUAAOkFAAC8BQAA6gUAALwFAADVBQAAuQUAANEFAAC/BQAA2wUAAL8FAADkBQAAvwUAANIFAQAHAwAA2gUBAAcDAACZEAEAuhABAJsQAQC6EAEApRABALoQAQAxEQEAJxEBADIRAQAnEQEARxMBAD4TAQBHEwEAVxMBAIITAQDJEwEAhBMBALsTAQCLEwEAwhMBAJATAQDJEwEAwhMBAMITAQDCEwEAuBMBAMITAQDJEwEAuRQBALoUAQC5FAEAsBQBALkUAQC9FAEAuBUBAK8VAQC5FQEArxUBADUZAQAwGQEAHmEBAB5hAQAeYQEAKWEBAB5hAQAfYQEAKWEBAB9hAQAeYQEAIGEBAB5hAQAeYQEAH2EBAB5hAQApYQEAH2EBAB5hAQAeYQEAIGEBAGdtAQBnbQEAY20BAGdtAQBjbQEAZ20BAGdtAQBX0QEAZdEBAFjRAQBl0QEAWNEBAGXRAQBu0QEAWNEBAGXRAQBv0QEAWNEBAGXRAQBw0QEAWNEBAGXRAQBx0QEAWNEBAGXRAQBy0QEAudEBAGXRAQC60QEAZdEBALnRAQBl0QEAbtEBALrRAQBl0QEAbtEBALnRAQBl0QEAb9EBALrRAQBl0QEAb9EBAD1OAAA4TgAAQU4AACIBAgBgTwAArk8AALtPAAACUAAAelAAAJlQAADnUAAAz1AAAJ40AAA6BgIATVEAAFRRAABkUQAAd1EAABwFAgC5NAAAZ1EAAI1RAABLBQIAl1EAAKRRAADMTgAArFEAALVRAADfkQIA9VEAAANSAADfNAAAO1IAAEZSAAByUgAAd1IAABU1AADHUgAAyVIAAORSAAD6UgAABVMAAAZTAAAXUwAASVMAAFFTAABaUwAAc1MAAH1TAAB/UwAAf1MAAH9TAAAsCgIAcHAAAMpTAADfUwAAYwsCAOtTAADxUwAABlQAAJ5UAAA4VAAASFQAAGhUAACiVAAA9lQAABBVAABTVQAAY1UAAIRVAACEVQAAmVUAAKtVAACzVQAAwlUAABZXAAAGVgAAF1cAAFFWAAB0VgAAB1IAAO5YAADOVwAA9FcAAA1YAACLVwAAMlgAADFYAACsWAAA5BQCAPJYAAD3WAAABlkAABpZAAAiWQAAYlkAAKgWAgDqFgIA7FkAABtaAAAnWgAA2FkAAGZaAADuNgAA/DYAAAhbAAA+WwAAPlsAAMgZAgDDWwAA2FsAAOdbAADzWwAAGBsCAP9bAAAGXAAAU18AACJcAACBNwAAYFwAAG5cAADAXAAAjVwAAOQdAgBDXQAA5h0CAG5dAABrXQAAfF0AAOFdAADiXQAALzgAAP1dAAAoXgAAPV4AAGleAABiOAAAgyECAHw4AACwXgAAs14AALZeAADKXgAAkqMCAP5eAAAxIwIAMSMCAAGCAAAiXwAAIl8AAMc4AAC4MgIA2mECAGJfAABrXwAA4zgAAJpfAADNXwAA118AAPlfAACBYAAAOjkAABw5AACUYAAA1CYCAMdgAABIYQAATGEAAE5hAABMYQAAemEAAI5hAACyYQAApGEAAK9hAADeYQAA8mEAAPZhAAAQYgAAG2IAAF1iAACxYgAA1GIAAFBjAAAMKwIAPWMAAPxiAABoYwAAg2MAAORjAADxKwIAImQAAMVjAACpYwAALjoAAGlkAAB+ZAAAnWQAAHdkAABsOgAAT2UAAGxlAAAKMAIA42UAAPhmAABJZgAAGTsAAJFmAAAIOwAA5DoAAJJRAACVUQAAAGcAAJxmAACtgAAA2UMAABdnAAAbZwAAIWcAAF5nAABTZwAAwzMCAEk7AAD6ZwAAhWcAAFJoAACFaAAAbTQCAI5oAAAfaAAAFGkAAJ07AABCaQAAo2kAAOppAACoagAAozYCANtqAAAYPAAAIWsAAKc4AgBUawAATjwAAHJrAACfawAAumsAALtrAACNOgIACx0CAPo6AgBObAAAvDwCAL9sAADNbAAAZ2wAABZtAAA+bQAAd20AAEFtAABpbQAAeG0AAIVtAAAePQIANG0AAC9uAABubgAAMz0AAMtuAADHbgAA0T4CAPltAABubwAAXj8CAI4/AgDGbwAAOXAAAB5wAAAbcAAAlj0AAEpwAAB9cAAAd3AAAK1wAAAlBQIARXEAAGNCAgCccQAAq0MCAChyAAA1cgAAUHIAAAhGAgCAcgAAlXIAADVHAgAUSAIAenMAAItzAACsPgAApXMAALg+AAC4PgAAR3QAAFx0AABxdAAAhXQAAMp0AAAbPwAAJHUAADZMAgA+dQAAkkwCAHB1AACfIQIAEHYAAKFPAgC4TwIARFACAPw/AAAIQAAA9HYAAPNQAgDyUAIAGVECADNRAgAedwAAH3cAAB93AABKdwAAOUAAAIt3AABGQAAAlkAAAB1UAgBOeAAAjHgAAMx4AADjQAAAJlYCAFZ5AACaVgIAxVYCAI95AADreQAAL0EAAEB6AABKegAAT3oAAHxZAgCnWgIAp1oCAO56AAACQgAAq1sCAMZ7AADJewAAJ0IAAIBcAgDSfAAAoEIAAOh8AADjfAAAAH0AAIZfAgBjfQAAAUMAAMd9AAACfgAARX4AADRDAAAoYgIAR2ICAFlDAADZYgIAen8AAD5jAgCVfwAA+n8AAAWAAADaZAIAI2UCAGCAAACoZQIAcIAAAF8zAgDVQwAAsoAAAAOBAAALRAAAPoEAALVaAACnZwIAtWcCAJMzAgCcMwIAAYIAAASCAACejwAAa0QAAJGCAACLggAAnYIAALNSAACxggAAs4IAAL2CAADmggAAPGsCAOWCAAAdgwAAY4MAAK2DAAAjgwAAvYMAAOeDAABXhAAAU4MAAMqDAADMgwAA3IMAADZsAgBrbQIA1WwCACtFAADxhAAA84QAABaFAADKcwIAZIUAACxvAgBdRQAAYUUAALFvAgDScAIAa0UAAFCGAABchgAAZ4YAAGmGAACphgAAiIYAAA6HAADihgAAeYcAACiHAABrhwAAhocAANdFAADhhwAAAYgAAPlFAABgiAAAY4gAAGd2AgDXiAAA3ogAADVGAAD6iAAAuzQAAK54AgBmeQIAvkYAAMdGAACgigAA7YoAAIqLAABVjAAAqHwCAKuMAADBjAAAG40AAHeNAAAvfwIABAgCAMuNAAC8jQAA8I0AAN4IAgDUjgAAOI8AANKFAgDthQIAlJAAAPGQAAARkQAALocCABuRAAA4kgAA15IAANiSAAB8kgAA+ZMAABWUAAD6iwIAi5UAAJVJAAC3lQAAd40CAOZJAADDlgAAsl0AACOXAABFkQIAGpICAG5KAAB2SgAA4JcAAAqUAgCySgAAlpQCAAuYAAALmAAAKZgAALaVAgDimAAAM0sAACmZAACnmQAAwpkAAP6ZAADOSwAAMJsCABKbAABAnAAA/ZwAAM5MAADtTAAAZ50AAM6gAgD4TAAABaECAA6iAgCRogIAu54AAFZNAAD5ngAA/p4AAAWfAAAPnwAAFp8AADufAAAApgIAFQQQAGYAAAA2AAAANAAAABUEEABmAAAANgAAAEYAAAAAAAAAAQAAAAAAAAANADcBBAAOCwMALgAAAAAAAQAAAAEABgA8AAAAQQEAAN4BAAAYAAEBUQQAAAAAAAAAAAsAEAIAAF4AAQBYAAAArwIAACQANwACANEARgAAADwDmAEFADMAAAAAAAAAAwAAAAAAAACmAIcABQAAAAAAagAqAAIAAAAAAAEAIgABAD8A1AEAABQAJwAjAAAAAAACAAkACAAAAOUAAwC7AQAARgAJADUACgAAAIgAAAAAAAIAzAAyADIAAADIAAAAZgEAAAAALQABABsABAAJAIYBBAADAAAAAAAkAAkAAAAAAAMAAgABAC0AAAAhAAMAJgAAAAEAAAAAAAAABAA9AE8ABwALAAAAuwAFAAAAAAAAAFoAYwAAAAAAAAAPAAEAAABqAAAAAQAAAAAABQAzABgAowA6AAYAHAAIAA0AAAAVAAEAAQAaAB8ACQA0AAgAAABIAAIABwAGAAsAAAAQAAAAIgAAAAAAAAAAAAEAAAAXAAMAHQAEAAAALwAAAAAAAwCXAC4ABwAAAE0AAQALADEAAAA7AAAAAAAtAAQAAwBAACIAAgAAAA4AdQAAAAAAYQBOAAAAIgAAAAAAAQAKAAQAAAAAAAYAHgBNAAAAAABDAB8AAgAAAAAAHgAAAAQAAAAAAAAAAAAFAAAAAAACAAAAAAAYAAoAAAAAAAoAAwBfABYABwAAAAoAAAAVAAEAmQAAAAAACgAHAAMADACpABUAQwAAAEIAAQACAAAABAACAAYABwABAAAANgAHAAAADgABAAAAAwACAAAAAwAAAAMATwAAAAMAAgBKAAAAAAA0AAIAAAAAAAgAAAAAAAQAAAAAAAIAAAACAAAADQAAABEAAAAaAAAABQAMAAQAAwAIAAIAXQACAAEALwABAAAAAQAEAAAAAQALAB4APgAAAAQAAAAaAAEACwAHAAAADgAAAAMAAAAAAC4ALAAAAAAABgAEAAwAAAACACUAAQAAABEACAABAAwAAQAMAAcAAQAAAAgAAAAIAE4AAgAOAAAAAAAAAAYAAQAAAAcADwADACoAAAAAAAAADAACAAAAAQARAAwAAgAyAAQABAA8AAAAAQANABQAAAAAAAEAAAAAAAwAAQAcAAAAAwA0ACUAJQAAAAEAAAAAAAAAIwAbABgAAAAOAAMAAAAxAAAAAAADABAAAAArAAAAAAAEAAQACAATAAEACQAIACMAOQAAAAcAAQAiAAAAAgAAAAAAIwAAAAAACgAAAAAAAwABABwAAQADAF0ABgAIACsACgAdAAAACAAIAAUAAAAAAAsADgAAAAEAEAABAAAAAAABAAAABQAAAAAAAAAAAAQAAQA1AAYAAAAIABEAAAABAAAAAAAEAAEAAQAMAAAAAwAdACUABAAXAAgAAAAAAAAAAAAAAAQAAQArAAAAVAAAAAMABAAAAAMAAgAAACkAAAAAAAYACAAAAAIALwAlADsAAAAAAAAAAwAAAAEAEQAMAAIAAgAAABUAAAABAAgAAgABAAAABwAMAAEAAAADADgAHAADAAcAEwAAAAcAAAACAAAAAQAMAAEAEQAAAAAABwAAAAYAIwAHAAAAAgABAAQAAQAAAAIAFwAEAAAAPgAFAAIAAAAHABwAAAAEAAAAAgABAAQAIAADACkACwAAAAAAAgAAABIAAAAVAAAAAwAkAAAADAACACUABwAAAA8AAAAAAAEAAAAEAAEAJwAKABoAEAAAAAIAAAA8AAAAIgAKAAEADAAqABEAAAAFAAAAGgAAAAAAAAAAAAgADAAAAAEAIQAAAAMAEgAOAAwAAwABAAYADQAIAAAAAAALAAIAAAAAAAEAAAAAAAYAAAACAAAAAAAMAAEACQABAAAACQADAAYABwAAAAMABAAAAA0AAAAAAAEACQAEAAEAAAAAAAAAEAAEAAAACwAAAAIAAAAGAAEAAAAFAAAAAgADACUAAAAAAAIABwABAAkAAwAAAAEAAgAAAAAAAgACAAgABQAAAAYAAAAFAAUAAAAAAAAAAAAAAAcABQAAAAEAAAACAAAAAQAAAAYAAAAAAAUAAwACACUAMAAAAAIACQAEAAIAAgACAAAAAABBAAkAAQAAAAYAEQABAAMAAAADAAAAAAAlAAEAPAABABUAAAADABEADwAAAAAACAABAAAAAAAAABsAEgAAAAYAAgBDACUAAwACAAEAAAAAAAIABgAFAAAAAAACACEAAAAAAAEABAAAAAYACwAQAAAACgAAAAEAAgAIAAUAAAAAAAsAAAAFAAQACQADAAQAAAAaAAoACAAAAAAAAQBZAAMAAAAAAAYABwAEAAAAAAABABYAAAAEAAEACgAAAAcAAAAAAAAAAAAAAAAACwAAAAAAAQAAAAEAAAADAAYAAwADAAYABQAEAAUAAAAMAAQAAAADAAEACAAAAAMAAQABAAQAAAAAAAAAAQABAAQAAAAAAAIAFAANAAQAAAAJAAAAAQACAAIAAAAAAAEADAAAAAAAAAAPAAAAAAAUAAEAAgAAAAQABgAAAAIAEwADAAAAAAAAAAAABQABAAAACAAAAAIADAADAAMAAQAEAAQAAAABAAMAAAAAAAEABQABAAEAEAAOAAMAAAAAAAAAAQABAAIAAAAAAAAADwAEACQAAQAAAAEAAQAAABIAAAADAAAABgAAAAAAAAABAAQAAAACAAYADwABAAAAAgAHAAEAAAAMAAAAAAABAAAAAgABAAAAAAAGAAUABQAAAAAABAACAAAAAAABAAAAAQADABIAAQAFABcADgADAAQAAQABAAIAEAAAAAYAEAAEAA0AAAAAAAwAAQAAAAsAGgAAAAEAAAAHAAAABgAQAAEABQACAAEAAAAKAAAAAQAHAAAAAAAAAAAAAAAAAAAAAAAAAAIACgAAAAAABgADAAEAAgAAABAAAAADABsADAAAAAkAAAAGAAAAAQAIAAoABwAAAAAAAAADAB8AAAADADAAAAAAAAIAAQAAAAwAAAAAAAgADgAEAAgAAAABAAEAGwADAAUAAgALAAEAAAALAAAAAAACAAYAAQADABsAAAABAAAABAAAAAAAAQAPAAAAAwAKAAYAAQANAAAABwAMAAQAAAAAAAAAAQAAAAAADAACAAEAAAAEAAAAAAAAAAEAAAAIAAUAAwACAAwAAAAAAAQAAAACAAAAAQAAAAMAAAAIAAEAAQAAAAEACQABAAEABwABAAAAAAABAAMAAQAFAAYAAQABAAUAAAACAAAAAgAKAAEABAALAAUAAAAAAAAAAQACAAQAAQAMAAEAAgAAAAUAAAABAAEAAQAdAAIAAgAKAAEAAQAHAAEAAAAFAAAABwAAAAAAAAAbAAMADwAAAAAAAAABAAEADAABAAMAGgAAAAAAAAAdAAoACgAHAAAAAAAAAAkACQABAAAAAwAPAAkAAQABAAIAAAAGAAAAAQAAAAMAAAAAAAYAAwAIAAQACgADAAIAFAABAA8AAQAGAAMABwAAAAUAAQAHAAAAAAAMAAMAAAABABwAAAAAAAAABgAAAAAAAgAAAAAADAAAAAkACQACAAAAAAAAAAAAAQAAAAAAAwABAAAAJAAEAAUABwAAAAEABwABAAoAAAAPAAIAAgABAAYAIAAIAAAAAAAAAAUAAQAAAAAAAAADAAAAAAAAAAQABgAAAAAAFgADAAAAAAAoAAwAAQABAAAAAAAAAAAABgAIAAUAAAAAAAAAAAADAAoACQABAAEADQADAAEAAAAAAAAAAAABAAkAHAAAAAAAAgACAAUAAAAAAAMAAAABAAEADAABAAwAAAAAAAAAAQAAAAAAAAAFAAMAAgADAAUAAAAKAAEAGwABAAoADAAAABQAAQACAAEAAQACAAYACQABAAQAAQABABAABAAAAAAAAAAAAAMAAwACAAMABgALAAAAAgAAAAUAAAAEAAIABQABAAEAAAAAAA4AAQABAAAAAAATAAAAAwAJAAEABAAIAAYAAAACAAEAAAAFAAAACQAAAAEAAQASAAIAAAAAAAsACwABAAYAAQABAAEAAQAAAAcAAQAMAAAAAwAAAAgADwAGAAAAAwAIAAIAAAAAAAEAAAAIAAAAAAABAAAAAwAAAAAAAAAAAAAAAAAAAAAAAQAAAAgAAwATABoAAQABAAAAAwABAAAADAALAAsAAAAJAAAABQAAAAAABQAAAAUAAAAAAAAACAABAAcAAAAAAAgAAwAAAAAACgABAAAABAAAAA8AAAALAAIAAAAAAAAABAAAAAMAAAADAA8ACwABAAMAAAAHAA4ABwABABIAAgAAAAEACAACABMAAgAFAAAAAAAAAAQAAgAAAAAAAQAHAAQAAAAAAAEAAAAAAAMAAAAIAAQAAQAAAAAAAQAAAAAAAgAEAAEAAQAJAAEAIQAAAAEAAgAIAAMAAAACAAkABAAAAAUAAAACAAEAFAAHAAEABgADAAIAAAARAAMABgAOAAMAAAAAAAEABwADAAIAAAADAAEAAgAAAAAAAQADAAIAAQAEAAIAAAAQAAgAAQAMAAgAAgABAAMAAAACAAMABgACAAAACAACAAEAAAALAAEABQAAABUAAAAQAAIAAQABAAMAAQAMAAIABAAQAAAAAQACAAUAAAABAAAAAQALAAIAAQAEAAAAAwAAAAEABQAAAAEAAQABAAAACgAAAAIAAAAAAAAAAAADAAIAAwAGAAAABQAAAAYAEAAAAA0AAAAKAAEAAAABAAQAAQAAAAAACQAGAAIAAgAAAAEAAAADAAMABAABAAAAAAABAAAAAAAAAAUAGQAHAAEAAAACAAEAAAACAAMABAAAAAAAAAABAAIAAAACAAIAAAAAAAcAAAACABUAAAAAAAAAAgABAAEAAwAAAAQAAAABAAgAAAAAAAEAAAAAAAAAAAAAAAAAAwADAAUAAAAAAAAAAQABAAAACwAAAAcAAAACAAQAAQAAAAQAAwABAAAAAQAGAAIAAQAAAAQAAAABAAAAAQABAAUAAAAAAAYAAAAhAAIAAAAPAAAAAAAAAAIABQABAAQAAAAAAAAAAAAAAAkAAAAFAAkAAQABAAoAAgAAAAEAAAAAAAAAAgAGAAgAAAABAAIABwABAAAABQABAAAAAAAEAAAAAAABAAUAAAACAAkACAAAAAEAAwACAAoAAQAAAAMAAgABAAAAAwAOAAMAAQABAAMAAQAAAAAAAAAAAAEAAAAAAAAAAQAKAAAAAAABAAEAAAABAAAAAAACAAAAAAAAAAEAAAABAAAABAABAAQAAAABAAIABAAEAAYAAAAAAAAAAAAAAAIAAQAAAAAAAQAHAAAAAgAAAAEAAgAAAAEAAAABAAAAAQAAAAAAAAACAAAAAAAfAAAAAAAAAAAAAAADAAAAAgABAAEAAAAFAAEABAAAAAAAAQABAAUAAAACAAAABAADAAAAAAACAAgAAQAGAAAAAAAEAAEAAwALAAgAAQABAAEAAAABAAAAAgABAAsABgABAAAABAAAAAAAAQACAAIAAwACAAMAAAAAAAAAAwACAAcABQAHAAQAAAADAAEABAAAAAMAAwAIAAIAAAAAAAQAAAABAAAABwAAAAIAAwAPAAYAAQABAAAACQAFAAYAAAAAAAAAAAABAAAAAQABAAMADwAEAAEAAAACAAgAAAABAAEAAQAAAAYAAAAAAAEAAAAAAAEABgAAAAAAAQAHAA4AAgAAAAEAAAAAAAYAAAACAAAAAQACAAIAAgAAAAAAAgABAAIABAABAAAABwABAAEAAAAFAAgABAAAAAMAAgAMAAEAAAAAAAAAAgABAAAAAAAEAAgAAQABAAEAAAAGAAAAAwAHAAAABAAAAAEACQAAAAAAAQACAAYAAQAAAAMAAAADAAAAAwAAAAAAAAABAAAAAAAFAAMAAAACAAcAAQABAAgAAAADAAIAAAAAAAAAAAAAAAAAAgAAAAAAAgAEAAIAAAAAAAAAAAAAAAEAAAAGAAMAAwAAAAAABgACAAAAAgAEAAAACgAEAAMAAQAEAAQAAQAJAAEAAQABAAUABAACAAAAAgAKAAEAAwAAAAMAAAABAAEAAAAAAAEAAgAHAAAAAQABAAAAAAAGAAAAAQAAAAIABwAAAAAAAAAEAAAADQAAAAMACwAAAAoAAAAEAAAABQAGAAIAAAABAAIAAQAAAAIAAAAIAAAAAgABAAQAAQACAAYACgACAAAAAgAIAA4AAwAAAAAABgABAAEAAgABAAAAAAAAAAQAAgABAAAABAABAAIABQAAAAEAAQAAAAEACQABAAIABgAAAAAAAQACAAUAAAAAAAgAAQAAAAEACAABAAEAAQAAAAEAAgAAAAEAAQAEAAcADwAAAAIAAAAAAAQAAAABAAIAAAAAAAEABAAAAAAAAgAAAAEAAAAAAAAAAQADAAAACgACAAIAAAAAAAEABAAFAAAAAQAAAAkABAABAAAAAQAAAAIAAQALAAIAAgAAAAIAAgAAAAEAAAAFAAAAFQAIAAAABAACAAUABAACAAAAAAADAAAAAAAKAAMABwACAAEAAAAAAAAAAAACAA8ACAADAAAAAgAAAAEAAAAAAAEADQABAAQAAQARAAIAAAABAAAAAAABAAAABQAAAAEAAQAAAAQAAgAFAAIAAgAAAAIAAgAAAAEAAAAAAAAAAAACAAAAAwACAAMAAwABAAUAAgAAAAMAAQABAAAABAAAAAAABAABAAEADwAAAAAAAAAAAAEAAAAAAAIAAAABAAAAAAAAAAEAAAADAAIAAAAIAAEAAAACAAIAAgABAAQAAAAGABEACAAAAAEAAQADAAUAAgAEAAAAAAACAAAABQAEAAoAAQABAAAAAAACAAMAAAADAAUAAAADAAAAAQADAAIAAAADAAIAAwABAAMAAAABAAAAAAAAAAEADAADAAIAAQADAAAAAgABAAAAAAAAAAAAAQAFAAAAAAABAAMAAAAAAAIAAAAAAAQAAwABAAQAAgABAAAAAgAHAAAABAAAAAUAAQAAAAEABwADAAEAAgACAAEABAADAAAAAgAAAAUAAwADAAAAAQANAAEAAQAAAAcAAQABAAEABQAAAAEAAAAEAAgAAAACAAAAAAABAAAAAAAEAAcAAAACAAAAAAAAAAMAAgAAAAAAAQACAAEAAQAIAAMAAQABAAMAAgACAAAAAAAAAAAAAAABAAAAAQACAAAAAAABAAIAAgAGAAMAAAABAAQAAgAAAAAAAgACAAMAAQABAAAAAQAAAAEAAQAAAAEAAAAAAAAAAAACAAIAAQAGAAIAAAAAAAEAAgABAAAAAAABAAMAAgABAAAABQAAAAAAAAAAAAEAAQABAAQAAwACAAYABQAAAAEAAQAAAAIABgAOAAEAAAADAAEACAABAAQABQABAAEAAwAAAAIABQACAAAABQAAAAAAAAAHAAAAAAAAAAAAAwAAAAIAAAAMAAEAAgABAAkAAgABAAAAAgABAAAABQAAAAEAAAAAAAkABAABAAEAAwADAAIAAAABAAYAAQAJAAYABQAAAAEAAgAEAAEAAQABAAEAAAAAAAEAAAACAAQABgAAAAEAAAABAAEAAQADAAEABgACAAAABQAAAAIABgABAAIAAAAFAAIAAwAAAAAABAAEAAAAAQAAAAIAAgABAAAAAQAAAAQAAgABAAAACgAFAAAABgABAAEAAAAEAAAAAAADAAAAAQAAAAAAAAAEAAIAAQAFAAAABAABAAAAAgAAAAAAAAAAAAAAAQAAAAMAAQAGAAAAAgADAAEAAAACAAAAAAACAAAAAAADAAIAAAAAAAQAAAAAAAAAAgACAAEAAAADAAEABAABAAAAAwAAAAAAAQAAAAIAAgAAAAEAAwAGAAEABAAGAAAAAgAGAAMAAgACAAEAAAADAAAABAABAAsAAAAAAAAAAAABAAAAAQABAAAAAQADAAAAAAABAAAAAAACAAEAAQAHAAAAAQAJAAAAAwADAAAABgAAAAMABQAAAAQAAAABAAUAAQACAAEAAAAAAAgAAgABAAUAAAAAAAEAAAAGAAIAAAAAAAkAAQAFAAEAAgACAAMAAwABAAAAAAAAAAYAAQAKAAEAAAAAAAEACQAEAAAAAAACAAQAAQAAAAEAAgABAAUAAQAAAAEAAQABAAAAAQADAAAAAAAHAAIAAQABAAIAAAAAAAAAAQAAAAEAAwADAAIABQAAAAIAAwABAAAAAwAAAAEAAgAEAAYAAAABAAMAAAABAAIAAwAGAAIAAAABAAAABwACAAEABAADAAIAAgABAAEAAwACAAAAAwADAAAAAQACAAAAAQADAAAAAAAFAAEAAQAAAAQAAAAAAAAAAwACAAAAAAACAAAAAgAAAAEAAAADAAAAAAAGAAAAAAAAAAIABgACAAwAAwADAAYAAgAAAAEAAwAAAAAAAAACAAAAAAABAAEAAAACAAQABQAAAAQAAAAAAAAAAAAAAAAAAQACAAAAAgAAAAAAAQAAAAMAAQAAAAEACAAEAAAAAQAAAAAAAQAAAAcABQACAAAAAAABAAoAAQABAAIABQAAAAAAAQAEAAAAAQAAAAIABQACAAQAAAABAAEAAQAAAAMAAQADAAIAAQABAAEAAwAAAAAAAQABAAEAAQAEAAIABAAAAAAAAAABAAUAAQAFAAUAAQABAAEAAAAAAAcAAwAAAAAAAAABAAIAAQAAAAEAAAACAAQAAwABAAEAAwAAAAIAAAABAAQAAQAAAAEAAQABAAQAAwABAAgAAQAAAAMAAQABAAAAAwADAAAAAQAAAAEAAQAAAAIAAwABAAIAAAAAAAEAAgAAAAYAAAAAAAIAAQAAAAEAAAABAAAAAQABAAAAAQAAAAAAAgABAAEABgAAAAAAAwABAAIAAQAEAAAAAQABAAQAAQAAAAQAAQABAAIAAgADAAEAAQABAAEAAwAAAAIAAQAFAAAAAgAAAAQADgAAAAQAAwAFAAAABAACAAAAAwAAAAEAAQABAAIAAAACAAAAAQACAAEAAAAAAAAAAQAAAAAAAgAAAAIAAAAAAAAAAQACAAAAAAAAAAEAAQACAAIAAAACAAEAAQABAAAAAAAAAAAAAAABAAIAAQAAAAAAAQACAAAABQADAAAAAQABAAAAAQACAAIAAgAEAAMABAABAAAAAAAAAAAAAAABAAAAAQAMAAIAAQACAAEABQAAAAIAAgAAAAQAAwAEAAAAAQAAAAIAAAABAAYAAgACAAEAAAAAAAEAAwAEAAIAAgABAAIAAAABAAMAAAACAAAAAwABAAQAAQACAAEAAwABAAAAAQAAAAEAAgADAAAABQAAAAMAAQABAAUAAQABAAEAAQABAAAAAAABAAEAAgABAAAABQACAAIAAAABAAAABQADAAEAAQABAAIAAQAAAAEAAAAAAAEAAAAEAAEAAQABAAAAAAABAAEAAQAAAAEAAgAAAAEAAAACAAAABgAAAAEAAAABAAAAAAADAAEAAQAAAAAAAwACAAAAAAACAAEAAQAAAAAAAgABAAEAAAAAAAAAAAABAAIAAQAAAAAAAwACAAIAAQAAAAEAAQABAAAAAQADAAIAAQAAAAAAAQAAAAIAAAAAAAAAAgACAAMAAQAJAAIABgAAAAAAAQAAAAEAAQAAAAEAAwACAAAAAAAAAAAAAgADAAAABAAAAAEAAAACAAEAAQAHAAAAAAAAAAAAAAAAAAMAAAACAAQAAgAAAAIAAAAAAAQABwADAAAAAAAAAAIAAQAAAAAAAAAAAAIAAwAEAAIAAAAEAAMAAAAEAAEAAAADAAIAAgACAAEABAAFAAQAAAAAAAIAAAAAAAAAAQAJAAMAAAADAAIAAQACAAAAAQACAAAAAQABAAAAAQABAAAAAQAAAAAAAQABAAEABAACAAEABQAAAAYABAAAAAEAAAABAAAAAgAAAAMAAQAEAAEAAQACAAMAAQAFAAUAAQABAAIAAQADAAIAAAAAAAoAAAABAAIABAAAAAEAAAABAAIAAQAAAAUABAADAAIAAgABAAEAAAACAAEAAgAAAAAAAQABAAIAAQACAAAAAQAHAAAAAQAAAAEAAAABAAIAAAADAAEAAgAAAAQAAgAAAAMAAAACAAEAAQACAAAAAQABAAQAAgAAAAAABAAAAAEAAwAAAAIAAQAAAAAAAgAAAAEAAAAAAAEAAAACAAIAAwABAAIAAAAAAAMAAAAAAAAAAAAAAAQAAAABAAEAAgABAAIAAAABAAEAAAAAAB/UAQAHEQEAMNQBABgRAQCg/AAAyAsCAN8kAAA8AwEAVf4AAOEOAQBy/QAAbw0DAFD9AAAJDQMAxdUBAJUSAQCo1gEAdhMBADkzAABuBwMAfO4BAGEVAQCQBwEAmhABAIkyAADqBQEAmtcBAGgUAQC31gEAhRMBAGvXAQA5FAEAcfwAAGULAgCgHQAAygABAEf/AADwDwEAEP0AAK0MAgDeMwAAKQkDAHchAAAPAgQASfEBAB0WAQBGHQAAoAABAFszAADoBwIADDMAALUGBAB/+wAA6QkBAPTVAQDEEgEA5tYBALQTAQDgMwAALwkCAKz/AABVEAEAQh0AAJwAAQCb/wAARBABAIXXAQBTFAEA2QIAAEkAAgBIIQAAsQEBAIL+AAAWDwIAPNcBAAoUAQCoHQAA0gABALr+AABZDwEAK9cBAPkTAQC+MwAA3ggCAEL8AAD7CgIAfS8AANYDAQBO1AEANhEBAJIkAACnAgMA9tYBAMQTAQBOLwAApwMBALkzAADUCAIAJDIAACcFAwDb/wAAexABAP/XAQDLFAEABDIAALQEAwCSIAAAYAEBADXxAQAJFgEAhjEAAJEEAQAw7gEANBUBAObMAQDUEAEAmTMAAIAIAgAF/QAAlwwCAEQvAACdAwEAMy8AAIwDAQCv/gAATg8BAHAhAAABAgEAde4BAFsVAQCCMwAASwgCAJXuAQB3FQEApv4AAEUPAQAkLwAAfQMBAFPXAQAhFAEAQx0AAJ0AAQDC/gAAYQ8BADz9AAAFDQIAatYBADoTAQAM/wAAtQ8BAGzUAQBTEQEAodcBAG8UAQBiMwAA9gcDAH3/AAAmEAEAgyQAAH4CBACn/AAA1gsCABn8AACpCgIAmf0AAN4NAwAO1gEA3hIBAAcvAABgAwEAOeABANUUAQDuMgAAcwYBAKgHAQCyEAEAdv8AAB8QAQBdMwAA7AcCAEjWAQAYEwEA2NcBAKQUAQDZ+wAAJgoBAPMBAAA8AAIAdtUBAEYSAQDD1QEAkxIBAJbVAQBmEgEAQP8AAOkPAQBHLwAAoAMBAFHUAQA5EQEAxv0AAGUOAwCx1wEAfxQBAKEdAADLAAEA7tcBALoUAQAG1wEA1BMBAIjWAQBYEwEAWjIAAKAFAgDOMgAAUAYCAKH8AADKCwIAPtYBAA4TAQCCBwEAjRABADv9AAADDQIA/vwAAIkMAgCH1AEAbhEBAB//AADIDwEAcTIAAMAFAgBMMwAAtQcFABIhAACHAQEA8P0AAGsOAwB61wEASBQBAM8yAABSBgMA9KcAAIwJAQBS7gEARRUBAJbUAQB9EQEAnNQBAIMRAQA6/wAA4w8BACDyAQBJFgEAwf4AAGAPAQA61gEAChMBAOz8AABiDAIALvEBAAIWAgBJ/AAACQsCAAP8AAB7CgMA6vwAAF4MAgC4MwAA0ggCALovAAATBAEAyNYBAJYTAQA+/wAA5w8BAMr+AABpDwEAcfsAANsJAQBf1QEALxIBAMwBAAA2AAIASS8AAKIDAQBt1AEAVBEBAKMzAACXCAMAuSQAABYDAQCxMgAAEgYCAHUkAABOAgMAoP0AAPMNAwCf/wAASBABALYdAADgAAEAj/sAAPkJAQCF1AEAbBEBAIv9AAC6DQMAmgcBAKQQAQARIAAAHgEBAB/VAQD2EQEArwcBALkQAQCbIAAAaQEBALDWAQB+EwEAgjIAAOMFAQC5AAAADQABACLuAQAqFQEAKv0AAOEMAgB6MwAAOQgCAJn8AAC3CwMAef0AAIQNAwBnMQAAcgQBAMLVAQCSEgEAvtYBAIwTAQAj8QEA5hUDALMkAAAKAwMAtdcBAIMUAQC2/AAA9AsCAKvUAQCLEQEAT+ABAOsUAQCzAAAABwABAEv/AAD0DwEAr9UBAH8SAQBvMQAAegQBAPf+AACaDwMA6DMAAD8JAgDF/AAAEgwCAHwgAABLAQEAOB0AAJMAAQCJ1wEAVxQBAEj/AADxDwEAdtYBAEYTAQB51wEARxQBAO/8AABoDAIA0tQBAK4RAQCKMgAA6wUBAKX/AABOEAEALe4BADEVAQDE1QEAlBIBAN/UAQC7EQEA9DMAAGIJAwDq1wEAthQBAEgzAACmBwQAqyQAAPICAwDZMwAAHgkDAOcyAABsBgEA1SQAADIDAQDAJAAAHQMBAK78AADkCwIAISEAAJMBAwBa/QAAJw0DAOnMAQDXEAEAFNUBAO0RAQCG/wAALxABABf8AAClCgIAwf0AAFYOAwARMwAAyQYEAHL8AABnCwIAKPIBAFEWAQBq/QAAVw0DAHj8AABzCwIAPjMAAIMHBABL1wEAGRQBABvxAQDOFQMAt9cBAIUUAQBY1AEAPxEBAPr7AABoCgMAs/4AAFIPAQBf+wAAyQkBAMkvAAAiBAEAxv8AAGwQAQBV1QEAJRIBADH9AADvDAIAOTEAAEQEAQDNLwAAJgQBAJcxAACfBAEAz/wAACYMAgAC+wAAmAkCAG78AABfCwIA3fsAACoKAgCX1wEAZRQBANfWAQClEwEAYP8AAAkQAQDJ1wEAlxQBAIYgAABVAQEABiAAABkBAQBF1AEALREBAH7XAQBMFAEAG9QBAAMRAQAKLwAAYwMBAEr+AADUDgIAav4AAPUOAQBl1wEAMxQBAH39AACQDQMAHfIBAEYWAQC71gEAiRMBAKT9AAD/DQMANP0AAPUMAgAS/wAAuw8BAMYvAAAfBAEABfsAAKAJAgAb7gEAJBUBAOEkAAA+AwEAfzMAAEMIBACA1wEAThQBAGYzAAACCAMA3tQBALoRAQDSJAAALwMBAJrVAQBqEgEARtUBABoSAQBe/gAA6g4BAKMdAADNAAEAJTIAACoFAwAd/QAAxwwCALrWAQCIEwEAr9QBAI4RAQAVLwAAbgMBABPuAQAcFQEA4NQBALwRAQA6MAAAMwQBAJz+AAA7DwEAZyQAADMCAQDILwAAIQQBANnUAQC1EQEAMPwAANcKAgB57gEAXhUBAIz9AAC9DQMAzTIAAE0GAwBU+wAAvgkBANoCAABLAAIA6NQBAMQRAQCQ/wAAORABAJAzAABqCAIAbtUBAD4SAQDdzAEAyxABAKjuAQCEFQEAUPwAABcLAgCxMwAAxAgCAM7/AAByEAEAK/8AANQPAQBI8QEAHBYBAKz9AAAXDgMAd/wAAHELAgDyMgAAdwYBAFYhAADGAQMAQfIBAGgWAwBZ1wEAJxQBAGrUAQBREQEALdcBAPsTAQAC1wEA0BMBAHggAABHAQEAqf8AAFIQAQA+8QEAEhYBAK7UAQCNEQEAbP0AAF0NAwCsBwEAthABAF2rAACQCQEAMDMAAEkHAwCg/gAAPw8BAPT7AQCGFgEAUe4BAEQVAQA11gEABRMBACb8AADDCgIAIC8AAHkDAQCa/AAAugsDALgdAADiAAEAU/0AABINAwA5/wAA4g8BAGvgAQAHFQEAYv4AAO4OAQAc1AEABBEBALr8AAD8CwIAiNQBAG8RAQBnHQAAwAABAEH+AADMDgEAtPwAAPALAgCc1QEAbBIBAL38AAACDAIAH/0AAMsMAgDS1QEAohIBAAfWAQDXEgEAl/8AAEAQAQBsIQAA/QEBABr9AADBDAIAVuABAPIUAQC3LwAAEAQBAJ0kAADIAgMA+jMAAHQJAwBy7gEAWRUBAOT/AACCEAEA09UBAKMSAQAPMwAAwQYEAI3UAQB0EQEAhu4BAGkVAQAZMgAA/gQEADnXAQAHFAEATNQBADQRAQDL/wAAbxABAHkkAABaAgMA1swBAMQQAQAI8QEApxUCAHDVAQBAEgEA8cwBAN8QAQBpqwAAkwkBAHP/AAAcEAEAQDEAAEsEAQBjMwAA+QcDAFUxAABgBAEAPuABANoUAQC7HQAA5QABAAjXAQDWEwEAvTMAANwIAgBm1gEANhMBAGb7AADQCQEA0jMAAAsJAwAKIAAAHQEBABLxAQCzFQMANx0AAJIAAQBPMQAAWgQBAC/8AADVCgIAL/IBAFgWAQBdLwAAtgMBAL/+AABeDwEAcS8AAMoDAQAxHQAAjAABAGsvAADEAwEAidQBAHARAQDM/AAAIAwCADoxAABFBAEAdtQBAF0RAQDK/AAAHAwCAK77AAAaCgEArNUBAHwSAQD0AwAAawABADXgAQDRFAEArDIAAA0GAQD61AEA1hEBABvVAQDzEQEAHv8AAMcPAQAl/wAAzg8BAKbVAQB2EgEAAyAAABYBAQDu/wAAixABAHfXAQBFFAEAVf8AAP4PAQDiJAAAPwMBAILUAQBpEQEAfwEAACIAAQDmJAAAQwMBAGfuAQBPFQEAeyAAAEoBAQBqMgAAtgUBAG/WAQA/EwEARTMAAJsHAwBWHQAArwABAOT7AAAyCgEAfNQBAGMRAQAN1AEA9RABAH38AAB9CwIAXPsAAMYJAQBCLwAAmwMBAFbWAQAmEwEAPR0AAJcAAQBX/gAA4w4BADT+AAC/DgEAmCQAALkCAwDt/wAAihABAEbUAQAuEQEAxQEAACYAAwDY+wAAJQoBAKfVAQB3EgEA+fsAAGUKAwDY1gEAphMBANT/AAB2EAEAzf4AAGwPAQCVMwAAeAgCAHrWAQBKEwEAStcBABgUAQDz1gEAwRMBAGoxAAB1BAEAO/wAAO0KAgD1zAEA4xABAC7/AADXDwEAif4AACQPAgCv+wAAGwoBAMnVAQCZEgEAZjIAALIFAQCHIAAAVgEBAL/UAQCcEQEA4tUBALISAQDj1wEArxQBAGD7AADKCQEAFPIBAD0WAQDh1wEArRQBAN77AAAsCgEA4v8AAH8QAQAvIgAAJwICAFLgAQDuFAEAutcBAIgUAQBo1wEANhQBALMvAAAMBAEAgu4BAGUVAQCD1wEAURQBAKb/AABPEAEArCQAAPUCAwCS/AAApwsCAHAkAABDAgIAo+4BAIAVAQCL/wAANBABALr9AABBDgMADzIAANYEBAAY8QEAxRUDADPxAQAHFgEAjNcBAFoUAQBaMwAA5gcCAETgAQDgFAEAwNcBAI4UAQAg1gEA8BIBACj/AADRDwEAWB0AALEAAQC8/QAARw4DADHgAQDNFAEA8zMAAF8JAwCOMwAAZggCAOIyAABnBgEAfSwAAFUDAQA17gEAOBUBAGnVAQA5EgEAXzIAAKoFAgDyMwAAXAkDAE8dAACoAAEAOv0AAAENAgCcBwEAphABAAbUAQDuEAEA1vsAACMKAQBvLQAAVgMBAAQvAABdAwEAFdQBAP0QAQDx/gAAkA8BACvUAQATEQEA9vsAAFwKAwCl/AAA0gsCAAHXAQDPEwEAE/8AALwPAQCyHQAA3AABAKwzAACxCAMAadQBAFARAQCEMgAA5QUBAOL7AAAwCgEAIjMAABYHAwBp/gAA9A4BAC4yAABFBQMA8QMAAGkAAQB2MwAALwgCAF7WAQAuEwEAliAAAGQBAQCA/wAAKRABAJjUAQB/EQEAQfEBABUWAQCV/QAA0g0DAIPWAQBTEwEA+TMAAHEJAwAHMgAAvQQDANzWAQCqEwEAVtUBACYSAQCb/gAAOg8BAEwdAACmAAEAe/wAAHkLAgAlIAAAIgECAF79AAAzDQMAkP4AAC8PAQDaJAAANwMBAAT7AACdCQMA1DMAABAJAgDbMwAAIwkCAAvXAQDZEwEAjjIAAO8FAQCV1wEAYxQBAIgkAACSAgIAkNYBAGATAQCcMQAApAQBAOP8AABQDAIAQtQBACoRAQCC1QEAUhIBAP7XAQDKFAEARfwAAAELAgB4/wAAIRABAG39AABgDQMAJCEAAJgBAQBo/wAAERABAOXWAQCzEwEAvtUBAI4SAQCqJAAA7wIDAN0OAAB+AAIAa9QBAFIRAQDx1QEAwRIBANnMAQDHEAEABdYBANUSAQCo+wAAFAoBAM//AABzEAEACy8AAGQDAQDy/QAAcQ4EAA/WAQDfEgEA+tYBAMgTAQA+1QEAFBIBAOf+AACGDwEAdfwAAG0LAgDSAwAAYQABACTVAQD7EQEAmNYBAGgTAQD9HwAADwECAB/XAQDtEwEA+zMAAHcJAwCp7gEAhRUBAHszAAA7CAIALtQBABYRAQAtIQAAmwEBAEozAACsBwYAnNcBAGoUAQCi/AAAzAsCAFPgAQDvFAEAe9UBAEsSAQAR/QAArwwCALYzAADOCAIAeSAAAEgBAQCjBwEArRABAHkyAADQBQIAcf8AABoQAQA7/wAA5A8BAP/UAQDbEQEAcO4BAFcVAQC+AAAAFQADAHv/AAAkEAEAp/4AAEYPAQCBJAAAdgIEAJ7VAQBuEgEAp/sAABMKAQDd1AEAuREBACn7AAC3CQEAbPsAANYJAQAqMwAALQcDAF/UAQBGEQEA6PwAAFoMAgBIHQAAogABAF7gAQD6FAEAbjEAAHkEAQAh1gEA8RIBADMzAABVBwQA0TMAAAkJAgA88QEAEBYBAC0yAABCBQMAH/wAALUKAgCVLwAA7gMBAOTWAQCyEwEAx9cBAJUUAQC2MgAAHAYCABgyAAD6BAQA+f0AAI0OAwClHQAAzwABAAozAACvBgMActUBAEISAQBgLwAAuQMBAGgzAAAICAMAMu4BADYVAQDlMgAAagYBAJXWAQBlEwEAOe4BADsVAQDbzAEAyRABADH+AAC8DgEAG9YBAOsSAQAN7gEAFhUBAI0zAABkCAIANv0AAPkMAgBE/AAA/woCAA3XAQDbEwEAfDIAANYFBQDz1AEAzxEBAB0vAAB2AwEAO/IBAGQWAQAyLwAAiwMBABb/AAC/DwEAhDMAAE8IAgA38gEAYBYBABDUAQD4EAEALjMAAD8HBgC01QEAhBIBAGIhAADmAQMArdUBAH0SAQDR1AEArREBAELxAQAWFgEAjjEAAJkEAQD6MgAAfwYBANsyAABgBgEA9/0AAIUOBAAi1wEA8BMBAHCnAACICQEAv9cBAI0UAQBEMQAATwQBAHgyAADOBQIAGf8AAMIPAQBX/AAAJQsCAL0vAAAWBAEAESEAAIYBAQCOBwEAmBABAM0zAAABCQIAmiQAAL8CAwBj1QEAMxIBAGf/AAAQEAEAmC8AAPEDAQAI1QEA4xEBAMXXAQCTFAEA2vsAACcKAQCd1QEAbRIBAFcdAACwAAEAuAIAAEYAAQCz/QAALA4DAEP8AAD9CgIAKdUBAAASAQC4MgAAIAYCAG3VAQA9EgEAQNcBAA4UAQDH1QEAlxIBALnuAQCUFQEAtNYBAIITAQAB1gEA0RIBAI4gAABdAQEAn9YBAG8TAQB7/gAACQ8CAIUDAABcAAMAOdQBACERAQAwLwAAiQMBAE/8AAAVCwIAkjEAAJoEAQBC1QEAFxIBAFD/AAD5DwEArC8AAAUEAQB01gEARBMBADwhAACpAQEAfiAAAE0BAQB0LwAAzQMBAJrUAQCBEQEAN/4AAMIOAQBSMQAAXQQBAG3/AAAWEAEAqf0AAA4OAwC/MgAALgYCAHPUAQBaEQEAI/sAALEJAQAw1wEA/hMBAH3XAQBLFAEAmP8AAEEQAQC0HQAA3gABAFrgAQD2FAEAUtQBADoRAQA7/gAAxg4BAE3+AADaDgEArSQAAPgCAwDjzAEA0RABAB8zAAAIBwQAtf4AAFQPAQBo/QAAUQ0DALUkAAAQAwMAa9UBADsSAQB8IQAAGwIBAOTXAQCwFAEAhtUBAFYSAQC9AAAAEgADALXVAQCFEgEAlNYBAGQTAQBV1wEAIxQBANrVAQCqEgEAZ9YBADcTAQBv7gEAVhUBANgyAABdBgEAdQYAAHAAAgCC/wAAKxABADz/AADlDwEAoSQAANQCAwDI1wEAlhQBANjMAQDGEAEABdQBAO0QAQAt8gEAVhYBAGn/AAASEAEAkQcBAJsQAQBU1AEAPBEBABbXAQDkEwEAEf4AAK8OAQBp+wAA0wkBADD/AADZDwEAavEBACsWAgCv1wEAfRQBAJnXAQBnFAEAMjMAAE8HBgA1HQAAkAABAG37AADXCQEA1f8AAHcQAQA5MgAAZgUDACH8AAC5CgIAMB0AAIsAAQBCMQAATQQBAHwvAADVAwEAYdQBAEgRAQBT1gEAIxMBADb+AADBDgEABvsAAKIJAgAFMgAAtwQDAHn7AADjCQEAAC8AAFkDAQBY1gEAKBMBAM/XAQCbFAEAfSAAAEwBAQAB8gEANRYCABwyAAAKBQQAuf0AAD4OAwDs1gEAuhMBANX8AAAyDAIAXNUBACwSAQBB1QEAFhIBAJ4yAAD/BQEAv9UBAI8SAQD3+wEAiRYBABfyAQBAFgEAGdcBAOcTAQCnHQAA0QABALkyAAAiBgIAWyEAANUBAwAl/AAAwQoCAPzUAQDYEQEAk/0AAMwNAwBpJAAANQICAL8fAADwAAIApdQBAIcRAQCcLwAA9QMBAEzxAQAiFgIA9swBAOQQAQCpMwAAqQgCAAP7AACaCQMAXtcBACwUAQBD1QEAGBIBADvWAQALEwEA88wBAOEQAQAU1AEA/BABAN/WAQCtEwEAfP0AAI0NAwCoMgAACQYBAM8zAAAFCQIA/P4AAKgPAgAg1AEACBEBAEzWAQAcEwEAT/EBACkWAgDE1gEAkhMBAAfUAQDvEAEAqtQBAIoRAQCV1QEAZRIBAHT7AADeCQEAmyQAAMICAwDb1QEAqxIBADgwAAAxBAEAlwcBAKEQAQCJMQAAlAQBAAchAAB8AQEAXyAAAEABAQA44AEA1BQBAHcvAADQAwEAkPEBADEWAgCSMgAA8wUBAGchAADxAQQAaNQBAE8RAQD/1gEAzRMBAJIvAADrAwEADjMAAL0GBACfHQAAyQABAKIzAACUCAMApf4AAEQPAQAbMgAABgUEAPAyAAB1BgEA2dcBAKUUAQAQ/gAArg4BAKP+AABCDwEAaiEAAPgBAgCkMwAAmggDAHYqAABRAwMAj+4BAHEVAQAP7gEAGBUBACEvAAB6AwEAAdUBAN0RAQAQ1gEA4BIBAOAyAABlBgEAmSQAALwCAwAM/QAApQwCAD3xAQARFgEARjIAAIkFAQAY7gEAIRUBAC78AADTCgIAdCQAAEsCAwDkzAEA0hABAErgAQDmFAEAMvIBAFsWAQCD+wAA7QkBAPHXAQC9FAEAMjIAAFEFAwAe1wEA7BMBAJ4kAADLAgMAszMAAMgIAgAi+wAAsAkBABbVAQDuEQEAgtcBAFAUAQDw1wEAvBQBAIkHAQCTEAEAdf8AAB4QAQBzMgAAxAUCAJkxAAChBAEAJS8AAH4DAQAU+wAApgkCAEcyAACKBQEAiPwAAJMLAgCX1AEAfhEBAIXWAQBVEwEAnB0AAMYAAQBD/wAA7A8BAHz+AAALDwIACS8AAGIDAQAZ1AEAAREBAL/WAQCNEwEA1f4AAHQPAQAQ/AAAlwoCAPP9AAB1DgQAY/4AAO8OAQARMgAA3gQEALUdAADfAAEAjf4AACwPAQCb1AEAghEBAIf/AAAwEAEA+acAAI4JAQA9LwAAlgMBAFjXAQAmFAEAVf0AABgNAwBkMwAA/AcDACbUAQAOEQEA7P8AAIkQAQBe/AAAMwsDAJcvAADwAwEANv8AAN8PAQDs1QEAvBIBAHn8AAB1CwIA5fsAADMKAQAtIgAAJAIDALAkAAABAwMAIvEBAOMVAwAu1QEABRIBABHVAQDqEQEA9dUBAMUSAQCxLwAACgQBAKr/AABTEAEAlC8AAO0DAQA78QEADxYBAFPVAQAjEgEAMP4AALoOAgCqAAAAAwABAN4kAAA7AwEAdSoAAE8DAgB3+wAA4QkBAOn8AABcDAIAby8AAMgDAQC9MgAAKgYCAFovAACzAwEAMx0AAI4AAQCPBwEAmRABALAdAADaAAEA0PwAACgMAgCN7gEAbxUBAG4kAAA/AgIAlv4AADUPAQCN/wAANhABAAX/AACuDwEAVv4AAOIOAQB0MQAAfwQBAFcxAABiBAEA9fsAAFkKAwCL7gEAbRUBAHIxAAB9BAEAA/8AAKwPAQAA1AEA6BABABYvAABvAwEAMdcBAP8TAQCR/gAAMA8BAIUgAABUAQEA9TMAAGUJAwCdMwAAiAgCABLyAQA6FgEAZtQBAE0RAQAc/AAArwoCAD8hAACsAQEAOfwAAOkKAgAz1wEAARQBAOHMAQDPEAEAXDMAAOoHAgAiIQAAlgECAPGnAACJCQEAGtYBAOoSAQD5MgAAfgYBAJPUAQB6EQEAnvsAAAgKAQBd/AAAMQsCAGPgAQD/FAEAk/4AADIPAQCP1QEAXxIBAJ3XAQBrFAEARTEAAFAEAQBH/gAA0A4BAJ4dAADIAAEAGdYBAOkSAQBhIQAA5AECAGz/AAAVEAEAwNUBAJASAQBe+wAAyAkBAEbXAQAUFAEAGf0AAL8MAgDjAgAAVgABAPT8AAB0DAMAYeABAP0UAQCp/gAASA8BAN3XAQCpFAEAAzMAAJQGAwDdMgAAYgYBAITXAQBSFAEAZDIAALAFAQCX1QEAZxIBANTUAQCwEQEAeiEAABYCAgCQ1wEAXhQBACL/AADLDwEAHSEAAJABAQAL1AEA8xABAFfgAQDzFAEAePsAAOIJAQBm4AEAAhUBAGjWAQA4EwEAX/4AAOsOAQBw1wEAPhQBAAAwAAAvBAEA79UBAL8SAQD9+wAAbwoBANzVAQCsEgEAcNQBAFcRAQBg/AAAOQsDAN78AABEDAIA5/sAADUKAQCgLwAA+QMBAJwkAADFAgMAVNYBACQTAQCHBwEAkRABAAIzAACPBgUA2fwAADoMAgA51gEACRMBAFL9AAAPDQMAstYBAIATAQBxMwAAIwgDAI7+AAAtDwEASOABAOQUAQC0/wAAXRABAPzWAQDKEwEAoDMAAI8IAwCq/AAA3AsCADH8AADZCgIAkdUBAGESAQCR1AEAeBEBAFP7AAC9CQEA5tcBALIUAQBoHQAAwQABAOICAABVAAEA5AIAAFcAAQB61AEAYREBAH3VAQBNEgEAMvEBAAYWAQD21QEAxhIBAHzXAQBKFAEAXOABAPgUAQAT8gEAOxYCAI//AAA4EAEAf/4AABEPAgAO7gEAFxUBAOjWAQC2EwEA/jMAAIAJAwD2/AAAeQwCADf9AAD7DAIA8gEAADoAAgDELwAAHQQBALckAAAUAwEA/BAAAIcAAQCw/QAAIw4DADbUAQAeEQEAh+4BAGoVAQD2+wEAiBYBAFQvAACtAwEAt/wAAPYLAgCFMwAAUQgCAMPWAQCREwEACvwAAIsKAgCTMgAA9AUBACbXAQD0EwEA1jIAAFsGAQAZMwAA7gYGALH7AAAeCgIATfEBACQWAgBhHQAAugABAErWAQAaEwEA2tcBAKYUAQAX+wAArAkCAAbxAQCjFQIAlyAAAGUBAQCj/AAAzgsCAMT+AABjDwEA4zMAADUJAgBX1gEAJxMBAI/UAQB2EQEAKvwAAMsKAgBb1QEAKxIBAIrWAQBaEwEAUzEAAF4EAQA7LwAAlAMBALb+AABVDwEApAcBAK4QAQAc/QAAxQwCAP8wAAA6BAIAPf8AAOYPAQCAIAAATwEBAGMhAADpAQIAri8AAAcEAQBZ/AAAKQsCALL/AABbEAEANzIAAGAFAwBlJAAAMQIBAFn/AAACEAEAaPwAAFELAwC5BwEAwhABAAPWAQDTEgEAXP4AAOgOAQCbLwAA9AMBANczAAAYCQIACf0AAJ8MAgBB1gEAERMBAAD9AACNDAIA+NYBAMYTAQCUJAAArQIDAIAvAADZAwEAZ/sAANEJAQChMgAAAgYBAIwyAADtBQEAnDAAADYEAgD71wEAxxQBAHv9AACKDQMAYfsAAMsJAQAQ/wAAuQ8BAA/8AACVCgIAOPEBAAwWAQCKBwEAlBABAJgHAQCiEAEAq/4AAEoPAQAp7gEALRUBACryAQBTFgEASy8AAKQDAQDW1wEAohQBAEz+AADYDgIAnQcBAKcQAQA1MQAAQAQBAOXUAQDBEQEAl/sAAAEKAQAF1wEA0xMBAPH8AABsDAIAWe4BAEgVAQBJHQAAowABAKX7AAAQCgIAGNYBAOgSAQDu1gEAvBMBAKwdAADWAAEAuPwAAPgLAgB8+wAA5gkBAH8xAACKBAEA4NcBAKwUAQAzIAAAKAECAFAxAABbBAEAl/wAALELAwDU/gAAcw8BALwyAAAoBgIAMwEAABoAAgBF8gEAdBYDAD4hAACrAQEAjdcBAFsUAQAbLwAAdAMBANf/AAB5EAEA8dQBAM0RAQAM1AEA9BABANL+AABxDwEAgf0AAJwNAwAGMwAAnwYDAIv8AACZCwIAUDMAAMUHAwC0MwAAyggCABQyAADqBAQAwjIAADQGAgDKJAAAJwMBAJj9AADbDQMA+DMAAG4JAwB01QEARBIBAPfUAQDTEQEAhS8AAN4DAQDT/AAALgwCAIT7AADuCQEA4fsAAC8KAQAC1QEA3hEBAO3+AACMDwEAcjMAACYIAgBlHQAAvgABACzuAQAwFQEANf4AAMAOAQBd+wAAxwkBAHvuAQBgFQEASSEAALIBAQCz1AEAkhEBAC8yAABIBQMAxf0AAGIOAwA2LwAAjwMBAHT8AABrCwIApdUBAHUSAQBZ+wAAwwkBAEfWAQAXEwEAZv0AAEsNAwCi1QEAchIBAITUAQBrEQEAlfsAAP8JAQAS1QEA6xEBAIAkAAByAgQApzIAAAgGAQC41gEAhhMBAJQxAACcBAEAEdcBAN8TAQAXMwAA5QYFAJ2mAACHCQEAyP4AAGcPAQBA1gEAEBMBAB3xAQDUFQMAzfwAACIMAgAy1wEAABQBAEHgAQDdFAEAodUBAHESAQCzHQAA3QABAN7+AAB9DwEA59YBALUTAQAcLwAAdQMBAOQzAAA3CQIAbtYBAD4TAQCIBwEAkhABAMfWAQCVEwEAUv8AAPsPAQACIQAAcwEBAKn8AADaCwIA/DIAAIEGAQA5LwAAkgMBANn+AAB4DwEAjfsAAPcJAQAg/QAAzQwCAGruAQBSFQEAaR0AAMIAAQBh1QEAMRIBAE/uAQBDFQEA9NcBAMAUAQB2/gAA/w4CAJsHAQClEAEAKPsAALYJAQAFIAAAGAEBACTWAQD0EgEAd9UBAEcSAQDc/gAAew8BAFvXAQApFAEAudQBAJgRAQC6MwAA1ggCABfVAQDvEQEAHDMAAP4GAwDuMwAAUAkDAD8xAABKBAEAaTMAAAsIAwD4+wAAYgoDAO7MAQDcEAEAkNUBAGASAQBJ4AEA5RQBAMHVAQCREgEAJtUBAP0RAQCe1wEAbBQBAKXXAQBzFAEA0jIAAFcGAQAs1wEA+hMBAHL+AAD7DgIAhNYBAFQTAQDkJAAAQQMBADPWAQADEwEAkCAAAF4BAQDX1AEAsxEBAEvVAQAcEgEAUNUBACESAQA84AEA2BQBAMUzAADuCAIAktYBAGITAQCIMgAA6QUBABb+AAC0DgEAVNcBACIUAQDv1wEAuxQBABH/AAC6DwEAyTMAAPkIAgCo/gAARw8BADTUAQAcEQEAg9QBAGoRAQAq7gEALhUBAAn/AACyDwEAHP8AAMUPAQDB1gEAjxMBAOP7AAAxCgEApNYBAHQTAQBcIQAA2AEDAGXUAQBMEQEAK/wAAM0KAgCe1AEAhBEBADPgAQDPFAEAizIAAOwFAQB4MwAAMwgDAGj+AADzDgEACzMAALIGAwBj1AEAShEBAArUAQDyEAEAHPIBAEUWAQCu7gEAiRUBAFHWAQAhEwEAeB0AAMQAAQDdMwAAJwkCADkwAAAyBAEAAf8AAKoPAQA77gEAPBUBAAzWAQDcEgEAyNQBAKQRAQCV/wAAPhABALPVAQCDEgEAuS8AABIEAQAT1AEA+xABANTXAQCgFAEAtCQAAA0DAwCK1AEAcREBADLVAQAJEgEAe9cBAEkUAQBC4AEA3hQBAHnUAQBgEQEApP4AAEMPAQDQ1wEAnBQBAHcyAADMBQIA78wBAN0QAQBQ8gEAgBYBAKHuAQB+FQEAt/4AAFYPAQCW/QAA1Q0DAB79AADJDAIAjzIAAPAFAQB6IAAASQEBANUDAABmAAEAazMAABEIAwDQMgAAVQYBAOn/AACGEAEAzDMAAP8IAgDV1QEApRIBAOr+AACJDwEA4P4AAH8PAQBM1wEAGhQBAPEBAAA4AAIAoPsAAAoKAQBYIQAAzAEDALbXAQCEFAEATvwAABMLAgDg+wAALgoBAGv7AADVCQEAsNQBAI8RAQBlMQAAcAQBAD0yAAByBQMAYP4AAOwOAQDv+wAARwoDAIYvAADfAwEAVPwAAB8LAgCuBwEAuBABAFb7AADACQEA69YBALkTAQBl4AEAARUBAHExAAB8BAEA2yQAADgDAQBO8QEAJhYDAJb7AAAACgEAb/0AAGYNAwB6MQAAhQQBACbyAQBPFgEA8tcBAL4UAQDn/AAAWAwCAMsyAABIBgMAJPEBAOkVAwBb/QAAKg0DAHIyAADCBQIAmi8AAPMDAQBh/QAAPA0DAAL9AACRDAIAq+4BAIYVAQCe/gAAPQ8BAEn+AADSDgIAa9YBADsTAQDDJAAAIAMBAI78AACfCwIA4NUBALASAQBP1QEAIBIBAHMhAAAHAgIAFf8AAL4PAQB51gEASRMBAGfXAQA1FAEAzjMAAAMJAgABLwAAWgMBAD/8AAD1CgIAIjIAACEFAwDA1gEAjhMBAMXUAQChEQEAUTEAAFwEAQA1MwAAXwcDACP8AAC9CgIAqCQAAOkCAwDW1QEAphIBALoAAAAOAAEAdNQBAFsRAQAK1wEA2BMBABf9AAC7DAIAjTEAAJgEAQCnLwAAAAQBABcgAAAfAQIAhvwAAI8LAgAh8QEA4BUDADIBAAAYAAIAbyEAAAACAQCbMQAAowQBAEb/AADvDwEAPDEAAEcEAQB9+wAA5wkBAJQzAAB1CAMAUtcBACAUAQB+1AEAZREBAOzUAQDIEQEAZv8AAA8QAQD6/QAAkA4SAMQkAAAhAwEAUf8AAPoPAQAW/AAAowoCAJ8wAAA4BAIACv0AAKEMAgC4/gAAVw8BADUhAAChAQEAZTMAAP8HAwAD8QEAnRUCAH79AACTDQMAtdQBAJQRAQBN1QEAHhIBACX9AADXDAIAJCAAACEBAQDPJAAALAMBAMD8AAAIDAIAwTIAADIGAgAgMwAADAcFAIT9AAClDQMA/dUBAM0SAQD31QEAxxIBAOb/AACEEAEAOtcBAAgUAQCO1gEAXhMBAGUhAADsAQIAuzIAACYGAgBI8gEAfRYDAF4hAADeAQMAUB0AAKkAAQDy/gAAkQ8BAEUyAACIBQEAJ+4BACwVAQAu1wEA/BMBAE4zAAC+BwQA6TMAAEEJAwBYMgAAnAUCAMQzAADsCAIAhzIAAOgFAQAQ8gEAOBYBAK4kAAD7AgMAbeABAAkVAQAjMwAAGQcDAOczAAA9CQIAyf4AAGgPAQAs8gEAVRYBAEL/AADrDwEAu/8AAGQQAQAd/wAAxg8BAH8hAAAeAgEA4dYBAK8TAQBH7gEAPhUBAE7+AADbDgEA8fsBAIMWAQAV8QEAvBUDAMQBAAAjAAMAZ9UBADcSAQCx/wAAWhABAPEyAAB2BgEAaOABAAQVAQCwBwEAuhABAFwxAABnBAEA+NcBAMQUAQDvMwAAUwkDAFnWAQApEwEA7v4AAI0PAQBD4AEA3xQBAMEzAADkCAIAciQAAEcCAgDu1AEAyhEBAOD/AAB9EAEAciEAAAQCAwAn/wAA0A8BAEABAAAeAAIAZtcBADQUAQCAMwAARwgCAK7+AABNDwEANCAAACoBAwCj1QEAcxIBAFDWAQAgEwEA6v8AAIcQAQCw1wEAfhQBAFv/AAAEEAEAsvwAAOwLAgBk1AEASxEBAB/uAQAoFQEAHNcBAOoTAQAoIQAAmQEBAJruAQB8FQEAo9YBAHMTAQCcpgAAhgkBAHQqAABMAwMA+P4AAJ0PAwBk1wEAMhQBADYzAABiBwUAN/wAAOUKAgA11AEAHREBADYgAAAtAQIAvR8AAO4AAgAn1QEA/hEBAED8AAD3CgIAV/0AAB4NAwCLMQAAlgQBAA//AAC4DwEA9f0AAH0OBAD5zAEA5xABABAhAACFAQEABNcBANITAQDj1gEAsRMBAG0zAAAXCAMAijMAAF4IAgBfLwAAuAMBAFD7AAC6CQEAgS8AANoDAQAX7gEAIBUBAAP9AACTDAIAodYBAHETAQDXzAEAxRABANXXAQChFAEAfTIAANsFBABk/gAA8A4BAHX7AADfCQEA9jIAAHsGAQD61wEAxhQBAFzXAQAqFAEAqP0AAAsOAwDJAQAAMAACAMwvAAAlBAEAAPIBADMWAgCB1wEATxQBAIszAABgCAIA3h8AAAMBAwBP1gEAHxMBANTVAQCkEgEAANUBANwRAQA41QEADxIBAC0dAACJAAEARNQBACwRAQDRMgAAVgYBAJf9AADYDQMAOSEAAKUBAQDW/wAAeBABAMP8AAAODAIAkyQAAKoCAwDG1gEAlBMBAI0gAABcAQEAP9cBAA0UAQBT/wAA/A8BABv/AADEDwEAFTIAAO4EBADh/gAAgA8BAGX8AABICwMAFPwAAJ8KAgCi/gAAQQ8BAJH7AAD7CQEAVR0AAK4AAQBj/AAAQgsDAK8kAAD+AgMAgwcBAI4QAQC/LwAAGAQBAAT9AACVDAIAYTIAAK0FAQACLwAAWwMBAHYgAABFAQEAPC8AAJUDAQA8HQAAlgABAKYkAADjAgMA9fsBAIcWAQC0AgAAQgABAJ4xAACmBAEAPCAAADIBAgDa/gAAeQ8BADDyAQBZFgEAVdYBACUTAQDFLwAAHgQBAFL7AAC8CQEAYP0AADkNAwCI1QEAWBIBAGAkAAAsAgEAuf4AAFgPAQBTMwAAzgcEAJsdAADFAAEAf9QBAGYRAQAE8QEAnxUCAFL+AADfDgEAJfsAALMJAQBhLwAAugMBAAD8AAByCgMAKfEBAPgVAwDwAwAAaAABADExAAA8BAEAf9UBAE8SAQBU/QAAFQ0DACbxAQDvFQMAM/4AAL4OAQD5AwAAbQABADMyAABUBQMA5vwAAFYMAgAV/AAAoQoCAGUyAACxBQEAqi8AAAMEAQBL/AAADQsCACMyAAAkBQMAYOABAPwUAQBw/QAAaQ0DADPUAQAbEQEAkjMAAG8IAwB9/gAADQ8CANnWAQCnEwEAQTEAAEwEAQCWMgAA9wUBACYvAAB/AwEAMP0AAO0MAgA28gEAXxYBAMIkAAAfAwEAX/wAADYLAwCdLwAA9gMBAHvWAQBLEwEAXdUBAC0SAQCq1gEAeBMBAOMkAABAAwEAbNcBADoUAQAu8gEAVxYBALfUAQCWEQEAptcBAHQUAQC1/QAAMg4DAP/8AACLDAIA3NcBAKgUAQA1/QAA9wwCAFUzAADXBwIAJvsAALQJAQCb/AAAvQsDAIggAABXAQEAVP4AAOAOAQCi1wEAcBQBAMT/AABqEAEAhNUBAFQSAQBWMQAAYQQBAH0zAAA/CAIAVzMAAN8HAwB+/wAAJxABAEwxAABXBAEA4AIAAFMAAQAo/QAA3QwCAKckAADmAgMAdDIAAMYFAgBTHQAArAABAEoxAABVBAEAhzEAAJIEAQBQ1AEAOBEBAHghAAATAgIAUdcBAB8UAQCU7gEAdhUBAMHXAQCPFAEAIfsAAK8JAQCH/QAArg0DALoHAQDDEAEAP9QBACcRAQCM1QEAXBIBALPWAQCBEwEAh9cBAFUUAQBXMgAAmgUCAGzWAQA8EwEATzMAAMIHAwBN4AEA6RQBAPn7AQCLFgEAWv4AAOYOAQBM1QEAHRIBABMvAABsAwEADf0AAKcMAgBK1QEAGxIBAJnWAQBpEwEAYSQAAC0CAQD+1gEAzBMBAGIdAAC7AAEAS+ABAOcUAQBX7gEARxUBAFrUAQBBEQEATP8AAPUPAQAD7gEADRUBAPKnAACKCQEADO4BABUVAQB8/wAAJRABAAXuAQAOFQEA6iQAAEcDAQBq1wEAOBQBADb8AADjCgIAwS8AABoEAQBv1AEAVhEBAL7UAQCbEQEAgNUBAFASAQBfMwAA8AcCAKH/AABKEAEA3tcBAKoUAQCZ1AEAgBEBAHzVAQBMEgEAadYBADkTAQDnzAEA1RABAC/9AADrDAIAZzMAAAUIAwAS/gAAsA4BAAoyAADGBAMARNYBABQTAQBHMQAAUgQBAKjXAQB2FAEA2PwAADgMAgDw1QEAwBIBAPv9AACiDggAMv8AANsPAQCt+wAAGQoBAGwxAAB3BAEAcCAAAEEBAQD31wEAwxQBAML8AAAMDAIAbOABAAgVAQBuMwAAGggDAL8zAADgCAIAZNYBADQTAQAk8gEATRYBAHYGAAByAAIAUP4AAN0OAQA3/wAA4A8BAKzWAQB6EwEARR0AAJ8AAQBwMwAAIAgDAKPXAQBxFAEAvtcBAIwUAQAJIQAAfQECAPz7AABuCgEAKC8AAIEDAQDd1QEArRIBALX/AABeEAEA3/wAAEYMAwA31QEADhIBAMn8AAAaDAIAJdQBAA0RAQCfMwAAjAgDACYzAAAjBwMAlgcBAKAQAQAI/wAAsQ8BAEXXAQATFAEAOvIBAGMWAQDoMgAAbQYBAEPXAQARFAEAsNUBAIASAQCyMgAAFAYCAHoDAABYAAIAu9cBAIkUAQAz/wAA3A8BAFvuAQBJFQEAf/wAAIELAgBH1wEAFRQBAPj8AAB9DAIAxzIAAD4GAgCW1wEAZBQBACovAACDAwEAivsAAPQJAQA9/QAABw0CAEz8AAAPCwIASTEAAFQEAQDC1gEAkBMBAIzWAQBcEwEA8tQBAM4RAQC2/QAANQ4DAJUgAABjAQEADTMAALkGBADdAgAAUQACAMrWAQCYEwEA0C8AACkEAQAOIQAAgwEBAFLWAQAiEwEAw/4AAGIPAQDr+wAAOwoDAJMgAABhAQEAFdYBAOUSAQAMLwAAZQMBAIUxAACQBAEAKtQBABIRAQDLAQAANAACAH4vAADXAwEAhQcBAJAQAQCDMgAA5AUBAHL7AADcCQEA0/4AAHIPAQBa/wAAAxABAMMzAADqCAIAYjEAAG0EAQBK1AEAMhEBAH8kAABuAgQAvPwAAAAMAgDj/gAAgg8BACDXAQDuEwEAHjIAABUFBgAFLwAAXgMBAFYyAACYBQIAGdUBAPERAQClBwEArxABAAEgAAAUAQEAhPwAAIsLAgA07gEANxUBAMHUAQCeEQEAXNQBAEMRAQB6/QAAhw0DAAnxAQCpFQIAySQAACYDAQCZ/wAAQhABAI8kAACgAgIAdwYAAHQAAgDaMgAAXwYBACQzAAAcBwQAoDIAAAEGAQCO7gEAcBUBAKQkAADdAgMAXjMAAO4HAgCQLwAA6QMBACXVAQD8EQEAj/4AAC4PAQAr7gEALxUBAEDyAQBlFgMAK/IBAFQWAQBt/AAAXQsCAEEvAACaAwEAMTMAAEwHAwBb4AEA9xQBAHMzAAAoCAIABv0AAJkMAgBY/wAAARABAMovAAAjBAEAogcBAKwQAQCHBQAAbgACADYxAABBBAEAjy8AAOgDAQCH+wAA8QkBAKz+AABLDwEAIdQBAAkRAQBlLwAAvgMBAFLVAQAiEgEAnPsAAAYKAQAG7gEADxUBALwvAAAVBAEA0tYBAKATAQBd1AEARBEBAOnWAQC3EwEAFvIBAD8WAQCqBwEAtBABABP7AACkCQIAb/wAAGELAgB7MgAA1AUCAK8vAAAIBAEA9tcBAMIUAQBBMgAAfgUDAJIHAQCcEAEA2CQAADUDAQD21AEA0hEBAK79AAAdDgMA19cBAKMUAQA1/wAA3g8BAFwdAAC1AAEAaiQAADcCAgC5/AAA+gsCAHsvAADUAwEAzP8AAHAQAQAI/QAAnQwCADczAABnBwMA3DIAAGEGAQD/1QEAzxIBAOwyAABxBgEAgO4BAGMVAQDz/AAAcQwDADszAAB1BwUAH/EBANoVAwBm1QEANhIBADvUAQAjEQEALf0AAOcMAgClJAAA4AIDAI0yAADuBQEAzvwAACQMAgBn4AEAAxUBANAzAAAHCQIAWvwAACsLAgDp/gAAiA8BAKH9AAD2DQMAoAcBAKoQAQBx1wEAPxQBAMb8AAAUDAIAgyAAAFIBAQAvLwAAiAMBAB3uAQAmFQEANfwAAOEKAgCiMgAAAwYBAJ7WAQBuEwEA1tYBAKQTAQDP/gAAbg8BAPMuAABYAwEADtcBANwTAQBSIQAAuQEEAEH/AADqDwEAPdUBABMSAQAM1wEA2hMBADrUAQAiEQEAYvsAAMwJAQCZ/gAAOA8BAHfWAQBHEwEAOv4AAMUOAQDP1AEAqxEBAH3UAQBkEQEAafwAAFQLAwCVBwEAnxABANr/AAB6EAEAbzIAALwFAgCTBwEAnRABAK38AADiCwIAVi8AAK8DAQBoMgAAtAUBAPv7AABrCgMAlPwAAKsLAgDT1AEArxEBAOLMAQDQEAEAq9YBAHkTAQCk1QEAdBIBAEbWAQAWEwEAOR0AAJQAAQAQMgAA2gQEACHXAQDvEwEAkf8AADoQAQCp1AEAiREBAKDXAQBuFAEA+fwAAH8MAgDyzAEA4BABACLyAQBLFgEApTMAAJ0IAgDT1wEAnxQBAD/xAQATFgEAJ/wAAMUKAgCW7gEAeBUBAAD7AACUCQIAq/0AABQOAwBC8gEAaxYDANvWAQCpEwEAm9UBAGsSAQCEJAAAggIEAPLWAQDAEwEASzEAAFYEAQBpLwAAwgMBAE0xAABYBAEAEfIBADkWAQAs1QEAAxIBALX8AADyCwIAuO4BAJMVAQA/MgAAeAUDAKX9AAACDgMAoP8AAEkQAQAe8gEARxYBAMrVAQCaEgEA+dQBANURAQA+HQAAmAABAI39AADADQMAvAAAAA8AAwAV+wAAqAkCAJwgAABqAQEAQDMAAIkHBQBuMgAAugUCAF0yAACmBQIAnNYBAGwTAQB8MQAAhwQBAKrVAQB6EgEAie4BAGwVAQCG1wEAVBQBALjUAQCXEQEAxiQAACMDAQCt/QAAGg4DANEvAAAqBAEAYv8AAAsQAQCf/gAAPg8BAGT/AAANEAEAxy8AACAEAQB81gEATBMBAH3WAQBNEwEA5f8AAIMQAQBaHQAAswABABHUAQD5EAEArf8AAFYQAQAI1gEA2BIBADjyAQBhFgEAjzMAAGgIAgAL7gEAFBUBALHVAQCBEgEAgzEAAI4EAQBjMQAAbgQBAJ0yAAD+BQEA3PwAAEAMAgC8HQAA5gABAFUyAACWBQIA3MwBAMoQAQAP/QAAqwwCAJ8vAAD4AwEAke4BAHMVAQCaMQAAogQBALUAAAAKAAEA6dcBALUUAQCQ1AEAdxEBANH+AABwDwEA6NUBALgSAQAX1wEA5RMBAAT/AACtDwEAse4BAIwVAQBsMgAAuAUBAHT+AAD9DgIAgP4AABMPAQB+MwAAQQgCAIwvAADlAwEArNcBAHoUAQCeBwEAqBABAJ8kAADOAgMAge4BAGQVAQDR/AAAKgwCAJDuAQByFQEAfiQAAGoCBADfMgAAZAYBAD78AADzCgIAjdYBAF0TAQAk/QAA1QwCAArxAQCrFQIA8gMAAGoAAQC5HQAA4wABADjXAQAGFAEARzMAAKEHBQBc/wAABRABADYyAABdBQMAZf8AAA4QAQANIQAAggEBAC/WAQD/EgEAFy8AAHADAQDcMwAAJQkCAH8vAADYAwEAYtQBAEkRAQCZIAAAZwEBAFL8AAAbCwIAsO4BAIsVAQCVJAAAsAIDAPD8AABqDAIA0zIAAFgGAQCUBwEAnhABAKr7AAAWCgEAnTEAAKUEAQDn1QEAtxIBAHYyAADKBQIA3SQAADoDAQCL1wEAWRQBAFsdAAC0AAEAhDEAAI8EAQCb+wAABQoBAH78AAB/CwIAPPwAAO8KAgAE1QEA4BEBABbxAQC/FQMAZzIAALMFAQAO1AEA9hABAIoxAACVBAEAziQAACsDAQDH/gAAZg8BAN/7AAAtCgEAhtYBAFYTAQBA/gAAyw4BACwvAACFAwEAzy8AACgEAQBoJAAANAIBAD8vAACYAwEAsPsAABwKAgAU/wAAvQ8BAE3XAQAbFAEAcf4AAPkOAgA21gEABhMBANHWAQCfEwEAtDIAABgGAgAl1gEA9RIBABPxAQC2FQMAkv0AAMkNAwBW/AAAIwsCAMvXAQCZFAEAs/8AAFwQAQBF4AEA4RQBACwdAACIAAEAjPsAAPYJAQBbLwAAtAMBAJb8AACvCwIAZdUBADUSAQCZMgAA+gUBAHEgAABCAQEAxgEAACkAAwDrzAEA2RABAMwyAABLBgIARNUBABkSAQAI1AEA8BABALfuAQCSFQEAVP8AAP0PAQB4BgAAdgACAO77AABECgMAQNUBABUSAQB3JAAAVAIDAK3+AABMDwEA8NYBAL4TAQCm/QAABQ4DAEAhAACtAQEAHdYBAO0SAQBX1wEAJRQBAAfuAQAQFQEANh0AAJEAAQAxMgAATgUDAF0hAADbAQMAX9YBAC8TAQDD1wEAkRQBAJj+AAA3DwEA2vwAADwMAgBz/QAAcg0DAFDgAQDsFAEANPIBAF0WAQDh/wAAfhABAPDUAQDMEQEAuy8AABQEAQAVIQAAiQEBADHuAQA1FQEAh/wAAJELAgBL7gEAQBUBAGv+AAD2DgEAifwAAJULAgA7MQAARgQBAK7XAQB8FAEAvNYBAIoTAQBi7gEATRUBAE0dAACnAAEAauABAAYVAQA6LwAAkwMBAADWAQDQEgEALf8AANYPAQCY/AAAtAsDABkvAAByAwEAJ/sAALUJAQCjJAAA2gIDALHUAQCQEQEAWSEAAM8BAwAO1QEA5xEBANMzAAAOCQIAbyQAAEECAgBfqwAAkgkBADPyAQBcFgEAN+4BADoVAQCfLgAAVwMBAHf+AAABDwIAdTEAAIAEAQAv1QEABhIBAAYyAAC6BAMAiv8AADMQAQDhMgAAZgYBAJvWAQBrEwEAK9UBAAISAQCT/AAAqQsCAPjUAQDUEQEAte4BAJAVAQBfMQAAagQBAMnWAQCXEwEAofsAAAsKAQAxIQAAngEBADbXAQAEFAEA8f0AAG4OAwBP+wAAuAkCALv8AAD+CwIActQBAFkRAQC9HQAA5wABAH8gAABOAQEA7vwAAGYMAgBz1gEAQxMBALcyAAAeBgIAci8AAMsDAQBJ1gEAGRMBACXXAQDzEwEAODEAAEMEAQDWAwAAZwABAH0kAABmAgQAI9cBAPETAQDE/AAAEAwCAIr+AAAmDwIAB9cBANUTAQDfMwAALAkDAF4vAAC3AwEAnDIAAP0FAQCk/AAA0AsCABryAQBDFgEAwR8AAPQAAwA0MgAAVwUDABjUAQAAEQEACu4BABMVAQBGMwAAngcDAGf9AABODQMAk9UBAGMSAQBhMwAA9AcCAATWAQDUEgEA7dQBAMkRAQDgJAAAPQMBAF39AAAwDQMAUjMAAMwHAgCd1gEAbRMBAAohAAB/AQEA5NUBALQSAQCC1gEAUhMBAGP/AAAMEAEAIf8AAMoPAQDm/gAAhQ8BAPL7AABQCgMAHu4BACcVAQD2/QAAgQ4EAMvVAQCbEgEAgQcBAIwQAQDX+wAAJAoBADMxAAA+BAEAM/0AAPMMAgCD7gEAZhUBAFIvAACrAwEAuv8AAGMQAQCKJAAAlgICAOjXAQC0FAEAyDIAAEAGAgAMIQAAgQEBAO3VAQC9EgEAxdYBAJMTAQCJ1gEAWRMBABPWAQDjEgEAq/8AAFQQAQCiHQAAzAABAEsdAAClAAEAE/0AALMMAgBN7gEAQRUBAK8AAAAEAAIAZNUBADQSAQCJIAAAWAEBAHYxAACBBAEAADMAAIYGBQAOMgAA0gQEAC/UAQAXEQEAn/wAAMYLAgAc1QEA9BEBAJL/AAA7EAEAivwAAJcLAgCaIAAAaAEBAOb7AAA0CgEA0QMAAGAAAQBfHQAAuAABANX7AAAiCgEAn9cBAG0UAQDP1gEAnRMBALbWAQCEEwEAwP4AAF8PAQDtMwAATQkDADLWAQACEwEAp/0AAAgOAwAQMwAAxQYEAKcHAQCxEAEAvdcBAIsUAQA/4AEA2xQBALHWAQB/EwEAV/8AAAAQAQB6MgAA0gUCAND+AABvDwEAXtUBAC4SAQAN/AAAkQoCAPX8AAB3DAIAeCQAAFcCAwBk/AAARQsDAIsHAQCVEAEAcC8AAMkDAQDd1gEAqxMBAE0zAAC6BwQAEyEAAIgBAQBf4AEA+xQBAK0yAAAOBgEA/fwAAIcMAgBoMQAAcwQBACz8AADPCgIAKzMAADAHBgDL/AAAHgwCAGYhAADuAQMAhyQAAI4CBACyMwAAxggCAF3+AADpDgEAYi8AALsDAQC11gEAgxMBAEj+AADRDgEA/h8AABEBAgDJ1AEApREBADLUAQAaEQEAr9YBAH0TAQD0MgAAeQYBAPLVAQDCEgEAI9QBAAsRAQC81wEAihQBAKDWAQBwEwEAgzMAAE0IAgAD1wEA0RMBABzWAQDsEgEAEjMAAM0GBADm1AEAwhEBAJ/9AADwDQMAnzEAAKcEAQChLwAA+gMBAEv+AADWDgIAWdUBACkSAQA4MgAAYwUDAKT/AABNEAEAuP0AADsOAwAW+wAAqgkCABQzAADXBgIArv8AAFcQAQAk1AEADBEBAPsyAACABgEAm+4BAH0VAQDYAgAARwACAIwzAABiCAIA6swBANgQAQBD8QEAFxYBACjxAQD1FQMAmNUBAGgSAQCrLwAABAQBABcyAAD2BAQA1vwAADQMAgBkIQAA6wEBAE7gAQDqFAEAg/8AACwQAQCB1QEAURIBAO3WAQC7EwEAY/sAAM0JAQC8MwAA2ggCADfXAQAFFAEAYjIAAK4FAQAZ7gEAIhUBAFj+AADkDgEAGfIBAEIWAQC1LwAADgQBAD0xAABIBAEA9TIAAHoGAQAFMwAAnAYDABbWAQDmEgEAF/4AALUOAQBZMQAAZAQBAFfUAQA+EQEAf9YBAE8TAQCLIAAAWgEBAO/WAQC9EwEAAtQBAOoQAQDD1AEAoBEBALcdAADhAAEAc/sAAN0JAQBK8QEAHhYCANz7AAApCgEAWdQBAEARAQAgMgAAGwUDAD3WAQANEwEA4v4AAIEPAQDA/QAAUw4DAB4vAAB3AwEAItYBAPISAQDl/AAAVAwCABzxAQDRFQMAt/0AADgOAwAm/wAAzw8BALTuAQCPFQEASvwAAAsLAgA9IQAAqgEBAG7/AAAXEAEAsu4BAI0VAQDmMgAAawYBACT/AADNDwEAUR0AAKoAAQAn/QAA2wwCAOvXAQC3FAEAaNUBADgSAQBb+wAAxQkBAFYzAADZBwYAY/0AAEINAwDK1wEAmBQBABPVAQDsEQEAGPIBAEEWAQCv/AAA5gsCAGn9AABUDQMApDIAAAUGAQBXLwAAsAMBAMTXAQCSFAEAXKsAAI8JAQAs/wAA1Q8BAEvUAQAzEQEACO4BABEVAQB5IQAAFQIBAJXUAQB8EQEATf8AAPYPAQDzpwAAiwkBAOXXAQCxFAEAhf4AABwPAgBp4AEABRUBALIAAAAGAAEAiC8AAOEDAQADIQAAdAECAGT7AADOCQEAMh0AAI0AAQBr8QEALRYCAHQgAABDAQEARf8AAO4PAQD9MgAAggYBAGDUAQBHEQEAfCQAAGMCAwDozAEA1hABAFchAADJAQMAjtQBAHURAQAQ7gEAGRUBAKYdAADQAAEAOjIAAGkFAwCa+wAABAoBAIwHAQCWEAEAlNUBAGQSAQBR/AAAGQsCABX+AACzDgEAv/0AAFAOAwC5/wAAYhABAOLUAQC+EQEAAv8AAKsPAQALMgAAyQQDAJQgAABiAQEAnjMAAIoIAgD8/AAAhQwCAJr+AAA5DwEAvh0AAOgAAQDl1QEAtRIBAL3UAQCaEQEAjvsAAPgJAQAF1QEA4REBABYzAADfBgYAkTMAAGwIAwAK1gEA2hIBAFv8AAAtCwIAhO4BAGcVAQBZHQAAsgABALMyAAAWBgIAjdUBAF0SAQDj1QEAsxIBAAf/AACwDwEAmPsAAAIKAQAi1QEA+REBAM8fAAD9AAMAR/IBAHoWAwDVMwAAEgkDADj+AADDDgEAWTMAAOQHAgDK/wAAbhABAFz9AAAtDQMAzdYBAJsTAQCYMgAA+QUBAAczAACiBgYA39cBAKsUAQDY/gAAdw8BAEPWAQATEwEACdUBAOQRAQDezAEAzBABAJoeAADqAAIAFP0AALUMAgAb/AAArQoCADj9AAD9DAIAJy8AAIADAQBw+wAA2gkBAAYvAABfAwEAGjIAAAIFBABQMgAAiwUDAEAdAACaAAEAENUBAOkRAQBAMgAAewUDAGHXAQAvFAEAjP8AADUQAQAx/wAA2g8BAFb/AAD/DwEAtP0AAC8OAwCu1QEAfhIBANT8AAAwDAIAJNcBAPITAQA/AQAAHAACADAhAACdAQEAZy8AAMADAQAF/AAAgQoCAGozAAAOCAMAj9YBAF8TAQC31QEAhxIBALokAAAXAwEATtcBABwUAQALIQAAgAEBAGDVAQAwEgEAbSEAAP4BAQCaMgAA+wUBAPf8AAB7DAIAXf8AAAYQAQAMDwAAgAABAET+AADPDgEAACAAABMBAQDwzAEA3hABABHuAQAaFQEAbDMAABQIAwC7JAAAGAMBAJ78AADECwIA9P0AAHkOBADQAwAAXwABALUCAABDAAEAJPsAALIJAQAsMwAANgcEAOz7AAA+CgMAMv0AAPEMAgC+/AAABAwCALgHAQDBEAEAsSQAAAQDAwAx1gEAARMBAKzuAQCHFQEAuC8AABEEAQD71AEA1xEBACnUAQAREQEAIvwAALsKAgD2/gAAlw8DACn8AADJCgIAdyAAAEYBAQBzJAAASQICALbuAQCRFQEArtYBAHwTAQBEMwAAmAcDAIAyAADhBQEAR9QBAC8RAQCpBwEAsxABAHXXAQBDFAEA0dUBAKESAQAAIQAAbQEDAIX7AADvCQEA9jMAAGgJAwAzDgAAeAACAKj8AADYCwIAN9QBAB8RAQDXJAAANAMBABj9AAC9DAIAxtUBAJYSAQDX1QEApxIBAFoxAABlBAEAiS8AAOIDAQDHJAAAJAMBAJP7AAD9CQEAnf8AAEYQAQA6MwAAcQcEAADXAQDOEwEAHTIAAA4FBwCEIAAAUwEBAA0vAABmAwEAEjIAAOIEBADAMgAAMAYCAF8hAADhAQIABPwAAH4KAwBO/wAA9w8BAFf7AADBCQEAFfIBAD4WAQD1AwAAbAABAJsyAAD8BQEAy9YBAJkTAQAG/wAArw8BAJv9AADkDQMAei8AANMDAQBCMgAAgQUDABT+AACyDgEAxjIAADwGAgAJ1AEA8RABAEP+AADODgEAjCAAAFsBAQDR1wEAnRQBAEHXAQAPFAEAhvsAAPAJAQDa1AEAthEBADDxAQAEFgEAWzIAAKIFAgBSMgAAkAUCAI7VAQBeEgEAzP4AAGsPAQD7/AAAgwwCAFH9AAAMDQMAEfEBALAVAwCA1gEAUBMBAMzVAQCcEgEAGfEBAMgVAwA41gEACBMBAMszAAD9CAIArjMAALcIBQDeMgAAYwYBAP7VAQDOEgEAWDEAAGMEAQBkHQAAvQABADLgAQDOFAEA4tYBALATAQAB7gEACxUBACPWAQDzEgEAHTMAAAEHAwDZMgAAXgYBAGovAADDAwEAcdYBAEETAQBR4AEA7RQBAFEhAAC2AQMAzv4AAG0PAQCA+wAA6gkBANMvAAAsBAEACfwAAIkKAgB+1QEAThIBAJn7AAADCgEApPsAAA4KAgC0LwAADQQBAML/AABoEAEALDIAAD8FAwCT1gEAYxMBAHYkAABRAgMAnzIAAAAGAQBVLwAArgMBAHwzAAA9CAIAswcBALwQAQCq1wEAeBQBAIP9AACiDQMANPwAAN8KAgAvIQAAnAEBAI/XAQBdFAEA2dUBAKkSAQCw/AAA6AsCAKkkAADsAgMACTIAAMMEAwAh7gEAKRUBAAIyAACuBAMAMCIAACkCAwDlzAEA0xABAHcxAACCBAEAedUBAEkSAQAs/QAA5QwCAG3XAQA7FAEArdYBAHsTAQCmLwAA/wMBAIwkAACaAgIAcSQAAEUCAgBr/wAAFBABADL8AADbCgIAE/wAAJ0KAgDd/gAAfA8BALYkAAATAwEAtQcBAL4QAQBC1gEAEhMBAGH/AAAKEAEAi9UBAFsSAQA94AEA2RQBALD/AABZEAEAhjMAAFMIAgAAMgAAqAQDAG0kAAA9AgIA+dcBAMUUAQCJMwAAWggEAM3/AABxEAEAdTMAAC0IAgAp/QAA3wwCAKnVAQB5EgEAAO4BAAoVAQDN1AEAqREBAHEhAAACAgIAfvsAAOgJAQBO1gEAHhMBAO7VAQC+EgEAsR0AANsAAQD0/gAAkw8BAC79AADpDAIAYzIAAK8FAQDL1AEApxEBAPjMAQDmEAEAKdYBAPkSAQCrMwAArggDAHjWAQBIEwEAsC8AAAkEAQC9/QAASg4DAL3WAQCLEwEAyy8AACQEAQDM1gEAmhMBAE/UAQA3EQEAczEAAH4EAQCRIAAAXwEBAKkdAADTAAEA39UBAK8SAQD61QEAyhIBAHshAAAYAgMAliQAALMCAwBUMgAAlAUCAFbXAQAkFAEAjDEAAJcEAQCIMwAAVwgDAEcdAAChAAEAQfwAAPkKAgAJ1gEA2RIBAIv7AAD1CQEAkzMAAHIIAwBH4AEA4xQBAMoBAAAyAAIA49QBAL8RAQBoLwAAwQMBAKLWAQByEwEALh0AAIoAAQB5/gAABQ8CAJszAACECAIAFTMAANkGBgBt7gEAVBUBAFQdAACtAAEA0tcBAJ4UAQAtMwAAOgcFACrxAQD7FQMAdv0AAHsNAwA9MwAAfgcFAHXVAQBFEgEA1v4AAHUPAQBx/QAAbA0DAJr/AABDEAEAIdUBAPgRAQAv7gEAMxUBALLVAQCCEgEAeyQAAGACAwAf8gEASBYBAJTXAQBiFAEAbPEBAC8WAgBw/gAA9w4CAHYhAAAMAgMAidUBAFkSAQBv1wEAPRQBAA79AACpDAIACDMAAKgGBABkMQAAbwQBAPfMAQDlEAEASi8AAKMDAQAP1AEA9xABAD7XAQAMFAEAQNQBACgRAQBL1gEAGxMBALECAAA/AAEAFPEBALkVAwCmMwAAnwgDAFn9AAAkDQMApB0AAM4AAQAa1wEA6BMBAFkvAACyAwEA6PsAADYKAQAg/AAAtwoCADrgAQDWFAEA1S8AAC4EAQCn/wAAUBABADTgAQDQFAEA3fwAAEIMAgDVMgAAWgYBAHUgAABEAQEAHtUBAPURAQCI/gAAIg8CAKXWAQB1EwEAOf0AAP8MAgC9JAAAGgMBALYHAQC/EAEAFu4BAB8VAQBmJAAAMgIBAHr8AAB3CwIACTMAAKwGAwBm/AAASwsDAJEyAADyBQEA6CQAAEUDAQDTJAAAMAMBAPXXAQDBFAEApjIAAAcGAQAV1wEA4xMBAC8zAABFBwQAGjMAAPQGBgAP1QEA6BEBAIQHAQCPEAEAgTIAAOIFAQDULwAALQQBAD8zAACHBwIA0vwAACwMAgCJIQAAHwIDACjVAQD/EQEA3CQAADkDAQBkJAAAMAIBAAUhAAB2AQMANfIBAF4WAQD/+wAAcQoBAKr+AABJDwEAzdUBAJ0SAQBC7gEAPRUBAPnWAQDHEwEAGf4AALcOAwA81AEAJBEBAJkHAQCjEAEAa/0AAFoNAwBTIQAAvQEDAKfXAQB1FAEAUfsAALsJAQD9MwAAfQkDACnXAQD3EwEAGPwAAKcKAgDF/gAAZA8BAATUAQDsEAEAgSAAAFABAQCd/gAAPA8BAJD8AACjCwIAm9cBAGkUAQBSHQAAqwABAPn+AACgDwMAINUBAPcRAQBP/wAA+A8BAPHWAQC/EwEAP9YBAA8TAQCx/AAA6gsCAMbXAQCUFAEA89UBAMMSAQAS/AAAmwoCADr8AADrCgIARPIBAHEWAwC4JAAAFQMBAFQzAADSBwUAGiEAAI0BAQCL/gAAKA8CAF/uAQBLFQEAGu4BACMVAQCcMwAAhggCAAMyAACxBAMAx/8AAG0QAQDNJAAAKgMBACfyAQBQFgEAK9YBAPsSAQBVIQAAwwEDAEzgAQDoFAEAiyQAAJgCAgBH8QEAGxYBAIP+AAAYDwIA/NcBAMgUAQCi/wAASxABAAXxAQChFQIA7fwAAGQMAgCt1wEAexQBAJ7/AABHEAEAky8AAOwDAQDH/AAAFgwCAKv8AADeCwIAstQBAJERAQDI/AAAGAwCAB7WAQDuEgEAs/wAAO4LAgCG/QAAqw0DADQvAACNAwEAFNcBAOITAQClLwAA/gMBANf+AAB2DwEAk9cBAGEUAQCU1AEAexEBALQHAQC9EAEAJtYBAPYSAQAEMwAAlwYFAMv+AABqDwEArNQBAIwRAQBg1gEAMBMBANTWAQCiEwEAsv4AAFEPAQBp1wEANxQBADbxAQAKFgEAICEAAJEBAgB07gEAWhUBAKsyAAAMBgEAUC8AAKkDAQA1LwAAjgMBAJYzAAB6CAIA2swBAMgQAQB41AEAXxEBAML9AABZDgMAW9QBAEIRAQCzAgAAQQABANv7AAAoCgEAG/IBAEQWAQAoMgAAMwUDAH//AAAoEAEACSAAABwBAQDKMwAA+wgCAM4vAAAnBAEArx0AANkAAQB1IQAACgICAGT9AABFDQMActYBAEITAQA0HQAAjwABAEQyAACHBQEAAvwAAHgKAwDF/wAAaxABAK4yAAAPBgEA+dUBAMkSAQA1MgAAWgUDALwkAAAZAwEA5NQBAMARAQCj/wAATBABADYwAAAwBAEAK/EBAP4VAQAR/AAAmQoCAK0dAADXAAEA0/sAACAKAQDH/QAAaA4DAAfVAQDiEQEARvEBABoWAQCoMwAApQgEALPuAQCOFQEAWvsAAMQJAQCCLwAA2wMBAKL7AAAMCgEA1tQBALIRAQATMgAA5gQEAI4vAADnAwEAeNUBAEgSAQD7/gAApg8CAK8zAAC8CAYAae4BAFEVAQBu+wAA2AkBAH0hAAAcAgEA6tQBAMYRAQCk1wEAchQBAEIzAACRBwMAF/EBAMIVAwC7MwAA2AgCAEYvAACfAwEAcdQBAFgRAQAU1gEA5BIBAHHVAQBBEgEA99YBAMUTAQB+1gEAThMBAB78AACzCgIA+DIAAH0GAQBDMQAATgQBAOrVAQC6EgEA2wIAAE0AAgBRMwAAyAcEAKkyAAAKBgEAf9cBAE0UAQC9/gAAXA8BAHbXAQBEFAEAUNcBAB4UAQCpLwAAAgQBACIvAAB7AwEANNYBAAQTAQCbMAAANAQCALvVAQCLEgEAhy8AAOADAQBqHQAAwwABAOj/AACFEAEA5SQAAEIDAQBpMgAAtQUBAKz7AAAYCgEADi8AAGcDAQBdHQAAtgABADTxAQAIFgEApe4BAIEVAQDUAwAAZAACAIL8AACHCwIAmO4BAHoVAQCGJAAAigIEAB8vAAB4AwEAEzMAANEGBgDH1AEAoxEBAN/+AAB+DwEAEtQBAPoQAQBa1wEAKBQBAIXVAQBVEgEAZv4AAPIOAQDlMwAAOQkCAF/9AAA2DQMAi9QBAHIRAQCyJAAABwMDADgvAACRAwEAb/8AABgQAQDY1AEAtBEBACEyAAAeBQMAT/4AANwOAQCiLwAA+wMBAFHyAQCBFgEAPDIAAG8FAwB6JAAAXQIDAHkzAAA2CAMAEv0AALEMAgDg/AAASQwDAI/8AAChCwIAKS8AAIIDAQAMKgAASAMEALnVAQCJEgEAEPEBAK0VAwCQ+wAA+gkBALjXAQCGFAEALdQBABURAQA71wEACRQBACjWAQD4EgEAlv8AAD8QAQCwMwAAwggCAGf8AABOCwMANP8AAN0PAQBl1gEANRMBAFzWAQAsEwEAvjIAACwGAgDs1wEAuBQBAKczAACiCAMASCAAADgBAgBz1wEAQRQBAKbUAQCIEQEAX/8AAAgQAQB0IQAACQIBABfWAQDnEgEAI9UBAPoRAQDB/AAACgwCAG4hAAD/AQEAcNYBAEATAQBl/gAA8Q4BAGLXAQAwFAEAjO4BAG4VAQDOHwAA+gADAAv9AACjDAIASv8AAPMPAQB31AEAXhEBAHL/AAAbEAEAov0AAPkNAwCU/wAAPRABAI7/AAA3EAEA69QBAMcRAQDnJAAARAMBAHvUAQBiEQEA6dQBAMURAQB37gEAXRUBAMP9AABcDgMAPx0AAJkAAQC+JAAAGwMBAMIzAADmCAQAYdYBADETAQAB/QAAjwwCAGAdAAC5AAEACCAAABsBAQCP/QAAxg0DACcyAAAwBQMAztUBAJ4SAQBN1gEAHRMBAJLUAQB5EQEA/NUBAMwSAQAK1QEA5REBAIH+AAAUDwIAlzMAAHwIAgCR1gEAYRMBAK/uAQCKFQEAnfwAAMILAgCU/QAAzw0DAGTgAQAAFQEAVeABAPEUAQCv/wAAWBABAODWAQCuEwEA/vsAAHAKAQCf1QEAbxIBAPD7AABKCgMAzCQAACkDAQDO1wEAmhQBAMYzAADwCAQALfEBAAAWAgBU1QEAJBIBAJHXAQBfFAEAgiAAAFEBAQD0+wAAVgoDAK/9AAAgDgMAgTMAAEkIAgArMgAAPAUDADj8AADnCgIA38wBAM0QAQBB1AEAKREBAEUvAACeAwEA79QBAMsRAQDp1QEAuRIBAGsyAAC3BQEAz9UBAJ8SAQCS/gAAMQ8BALPXAQCBFAEAhf0AAKgNAwCF7gEAaBUBAAwyAADMBAMAhP8AAC0QAQA0IQAAoAEBADcgAAAvAQMA/jIAAIMGAQB5MQAAhAQBAJfWAQBnEwEAAvEBAJsVAgDwMwAAVgkDADghAACkAQEAsgIAAEAAAQDHMwAA9AgDAHT9AAB1DQMAiiAAAFkBAQA4/wAA4Q8BAFP8AAAdCwIAGP4AALYOAQBu1wEAPBQBAL3VAQCNEgEAH9YBAO8SAQA58QEADRYBAIDUAQBnEQEALS8AAIYDAQAd/AAAsQoCAFvWAQArEwEAhjIAAOcFAQBi4AEA/hQBADwzAAB6BwQAVfwAACELAgAm/QAA2QwCAGsxAAB2BAEAGtQBAAIRAQARLwAAagMBAE7VAQAfEgEAGzMAAPoGBABg1wEALhQBAHQzAAAqCAMAudcBAIcUAQDjMgAAaAYBAIrVAQBaEgEAwtQBAJ8RAQCD/AAAiQsCADcxAABCBAEAlTEAAJ0EAQBX1QEAJxIBACkyAAA2BQMAB/0AAJsMAgDt+wAAQQoDAH7+AAAPDwIA9/sAAF8KAwCH1gEAVxMBAA0yAADPBAMAXTEAAGgEAQCFJAAAhgIEAL7+AABdDwEAT9cBAB0UAQBoIQAA9QECAKjVAQB4EgEA6P4AAIcPAQCj+wAADQoBAEb8AAADCwIASzMAALIHAwCjLwAA/AMBAJozAACCCAIAgfsAAOsJAQCUMgAA9QUBACwhAACaAQEASSAAADoBAgBe1AEARREBAKIkAADXAgMA+vwAAIEMAgAx8QEABRYBAFQxAABfBAEAoCQAANECAwABMwAAiwYEAPD7AQCCFgEAPNUBABISAQDcAgAATwACAF4dAAC3AAEAOh0AAJUAAQCNLwAA5gMBAH7uAQBiFQEAetUBAEoSAQBu/QAAYw0DAHkvAADSAwEAPjIAAHUFAwBsJAAAOwICANgzAAAaCQQARfEBABkWAQAoMwAAKAcCAE8vAACoAwEAJfEBAOwVAwCO/QAAww0DAG0vAADGAwEAmCAAAGYBAQBD1AEAKxEBAN8fAAAGAQMARP8AAO0PAQCr+wAAFwoBANcyAABcBgEAMNUBAAcSAQDrMgAAcAYBAHcPAACBAAMAlzIAAPgFAQDrMwAARwkDAG0yAAC5BQEA1PsAACEKAQCzDgAAegACABHWAQDhEgEAlfwAAK0LAgCm+wAAEgoBAPH7AABNCgMAIy8AAHwDAQAs1AEAFBEBABrxAQDLFQMActcBAEAUAQAzIQAAnwEBAL8dAADpAAEAgDEAAIsEAQAn1AEADxEBAAgvAABhAwEAgP0AAJkNAwBZ/gAA5Q4BAF7/AAAHEAEAYe4BAEwVAQADLwAAXAMBADnVAQAQEgEAQy8AAJwDAQDl/gAAhA8BAJ4vAAD3AwEA8/sBAIUWAQDA1AEAnREBAIHUAQBoEQEAPv4AAMkOAQC3/wAAYBABAMMvAAAcBAEA/dYBAMsTAQB6/wAAIxABAFjgAQD0FAEAVO4BAEYVAQCA/AAAgwsCAIH8AACFCwIALPEBAP8VAQDZJAAANgMBAF3WAQAtEwEA0v8AAHQQAQA//gAAyg4BAH0xAACIBAEA4QIAAFQAAQC/JAAAHAMBANDVAQCgEgEA4fwAAEwMAgDdHwAAAAEDAHX9AAB4DQMAEi8AAGsDAQAX/wAAwA8BAEj8AAAHCwIAsf0AACYOAwDtMgAAcgYBAJAyAADxBQEAljEAAJ4EAQCI+wAA8gkBADrxAQAOFgEARPEBABgWAQCH/gAAIA8CAJLuAQB0FQEA4jMAADMJAgBA8QEAFBYBAGAxAABrBAEA9zMAAGsJAwAY1QEA8BEBAF3XAQArFAEAQC8AAJkDAQCM/AAAmwsCADEvAACKAwEA8vsBAIQWAQBx7gEAWBUBAFQhAADAAQMATdQBADURAQDE/QAAXw4DAI0HAQCXEAEA7h8AAAwBAwBI1AEAMBEBAFohAADSAQMASTMAAKoHAgDc/wAAfBABAKn7AAAVCgEA7zIAAHQGAQA58gEAYhYBAB7xAQDXFQMASNcBABYUAQDk/gAAgw8BANrWAQCoEwEAmNcBAGYUAQAs1gEA/BIBAGYxAABxBAEAk/8AADwQAQB7MQAAhgQBAPvVAQDLEgEAITMAABEHBQCX7gEAeRUBAIPVAQBTEgEAvf8AAGYQAQDAMwAA4ggCALQAAAAIAAIAUTIAAI4FAgBa1gEAKhMBAPXUAQDREQEARSEAAK4BAQCn7gEAgxUBADTVAQALEgEAFv0AALkMAgB2/AAAbwsCAK0zAAC0CAMAtwIAAEUAAQAd1wEA6xMBAJLXAQBgFAEAdS8AAM4DAQD6/gAAow8DAF3uAQBKFQEA6jIAAG8GAQDFMgAAOgYCAKsdAADVAAEA3A4AAHwAAgCX/gAANg8BAH4xAACJBAEAlf4AADQPAQA41AEAIBEBACD/AADJDwEAJ9cBAPUTAQBb/gAA5w4BAF4yAACoBQIAF9QBAP8QAQA71QEAERIBABr8AACrCgIAB/EBAKUVAgD71gEAyRMBANYzAAAVCQMAkzEAAJsEAQAS1wEA4BMBAKLUAQCGEQEAfCwAAFQDAQBG4AEA4hQBAObVAQC2EgEASf8AAPIPAQBJAQAAIAACABXuAQAeFQEAHCEAAI8BAQBYLwAAsQMBABgvAABxAwEADPwAAI8KAgB11AEAXBEBABDXAQDeEwEAE/4AALEOAQCr1wEAeRQBAMUkAAAiAwEAnfsAAAcKAQDLJAAAKAMBALruAQCVFQEADv8AALcPAQDe1QEArhIBAGrVAQA6EgEAjSQAAJwCAgCg1QEAcBIBAGX9AABIDQMAXjEAAGkEAQBY+wAAwgkBANXUAQCxEQEA/zIAAIQGAgB67gEAXxUBAODMAQDOEAEAN9YBAAcTAQDBJAAAHgMBAFj8AAAnCwIAGi8AAHMDAQD8/QAAqg4EAJckAAC2AgMAo/0AAPwNAwAqMgAAOQUDAIovAADjAwEAXqsAAJEJAQBv1QEAPxIBAAPVAQDfEQEAK/0AAOMMAgB41wEARhQBAA3VAQDmEQEAiSQAAJQCAgBu1AEAVREBAG4vAADHAwEALyAAACcBAQCoIAAAawECAD3+AADIDgEAI/0AANMMAgBIMQAAUwQBAD3UAQAlEQEAMdUBAAgSAQCqMwAAqwgDAKsHAQC1EAEAbTEAAHgEAQD4+wEAihYBAAHxAQCZFQIAiP8AADEQAQCp1wEAdxQBAOfXAQCzFAEAwB8AAPIAAgAyMQAAPQQBAMLXAQCQFAEAnP0AAOcNAwCF/wAALhABAMIvAAAbBAEAJzMAACYHAgCF/AAAjQsCAOkkAABGAwEA59QBAMMRAQAwMgAASwUDAG8zAAAdCAMAd/0AAH4NAwA+MQAASQQBAOozAABECQMAbPwAAFsLAgAl8gEAThYBAALyAQA3FgEA8/sAAFMKAwB4LwAA0QMBAHj+AAADDwIALi8AAIcDAQDz/gAAkg8BAGTuAQBOFQEASh0AAKQAAQAx1AEAGREBAG7uAQBVFQEAcDEAAHsEAQBq/wAAExABAKb8AADUCwIAJjIAAC0FAwAx8gEAWhYBACYgAAAkAQMA/dcBAMkUAQAB+wAAlgkCALrVAQCKEgEA6TIAAG4GAQA81gEADBMBAJ8HAQCpEAEAbdYBAD0TAQA0MwAAWQcGAGr8AABXCwIAFC8AAG0DAQBGMQAAUQQBADDgAQDMFAEAbNUBADwSAQCVMgAA9gUBAA78AACTCgIAFtQBAP4QAQC2AgAARAABAIczAABVCAIA+P0AAIkOBAC6MgAAJAYCAIb+AAAeDwIAyjIAAEUGAwBrIQAA+gEDALYvAAAPBAEAnf0AAOoNAwDALwAAGQQBALbVAQCGEgEAqP8AAFEQAQCIMQAAkwQBAJrWAQBqEwEALdYBAP0SAQDy/AAAbgwDAC//AADYDwEARNcBABIUAQBTMgAAkgUCAAIgAAAVAQEAwzIAADYGAgDb/AAAPgwCAJ/UAQCFEQEA6fsAADcKAQAY/wAAwQ8BAGExAABsBAEAu+4BAJYVAQBW1AEAPREBAPPXAQC/FAEAtv8AAF8QAQBj1gEAMxMBAPTMAQDiEAEAuP8AAGEQAQABMgAAqwQDANf8AAA2DAIADy8AAGgDAQBQIQAAswEDAIbUAQBtEQEAP/8AAOgPAQAPIQAAhAEBADbuAQA5FQEAQOABANwUAQCZ1QEAaRIBAFjVAQAoEgEAuNUBAIgSAQAi1AEAChEBAF/XAQAtFAEAR/wAAAULAgAb/QAAwwwCAGkhAAD3AQEAvv8AAGcQAQAS1gEA4hIBAFX7AAC/CQEAi9YBAFsTAQBL8QEAIBYCAALWAQDSEgEAxtQBAKIRAQCOJAAAngICACjUAQAQEQEAa/wAAFkLAgBpMQAAdAQBACfWAQD3EgEAyCQAACUDAQDr/gAAig8BAKQvAAD9AwEAmv0AAOENAwA24AEA0hQBAGMdAAC8AAEAgdYBAFETAQDq1gEAuBMBAMb+AABlDwEAbO4BAFMVAQAsIgAAIgICADgzAABqBwQAE9cBAOETAQCM/gAAKg8CAHczAAAxCAIAVOABAPAUAQCM1AEAcxEBAA/XAQDdEwEA0NQBAKwRAQAD1AEA6xABAMcBAAAsAAIAsf4AAFAPAQAG1gEA1hIBAHb7AADgCQEAdi8AAM8DAQA11wEAAxQBABzuAQAlFQEAKTMAACoHAwCCMQAAjQQBABr/AADDDwEAZi8AAL8DAQAbIQAAjgEBALv+AABaDwEAKf8AANIPAQAu7gEAMhUBAPinAACNCQEAu9QBAJkRAQD8MwAAegkDAO/+AACODwEApu4BAIIVAQAo1wEA9hMBAGQvAAC9AwEAoQcBAKsQAQA11QEADBIBALzVAQCMEgEAf/0AAJYNAwDsMwAASgkDAKodAADUAAEAYvwAAD8LAwAq1gEA+hIBALodAADkAAEAYyQAAC8CAQDi/AAATgwCAFwvAAC1AwEAif0AALQNAwD01AEA0BEBAEYhAACvAQEAkS8AAOoDAQBrJAAAOQICAK0HAQC3EAEAC/wAAI0KAgC8/wAAZRABAJ79AADtDQMAgf8AACoQAQBd4AEA+RQBAKz8AADgCwIAdNcBAEIUAQA+IAAANAECAAHUAQDpEAEACv8AALMPAQBo7gEAUBUBAEnuAQA/FQEAGSEAAIwBAQCC+wAA7AkBAGAzAADyBwIAkCQAAKICAgC3BwEAwBABAJgxAACgBAEAbC8AAMUDAQAk7gEAKxUBALL9AAApDgMA9f4AAJQPAwBTLwAArAMBAM7UAQCqEQEANNcBAAIUAQAGIQAAeQEDAIsvAADkAwEAUS8AAKoDAQDM1AEAqBEBANAkAAAtAwEA5jMAADsJAgAL/wAAtA8BAJH8AAClCwIA7R8AAAkBAwDV1gEAoxMBAE0vAACmAwEAsi8AAAsEAQDEMgAAOAYCAOP/AACAEAIA09YBAKETAQClMgAABgYBAGX7AADPCQEACDIAAMAEAwCuHQAA2AABAJT+AAAzDwEAozIAAAQGAQAJ1wEA1xMBAMrUAQCmEQEAjfwAAJ0LAgA34AEA0xQBAHXWAQBFEwEA4dUBALESAQDh1AEAvREBAH4yAADfBQIAv/wAAAYMAgCCJAAAegIEAIMvAADcAwEA5PwAAFIMAgBZMgAAngUCANEkAAAuAwEAhAMAAFoAAgDWJAAAMwMBADshAACmAQMA0wMAAGIAAgD3MgAAfAYBAHD8AABjCwIAmDMAAH4IAgDJMgAAQgYDALUzAADMCAIAb/sAANkJAQArLwAAhAMBAHD/AAAZEAEAWP0AACENAwCwMgAAEQYBAC7WAQD+EgEArS8AAAYEAQCJ+wAA8wkBAIT+AAAaDwIA6vsAADgKAwAj8gEATBYBAO3MAQDbEAEA0i8AACsEAQBD8gEAbhYDAALuAQAMFQEAeP0AAIENAwBi1QEAMhIBAADxAQCXFQIAPtQBACYRAQCwAgAAPgABACL9AADRDAIA2jMAACEJAgBOMQAAWQQBAPjVAQDIEgEAvP4AAFsPAQAz1QEAChIBAEMyAACEBQMAiNcBAFYUAQBq+wAA1AkBAB7UAQAGEQEAfiEAAB0CAQC7/QAARA4DAJseAADsAAIAdTIAAMgFAgAV/QAAtwwCAAH8AAB1CgMAfPwAAHsLAgBBMwAAjgcDAGj7AADSCQEAqC8AAAEEAQAWMgAA8gQEAJz/AABFEAEAIPsAAK4JAQAj/wAAzA8BAOvVAQC7EgEAKfIBAFIWAQD+1AEA2hEBAJz8AADACwIAFO4BAB0VAQDUMgAAWQYBALgAAAALAAIAIfIBAEoWAQCI/QAAsQ0DAGLWAQAyEwEAqv0AABEOAwBN/AAAEQsCADYhAACiAQEAKtcBAPgTAQCvMgAAEAYBAOEzAAAxCQIAIPEBAN0VAwAL1gEA2xIBAKnWAQB3EwEA6/8AAIgQAQBz1QEAQxIBAAf8AACFCgIANDEAAD8EAQBW/QAAGw0DADDWAQAAEwEAFiEAAIoBAgCS+wAA/AkBANQkAAAxAwEAkSQAAKQCAwAt/AAA0QoCAAj8AACHCgIAstcBAIAUAQD/MwAAgwkDAGYdAAC/AAEAmS8AAPIDAQAEIAAAFwEBALTXAQCCFAEAlPsAAP4JAQBh/gAA7Q4BAGMvAAC8AwEAJPwAAL8KAgAG/AAAgwoCAKvVAQB7EgEAVyAAADwBBAB5/wAAIhABAD3XAQALFAEAYiQAAC4CAQBHIQAAsAEBACj8AADHCgIAWzEAAGYEAQB3/wAAIBABACfxAQDyFQMARB0AAJ4AAQAb1wEA6RMBAFz8AAAvCwIAOzIAAGwFAwDIAQAALgACAGL9AAA/DQMAn/sAAAkKAQDc1AEAuBEBADvgAQDXFAEASdcBABcUAQDD/wAAaRABAIExAACMBAEAyNUBAJgSAQAq1QEAARIBANjVAQCoEgEASdQBADERAQDT/wAAdRABAEcgAAA2AQIAYDIAAKwFAQB27gEAXBUBADn+AADEDgEAdP8AAB0QAQA38QEACxYBAIr9AAC3DQMAKv8AANMPAQA21QEADRIBAPMyAAB4BgEAZ9QBAE4RAQA8/gAAxw4BAKH+AABADwEADdYBAN0SAQAJ7gEAEhUBABLuAQAbFQEAGDMAAOoGBACW1gEAZhMBAKEzAACSCAIAev4AAAcPAgDQ1gEAnhMBAEL+AADNDgEAudYBAIcTAQBh/AAAPAsDAA3/AAC2DwEA3tYBAKwTAQB6+wAA5AkBAELXAQAQFAEATC8AAKUDAQBF1gEAFRMBALD+AABPDwEAXDIAAKQFAgDO1gEAnBMBAP3UAQDZEQEAMv4AAL0OAQC+LwAAFwQBAPTWAQDCEwEAQzMAAJQHBADb/gAAeg8BANvXAQCnFAEA4tcBAK4UAQAa1QEA8hEBABAvAABpAwEApgcBALAQAQCELwAA3QMBAEEdAACbAAEAGNcBAOYTAQCdHQAAxwABANvUAQC3EQEAli8AAO8DAQBO7gEAQhUBAO3XAQC5FAEAqjIAAAsGAQCI7gEAaxUBAHAyAAC+BQIA6/wAAGAMAgCt7gEAiBUBAIfVAQBXEgEA7MwBANoQAQCFMgAA5gUBAJnuAQB7FQEAjtcBAFwUAQAHIAAAGgEBALIHAQC7EAEAyDMAAPcIAgDkMgAAaQYBAD38AADxCgIAtNQBAJMRAQBG8gEAdxYDAL79AABNDgMAYCEAAOMBAQAv1wEA/RMBAHP8AABpCwIAzR8AAPcAAwA+LwAAlwMBACH9AADPDAIAWDMAAOIHAgCK1wEAWBQBADcvAACQAwEAqAAAAAEAAgBT1AEAOxEBAGPXAQAxFAEA7P4AAIsPAQAlMwAAIAcDAHgxAACDBAEAtP4AAFMPAQAd1AEABREBAEgvAAChAwEAk+4BAHUVAQCi7gEAfxUBAAEhAABwAQMAktUBAGISAQA3IQAAowEBALUyAAAaBgIAoAAAAAAAAQDxMwAAWQkDAFH+AADeDgEAeQ8AAIQAAwAt1QEABBIBALbUAQCVEQEA8P4AAI8PAQC3MwAA0AgCAHv7AADlCQEAWtUBACoSAQAz/AAA3QoCAB4zAAAEBwQAgv0AAJ8NAwBzLwAAzAMBAFngAQD1FAEAif8AADIQAQD11gEAwxMBACAAAAAgAAAACAMAAGEAAAAgAAAABAMAADIAAAAzAAAAIAAAAAEDAAC8AwAAIAAAACcDAAAxAAAAbwAAADEAAABEIAAANAAAADEAAABEIAAAMgAAADMAAABEIAAANAAAAEkAAABKAAAAaQAAAGoAAABMAAAAtwAAAGwAAAC3AAAAvAIAAG4AAABzAAAARAAAAFoAAAAMAwAARAAAAHoAAAAMAwAAZAAAAHoAAAAMAwAATAAAAEoAAABMAAAAagAAAGwAAABqAAAATgAAAEoAAABOAAAAagAAAG4AAABqAAAARAAAAFoAAABEAAAAegAAAGQAAAB6AAAAaAAAAGYCAABqAAAAcgAAAHkCAAB7AgAAgQIAAHcAAAB5AAAAIAAAAAYDAAAgAAAABwMAACAAAAAKAwAAIAAAACgDAAAgAAAAAwMAACAAAAALAwAAYwIAAGwAAABzAAAAeAAAAJUCAAAgAAAARQMAACAAAAABAwAAIAAAAAgDAAABAwAAsgMAALgDAAClAwAApQMAAAEDAAClAwAACAMAAMYDAADAAwAAugMAAMEDAADCAwAAmAMAALUDAACjAwAAZQUAAIIFAAAnBgAAdAYAAEgGAAB0BgAAxwYAAHQGAABKBgAAdAYAAE0OAAAyDgAAzQ4AALIOAACrDgAAmQ4AAKsOAAChDgAACw8AALIPAABxDwAAgA8AALMPAABxDwAAgA8AANwQAABBAAAAxgAAAEIAAABEAAAARQAAAI4BAABHAAAASAAAAEkAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAAAiAgAAUAAAAFIAAABUAAAAVQAAAFcAAABhAAAAUAIAAFECAAACHQAAYgAAAGQAAABlAAAAWQIAAFsCAABcAgAAZwAAAGsAAABtAAAASwEAAG8AAABUAgAAFh0AABcdAABwAAAAdAAAAHUAAAAdHQAAbwIAAHYAAAAlHQAAsgMAALMDAAC0AwAAxgMAAMcDAABpAAAAcgAAAHUAAAB2AAAAsgMAALMDAADBAwAAxgMAAMcDAAA9BAAAUgIAAGMAAABVAgAA8AAAAFwCAABmAAAAXwIAAGECAABlAgAAaAIAAGkCAABqAgAAex0AAJ0CAABtAgAAhR0AAJ8CAABxAgAAcAIAAHICAABzAgAAdAIAAHUCAAB4AgAAggIAAIMCAACrAQAAiQIAAIoCAAAcHQAAiwIAAIwCAAB6AAAAkAIAAJECAACSAgAAuAMAAGEAAAC+AgAAcwAAAAcDAAAgAAAAEwMAACAAAAATAwAAIAAAAEIDAAAgAAAACAMAAEIDAAAgAAAAEwMAAAADAAAgAAAAEwMAAAEDAAAgAAAAEwMAAEIDAAAgAAAAFAMAAAADAAAgAAAAFAMAAAEDAAAgAAAAFAMAAEIDAAAgAAAACAMAAAADAAAgAAAACAMAAAEDAAAgAAAAAQMAACAAAAAUAwAAIAAAACAAAAAgAAAAIAAAACAAAAAgAAAAIAAAACAAAAAgAAAAIAAAACAAAAAQIAAAIAAAADMDAAAuAAAALgAAAC4AAAAuAAAALgAAAC4AAAAgAAAAMiAAADIgAAAyIAAAMiAAADIgAAA1IAAANSAAADUgAAA1IAAANSAAACEAAAAhAAAAIAAAAAUDAAA/AAAAPwAAAD8AAAAhAAAAIQAAAD8AAAAyIAAAMiAAADIgAAAyIAAAIAAAADAAAABpAAAANAAAADUAAAA2AAAANwAAADgAAAA5AAAAKwAAABIiAAA9AAAAKAAAACkAAABuAAAAMAAAADEAAAAyAAAAMwAAADQAAAA1AAAANgAAADcAAAA4AAAAOQAAACsAAAASIgAAPQAAACgAAAApAAAAYQAAAGUAAABvAAAAeAAAAFkCAABoAAAAawAAAGwAAABtAAAAbgAAAHAAAABzAAAAdAAAAFIAAABzAAAAYQAAAC8AAABjAAAAYQAAAC8AAABzAAAAQwAAALAAAABDAAAAYwAAAC8AAABvAAAAYwAAAC8AAAB1AAAAkAEAALAAAABGAAAAZwAAAEgAAABIAAAASAAAAGgAAAAnAQAASQAAAEkAAABMAAAAbAAAAE4AAABOAAAAbwAAAFAAAABRAAAAUgAAAFIAAABSAAAAUwAAAE0AAABUAAAARQAAAEwAAABUAAAATQAAAFoAAABaAAAAQgAAAEMAAABlAAAARQAAAEYAAABNAAAAbwAAANAFAADRBQAA0gUAANMFAABpAAAARgAAAEEAAABYAAAAwAMAALMDAACTAwAAoAMAABEiAABEAAAAZAAAAGUAAABpAAAAagAAADEAAABEIAAANwAAADEAAABEIAAAOQAAADEAAABEIAAAMQAAADAAAAAxAAAARCAAADMAAAAyAAAARCAAADMAAAAxAAAARCAAADUAAAAyAAAARCAAADUAAAAzAAAARCAAADUAAAA0AAAARCAAADUAAAAxAAAARCAAADYAAAA1AAAARCAAADYAAAAxAAAARCAAADgAAAAzAAAARCAAADgAAAA1AAAARCAAADgAAAA3AAAARCAAADgAAAAxAAAARCAAAEkAAABJAAAASQAAAEkAAABJAAAASQAAAEkAAABWAAAAVgAAAFYAAABJAAAAVgAAAEkAAABJAAAAVgAAAEkAAABJAAAASQAAAEkAAABYAAAAWAAAAFgAAABJAAAAWAAAAEkAAABJAAAATAAAAEMAAABEAAAATQAAAGkAAABpAAAAaQAAAGkAAABpAAAAaQAAAGkAAAB2AAAAdgAAAHYAAABpAAAAdgAAAGkAAABpAAAAdgAAAGkAAABpAAAAaQAAAGkAAAB4AAAAeAAAAHgAAABpAAAAeAAAAGkAAABpAAAAbAAAAGMAAABkAAAAbQAAADAAAABEIAAAMwAAACsiAAArIgAAKyIAACsiAAArIgAALiIAAC4iAAAuIgAALiIAAC4iAAAxAAAAMgAAADMAAAA0AAAANQAAADYAAAA3AAAAOAAAADkAAAAxAAAAMAAAADEAAAAxAAAAMQAAADIAAAAxAAAAMwAAADEAAAA0AAAAMQAAADUAAAAxAAAANgAAADEAAAA3AAAAMQAAADgAAAAxAAAAOQAAADIAAAAwAAAAKAAAADEAAAApAAAAKAAAADIAAAApAAAAKAAAADMAAAApAAAAKAAAADQAAAApAAAAKAAAADUAAAApAAAAKAAAADYAAAApAAAAKAAAADcAAAApAAAAKAAAADgAAAApAAAAKAAAADkAAAApAAAAKAAAADEAAAAwAAAAKQAAACgAAAAxAAAAMQAAACkAAAAoAAAAMQAAADIAAAApAAAAKAAAADEAAAAzAAAAKQAAACgAAAAxAAAANAAAACkAAAAoAAAAMQAAADUAAAApAAAAKAAAADEAAAA2AAAAKQAAACgAAAAxAAAANwAAACkAAAAoAAAAMQAAADgAAAApAAAAKAAAADEAAAA5AAAAKQAAACgAAAAyAAAAMAAAACkAAAAxAAAALgAAADIAAAAuAAAAMwAAAC4AAAA0AAAALgAAADUAAAAuAAAANgAAAC4AAAA3AAAALgAAADgAAAAuAAAAOQAAAC4AAAAxAAAAMAAAAC4AAAAxAAAAMQAAAC4AAAAxAAAAMgAAAC4AAAAxAAAAMwAAAC4AAAAxAAAANAAAAC4AAAAxAAAANQAAAC4AAAAxAAAANgAAAC4AAAAxAAAANwAAAC4AAAAxAAAAOAAAAC4AAAAxAAAAOQAAAC4AAAAyAAAAMAAAAC4AAAAoAAAAYQAAACkAAAAoAAAAYgAAACkAAAAoAAAAYwAAACkAAAAoAAAAZAAAACkAAAAoAAAAZQAAACkAAAAoAAAAZgAAACkAAAAoAAAAZwAAACkAAAAoAAAAaAAAACkAAAAoAAAAaQAAACkAAAAoAAAAagAAACkAAAAoAAAAawAAACkAAAAoAAAAbAAAACkAAAAoAAAAbQAAACkAAAAoAAAAbgAAACkAAAAoAAAAbwAAACkAAAAoAAAAcAAAACkAAAAoAAAAcQAAACkAAAAoAAAAcgAAACkAAAAoAAAAcwAAACkAAAAoAAAAdAAAACkAAAAoAAAAdQAAACkAAAAoAAAAdgAAACkAAAAoAAAAdwAAACkAAAAoAAAAeAAAACkAAAAoAAAAeQAAACkAAAAoAAAAegAAACkAAABBAAAAQgAAAEMAAABEAAAARQAAAEYAAABHAAAASAAAAEkAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAABQAAAAUQAAAFIAAABTAAAAVAAAAFUAAABWAAAAVwAAAFgAAABZAAAAWgAAAGEAAABiAAAAYwAAAGQAAABlAAAAZgAAAGcAAABoAAAAaQAAAGoAAABrAAAAbAAAAG0AAABuAAAAbwAAAHAAAABxAAAAcgAAAHMAAAB0AAAAdQAAAHYAAAB3AAAAeAAAAHkAAAB6AAAAMAAAACsiAAArIgAAKyIAACsiAAA6AAAAOgAAAD0AAAA9AAAAPQAAAD0AAAA9AAAAPQAAAGoAAABWAAAAYS0AAM1rAACfnwAAAE4AAChOAAA2TgAAP04AAFlOAACFTgAAjE4AAKBOAAC6TgAAP1EAAGVRAABrUQAAglEAAJZRAACrUQAA4FEAAPVRAAAAUgAAm1IAAPlSAAAVUwAAGlMAADhTAABBUwAAXFMAAGlTAACCUwAAtlMAAMhTAADjUwAA11YAAB9XAADrWAAAAlkAAApZAAAVWQAAJ1kAAHNZAABQWwAAgFsAAPhbAAAPXAAAIlwAADhcAABuXAAAcVwAANtdAADlXQAA8V0AAP5dAAByXgAAel4AAH9eAAD0XgAA/l4AAAtfAAATXwAAUF8AAGFfAABzXwAAw18AAAhiAAA2YgAAS2IAAC9lAAA0ZQAAh2UAAJdlAACkZQAAuWUAAOBlAADlZQAA8GYAAAhnAAAoZwAAIGsAAGJrAAB5awAAs2sAAMtrAADUawAA22sAAA9sAAAUbAAANGwAAGtwAAAqcgAANnIAADtyAAA/cgAAR3IAAFlyAABbcgAArHIAAIRzAACJcwAA3HQAAOZ0AAAYdQAAH3UAACh1AAAwdQAAi3UAAJJ1AAB2dgAAfXYAAK52AAC/dgAA7nYAANt3AADidwAA83cAADp5AAC4eQAAvnkAAHR6AADLegAA+XoAAHN8AAD4fAAANn8AAFF/AACKfwAAvX8AAAGAAAAMgAAAEoAAADOAAAB/gAAAiYAAAOOBAADqgQAA84EAAPyBAAAMggAAG4IAAB+CAABuggAAcoIAAHiCAABNhgAAa4YAAECIAABMiAAAY4gAAH6JAACLiQAA0okAAACKAAA3jAAARowAAFWMAAB4jAAAnYwAAGSNAABwjQAAs40AAKuOAADKjgAAm48AALCPAAC1jwAAkZAAAEmRAADGkQAAzJEAANGRAAB3lQAAgJUAAByWAAC2lgAAuZYAAOiWAABRlwAAXpcAAGKXAABplwAAy5cAAO2XAADzlwAAAZgAAKiYAADbmAAA35gAAJaZAACZmQAArJkAAKiaAADYmgAA35oAACWbAAAvmwAAMpsAADybAABamwAA5ZwAAHWeAAB/ngAApZ4AALueAADDngAAzZ4AANGeAAD5ngAA/Z4AAA6fAAATnwAAIJ8AADufAABKnwAAUp8AAI2fAACcnwAAoJ8AACAAAAASMAAAQVMAAERTAABFUwAAIAAAAJkwAAAgAAAAmjAAAIgwAACKMAAAszAAAMgwAAAAEQAAAREAAKoRAAACEQAArBEAAK0RAAADEQAABBEAAAURAACwEQAAsREAALIRAACzEQAAtBEAALURAAAaEQAABhEAAAcRAAAIEQAAIREAAAkRAAAKEQAACxEAAAwRAAANEQAADhEAAA8RAAAQEQAAEREAABIRAABhEQAAYhEAAGMRAABkEQAAZREAAGYRAABnEQAAaBEAAGkRAABqEQAAaxEAAGwRAABtEQAAbhEAAG8RAABwEQAAcREAAHIRAABzEQAAdBEAAHURAABgEQAAFBEAABURAADHEQAAyBEAAMwRAADOEQAA0xEAANcRAADZEQAAHBEAAN0RAADfEQAAHREAAB4RAAAgEQAAIhEAACMRAAAnEQAAKREAACsRAAAsEQAALREAAC4RAAAvEQAAMhEAADYRAABAEQAARxEAAEwRAADxEQAA8hEAAFcRAABYEQAAWREAAIQRAACFEQAAiBEAAJERAACSEQAAlBEAAJ4RAAChEQAAAE4AAIxOAAAJTgAA21YAAApOAAAtTgAAC04AADJ1AABZTgAAGU4AAAFOAAApWQAAMFcAALpOAAAoAAAAABEAACkAAAAoAAAAAhEAACkAAAAoAAAAAxEAACkAAAAoAAAABREAACkAAAAoAAAABhEAACkAAAAoAAAABxEAACkAAAAoAAAACREAACkAAAAoAAAACxEAACkAAAAoAAAADBEAACkAAAAoAAAADhEAACkAAAAoAAAADxEAACkAAAAoAAAAEBEAACkAAAAoAAAAEREAACkAAAAoAAAAEhEAACkAAAAoAAAAABEAAGERAAApAAAAKAAAAAIRAABhEQAAKQAAACgAAAADEQAAYREAACkAAAAoAAAABREAAGERAAApAAAAKAAAAAYRAABhEQAAKQAAACgAAAAHEQAAYREAACkAAAAoAAAACREAAGERAAApAAAAKAAAAAsRAABhEQAAKQAAACgAAAAMEQAAYREAACkAAAAoAAAADhEAAGERAAApAAAAKAAAAA8RAABhEQAAKQAAACgAAAAQEQAAYREAACkAAAAoAAAAEREAAGERAAApAAAAKAAAABIRAABhEQAAKQAAACgAAAAMEQAAbhEAACkAAAAoAAAACxEAAGkRAAAMEQAAZREAAKsRAAApAAAAKAAAAAsRAABpEQAAEhEAAG4RAAApAAAAKAAAAABOAAApAAAAKAAAAIxOAAApAAAAKAAAAAlOAAApAAAAKAAAANtWAAApAAAAKAAAAJROAAApAAAAKAAAAG1RAAApAAAAKAAAAANOAAApAAAAKAAAAGtRAAApAAAAKAAAAF1OAAApAAAAKAAAAEFTAAApAAAAKAAAAAhnAAApAAAAKAAAAGtwAAApAAAAKAAAADRsAAApAAAAKAAAAChnAAApAAAAKAAAANGRAAApAAAAKAAAAB9XAAApAAAAKAAAAOVlAAApAAAAKAAAACpoAAApAAAAKAAAAAlnAAApAAAAKAAAAD55AAApAAAAKAAAAA1UAAApAAAAKAAAAHlyAAApAAAAKAAAAKGMAAApAAAAKAAAAF15AAApAAAAKAAAALRSAAApAAAAKAAAAONOAAApAAAAKAAAAHxUAAApAAAAKAAAAGZbAAApAAAAKAAAAON2AAApAAAAKAAAAAFPAAApAAAAKAAAAMeMAAApAAAAKAAAAFRTAAApAAAAKAAAAG15AAApAAAAKAAAABFPAAApAAAAKAAAAOqBAAApAAAAKAAAAPOBAAApAAAAT1UAAHxeAACHZQAAj3sAAFAAAABUAAAARQAAADIAAAAxAAAAMgAAADIAAAAyAAAAMwAAADIAAAA0AAAAMgAAADUAAAAyAAAANgAAADIAAAA3AAAAMgAAADgAAAAyAAAAOQAAADMAAAAwAAAAMwAAADEAAAAzAAAAMgAAADMAAAAzAAAAMwAAADQAAAAzAAAANQAAAAARAAACEQAAAxEAAAURAAAGEQAABxEAAAkRAAALEQAADBEAAA4RAAAPEQAAEBEAABERAAASEQAAABEAAGERAAACEQAAYREAAAMRAABhEQAABREAAGERAAAGEQAAYREAAAcRAABhEQAACREAAGERAAALEQAAYREAAAwRAABhEQAADhEAAGERAAAPEQAAYREAABARAABhEQAAEREAAGERAAASEQAAYREAAA4RAABhEQAAtxEAAAARAABpEQAADBEAAG4RAAALEQAAdBEAAAsRAABuEQAAAE4AAIxOAAAJTgAA21YAAJROAABtUQAAA04AAGtRAABdTgAAQVMAAAhnAABrcAAANGwAAChnAADRkQAAH1cAAOVlAAAqaAAACWcAAD55AAANVAAAeXIAAKGMAABdeQAAtFIAANh5AAA3dQAAc1kAAGmQAAAqUQAAcFMAAOhsAAAFmAAAEU8AAJlRAABjawAACk4AAC1OAAALTgAA5l0AAPNTAAA7UwAAl1sAAGZbAADjdgAAAU8AAMeMAABUUwAAHFkAADMAAAA2AAAAMwAAADcAAAAzAAAAOAAAADMAAAA5AAAANAAAADAAAAA0AAAAMQAAADQAAAAyAAAANAAAADMAAAA0AAAANAAAADQAAAA1AAAANAAAADYAAAA0AAAANwAAADQAAAA4AAAANAAAADkAAAA1AAAAMAAAADEAAAAIZwAAMgAAAAhnAAAzAAAACGcAADQAAAAIZwAANQAAAAhnAAA2AAAACGcAADcAAAAIZwAAOAAAAAhnAAA5AAAACGcAADEAAAAwAAAACGcAADEAAAAxAAAACGcAADEAAAAyAAAACGcAAEgAAABnAAAAZQAAAHIAAABnAAAAZQAAAFYAAABMAAAAVAAAAEQAAACiMAAApDAAAKYwAACoMAAAqjAAAKswAACtMAAArzAAALEwAACzMAAAtTAAALcwAAC5MAAAuzAAAL0wAAC/MAAAwTAAAMQwAADGMAAAyDAAAMowAADLMAAAzDAAAM0wAADOMAAAzzAAANIwAADVMAAA2DAAANswAADeMAAA3zAAAOAwAADhMAAA4jAAAOQwAADmMAAA6DAAAOkwAADqMAAA6zAAAOwwAADtMAAA7zAAAPAwAADxMAAA8jAAAOROAACMVAAAojAAAM8wAACaMAAA/DAAAMgwAACiMAAA6zAAANUwAAChMAAAojAAAPMwAADYMAAAmjAAAKIwAACiMAAA/DAAAOswAACkMAAAyzAAAPMwAACvMAAAmTAAAKQwAADzMAAAwTAAAKYwAACpMAAA8zAAAKgwAAC5MAAArzAAAPwwAADIMAAAmTAAAKgwAAD8MAAAqzAAAPwwAACqMAAA8zAAALkwAACqMAAA/DAAAOAwAACrMAAApDAAAOowAACrMAAA6TAAAMMwAADIMAAAqzAAAO0wAADqMAAA/DAAAKswAACZMAAA7TAAAPMwAACrMAAAmTAAAPMwAADeMAAArTAAAJkwAACrMAAAmTAAAK0wAACZMAAAyzAAAPwwAACtMAAA5TAAAOowAAD8MAAArTAAAJkwAADrMAAAvzAAAJkwAAD8MAAArTAAAO0wAACtMAAA7TAAAK8wAACZMAAA6TAAAOAwAACtMAAA7TAAAOEwAAD8MAAAyDAAAOswAACtMAAA7TAAAO8wAADDMAAAyDAAAK8wAACZMAAA6TAAAOAwAACvMAAAmTAAAOkwAADgMAAAyDAAAPMwAACvMAAA6zAAALswAACZMAAApDAAAO0wAACvMAAA7TAAAPwwAADNMAAAsTAAAPwwAAC5MAAAszAAAOswAADKMAAAszAAAPwwAADbMAAAmjAAALUwAACkMAAArzAAAOswAAC1MAAA8zAAAMEwAAD8MAAA4DAAALcwAADqMAAA8zAAAK8wAACZMAAAuzAAAPMwAADBMAAAuzAAAPMwAADIMAAAvzAAAJkwAAD8MAAAuTAAAMYwAACZMAAAtzAAAMgwAACZMAAA6zAAAMgwAADzMAAAyjAAAM4wAADOMAAAwzAAAMgwAADPMAAApDAAAMQwAADPMAAAmjAAAPwwAAC7MAAA8zAAAMgwAADPMAAAmjAAAPwwAADEMAAAzzAAAJkwAAD8MAAA7DAAAOswAADSMAAAmjAAAKIwAAC5MAAAyDAAAOswAADSMAAAmjAAAK8wAADrMAAA0jAAAJowAACzMAAA0jAAAJkwAADrMAAA1TAAAKEwAADpMAAAwzAAAMgwAACZMAAA1TAAAKMwAAD8MAAAyDAAANUwAACZMAAAwzAAALcwAACnMAAA6zAAANUwAADpMAAA8zAAANgwAACvMAAAvzAAAPwwAADrMAAA2DAAAJowAAC9MAAA2DAAAJowAADLMAAA0jAAANgwAADrMAAAxDAAANgwAACaMAAA8zAAALkwAADYMAAAmjAAAPwwAAC3MAAAmTAAANgwAACZMAAA/DAAAL8wAADbMAAAmjAAAKQwAADzMAAAyDAAANswAACZMAAA6zAAAMgwAADbMAAA8zAAANswAACaMAAA8zAAAMgwAACZMAAA2zAAAPwwAADrMAAA2zAAAPwwAADzMAAA3jAAAKQwAACvMAAA7TAAAN4wAACkMAAA6zAAAN4wAADDMAAAzzAAAN4wAADrMAAArzAAAN4wAADzMAAAtzAAAOcwAADzMAAA3zAAAK8wAADtMAAA8zAAAN8wAADqMAAA3zAAAOowAADPMAAAmTAAAPwwAADrMAAA4TAAAKswAACZMAAA4TAAAKswAACZMAAAyDAAAPMwAADhMAAA/DAAAMgwAADrMAAA5DAAAPwwAADIMAAAmTAAAOQwAAD8MAAA6zAAAOYwAACiMAAA8zAAAOowAADDMAAAyDAAAOswAADqMAAA6TAAAOswAADSMAAAmjAAAPwwAADrMAAA/DAAANUwAACZMAAA6zAAAOwwAADgMAAA7DAAAPMwAADIMAAAsTAAAJkwAADzMAAA7zAAAMMwAADIMAAAMAAAALlwAAAxAAAAuXAAADIAAAC5cAAAMwAAALlwAAA0AAAAuXAAADUAAAC5cAAANgAAALlwAAA3AAAAuXAAADgAAAC5cAAAOQAAALlwAAAxAAAAMAAAALlwAAAxAAAAMQAAALlwAAAxAAAAMgAAALlwAAAxAAAAMwAAALlwAAAxAAAANAAAALlwAAAxAAAANQAAALlwAAAxAAAANgAAALlwAAAxAAAANwAAALlwAAAxAAAAOAAAALlwAAAxAAAAOQAAALlwAAAyAAAAMAAAALlwAAAyAAAAMQAAALlwAAAyAAAAMgAAALlwAAAyAAAAMwAAALlwAAAyAAAANAAAALlwAABoAAAAUAAAAGEAAABkAAAAYQAAAEEAAABVAAAAYgAAAGEAAAByAAAAbwAAAFYAAABwAAAAYwAAAGQAAABtAAAAZAAAAG0AAAAyAAAAZAAAAG0AAAAzAAAASQAAAFUAAABzXgAAEGIAAC1mAACMVAAAJ1kAAGNrAAAOZgAAu2wAACpoAAAPXwAAGk8AAD55AABwAAAAQQAAAG4AAABBAAAAvAMAAEEAAABtAAAAQQAAAGsAAABBAAAASwAAAEIAAABNAAAAQgAAAEcAAABCAAAAYwAAAGEAAABsAAAAawAAAGMAAABhAAAAbAAAAHAAAABGAAAAbgAAAEYAAAC8AwAARgAAALwDAABnAAAAbQAAAGcAAABrAAAAZwAAAEgAAAB6AAAAawAAAEgAAAB6AAAATQAAAEgAAAB6AAAARwAAAEgAAAB6AAAAVAAAAEgAAAB6AAAAvAMAAGwAAABtAAAAbAAAAGQAAABsAAAAawAAAGwAAABmAAAAbQAAAG4AAABtAAAAvAMAAG0AAABtAAAAbQAAAGMAAABtAAAAawAAAG0AAABtAAAAbQAAADIAAABjAAAAbQAAADIAAABtAAAAMgAAAGsAAABtAAAAMgAAAG0AAABtAAAAMwAAAGMAAABtAAAAMwAAAG0AAAAzAAAAawAAAG0AAAAzAAAAbQAAABUiAABzAAAAbQAAABUiAABzAAAAMgAAAFAAAABhAAAAawAAAFAAAABhAAAATQAAAFAAAABhAAAARwAAAFAAAABhAAAAcgAAAGEAAABkAAAAcgAAAGEAAABkAAAAFSIAAHMAAAByAAAAYQAAAGQAAAAVIgAAcwAAADIAAABwAAAAcwAAAG4AAABzAAAAvAMAAHMAAABtAAAAcwAAAHAAAABWAAAAbgAAAFYAAAC8AwAAVgAAAG0AAABWAAAAawAAAFYAAABNAAAAVgAAAHAAAABXAAAAbgAAAFcAAAC8AwAAVwAAAG0AAABXAAAAawAAAFcAAABNAAAAVwAAAGsAAACpAwAATQAAAKkDAABhAAAALgAAAG0AAAAuAAAAQgAAAHEAAABjAAAAYwAAAGMAAABkAAAAQwAAABUiAABrAAAAZwAAAEMAAABvAAAALgAAAGQAAABCAAAARwAAAHkAAABoAAAAYQAAAEgAAABQAAAAaQAAAG4AAABLAAAASwAAAEsAAABNAAAAawAAAHQAAABsAAAAbQAAAGwAAABuAAAAbAAAAG8AAABnAAAAbAAAAHgAAABtAAAAYgAAAG0AAABpAAAAbAAAAG0AAABvAAAAbAAAAFAAAABIAAAAcAAAAC4AAABtAAAALgAAAFAAAABQAAAATQAAAFAAAABSAAAAcwAAAHIAAABTAAAAdgAAAFcAAABiAAAAVgAAABUiAABtAAAAQQAAABUiAABtAAAAMQAAAOVlAAAyAAAA5WUAADMAAADlZQAANAAAAOVlAAA1AAAA5WUAADYAAADlZQAANwAAAOVlAAA4AAAA5WUAADkAAADlZQAAMQAAADAAAADlZQAAMQAAADEAAADlZQAAMQAAADIAAADlZQAAMQAAADMAAADlZQAAMQAAADQAAADlZQAAMQAAADUAAADlZQAAMQAAADYAAADlZQAAMQAAADcAAADlZQAAMQAAADgAAADlZQAAMQAAADkAAADlZQAAMgAAADAAAADlZQAAMgAAADEAAADlZQAAMgAAADIAAADlZQAAMgAAADMAAADlZQAAMgAAADQAAADlZQAAMgAAADUAAADlZQAAMgAAADYAAADlZQAAMgAAADcAAADlZQAAMgAAADgAAADlZQAAMgAAADkAAADlZQAAMwAAADAAAADlZQAAMwAAADEAAADlZQAAZwAAAGEAAABsAAAASgQAAEwEAABvpwAAUwAAAEMAAABGAAAAUQAAACYBAABTAQAAJ6cAADerAABrAgAAUqsAAI0CAABmAAAAZgAAAGYAAABpAAAAZgAAAGwAAABmAAAAZgAAAGkAAABmAAAAZgAAAGwAAABzAAAAdAAAAHMAAAB0AAAAdAUAAHYFAAB0BQAAZQUAAHQFAABrBQAAfgUAAHYFAAB0BQAAbQUAAOIFAADQBQAA0wUAANQFAADbBQAA3AUAAN0FAADoBQAA6gUAACsAAADQBQAA3AUAAHEGAABxBgAAewYAAHsGAAB7BgAAewYAAH4GAAB+BgAAfgYAAH4GAACABgAAgAYAAIAGAACABgAAegYAAHoGAAB6BgAAegYAAH8GAAB/BgAAfwYAAH8GAAB5BgAAeQYAAHkGAAB5BgAApAYAAKQGAACkBgAApAYAAKYGAACmBgAApgYAAKYGAACEBgAAhAYAAIQGAACEBgAAgwYAAIMGAACDBgAAgwYAAIYGAACGBgAAhgYAAIYGAACHBgAAhwYAAIcGAACHBgAAjQYAAI0GAACMBgAAjAYAAI4GAACOBgAAiAYAAIgGAACYBgAAmAYAAJEGAACRBgAAqQYAAKkGAACpBgAAqQYAAK8GAACvBgAArwYAAK8GAACzBgAAswYAALMGAACzBgAAsQYAALEGAACxBgAAsQYAALoGAAC6BgAAuwYAALsGAAC7BgAAuwYAANUGAABUBgAA1QYAAFQGAADBBgAAwQYAAMEGAADBBgAAvgYAAL4GAAC+BgAAvgYAANIGAADSBgAA0gYAAFQGAADSBgAAVAYAAK0GAACtBgAArQYAAK0GAADHBgAAxwYAAMYGAADGBgAAyAYAAMgGAADHBgAAdAYAAMsGAADLBgAAxQYAAMUGAADJBgAAyQYAANAGAADQBgAA0AYAANAGAABJBgAASQYAAEoGAABUBgAAJwYAAEoGAABUBgAAJwYAAEoGAABUBgAA1QYAAEoGAABUBgAA1QYAAEoGAABUBgAASAYAAEoGAABUBgAASAYAAEoGAABUBgAAxwYAAEoGAABUBgAAxwYAAEoGAABUBgAAxgYAAEoGAABUBgAAxgYAAEoGAABUBgAAyAYAAEoGAABUBgAAyAYAAEoGAABUBgAA0AYAAEoGAABUBgAA0AYAAEoGAABUBgAA0AYAAEoGAABUBgAASQYAAEoGAABUBgAASQYAAEoGAABUBgAASQYAAMwGAADMBgAAzAYAAMwGAABKBgAAVAYAACwGAABKBgAAVAYAAC0GAABKBgAAVAYAAEUGAABKBgAAVAYAAEkGAABKBgAAVAYAAEoGAAAoBgAALAYAACgGAAAtBgAAKAYAAC4GAAAoBgAARQYAACgGAABJBgAAKAYAAEoGAAAqBgAALAYAACoGAAAtBgAAKgYAAC4GAAAqBgAARQYAACoGAABJBgAAKgYAAEoGAAArBgAALAYAACsGAABFBgAAKwYAAEkGAAArBgAASgYAACwGAAAtBgAALAYAAEUGAAAtBgAALAYAAC0GAABFBgAALgYAACwGAAAuBgAALQYAAC4GAABFBgAAMwYAACwGAAAzBgAALQYAADMGAAAuBgAAMwYAAEUGAAA1BgAALQYAADUGAABFBgAANgYAACwGAAA2BgAALQYAADYGAAAuBgAANgYAAEUGAAA3BgAALQYAADcGAABFBgAAOAYAAEUGAAA5BgAALAYAADkGAABFBgAAOgYAACwGAAA6BgAARQYAAEEGAAAsBgAAQQYAAC0GAABBBgAALgYAAEEGAABFBgAAQQYAAEkGAABBBgAASgYAAEIGAAAtBgAAQgYAAEUGAABCBgAASQYAAEIGAABKBgAAQwYAACcGAABDBgAALAYAAEMGAAAtBgAAQwYAAC4GAABDBgAARAYAAEMGAABFBgAAQwYAAEkGAABDBgAASgYAAEQGAAAsBgAARAYAAC0GAABEBgAALgYAAEQGAABFBgAARAYAAEkGAABEBgAASgYAAEUGAAAsBgAARQYAAC0GAABFBgAALgYAAEUGAABFBgAARQYAAEkGAABFBgAASgYAAEYGAAAsBgAARgYAAC0GAABGBgAALgYAAEYGAABFBgAARgYAAEkGAABGBgAASgYAAEcGAAAsBgAARwYAAEUGAABHBgAASQYAAEcGAABKBgAASgYAACwGAABKBgAALQYAAEoGAAAuBgAASgYAAEUGAABKBgAASQYAAEoGAABKBgAAMAYAAHAGAAAxBgAAcAYAAEkGAABwBgAAIAAAAEwGAABRBgAAIAAAAE0GAABRBgAAIAAAAE4GAABRBgAAIAAAAE8GAABRBgAAIAAAAFAGAABRBgAAIAAAAFEGAABwBgAASgYAAFQGAAAxBgAASgYAAFQGAAAyBgAASgYAAFQGAABFBgAASgYAAFQGAABGBgAASgYAAFQGAABJBgAASgYAAFQGAABKBgAAKAYAADEGAAAoBgAAMgYAACgGAABFBgAAKAYAAEYGAAAoBgAASQYAACgGAABKBgAAKgYAADEGAAAqBgAAMgYAACoGAABFBgAAKgYAAEYGAAAqBgAASQYAACoGAABKBgAAKwYAADEGAAArBgAAMgYAACsGAABFBgAAKwYAAEYGAAArBgAASQYAACsGAABKBgAAQQYAAEkGAABBBgAASgYAAEIGAABJBgAAQgYAAEoGAABDBgAAJwYAAEMGAABEBgAAQwYAAEUGAABDBgAASQYAAEMGAABKBgAARAYAAEUGAABEBgAASQYAAEQGAABKBgAARQYAACcGAABFBgAARQYAAEYGAAAxBgAARgYAADIGAABGBgAARQYAAEYGAABGBgAARgYAAEkGAABGBgAASgYAAEkGAABwBgAASgYAADEGAABKBgAAMgYAAEoGAABFBgAASgYAAEYGAABKBgAASQYAAEoGAABKBgAASgYAAFQGAAAsBgAASgYAAFQGAAAtBgAASgYAAFQGAAAuBgAASgYAAFQGAABFBgAASgYAAFQGAABHBgAAKAYAACwGAAAoBgAALQYAACgGAAAuBgAAKAYAAEUGAAAoBgAARwYAACoGAAAsBgAAKgYAAC0GAAAqBgAALgYAACoGAABFBgAAKgYAAEcGAAArBgAARQYAACwGAAAtBgAALAYAAEUGAAAtBgAALAYAAC0GAABFBgAALgYAACwGAAAuBgAARQYAADMGAAAsBgAAMwYAAC0GAAAzBgAALgYAADMGAABFBgAANQYAAC0GAAA1BgAALgYAADUGAABFBgAANgYAACwGAAA2BgAALQYAADYGAAAuBgAANgYAAEUGAAA3BgAALQYAADgGAABFBgAAOQYAACwGAAA5BgAARQYAADoGAAAsBgAAOgYAAEUGAABBBgAALAYAAEEGAAAtBgAAQQYAAC4GAABBBgAARQYAAEIGAAAtBgAAQgYAAEUGAABDBgAALAYAAEMGAAAtBgAAQwYAAC4GAABDBgAARAYAAEMGAABFBgAARAYAACwGAABEBgAALQYAAEQGAAAuBgAARAYAAEUGAABEBgAARwYAAEUGAAAsBgAARQYAAC0GAABFBgAALgYAAEUGAABFBgAARgYAACwGAABGBgAALQYAAEYGAAAuBgAARgYAAEUGAABGBgAARwYAAEcGAAAsBgAARwYAAEUGAABHBgAAcAYAAEoGAAAsBgAASgYAAC0GAABKBgAALgYAAEoGAABFBgAASgYAAEcGAABKBgAAVAYAAEUGAABKBgAAVAYAAEcGAAAoBgAARQYAACgGAABHBgAAKgYAAEUGAAAqBgAARwYAACsGAABFBgAAKwYAAEcGAAAzBgAARQYAADMGAABHBgAANAYAAEUGAAA0BgAARwYAAEMGAABEBgAAQwYAAEUGAABEBgAARQYAAEYGAABFBgAARgYAAEcGAABKBgAARQYAAEoGAABHBgAAQAYAAE4GAABRBgAAQAYAAE8GAABRBgAAQAYAAFAGAABRBgAANwYAAEkGAAA3BgAASgYAADkGAABJBgAAOQYAAEoGAAA6BgAASQYAADoGAABKBgAAMwYAAEkGAAAzBgAASgYAADQGAABJBgAANAYAAEoGAAAtBgAASQYAAC0GAABKBgAALAYAAEkGAAAsBgAASgYAAC4GAABJBgAALgYAAEoGAAA1BgAASQYAADUGAABKBgAANgYAAEkGAAA2BgAASgYAADQGAAAsBgAANAYAAC0GAAA0BgAALgYAADQGAABFBgAANAYAADEGAAAzBgAAMQYAADUGAAAxBgAANgYAADEGAAA3BgAASQYAADcGAABKBgAAOQYAAEkGAAA5BgAASgYAADoGAABJBgAAOgYAAEoGAAAzBgAASQYAADMGAABKBgAANAYAAEkGAAA0BgAASgYAAC0GAABJBgAALQYAAEoGAAAsBgAASQYAACwGAABKBgAALgYAAEkGAAAuBgAASgYAADUGAABJBgAANQYAAEoGAAA2BgAASQYAADYGAABKBgAANAYAACwGAAA0BgAALQYAADQGAAAuBgAANAYAAEUGAAA0BgAAMQYAADMGAAAxBgAANQYAADEGAAA2BgAAMQYAADQGAAAsBgAANAYAAC0GAAA0BgAALgYAADQGAABFBgAAMwYAAEcGAAA0BgAARwYAADcGAABFBgAAMwYAACwGAAAzBgAALQYAADMGAAAuBgAANAYAACwGAAA0BgAALQYAADQGAAAuBgAANwYAAEUGAAA4BgAARQYAACcGAABLBgAAJwYAAEsGAAAqBgAALAYAAEUGAAAqBgAALQYAACwGAAAqBgAALQYAACwGAAAqBgAALQYAAEUGAAAqBgAALgYAAEUGAAAqBgAARQYAACwGAAAqBgAARQYAAC0GAAAqBgAARQYAAC4GAAAsBgAARQYAAC0GAAAsBgAARQYAAC0GAAAtBgAARQYAAEoGAAAtBgAARQYAAEkGAAAzBgAALQYAACwGAAAzBgAALAYAAC0GAAAzBgAALAYAAEkGAAAzBgAARQYAAC0GAAAzBgAARQYAAC0GAAAzBgAARQYAACwGAAAzBgAARQYAAEUGAAAzBgAARQYAAEUGAAA1BgAALQYAAC0GAAA1BgAALQYAAC0GAAA1BgAARQYAAEUGAAA0BgAALQYAAEUGAAA0BgAALQYAAEUGAAA0BgAALAYAAEoGAAA0BgAARQYAAC4GAAA0BgAARQYAAC4GAAA0BgAARQYAAEUGAAA0BgAARQYAAEUGAAA2BgAALQYAAEkGAAA2BgAALgYAAEUGAAA2BgAALgYAAEUGAAA3BgAARQYAAC0GAAA3BgAARQYAAC0GAAA3BgAARQYAAEUGAAA3BgAARQYAAEoGAAA5BgAALAYAAEUGAAA5BgAARQYAAEUGAAA5BgAARQYAAEUGAAA5BgAARQYAAEkGAAA6BgAARQYAAEUGAAA6BgAARQYAAEoGAAA6BgAARQYAAEkGAABBBgAALgYAAEUGAABBBgAALgYAAEUGAABCBgAARQYAAC0GAABCBgAARQYAAEUGAABEBgAALQYAAEUGAABEBgAALQYAAEoGAABEBgAALQYAAEkGAABEBgAALAYAACwGAABEBgAALAYAACwGAABEBgAALgYAAEUGAABEBgAALgYAAEUGAABEBgAARQYAAC0GAABEBgAARQYAAC0GAABFBgAALQYAACwGAABFBgAALQYAAEUGAABFBgAALQYAAEoGAABFBgAALAYAAC0GAABFBgAALAYAAEUGAABFBgAALgYAACwGAABFBgAALgYAAEUGAABFBgAALAYAAC4GAABHBgAARQYAACwGAABHBgAARQYAAEUGAABGBgAALQYAAEUGAABGBgAALQYAAEkGAABGBgAALAYAAEUGAABGBgAALAYAAEUGAABGBgAALAYAAEkGAABGBgAARQYAAEoGAABGBgAARQYAAEkGAABKBgAARQYAAEUGAABKBgAARQYAAEUGAAAoBgAALgYAAEoGAAAqBgAALAYAAEoGAAAqBgAALAYAAEkGAAAqBgAALgYAAEoGAAAqBgAALgYAAEkGAAAqBgAARQYAAEoGAAAqBgAARQYAAEkGAAAsBgAARQYAAEoGAAAsBgAALQYAAEkGAAAsBgAARQYAAEkGAAAzBgAALgYAAEkGAAA1BgAALQYAAEoGAAA0BgAALQYAAEoGAAA2BgAALQYAAEoGAABEBgAALAYAAEoGAABEBgAARQYAAEoGAABKBgAALQYAAEoGAABKBgAALAYAAEoGAABKBgAARQYAAEoGAABFBgAARQYAAEoGAABCBgAARQYAAEoGAABGBgAALQYAAEoGAABCBgAARQYAAC0GAABEBgAALQYAAEUGAAA5BgAARQYAAEoGAABDBgAARQYAAEoGAABGBgAALAYAAC0GAABFBgAALgYAAEoGAABEBgAALAYAAEUGAABDBgAARQYAAEUGAABEBgAALAYAAEUGAABGBgAALAYAAC0GAAAsBgAALQYAAEoGAAAtBgAALAYAAEoGAABFBgAALAYAAEoGAABBBgAARQYAAEoGAAAoBgAALQYAAEoGAABDBgAARQYAAEUGAAA5BgAALAYAAEUGAAA1BgAARQYAAEUGAAAzBgAALgYAAEoGAABGBgAALAYAAEoGAAA1BgAARAYAANIGAABCBgAARAYAANIGAAAnBgAARAYAAEQGAABHBgAAJwYAAEMGAAAoBgAAMQYAAEUGAAAtBgAARQYAAC8GAAA1BgAARAYAADkGAABFBgAAMQYAADMGAABIBgAARAYAADkGAABEBgAASgYAAEcGAABIBgAAMwYAAEQGAABFBgAANQYAAEQGAABJBgAANQYAAEQGAABJBgAAIAAAACcGAABEBgAARAYAAEcGAAAgAAAAOQYAAEQGAABKBgAARwYAACAAAABIBgAAMwYAAEQGAABFBgAALAYAAEQGAAAgAAAALAYAAEQGAAAnBgAARAYAAEcGAAAxBgAAzAYAACcGAABEBgAALAAAAAEwAAACMAAAOgAAADsAAAAhAAAAPwAAABYwAAAXMAAALgAAAC4AAAAuAAAALgAAAC4AAAAUIAAAEyAAAF8AAABfAAAAKAAAACkAAAB7AAAAfQAAABQwAAAVMAAAEDAAABEwAAAKMAAACzAAAAgwAAAJMAAADDAAAA0wAAAOMAAADzAAAFsAAABdAAAAIAAAAAUDAAAgAAAABQMAACAAAAAFAwAAIAAAAAUDAABfAAAAXwAAAF8AAAAsAAAAATAAAC4AAAA7AAAAOgAAAD8AAAAhAAAAFCAAACgAAAApAAAAewAAAH0AAAAUMAAAFTAAACMAAAAmAAAAKgAAACsAAAAtAAAAPAAAAD4AAAA9AAAAXAAAACQAAAAlAAAAQAAAACAAAABLBgAAQAYAAEsGAAAgAAAATAYAACAAAABNBgAAIAAAAE4GAABABgAATgYAACAAAABPBgAAQAYAAE8GAAAgAAAAUAYAAEAGAABQBgAAIAAAAFEGAABABgAAUQYAACAAAABSBgAAQAYAAFIGAAAhBgAAJwYAAFMGAAAnBgAAUwYAACcGAABUBgAAJwYAAFQGAABIBgAAVAYAAEgGAABUBgAAJwYAAFUGAAAnBgAAVQYAAEoGAABUBgAASgYAAFQGAABKBgAAVAYAAEoGAABUBgAAJwYAACcGAAAoBgAAKAYAACgGAAAoBgAAKQYAACkGAAAqBgAAKgYAACoGAAAqBgAAKwYAACsGAAArBgAAKwYAACwGAAAsBgAALAYAACwGAAAtBgAALQYAAC0GAAAtBgAALgYAAC4GAAAuBgAALgYAAC8GAAAvBgAAMAYAADAGAAAxBgAAMQYAADIGAAAyBgAAMwYAADMGAAAzBgAAMwYAADQGAAA0BgAANAYAADQGAAA1BgAANQYAADUGAAA1BgAANgYAADYGAAA2BgAANgYAADcGAAA3BgAANwYAADcGAAA4BgAAOAYAADgGAAA4BgAAOQYAADkGAAA5BgAAOQYAADoGAAA6BgAAOgYAADoGAABBBgAAQQYAAEEGAABBBgAAQgYAAEIGAABCBgAAQgYAAEMGAABDBgAAQwYAAEMGAABEBgAARAYAAEQGAABEBgAARQYAAEUGAABFBgAARQYAAEYGAABGBgAARgYAAEYGAABHBgAARwYAAEcGAABHBgAASAYAAEgGAABJBgAASQYAAEoGAABKBgAASgYAAEoGAABEBgAAJwYAAFMGAABEBgAAJwYAAFMGAABEBgAAJwYAAFQGAABEBgAAJwYAAFQGAABEBgAAJwYAAFUGAABEBgAAJwYAAFUGAABEBgAAJwYAAEQGAAAnBgAAIQAAACIAAAAjAAAAJAAAACUAAAAmAAAAJwAAACgAAAApAAAAKgAAACsAAAAsAAAALQAAAC4AAAAvAAAAMAAAADEAAAAyAAAAMwAAADQAAAA1AAAANgAAADcAAAA4AAAAOQAAADoAAAA7AAAAPAAAAD0AAAA+AAAAPwAAAEAAAABBAAAAQgAAAEMAAABEAAAARQAAAEYAAABHAAAASAAAAEkAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAABQAAAAUQAAAFIAAABTAAAAVAAAAFUAAABWAAAAVwAAAFgAAABZAAAAWgAAAFsAAABcAAAAXQAAAF4AAABfAAAAYAAAAGEAAABiAAAAYwAAAGQAAABlAAAAZgAAAGcAAABoAAAAaQAAAGoAAABrAAAAbAAAAG0AAABuAAAAbwAAAHAAAABxAAAAcgAAAHMAAAB0AAAAdQAAAHYAAAB3AAAAeAAAAHkAAAB6AAAAewAAAHwAAAB9AAAAfgAAAIUpAACGKQAAAjAAAAwwAAANMAAAATAAAPswAADyMAAAoTAAAKMwAAClMAAApzAAAKkwAADjMAAA5TAAAOcwAADDMAAA/DAAAKIwAACkMAAApjAAAKgwAACqMAAAqzAAAK0wAACvMAAAsTAAALMwAAC1MAAAtzAAALkwAAC7MAAAvTAAAL8wAADBMAAAxDAAAMYwAADIMAAAyjAAAMswAADMMAAAzTAAAM4wAADPMAAA0jAAANUwAADYMAAA2zAAAN4wAADfMAAA4DAAAOEwAADiMAAA5DAAAOYwAADoMAAA6TAAAOowAADrMAAA7DAAAO0wAADvMAAA8zAAAJkwAACaMAAAYBEAAAARAAABEQAAqhEAAAIRAACsEQAArREAAAMRAAAEEQAABREAALARAACxEQAAshEAALMRAAC0EQAAtREAABoRAAAGEQAABxEAAAgRAAAhEQAACREAAAoRAAALEQAADBEAAA0RAAAOEQAADxEAABARAAAREQAAEhEAAGERAABiEQAAYxEAAGQRAABlEQAAZhEAAGcRAABoEQAAaREAAGoRAABrEQAAbBEAAG0RAABuEQAAbxEAAHARAABxEQAAchEAAHMRAAB0EQAAdREAAKIAAACjAAAArAAAACAAAAAEAwAApgAAAKUAAACpIAAAAiUAAJAhAACRIQAAkiEAAJMhAACgJQAAyyUAANACAADRAgAA5gAAAJkCAABTAgAAowIAAGarAAClAgAApAIAAFYCAABXAgAAkR0AAFgCAABeAgAAqQIAAGQCAABiAgAAYAIAAJsCAAAnAQAAnAIAAGcCAACEAgAAqgIAAKsCAABsAgAABN8BAI6nAABuAgAABd8BAI4CAAAG3wEA+AAAAHYCAAB3AgAAcQAAAHoCAAAI3wEAfQIAAH4CAACAAgAAqAIAAKYCAABnqwAApwIAAIgCAABxLAAAjwIAAKECAACiAgAAmAIAAMABAADBAQAAwgEAAArfAQAe3wEAQQAAAEIAAABDAAAARAAAAEUAAABGAAAARwAAAEgAAABJAAAASgAAAEsAAABMAAAATQAAAE4AAABPAAAAUAAAAFEAAABSAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAAAwAAAAMQAAADIAAAAzAAAANAAAADUAAAA2AAAANwAAADgAAAA5AAAAQQAAAEIAAABDAAAARAAAAEUAAABGAAAARwAAAEgAAABJAAAASgAAAEsAAABMAAAATQAAAE4AAABPAAAAUAAAAFEAAABSAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAABhAAAAYgAAAGMAAABkAAAAZQAAAGYAAABnAAAAaAAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAAQwAAAEQAAABFAAAARgAAAEcAAABIAAAASQAAAEoAAABLAAAATAAAAE0AAABOAAAATwAAAFAAAABRAAAAUgAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABaAAAAYQAAAGIAAABjAAAAZAAAAGUAAABmAAAAZwAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAAQwAAAEQAAABFAAAARgAAAEcAAABIAAAASQAAAEoAAABLAAAATAAAAE0AAABOAAAATwAAAFAAAABRAAAAUgAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABaAAAAYQAAAGIAAABjAAAAZAAAAGUAAABmAAAAZwAAAGgAAABpAAAAagAAAGsAAABsAAAAbQAAAG4AAABvAAAAcAAAAHEAAAByAAAAcwAAAHQAAAB1AAAAdgAAAHcAAAB4AAAAeQAAAHoAAABBAAAAQwAAAEQAAABHAAAASgAAAEsAAABOAAAATwAAAFAAAABRAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAABhAAAAYgAAAGMAAABkAAAAZgAAAGgAAABpAAAAagAAAGsAAABsAAAAbQAAAG4AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAAQwAAAEQAAABFAAAARgAAAEcAAABIAAAASQAAAEoAAABLAAAATAAAAE0AAABOAAAATwAAAFAAAABRAAAAUgAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABaAAAAYQAAAGIAAABjAAAAZAAAAGUAAABmAAAAZwAAAGgAAABpAAAAagAAAGsAAABsAAAAbQAAAG4AAABvAAAAcAAAAHEAAAByAAAAcwAAAHQAAAB1AAAAdgAAAHcAAAB4AAAAeQAAAHoAAABBAAAAQgAAAEQAAABFAAAARgAAAEcAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAABQAAAAUQAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABhAAAAYgAAAGMAAABkAAAAZQAAAGYAAABnAAAAaAAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAARAAAAEUAAABGAAAARwAAAEkAAABKAAAASwAAAEwAAABNAAAATwAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABhAAAAYgAAAGMAAABkAAAAZQAAAGYAAABnAAAAaAAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAAQwAAAEQAAABFAAAARgAAAEcAAABIAAAASQAAAEoAAABLAAAATAAAAE0AAABOAAAATwAAAFAAAABRAAAAUgAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABaAAAAYQAAAGIAAABjAAAAZAAAAGUAAABmAAAAZwAAAGgAAABpAAAAagAAAGsAAABsAAAAbQAAAG4AAABvAAAAcAAAAHEAAAByAAAAcwAAAHQAAAB1AAAAdgAAAHcAAAB4AAAAeQAAAHoAAABBAAAAQgAAAEMAAABEAAAARQAAAEYAAABHAAAASAAAAEkAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAABQAAAAUQAAAFIAAABTAAAAVAAAAFUAAABWAAAAVwAAAFgAAABZAAAAWgAAAGEAAABiAAAAYwAAAGQAAABlAAAAZgAAAGcAAABoAAAAaQAAAGoAAABrAAAAbAAAAG0AAABuAAAAbwAAAHAAAABxAAAAcgAAAHMAAAB0AAAAdQAAAHYAAAB3AAAAeAAAAHkAAAB6AAAAQQAAAEIAAABDAAAARAAAAEUAAABGAAAARwAAAEgAAABJAAAASgAAAEsAAABMAAAATQAAAE4AAABPAAAAUAAAAFEAAABSAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAABhAAAAYgAAAGMAAABkAAAAZQAAAGYAAABnAAAAaAAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAAEEAAABCAAAAQwAAAEQAAABFAAAARgAAAEcAAABIAAAASQAAAEoAAABLAAAATAAAAE0AAABOAAAATwAAAFAAAABRAAAAUgAAAFMAAABUAAAAVQAAAFYAAABXAAAAWAAAAFkAAABaAAAAYQAAAGIAAABjAAAAZAAAAGUAAABmAAAAZwAAAGgAAABpAAAAagAAAGsAAABsAAAAbQAAAG4AAABvAAAAcAAAAHEAAAByAAAAcwAAAHQAAAB1AAAAdgAAAHcAAAB4AAAAeQAAAHoAAABBAAAAQgAAAEMAAABEAAAARQAAAEYAAABHAAAASAAAAEkAAABKAAAASwAAAEwAAABNAAAATgAAAE8AAABQAAAAUQAAAFIAAABTAAAAVAAAAFUAAABWAAAAVwAAAFgAAABZAAAAWgAAAGEAAABiAAAAYwAAAGQAAABlAAAAZgAAAGcAAABoAAAAaQAAAGoAAABrAAAAbAAAAG0AAABuAAAAbwAAAHAAAABxAAAAcgAAAHMAAAB0AAAAdQAAAHYAAAB3AAAAeAAAAHkAAAB6AAAAQQAAAEIAAABDAAAARAAAAEUAAABGAAAARwAAAEgAAABJAAAASgAAAEsAAABMAAAATQAAAE4AAABPAAAAUAAAAFEAAABSAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAABhAAAAYgAAAGMAAABkAAAAZQAAAGYAAABnAAAAaAAAAGkAAABqAAAAawAAAGwAAABtAAAAbgAAAG8AAABwAAAAcQAAAHIAAABzAAAAdAAAAHUAAAB2AAAAdwAAAHgAAAB5AAAAegAAADEBAAA3AgAAkQMAAJIDAACTAwAAlAMAAJUDAACWAwAAlwMAAJgDAACZAwAAmgMAAJsDAACcAwAAnQMAAJ4DAACfAwAAoAMAAKEDAACYAwAAowMAAKQDAAClAwAApgMAAKcDAACoAwAAqQMAAAciAACxAwAAsgMAALMDAAC0AwAAtQMAALYDAAC3AwAAuAMAALkDAAC6AwAAuwMAALwDAAC9AwAAvgMAAL8DAADAAwAAwQMAAMIDAADDAwAAxAMAAMUDAADGAwAAxwMAAMgDAADJAwAAAiIAALUDAAC4AwAAugMAAMYDAADBAwAAwAMAAJEDAACSAwAAkwMAAJQDAACVAwAAlgMAAJcDAACYAwAAmQMAAJoDAACbAwAAnAMAAJ0DAACeAwAAnwMAAKADAAChAwAAmAMAAKMDAACkAwAApQMAAKYDAACnAwAAqAMAAKkDAAAHIgAAsQMAALIDAACzAwAAtAMAALUDAAC2AwAAtwMAALgDAAC5AwAAugMAALsDAAC8AwAAvQMAAL4DAAC/AwAAwAMAAMEDAADCAwAAwwMAAMQDAADFAwAAxgMAAMcDAADIAwAAyQMAAAIiAAC1AwAAuAMAALoDAADGAwAAwQMAAMADAACRAwAAkgMAAJMDAACUAwAAlQMAAJYDAACXAwAAmAMAAJkDAACaAwAAmwMAAJwDAACdAwAAngMAAJ8DAACgAwAAoQMAAJgDAACjAwAApAMAAKUDAACmAwAApwMAAKgDAACpAwAAByIAALEDAACyAwAAswMAALQDAAC1AwAAtgMAALcDAAC4AwAAuQMAALoDAAC7AwAAvAMAAL0DAAC+AwAAvwMAAMADAADBAwAAwgMAAMMDAADEAwAAxQMAAMYDAADHAwAAyAMAAMkDAAACIgAAtQMAALgDAAC6AwAAxgMAAMEDAADAAwAAkQMAAJIDAACTAwAAlAMAAJUDAACWAwAAlwMAAJgDAACZAwAAmgMAAJsDAACcAwAAnQMAAJ4DAACfAwAAoAMAAKEDAACYAwAAowMAAKQDAAClAwAApgMAAKcDAACoAwAAqQMAAAciAACxAwAAsgMAALMDAAC0AwAAtQMAALYDAAC3AwAAuAMAALkDAAC6AwAAuwMAALwDAAC9AwAAvgMAAL8DAADAAwAAwQMAAMIDAADDAwAAxAMAAMUDAADGAwAAxwMAAMgDAADJAwAAAiIAALUDAAC4AwAAugMAAMYDAADBAwAAwAMAAJEDAACSAwAAkwMAAJQDAACVAwAAlgMAAJcDAACYAwAAmQMAAJoDAACbAwAAnAMAAJ0DAACeAwAAnwMAAKADAAChAwAAmAMAAKMDAACkAwAApQMAAKYDAACnAwAAqAMAAKkDAAAHIgAAsQMAALIDAACzAwAAtAMAALUDAAC2AwAAtwMAALgDAAC5AwAAugMAALsDAAC8AwAAvQMAAL4DAAC/AwAAwAMAAMEDAADCAwAAwwMAAMQDAADFAwAAxgMAAMcDAADIAwAAyQMAAAIiAAC1AwAAuAMAALoDAADGAwAAwQMAAMADAADcAwAA3QMAADAAAAAxAAAAMgAAADMAAAA0AAAANQAAADYAAAA3AAAAOAAAADkAAAAwAAAAMQAAADIAAAAzAAAANAAAADUAAAA2AAAANwAAADgAAAA5AAAAMAAAADEAAAAyAAAAMwAAADQAAAA1AAAANgAAADcAAAA4AAAAOQAAADAAAAAxAAAAMgAAADMAAAA0AAAANQAAADYAAAA3AAAAOAAAADkAAAAwAAAAMQAAADIAAAAzAAAANAAAADUAAAA2AAAANwAAADgAAAA5AAAAMAQAADEEAAAyBAAAMwQAADQEAAA1BAAANgQAADcEAAA4BAAAOgQAADsEAAA8BAAAPgQAAD8EAABABAAAQQQAAEIEAABDBAAARAQAAEUEAABGBAAARwQAAEgEAABLBAAATQQAAE4EAACJpgAA2QQAAFYEAABYBAAA6QQAAK8EAADPBAAAMAQAADEEAAAyBAAAMwQAADQEAAA1BAAANgQAADcEAAA4BAAAOgQAADsEAAA+BAAAPwQAAEEEAABDBAAARAQAAEUEAABGBAAARwQAAEgEAABKBAAASwQAAJEEAABWBAAAVQQAAF8EAACrBAAAUaYAALEEAAAnBgAAKAYAACwGAAAvBgAASAYAADIGAAAtBgAANwYAAEoGAABDBgAARAYAAEUGAABGBgAAMwYAADkGAABBBgAANQYAAEIGAAAxBgAANAYAACoGAAArBgAALgYAADAGAAA2BgAAOAYAADoGAABuBgAAugYAAKEGAABvBgAAKAYAACwGAABHBgAALQYAAEoGAABDBgAARAYAAEUGAABGBgAAMwYAADkGAABBBgAANQYAAEIGAAA0BgAAKgYAACsGAAAuBgAANgYAADoGAAAsBgAALQYAAEoGAABEBgAARgYAADMGAAA5BgAANQYAAEIGAAA0BgAALgYAADYGAAA6BgAAugYAAG8GAAAoBgAALAYAAEcGAAAtBgAANwYAAEoGAABDBgAARQYAAEYGAAAzBgAAOQYAAEEGAAA1BgAAQgYAADQGAAAqBgAAKwYAAC4GAAA2BgAAOAYAADoGAABuBgAAoQYAACcGAAAoBgAALAYAAC8GAABHBgAASAYAADIGAAAtBgAANwYAAEoGAABEBgAARQYAAEYGAAAzBgAAOQYAAEEGAAA1BgAAQgYAADEGAAA0BgAAKgYAACsGAAAuBgAAMAYAADYGAAA4BgAAOgYAACgGAAAsBgAALwYAAEgGAAAyBgAALQYAADcGAABKBgAARAYAAEUGAABGBgAAMwYAADkGAABBBgAANQYAAEIGAAAxBgAANAYAACoGAAArBgAALgYAADAGAAA2BgAAOAYAADoGAAAwAAAALgAAADAAAAAsAAAAMQAAACwAAAAyAAAALAAAADMAAAAsAAAANAAAACwAAAA1AAAALAAAADYAAAAsAAAANwAAACwAAAA4AAAALAAAADkAAAAsAAAAKAAAAEEAAAApAAAAKAAAAEIAAAApAAAAKAAAAEMAAAApAAAAKAAAAEQAAAApAAAAKAAAAEUAAAApAAAAKAAAAEYAAAApAAAAKAAAAEcAAAApAAAAKAAAAEgAAAApAAAAKAAAAEkAAAApAAAAKAAAAEoAAAApAAAAKAAAAEsAAAApAAAAKAAAAEwAAAApAAAAKAAAAE0AAAApAAAAKAAAAE4AAAApAAAAKAAAAE8AAAApAAAAKAAAAFAAAAApAAAAKAAAAFEAAAApAAAAKAAAAFIAAAApAAAAKAAAAFMAAAApAAAAKAAAAFQAAAApAAAAKAAAAFUAAAApAAAAKAAAAFYAAAApAAAAKAAAAFcAAAApAAAAKAAAAFgAAAApAAAAKAAAAFkAAAApAAAAKAAAAFoAAAApAAAAFDAAAFMAAAAVMAAAQwAAAFIAAABDAAAARAAAAFcAAABaAAAAQQAAAEIAAABDAAAARAAAAEUAAABGAAAARwAAAEgAAABJAAAASgAAAEsAAABMAAAATQAAAE4AAABPAAAAUAAAAFEAAABSAAAAUwAAAFQAAABVAAAAVgAAAFcAAABYAAAAWQAAAFoAAABIAAAAVgAAAE0AAABWAAAAUwAAAEQAAABTAAAAUwAAAFAAAABQAAAAVgAAAFcAAABDAAAATQAAAEMAAABNAAAARAAAAE0AAABSAAAARAAAAEoAAAB7MAAASzAAALMwAACzMAAAtTAAAEtiAABXWwAAzFMAAMYwAACZMAAAjE4AABpZAADjiQAAKVkAAKROAAAgZgAAIXEAAJllAABNUgAAjF8AAI1RAACwZQAAHVIAAEJ9AAAfdQAAqYwAAPBYAAA5VAAAFG8AAJViAABVYwAAAE4AAAlOAABKkAAA5l0AAC1OAADzUwAAB2MAAHCNAABTYgAAgXkAAHp6AAAIVAAAgG4AAAlnAAAIZwAAM3UAAHJSAAC2VQAATZEAABQwAAAsZwAAFTAAABQwAAAJTgAAFTAAABQwAACMTgAAFTAAABQwAACJWwAAFTAAABQwAAC5cAAAFTAAABQwAABTYgAAFTAAABQwAADXdgAAFTAAABQwAADdUgAAFTAAABQwAABXZQAAFTAAAJdfAADvUwAAMAAAADEAAAAyAAAAMwAAADQAAAA1AAAANgAAADcAAAA4AAAAOQAAABUEEABmAAAAQgAAADgAAAAVBBAAZgAAAEIAAABKAAAAbnVsbCBwb2ludGVyIHBhc3NlZCB0byBydXN0cmVjdXJzaXZlIHVzZSBvZiBhbiBvYmplY3QgZGV0ZWN0ZWQgd2hpY2ggd291bGQgbGVhZCB0byB1bnNhZmUgYWxpYXNpbmcgaW4gcnVzdEF0dGVtcHRlZCB0byBpbml0aWFsaXplIHRocmVhZC1sb2NhbCB3aGlsZSBpdCBpcyBiZWluZyBkcm9wcGVkfAQQAF4AAABrAAAADQAAAAUAAAAMAAAACwAAAAsAAAAEAAAAPB0QAEEdEABNHRAAWB0QAGMdEAAFAAAADAAAAAsAAAALAAAABAAAADwdEABBHRAATR0QAFgdEABjHRAADQAAAA4AAAAKAAAAbRwQAHocEACIHBAAAPRABG5hbWUADQxoY2lkX2pzLndhc20B3UCEAQA2d2FzbV9iaW5kZ2VuOjpfX3diaW5kZ2VuX3N0cmluZ19uZXc6OmhjMDIxNmRjYWI2MWQwMWJjATF3YXNtX2JpbmRnZW46Ol9fd2JpbmRnZW5fdGhyb3c6Omg2NGE3MGRjYjJhMTYzNWQ0AjFoY2lkOjpIY2lkRW5jb2Rpbmc6OnByZV9kZWNvZGU6Omg1MGY4Nzc4OTI2YWExNDUyAzloY2lkOjpIY2lkRW5jb2Rpbmc6OmRlY29kZV93aXRoX3BvbGljeTo6aDU4M2FjY2MxZGVlNTlkNzYELWhjaWQ6Om5vcm1hbGl6ZTo6bm9ybWFsaXplOjpoZDhmZmY3YmM2ODExMDE4OQV4PHVuaWNvZGVfbm9ybWFsaXphdGlvbjo6cmVjb21wb3NlOjpSZWNvbXBvc2l0aW9uczxJPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46Om5leHQ6OmhiMWZhMWNjNTAxNmYyNDkyBg9lbmNvZGluZ19lbmNvZGUHQmNvcmU6OnNsaWNlOjpzb3J0OjpzdGFibGU6OnF1aWNrc29ydDo6cXVpY2tzb3J0OjpoMzZkNjkzMjhjNGI1YTBmYggwY29yZVtjNTkzMGM4NWExMmRlODIyXTo6c3RyOjpzbGljZV9lcnJvcl9mYWlsX3J0CRJlbmNvZGluZ19yYW5kb21hcnQKGDxjaGFyPjo6ZXNjYXBlX2RlYnVnX2V4dAs5Y29yZTo6c2xpY2U6OnNvcnQ6OnN0YWJsZTo6ZHJpZnQ6OnNvcnQ6OmhiYmNiMmVmYTkwN2FmOWZiDBZlbmNvZGluZ19pZGVudGljb25fc3ZnDTNoY2lkOjpIY2lkRW5jb2Rpbmc6OmVyYXNlZF9jaGFyczo6aDQzMjFiOTU1MWU2NTU2ODEOPHVuaWNvZGVfbm9ybWFsaXphdGlvbjo6bm9ybWFsaXplOjpjb21wb3NlOjpoODcwNTUzY2JlMGNlNDU4ZA8wPCZUIGFzIGNvcmU6OmZtdDo6RGVidWc+OjpmbXQ6OmhlNjMwYmIyMTAzM2ViYjk2EAxlbmNvZGluZ19uZXcRUXVuaWNvZGVfbm9ybWFsaXphdGlvbjo6ZGVjb21wb3NlOjpEZWNvbXBvc2l0aW9uczxJPjo6cHVzaF9iYWNrOjpoMjc3ZTA0NDIyOTA0YzY4ZhIoaGNpZDo6a2luZF9mb3JfcHJlZml4OjpoYjBjZmM1MGE5OTdmZWMxNxMtd2VlX2FsbG9jOjphbGxvY19maXJzdF9maXQ6OmgxMGU2NTNkOGM4NmFhNTlkFGQ8Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpidWlsZGVyczo6UGFkQWRhcHRlciBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OldyaXRlPjo6d3JpdGVfc3RyFSJjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OndyaXRlFjJjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzdHI6OmNvdW50Ojpkb19jb3VudF9jaGFycxc2PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6Rm9ybWF0dGVyPjo6cGFkX2ludGVncmFsGDBoY2lkOjpub3JtYWxpemU6OmlzX3RyaW1tYWJsZTo6aDJlMmIxOTA2MjExMjljZjMZXTxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpvcHM6OnJhbmdlOjpSYW5nZTx1c2l6ZT4gYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEZWJ1Zz46OmZtdBotPGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6Rm9ybWF0dGVyPjo6cGFkG2g8Y29yZTo6aXRlcjo6YWRhcHRlcnM6Om1hcDo6TWFwPEksRj4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I+OjpuZXh0OjpoMWI5Zjc4NTljYmE4NjkyMRxVPHdlZV9hbGxvYzo6V2VlQWxsb2MgYXMgY29yZTo6YWxsb2M6Omdsb2JhbDo6R2xvYmFsQWxsb2M+OjpkZWFsbG9jOjpoZWE0NzE4MDhhYjkwYmY2ZB1pPGFsbG9jOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlOjppdGVyOjp0cmFpdHM6OmNvbGxlY3Q6OkZyb21JdGVyYXRvcjwmY2hhcj4+Ojpmcm9tX2l0ZXI6OmhmYmRmOWY4M2Y2MTM5NjliHk10aW55dmVjOjp0aW55dmVjOjpUaW55VmVjPEE+OjpwdXNoOjpkcmFpbl90b19oZWFwX2FuZF9wdXNoOjpoN2YxYWNjODA1YWQxZDIyNh8yYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OmZtdDo6Zm9ybWF0Ojpmb3JtYXRfaW5uZXIgD2VuY29kaW5nX2RlY29kZSETZW5jb2RpbmdfaXNfY29ycnVwdCJLY29yZVtjNTkzMGM4NWExMmRlODIyXTo6dW5pY29kZTo6dW5pY29kZV9kYXRhOjpncmFwaGVtZV9leHRlbmQ6Omxvb2t1cF9zbG93I010aW55dmVjOjp0aW55dmVjOjpUaW55VmVjPEE+OjpwdXNoOjpkcmFpbl90b19oZWFwX2FuZF9wdXNoOjpoY2JkZTBlNGE5Y2JhZjFjNyQtaGNpZDo6dXRpbDo6Y2FwX2VuY29kZV9iaW46Omg5NjNhZDQxNjM0OWU1MzI3JTZjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzbGljZTo6aW5kZXg6OnNsaWNlX2luZGV4X2ZhaWwmTGNvcmU6OnNsaWNlOjpzb3J0OjpzaGFyZWQ6OnNtYWxsc29ydDo6YmlkaXJlY3Rpb25hbF9tZXJnZTo6aDc0Yzc3YWEyOWU0MzRhMTYnMjx1MzIgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEaXNwbGF5Pjo6Zm10KDBoY2lkOjpIY2lkRW5jb2RpbmdDb25maWc6Om5ldzo6aDU5OWU4ZDY0YzU1ODRhMTcpWzxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6c3RyaW5nOjpTdHJpbmcgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpXcml0ZT46OndyaXRlX2NoYXIqMzxjaGFyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGlzcGxheT46OmZtdCtePGNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om51bTo6ZXJyb3I6OlBhcnNlSW50RXJyb3IgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEZWJ1Zz46OmZtdCwVZW5jb2RpbmdfZXJhc2VkX2NoYXJzLT5hbGxvYzo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI8QT46OmZpbmlzaF9ncm93OjpoOGE3Y2QwOTZiM2I5M2ZjYS5nPGNvcmU6Oml0ZXI6OmFkYXB0ZXJzOjp0YWtlOjpUYWtlPEk+IGFzIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0ZXJhdG9yPjo6bnRoOjpoYTdmOTY3MDAxNmYxODdiNC9FY29yZTo6c2xpY2U6OnNvcnQ6OnNoYXJlZDo6c21hbGxzb3J0Ojpzb3J0NF9zdGFibGU6OmhjMjI5ZmFlZWUyMTkyZWUwME11bmljb2RlX25vcm1hbGl6YXRpb246Omxvb2t1cHM6OmNhbm9uaWNhbF9mdWxseV9kZWNvbXBvc2VkOjpoZjdhNTkyZDdkMjEzZjA0MzEzYWxsb2M6OnNsaWNlOjo8aW1wbCBbVF0+OjpyZXBlYXQ6Omg2Yzc4N2IyYzY5MWEwYTNjMkNhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI8QT46OnNocmlua191bmNoZWNrZWQ6Omg5MjJiN2M3ODgxZjc3ZmEzM0Q8aGNpZDo6ZXJyb3I6OkhjaWRFcnJvciBhcyBjb3JlOjpmbXQ6OkRlYnVnPjo6Zm10OjpoMjBmOTcyMTE2MWZjZWI0NTRBYWxsb2M6OnJhd192ZWM6OlJhd1ZlY0lubmVyPEE+Ojpncm93X2Ftb3J0aXplZDo6aDM5NmZhN2E2OGEyZTljODM1K2hjaWQ6OmZpbmdlcnByaW50OjpmcmFtZTo6aDVjNTlkMzE2MmI0NWFiMGU2PGNvcmU6OnNsaWNlOjpzb3J0OjpzdGFibGU6OmRyaWZ0c29ydF9tYWluOjpoMjNjYTkzZWVjMDYyZDE2NjdSY29yZTo6c2xpY2U6OnNvcnQ6OnNoYXJlZDo6c21hbGxzb3J0OjppbnNlcnRpb25fc29ydF9zaGlmdF9sZWZ0OjpoODY0NWE2M2IzOWZjOGJhMThTPHdlZV9hbGxvYzo6V2VlQWxsb2MgYXMgY29yZTo6YWxsb2M6Omdsb2JhbDo6R2xvYmFsQWxsb2M+OjphbGxvYzo6aGJkNzNjZmMwMDc5MGViYTg5MTxjaGFyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGVidWc+OjpmbXQ6QGNvcmU6OnNsaWNlOjpzb3J0OjpzaGFyZWQ6OnBpdm90OjptZWRpYW4zX3JlYzo6aGQ5OWRmZjM0NGE3M2YzNTM7dDx3ZWVfYWxsb2M6OnNpemVfY2xhc3Nlczo6U2l6ZUNsYXNzQWxsb2NQb2xpY3kgYXMgd2VlX2FsbG9jOjpBbGxvY1BvbGljeT46Om5ld19jZWxsX2Zvcl9mcmVlX2xpc3Q6Omg2MmJkYjgxOTlmMzFhNDkzPHw8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnJhd192ZWM6OlJhd1ZlY0lubmVyPF8+Pjo6cmVzZXJ2ZTo6ZG9fcmVzZXJ2ZV9hbmRfaGFuZGxlOjo8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OmFsbG9jOjpHbG9iYWw+PTA8dTggYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpCaW5hcnk+OjpmbXQ+PDxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI+OjpmaW5pc2hfZ3Jvdz9ePGFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpjb252ZXJ0OjpGcm9tPCZzdHI+Pjo6ZnJvbUAkYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OmZtdDo6Zm9ybWF0QWI8d2VlX2FsbG9jOjpMYXJnZUFsbG9jUG9saWN5IGFzIHdlZV9hbGxvYzo6QWxsb2NQb2xpY3k+OjpuZXdfY2VsbF9mb3JfZnJlZV9saXN0OjpoNTc2ODMyYWM4YmI4NTlmOEImaGNpZDo6cnM6OmV2YWxfaGlnaDo6aDFmMmU5Y2RkOWU4NGJiMTVDJWhjaWQ6OnJzOjpldmFsX2xvdzo6aDhhMzhhMTQyYmVhMjc0OTlEPHN0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6cGFuaWNraW5nOjpwYW5pY19oYW5kbGVyOjp7Y2xvc3VyZSMwfUUTX193YmdfZW5jb2RpbmdfZnJlZUYvd2VlX2FsbG9jOjphbGxvY193aXRoX3JlZmlsbDo6aGYwZDZhOThhZDRlMTExYTJHMXN0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6cGFuaWNraW5nOjpwYW5pY193aXRoX2hvb2tIPWFsbG9jOjpyYXdfdmVjOjpSYXdWZWNJbm5lcjxBPjo6ZGVhbGxvY2F0ZTo6aGI3M2Q0MjI4MjQzOWUyYmNJODxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6cmF3X3ZlYzo6UmF3VmVjPHU4Pj46Omdyb3dfb25lSj1hbGxvYzo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI8QT46OmRlYWxsb2NhdGU6OmgxYjY1NzljMjBhN2U4MWUySzlhbGxvYzo6dmVjOjpWZWM8VCxBPjo6aW50b19ib3hlZF9zbGljZTo6aDllN2FiYjZiZDlhNGE1NjhMLmhjaWQ6OnJzOjpDb2RlYzo6aXNfY29ycnVwdDo6aGUxZWIxYjdmZGYzMGE3NDRNKGFsbG9jOjp2ZWM6OmZyb21fZWxlbTo6aDM4MTg3YjQ4Zjk4N2ViMTlOLWNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnJlc3VsdDo6dW53cmFwX2ZhaWxlZE8zYWxsb2M6OnNsaWNlOjo8aW1wbCBbVF0+Ojp0b192ZWM6Omg3NWNkOTNlMzU2ZGQxMjM3UC5hbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6Ym94ZWQ6OmJveF9uZXdfdW5pbml0UVo8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZzo6U3RyaW5nIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6V3JpdGU+Ojp3cml0ZV9zdHJSNWNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnBhbmlja2luZzo6cGFuaWNfYm91bmRzX2NoZWNrUwZtZW1jbXBUZTxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OmJ1aWxkZXJzOjpQYWRBZGFwdGVyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6V3JpdGU+Ojp3cml0ZV9jaGFyVThhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOjpoMTI5Y2NkN2JlMmNjMzhiYlY4YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxULEE+Ojpncm93X29uZTo6aDYxY2U3NzA3ZjQxYWIwM2ZXOGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCxBPjo6Z3Jvd19vbmU6OmgzOGNhYTlhODdiMWRmNTU5WDhhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOjpoZTkyNjViZjIzNjUyNmVjY1lRYWxsb2M6OnJhd192ZWM6OlJhd1ZlY0lubmVyPEE+OjpyZXNlcnZlOjpkb19yZXNlcnZlX2FuZF9oYW5kbGU6OmhmZGUzMzQ0YjVjMDdhNTM1WhFfX3diaW5kZ2VuX21hbGxvY1tlPGhjaWRfanM6OkVuY29kaW5nIGFzIHdhc21fYmluZGdlbjo6Y29udmVydDo6dHJhaXRzOjpSZWZGcm9tV2FzbUFiaT46OnJlZl9mcm9tX2FiaTo6aDA2OTMyOTc2NmM4YWNiMjVcSDxhbGxvYzo6dmVjOjpWZWM8VCxBPiBhcyBjb3JlOjpvcHM6OmRyb3A6OkRyb3A+Ojpkcm9wOjpoOGY4OTk3YzAyZGQwYmM2NV1OPGhjaWQ6OmVycm9yOjpIY2lkRXJyb3IgYXMgY29yZTo6Y29udmVydDo6RnJvbTwmc3RyPj46OmZyb206Omg5MGFlOWZmODgwMTJkYTEzXhJfX3diaW5kZ2VuX3JlYWxsb2NfLGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnBhbmlja2luZzo6cGFuaWNfZm10YClfX3J1c3RjW2I3OTc0ZTg2OTA0MzBkZDldOjpfX3J1c3RfcmVhbGxvY2FEPGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6Rm9ybWF0dGVyPjo6cGFkX2ludGVncmFsOjp3cml0ZV9wcmVmaXhiLF9fcnVzdGNbYjc5NzRlODY5MDQzMGRkOV06OnJ1c3RfYmVnaW5fdW53aW5kYz88aGNpZDo6cnM6OlJzRXJyb3IgYXMgY29yZTo6Zm10OjpEZWJ1Zz46OmZtdDo6aGQ2MTkwZDExYjAyOGFjZjFkRWNvcmU6OnNsaWNlOjpzb3J0OjpzaGFyZWQ6OnNtYWxsc29ydDo6c29ydDhfc3RhYmxlOjpoNWY4Njc2NmMyYjIyODdhOGVVY29yZVtjNTkzMGM4NWExMmRlODIyXTo6cHRyOjpkcm9wX2luX3BsYWNlOjo8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZzo6U3RyaW5nPmYuYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnJhd192ZWM6OmhhbmRsZV9lcnJvcmcyPCZUIGFzIGNvcmU6OmZtdDo6RGlzcGxheT46OmZtdDo6aGFkMjBkZTQyYzNiODEwNDNoTzxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkVycm9yIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGVidWc+OjpmbXRpD19fd2JpbmRnZW5fZnJlZWpUPCZkeW4gY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEZWJ1ZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRlYnVnPjo6Zm10ay1jb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzdHI6OnNsaWNlX2Vycm9yX2ZhaWxsMzwmc3RyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGlzcGxheT46OmZtdG0zPCZzdHIgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEaXNwbGF5Pjo6Zm10blo8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZzo6U3RyaW5nIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGlzcGxheT46OmZtdC4yMjhvM2FsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpyYXdfdmVjOjpjYXBhY2l0eV9vdmVyZmxvd3BaPGFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OldyaXRlPjo6d3JpdGVfZm10cWQ8Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpidWlsZGVyczo6UGFkQWRhcHRlciBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OldyaXRlPjo6d3JpdGVfZm10cihjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpwYW5pY2tpbmc6OnBhbmljc05jb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzbGljZTo6c29ydDo6c2hhcmVkOjpzbWFsbHNvcnQ6OnBhbmljX29uX29yZF92aW9sYXRpb250H19fd2JpbmRnZW5fYWRkX3RvX3N0YWNrX3BvaW50ZXJ1MXdhc21fYmluZGdlbjo6X19ydDo6dGhyb3dfbnVsbDo6aDZmNmU1MGU2MDk5NDBlYzB2Mndhc21fYmluZGdlbjo6X19ydDo6Ym9ycm93X2ZhaWw6OmhkMGZjNDk3MDBhN2Q5Zjk3dzI8JlQgYXMgY29yZTo6Zm10OjpEaXNwbGF5Pjo6Zm10OjpoNzQ5MDFkMjhlYjhjODNlNngyYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OmFsbG9jOjpoYW5kbGVfYWxsb2NfZXJyb3J5NV9fcnVzdGNbYjc5NzRlODY5MDQzMGRkOV06Ol9fcnVzdF9hbGxvY19lcnJvcl9oYW5kbGVyentzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OnN5czo6YmFja3RyYWNlOjpfX3J1c3RfZW5kX3Nob3J0X2JhY2t0cmFjZTo6PHN0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6YWxsb2M6OnJ1c3Rfb29tOjp7Y2xvc3VyZSMwfSwgIT57ejxzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OnBhbmlja2luZzo6cGFuaWNfaGFuZGxlcjo6U3RhdGljU3RyUGF5bG9hZCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpwYW5pYzo6UGFuaWNQYXlsb2FkPjo6YXNfc3RyfCZzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OmFsbG9jOjpydXN0X29vbX0qd2FzbV9iaW5kZ2VuOjp0aHJvd19zdHI6Omg3MDdhNmU1OWY0MWEwNGQ0fi1jb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpvcHRpb246OnVud3JhcF9mYWlsZWR/dTxzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OnBhbmlja2luZzo6YmVnaW5fcGFuaWM6OlBheWxvYWQ8JnN0cj4gYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6cGFuaWM6OlBhbmljUGF5bG9hZD46OmFzX3N0coABhAFzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OnN5czo6YmFja3RyYWNlOjpfX3J1c3RfZW5kX3Nob3J0X2JhY2t0cmFjZTo6PHN0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6cGFuaWNraW5nOjpwYW5pY19oYW5kbGVyOjp7Y2xvc3VyZSMwfSwgIT6BAWs8d2VlX2FsbG9jOjpzaXplX2NsYXNzZXM6OlNpemVDbGFzc0FsbG9jUG9saWN5IGFzIHdlZV9hbGxvYzo6QWxsb2NQb2xpY3k+OjptaW5fY2VsbF9zaXplOjpoMmI5MDk2N2ExZjQ1YWEwNIIBJV9fcnVzdGNbYjc5NzRlODY5MDQzMGRkOV06OnJ1c3RfcGFuaWODAVk8d2VlX2FsbG9jOjpMYXJnZUFsbG9jUG9saWN5IGFzIHdlZV9hbGxvYzo6QWxsb2NQb2xpY3k+OjptaW5fY2VsbF9zaXplOjpoYmViYzMwZTU0YWYxZDM0MQBvCXByb2R1Y2VycwIIbGFuZ3VhZ2UBBFJ1c3QADHByb2Nlc3NlZC1ieQMFcnVzdGMdMS45NS4wICg1OTgwNzYxNmUgMjAyNi0wNC0xNCkGd2FscnVzBjAuMjAuMwx3YXNtLWJpbmRnZW4GMC4yLjkyAJQBD3RhcmdldF9mZWF0dXJlcwgrC2J1bGstbWVtb3J5Kw9idWxrLW1lbW9yeS1vcHQrFmNhbGwtaW5kaXJlY3Qtb3ZlcmxvbmcrCm11bHRpdmFsdWUrD211dGFibGUtZ2xvYmFscysTbm9udHJhcHBpbmctZnB0b2ludCsPcmVmZXJlbmNlLXR5cGVzKwhzaWduLWV4dA==";
+                    if (typeof Buffer === 'undefined') {
+                        bytes = Uint8Array.from(atob(base64), c => c.charCodeAt(0));
+                    } else {
+                        bytes = Buffer.from(base64, 'base64');
+                    }
+                    
+            export const booted = 
+            WebAssembly.instantiate(bytes,{ './bindgen_bg.js': import_b,  })
+                .then(obj => {
+                    const wasm = obj.instance;
+                    memory = wasm.exports.memory;
+__wbg_encoding_free = wasm.exports.__wbg_encoding_free;
+encoding_decode = wasm.exports.encoding_decode;
+encoding_encode = wasm.exports.encoding_encode;
+encoding_erased_chars = wasm.exports.encoding_erased_chars;
+encoding_identicon_svg = wasm.exports.encoding_identicon_svg;
+encoding_is_corrupt = wasm.exports.encoding_is_corrupt;
+encoding_new = wasm.exports.encoding_new;
+encoding_randomart = wasm.exports.encoding_randomart;
+__wbindgen_add_to_stack_pointer = wasm.exports.__wbindgen_add_to_stack_pointer;
+__wbindgen_malloc = wasm.exports.__wbindgen_malloc;
+__wbindgen_realloc = wasm.exports.__wbindgen_realloc;
+__wbindgen_free = wasm.exports.__wbindgen_free;
+
+                })
+            ;
+            export let memory;
+export let __wbg_encoding_free;
+export let encoding_decode;
+export let encoding_encode;
+export let encoding_erased_chars;
+export let encoding_identicon_svg;
+export let encoding_is_corrupt;
+export let encoding_new;
+export let encoding_randomart;
+export let __wbindgen_add_to_stack_pointer;
+export let __wbindgen_malloc;
+export let __wbindgen_realloc;
+export let __wbindgen_free;
+
+            
\ No newline at end of file
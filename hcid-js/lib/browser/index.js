@@ -1,7 +1,7 @@
 /* eslint camelcase:0 */
 
 import * as bindgen from './bindgen'
-import { booted } from './bindgen_bg'
+import { booted } from './bindgen_wasm'
 
 function txError (fn) {
   try {
@@ -50,4 +50,28 @@ export class Encoding {
     }
     return txError(() => this._raw.is_corrupt(data))
   }
+
+  randomart (data) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    return txError(() => this._raw.randomart(data))
+  }
+
+  identicon_svg (data, size) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    if (typeof size !== 'number') {
+      throw new Error('size must be a number')
+    }
+    return txError(() => this._raw.identicon_svg(data, size))
+  }
+
+  erased_chars (data) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    return Array.from(this._raw.erased_chars(data))
+  }
 }
@@ -130,5 +130,18 @@ describe('Encoding Suite', () => {
       expect(enc.is_corrupt(TEST_ID_1.substr(0, 10) + 'A' + TEST_ID_1.substr(11)))
         .equals(true)
     })
+
+    it('should fingerprint the decoded key', () => {
+      expect(enc.randomart(TEST_ID_1.toLowerCase()))
+        .equals(enc.randomart(TEST_ID_1))
+      expect(enc.identicon_svg(TEST_ID_1, 48)).contains('<svg ')
+    })
+
+    it('should decode erasure markers', () => {
+      const smudged = TEST_ID_1.substr(0, 5) + '??' + TEST_ID_1.substr(7)
+      expect(enc.erased_chars(smudged)).deep.equals([5, 6])
+      expect(Buffer.from(enc.decode(smudged)).toString('hex'))
+        .equals(TEST_HEX_1)
+    })
   })
 })
@@ -46,6 +46,30 @@ class Encoding {
     }
     return txError(() => this._raw.is_corrupt(data))
   }
+
+  randomart (data) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    return txError(() => this._raw.randomart(data))
+  }
+
+  identicon_svg (data, size) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    if (typeof size !== 'number') {
+      throw new Error('size must be a number')
+    }
+    return txError(() => this._raw.identicon_svg(data, size))
+  }
+
+  erased_chars (data) {
+    if (typeof data !== 'string') {
+      throw new Error('data must be a string')
+    }
+    return Array.from(this._raw.erased_chars(data))
+  }
 }
 
 exports.Encoding = Encoding
@@ -39,4 +39,16 @@ impl Encoding {
     pub fn is_corrupt(&self, data: &str) -> JsResult<bool> {
         jserr!(self.0.is_corrupt(data))
     }
+
+    pub fn randomart(&self, data: &str) -> JsResult<String> {
+        jserr!(self.0.randomart(data))
+    }
+
+    pub fn identicon_svg(&self, data: &str, size: u32) -> JsResult<String> {
+        jserr!(self.0.identicon_svg(data, size))
+    }
+
+    pub fn erased_chars(&self, data: &str) -> Vec<u32> {
+        self.0.erased_chars(data).into_iter().map(|i| i as u32).collect()
+    }
 }
@@ -0,0 +1,272 @@
+//! Abbreviated ids, in the way git abbreviates commit hashes, for logs and tables.
+//!
+//! An abbreviation is the 3 character kind prefix, an ellipsis, and the leading characters of
+//! the key from character 5 on (characters 3 and 4 carry mostly prefix bits, so they are the
+//! same across most ids), e.g. `HcK…Dds5Oi`. Abbreviations are resolved against an `IdSet` of
+//! known ids, ignoring case and transliterations (0/O, 1/I/l, ...).
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{skeleton, HcidError, HcidResult};
+
+/// characters of the kind prefix kept in front of the ellipsis
+const KIND_CHARS: usize = 3;
+
+/// character the abbreviated body starts at
+const BODY_START: usize = 5;
+
+/// written between the kind and body; `...` is accepted too
+const ELLIPSIS: char = '…';
+
+/// the abbreviation of `id` with `len` body characters, without regard to other ids. Use
+/// `IdSet::abbreviate` for one that is unique among known ids.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let id = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+/// assert_eq!("HcK…Dds5Oi", hcid::abbreviate(id, 6));
+/// ```
+pub fn abbreviate(id: &str, len: usize) -> String {
+    let mut out: String = id.chars().take(KIND_CHARS).collect();
+    out.push(ELLIPSIS);
+    out.extend(id.chars().skip(BODY_START).take(len));
+    out
+}
+
+/// a set of known ids, to abbreviate ids uniquely and resolve abbreviations against
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let id = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+/// let mut set = hcid::IdSet::new();
+/// set.insert(id);
+/// set.insert("HcKcjDds5O4ogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i");
+///
+/// // "HcK…Dds5O" would match both
+/// assert_eq!("HcK…Dds5Oi", set.abbreviate(id, 4));
+/// assert_eq!(id, set.resolve("hck...dds50i").unwrap());
+/// assert!(set.resolve("HcK…Dds5").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IdSet {
+    /// ids as inserted, by skeleton
+    ids: BTreeMap<String, String>,
+    /// (skeleton body, skeleton) of every id, for looking up abbreviations by body
+    bodies: BTreeSet<(String, String)>,
+}
+
+impl IdSet {
+    /// an empty set
+    pub fn new() -> Self {
+        IdSet::default()
+    }
+
+    /// add `id` to the set. Returns false (and keeps the id already there) if the set already
+    /// holds `id`, or an id that only differs from it in case or transliterations.
+    pub fn insert(&mut self, id: &str) -> bool {
+        let skel = skeleton(id);
+        if self.ids.contains_key(&skel) {
+            return false;
+        }
+        self.bodies.insert((body(&skel), skel.clone()));
+        self.ids.insert(skel, id.to_string());
+        true
+    }
+
+    /// remove `id` (or an id that only differs from it in case or transliterations), returning
+    /// true if it was in the set
+    pub fn remove(&mut self, id: &str) -> bool {
+        let skel = skeleton(id);
+        if self.ids.remove(&skel).is_none() {
+            return false;
+        }
+        self.bodies.remove(&(body(&skel), skel));
+        true
+    }
+
+    /// true if the set holds `id`, ignoring case and transliterations
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains_key(&skeleton(id))
+    }
+
+    /// the number of ids in the set
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// true if the set holds no ids
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// the ids in the set, as inserted
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.ids.values().map(|id| id.as_str())
+    }
+
+    /// the shortest abbreviation of `id`, of at least `min_len` body characters, that no other
+    /// id of the set matches. `id` need not be in the set itself. If every abbreviation is
+    /// ambiguous, `id` is returned whole.
+    pub fn abbreviate(&self, id: &str, min_len: usize) -> String {
+        let skel = skeleton(id);
+        let id_kind = kind(&skel);
+        let skel_body = body(&skel);
+
+        for len in min_len.max(1)..=skel_body.chars().count() {
+            let short: String = skel_body.chars().take(len).collect();
+            let others = self
+                .body_matches(&short)
+                .into_iter()
+                .filter(|other| **other != skel && kind(other) == id_kind)
+                .count();
+            if others == 0 {
+                return abbreviate(id, len);
+            }
+        }
+        id.to_string()
+    }
+
+    /// every id of the set that `abbrev` matches. `abbrev` may be an abbreviation (with `…` or
+    /// `...`, and with or without the kind before it) or the leading characters of a full id.
+    /// Case and transliterations are ignored.
+    pub fn candidates(&self, abbrev: &str) -> Vec<&str> {
+        let abbrev = abbrev.trim();
+        let split = abbrev
+            .find(ELLIPSIS)
+            .map(|i| (i, ELLIPSIS.len_utf8()))
+            .or_else(|| abbrev.find("...").map(|i| (i, 3)));
+
+        let skels: Vec<&String> = match split {
+            Some((i, sep_len)) => {
+                let kind = skeleton(&abbrev[..i]);
+                let short = skeleton(&abbrev[i + sep_len..]);
+                self.body_matches(&short)
+                    .into_iter()
+                    .filter(|skel| skel.starts_with(&kind))
+                    .collect()
+            }
+            None => {
+                let prefix = skeleton(abbrev);
+                self.ids
+                    .range(prefix.clone()..)
+                    .map(|(skel, _)| skel)
+                    .take_while(|skel| skel.starts_with(&prefix))
+                    .collect()
+            }
+        };
+
+        skels.into_iter().map(|skel| self.ids[skel].as_str()).collect()
+    }
+
+    /// the one id of the set that `abbrev` matches, see `candidates`
+    pub fn resolve(&self, abbrev: &str) -> HcidResult<&str> {
+        let mut found = self.candidates(abbrev);
+        match found.len() {
+            0 => Err(HcidError(format!("UnknownId:{}", abbrev))),
+            1 => Ok(found.remove(0)),
+            _ => Err(HcidError(format!("AmbiguousId:{}", found.join(",")))),
+        }
+    }
+
+    /// skeletons of the ids whose bodies start with `short`
+    fn body_matches(&self, short: &str) -> Vec<&String> {
+        self.bodies
+            .range((short.to_string(), String::new())..)
+            .take_while(|(b, _)| b.starts_with(short))
+            .map(|(_, skel)| skel)
+            .collect()
+    }
+}
+
+impl<'a> std::iter::FromIterator<&'a str> for IdSet {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut set = IdSet::new();
+        for id in iter {
+            set.insert(id);
+        }
+        set
+    }
+}
+
+fn kind(skel: &str) -> String {
+    skel.chars().take(KIND_CHARS).collect()
+}
+
+fn body(skel: &str) -> String {
+    skel.chars().skip(BODY_START).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_encoding;
+    use super::*;
+
+    fn ids(kind: &str, keys: &[[u8; 32]]) -> Vec<String> {
+        let enc = test_encoding(kind);
+        keys.iter().map(|k| enc.encode(k).unwrap()).collect()
+    }
+
+    #[test]
+    fn it_abbreviates_uniquely() {
+        // keys that agree in their first 4 bytes give ids that agree in their first body chars
+        let mut a = [1; 32];
+        let mut b = [1; 32];
+        a[4] = 0x00;
+        b[4] = 0xff;
+        let ids = ids("hck0", &[a, b]);
+        let set: IdSet = ids.iter().map(|id| id.as_str()).collect();
+
+        let short_a = set.abbreviate(&ids[0], 1);
+        let short_b = set.abbreviate(&ids[1], 1);
+        assert_eq!(short_a.chars().count(), short_b.chars().count());
+        assert!(short_a.chars().count() > KIND_CHARS + 1 + 1);
+        assert_eq!(ids[0], set.resolve(&short_a).unwrap());
+        assert_eq!(ids[1], set.resolve(&short_b).unwrap());
+
+        // one character shorter matches both
+        let shorter: String = short_a.chars().take(short_a.chars().count() - 1).collect();
+        assert!(set.resolve(&shorter).unwrap_err().0.starts_with("AmbiguousId:"));
+        let mut found = set.candidates(&shorter);
+        found.sort();
+        assert_eq!(vec![ids[0].as_str(), ids[1].as_str()], found);
+
+        // a long enough minimum is kept
+        assert_eq!(abbreviate(&ids[0], 20), set.abbreviate(&ids[0], 20));
+    }
+
+    #[test]
+    fn it_resolves_loosely() {
+        let ids = ids("hcs0", &[[0; 32], [0x77; 32]]);
+        let set: IdSet = ids.iter().map(|id| id.as_str()).collect();
+
+        // git-style leading characters, any case, and kind-less abbreviations
+        assert_eq!(ids[1], set.resolve(&ids[1][..12].to_lowercase()).unwrap());
+        let body: String = ids[1].chars().skip(BODY_START).take(6).collect();
+        assert_eq!(ids[1], set.resolve(&format!("…{}", body)).unwrap());
+        assert_eq!(ids[1], set.resolve(&format!("hcs...{}", body.to_uppercase())).unwrap());
+
+        // a different kind does not match
+        assert_eq!(
+            Err(HcidError(format!("UnknownId:HcA…{}", body))),
+            set.resolve(&format!("HcA…{}", body))
+        );
+    }
+
+    #[test]
+    fn it_keeps_one_of_confusable_ids() {
+        let id = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+        let mut set = IdSet::new();
+        assert!(set.insert(id));
+        assert!(!set.insert(&id.to_lowercase()));
+        assert!(set.contains(&id.replace('O', "0")));
+        assert_eq!(vec![id], set.iter().collect::<Vec<_>>());
+
+        assert!(set.remove(&id.to_uppercase()));
+        assert!(set.is_empty());
+        assert!(set.resolve("HcK").is_err());
+    }
+}
@@ -0,0 +1,458 @@
+//! Autocompleting partially typed ids against a large directory of known ids.
+//!
+//! The index is a suffix array over the skeletons (upper-cased, transliterated forms, see
+//! `skeleton`) of the ids, so typed text matches anywhere in an id, in any case and with 0/O,
+//! 1/I/l etc. mixed up. It is kept as one flat byte buffer, which can be saved to a file and
+//! memory-mapped back (with the `mmap` feature) without copying it. Loading checks every table
+//! entry and that the ids are utf-8, so it takes time in proportion to the size of the index.
+//! Expect about 4 bytes per id character plus the ids themselves, i.e. ~390MB for a million 63
+//! char ids.
+//!
+//! Layout (integers u32 little endian):
+//!
+//! ```text
+//! magic "HCIDAC01" | id count | suffix count | skeletons length | ids length
+//! skeletons, each ended by '\n' | ids as given, each ended by '\n'
+//! skeleton start, per id | id start, per id | suffix start, sorted by suffix
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::{skeleton, HcidError, HcidResult};
+
+const MAGIC: &[u8; 8] = b"HCIDAC01";
+
+const HEADER_LEN: usize = MAGIC.len() + 4 * 4;
+
+/// an autocomplete index over `data`, a buffer in the layout of the module docs
+pub struct AutocompleteIndex<D> {
+    data: D,
+    id_count: usize,
+    suffix_count: usize,
+    skel_len: usize,
+    ids_len: usize,
+}
+
+impl AutocompleteIndex<Vec<u8>> {
+    /// index `ids`. Ids that only differ in case or transliterations from one already indexed
+    /// are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let ids: Vec<String> = (0..10).map(|i| enc.encode(&[i; 32]).unwrap()).collect();
+    /// let index = hcid::autocomplete::AutocompleteIndex::build(ids.iter().map(|id| id.as_str()))
+    ///     .unwrap();
+    ///
+    /// // typed text matches anywhere in the id, ignoring case and transliterations
+    /// let typed = ids[7][20..26].to_lowercase().replace('o', "0");
+    /// assert_eq!(vec![ids[7].as_str()], index.complete(&typed, 5));
+    /// ```
+    pub fn build<'a, I: IntoIterator<Item = &'a str>>(ids: I) -> HcidResult<Self> {
+        let mut seen = HashSet::new();
+        let mut skels = Vec::new();
+        let mut originals = Vec::new();
+        let mut skel_starts = Vec::new();
+        let mut id_starts = Vec::new();
+        let mut suffixes = Vec::new();
+
+        for id in ids {
+            let skel = skeleton(id);
+            if skel.is_empty() || skel.contains('\n') || id.contains('\n') {
+                return Err(HcidError(format!("BadIndexId:{}", id)));
+            }
+            if !seen.insert(skel.clone()) {
+                continue;
+            }
+
+            skel_starts.push(skels.len());
+            suffixes.extend(skel.char_indices().map(|(i, _)| skels.len() + i));
+            skels.extend_from_slice(skel.as_bytes());
+            skels.push(b'\n');
+
+            id_starts.push(originals.len());
+            originals.extend_from_slice(id.as_bytes());
+            originals.push(b'\n');
+        }
+
+        suffixes.sort_unstable_by(|a, b| skels[*a..].cmp(&skels[*b..]));
+
+        let len =
+            HEADER_LEN + skels.len() + originals.len() + 4 * (2 * seen.len() + suffixes.len());
+        if len > u32::MAX as usize {
+            return Err(HcidError(format!("IndexTooLarge:{}", len)));
+        }
+
+        let mut data = Vec::with_capacity(len);
+        data.extend_from_slice(MAGIC);
+        for n in &[seen.len(), suffixes.len(), skels.len(), originals.len()] {
+            data.extend_from_slice(&(*n as u32).to_le_bytes());
+        }
+        data.extend_from_slice(&skels);
+        data.extend_from_slice(&originals);
+        for n in skel_starts.iter().chain(&id_starts).chain(&suffixes) {
+            data.extend_from_slice(&(*n as u32).to_le_bytes());
+        }
+
+        AutocompleteIndex::from_bytes(data)
+    }
+
+    /// write the index to `path`, to `open` or `from_bytes` later
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> HcidResult<()> {
+        std::fs::write(path, &self.data).map_err(io_error)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl AutocompleteIndex<memmap2::Mmap> {
+    /// memory-map a saved index. The file must not change while the index is in use.
+    pub fn open<P: AsRef<Path>>(path: P) -> HcidResult<Self> {
+        let file = std::fs::File::open(path).map_err(io_error)?;
+        // safety: the mapping is read-only, and callers keep the file unchanged (see above)
+        let data = unsafe { memmap2::Mmap::map(&file) }.map_err(io_error)?;
+        AutocompleteIndex::from_bytes(data)
+    }
+}
+
+impl<D: AsRef<[u8]>> AutocompleteIndex<D> {
+    /// an index over `data` as built by `build` (e.g. read back from a saved file), checking
+    /// that it is well formed
+    pub fn from_bytes(data: D) -> HcidResult<Self> {
+        let bytes = data.as_ref();
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(HcidError(String::from("BadIndexHeader")));
+        }
+
+        let header = |i: usize| read_u32(bytes, MAGIC.len() + 4 * i);
+        let index = AutocompleteIndex {
+            id_count: header(0),
+            suffix_count: header(1),
+            skel_len: header(2),
+            ids_len: header(3),
+            data,
+        };
+
+        // header counts are untrusted, and overflow a 32 bit usize when large enough
+        let len = index
+            .id_count
+            .checked_mul(2)
+            .and_then(|n| n.checked_add(index.suffix_count))
+            .and_then(|n| n.checked_mul(4))
+            .and_then(|n| n.checked_add(index.skel_len))
+            .and_then(|n| n.checked_add(index.ids_len))
+            .and_then(|n| n.checked_add(HEADER_LEN));
+        if len != Some(index.bytes().len()) {
+            return Err(HcidError(format!(
+                "BadIndexLen:{},Expected:{}",
+                index.bytes().len(),
+                len.map_or(String::from("overflow"), |len| len.to_string())
+            )));
+        }
+
+        // each text holds one '\n' ended line per id, and each id's starts are the start of its
+        // lines: strictly increasing line starts, as many as there are lines, are exactly the
+        // line starts, from 0 on. Every suffix starts inside a skeleton. Lookups rely on all of
+        // this to stay in range from here on.
+        let lines_ok = |text: &[u8]| {
+            text.last().map_or(true, |b| *b == b'\n')
+                && text.iter().filter(|b| **b == b'\n').count() == index.id_count
+        };
+        let line_start = |text: &[u8], start: usize| {
+            start < text.len() && text[start] != b'\n' && (start == 0 || text[start - 1] == b'\n')
+        };
+        let starts_ok = (0..index.id_count).all(|i| {
+            line_start(index.skels(), index.skel_start(i))
+                && line_start(index.ids(), index.id_start(i))
+                && (i == 0
+                    || (index.skel_start(i - 1) < index.skel_start(i)
+                        && index.id_start(i - 1) < index.id_start(i)))
+        }) && (0..index.suffix_count).all(|i| {
+            let pos = index.suffix(i);
+            pos < index.skel_len && index.skels()[pos] != b'\n'
+        });
+        let text_ok = lines_ok(index.skels())
+            && lines_ok(index.ids())
+            && std::str::from_utf8(index.ids()).is_ok();
+        if !starts_ok || !text_ok {
+            return Err(HcidError(String::from("BadIndexData")));
+        }
+
+        Ok(index)
+    }
+
+    /// the index as a byte buffer, e.g. to write somewhere other than a file
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes()
+    }
+
+    /// the number of ids indexed
+    pub fn len(&self) -> usize {
+        self.id_count
+    }
+
+    /// true if no ids are indexed
+    pub fn is_empty(&self) -> bool {
+        self.id_count == 0
+    }
+
+    /// up to `k` ids that contain the partially typed `partial` (ignoring case and
+    /// transliterations). Ids it is found closer to the start of come first, then ids in the
+    /// order they were indexed.
+    ///
+    /// Finding the matches takes a binary search, but ranking them visits every match, however
+    /// small `k` is: a short `partial`, such as a kind prefix that starts every id, costs time
+    /// and memory in proportion to the number of ids indexed.
+    pub fn complete(&self, partial: &str, k: usize) -> Vec<&str> {
+        let query = skeleton(partial);
+        let query = query.as_bytes();
+        // a '\n' would match the end of one skeleton and the start of the next
+        if query.is_empty() || query.contains(&b'\n') || k == 0 {
+            return Vec::new();
+        }
+
+        // the suffixes starting with the query sit together in the sorted suffix array
+        let first = self.partition(0, self.suffix_count, |s| s < query);
+        let end = self.partition(first, self.suffix_count, |s| s.starts_with(query));
+
+        // the nearest to the start match of each id
+        let mut nearest: HashMap<usize, usize> = HashMap::new();
+        for i in first..end {
+            let pos = self.suffix(i);
+            let id = self.partition_ids(|start| start <= pos) - 1;
+            let offset = pos - self.skel_start(id);
+            let best = nearest.entry(id).or_insert(offset);
+            *best = offset.min(*best);
+        }
+
+        let mut found: Vec<(usize, usize)> = nearest.into_iter().map(|(id, o)| (o, id)).collect();
+        found.sort_unstable();
+        found.truncate(k);
+        found.into_iter().map(|(_, id)| self.id(id)).collect()
+    }
+
+    fn bytes(&self) -> &[u8] {
+        self.data.as_ref()
+    }
+
+    fn skels(&self) -> &[u8] {
+        &self.bytes()[HEADER_LEN..HEADER_LEN + self.skel_len]
+    }
+
+    fn ids(&self) -> &[u8] {
+        let start = HEADER_LEN + self.skel_len;
+        &self.bytes()[start..start + self.ids_len]
+    }
+
+    /// u32 number `i` of the tables following the text
+    fn table(&self, i: usize) -> usize {
+        read_u32(self.bytes(), HEADER_LEN + self.skel_len + self.ids_len + 4 * i)
+    }
+
+    fn skel_start(&self, id: usize) -> usize {
+        self.table(id)
+    }
+
+    fn id_start(&self, id: usize) -> usize {
+        self.table(self.id_count + id)
+    }
+
+    fn suffix(&self, i: usize) -> usize {
+        self.table(2 * self.id_count + i)
+    }
+
+    /// id number `id`, as indexed
+    fn id(&self, id: usize) -> &str {
+        let ids = &self.ids()[self.id_start(id)..];
+        let end = ids.iter().position(|b| *b == b'\n').unwrap_or(ids.len());
+        // checked to be utf-8 in from_bytes, and split at an ascii '\n'
+        std::str::from_utf8(&ids[..end]).unwrap_or_default()
+    }
+
+    /// the first suffix number in `lo..hi` whose suffix `pred` is false for (`pred` being true
+    /// for all suffixes before some point and false after it)
+    fn partition<F: Fn(&[u8]) -> bool>(&self, mut lo: usize, mut hi: usize, pred: F) -> usize {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(&self.skels()[self.suffix(mid)..]) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// the first id number whose skeleton start `pred` is false for
+    fn partition_ids<F: Fn(usize) -> bool>(&self, pred: F) -> usize {
+        let (mut lo, mut hi) = (0, self.id_count);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(self.skel_start(mid)) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> usize {
+    let mut n = [0; 4];
+    n.copy_from_slice(&bytes[at..at + 4]);
+    u32::from_le_bytes(n) as usize
+}
+
+fn io_error(e: std::io::Error) -> HcidError {
+    HcidError(format!("IndexIoError:{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HcidEncoding;
+    use super::*;
+
+    fn test_ids(count: u8) -> Vec<String> {
+        let enc = HcidEncoding::with_kind("hcs0").unwrap();
+        (0..count).map(|i| enc.encode(&[i; 32]).unwrap()).collect()
+    }
+
+    fn test_index(ids: &[String]) -> AutocompleteIndex<Vec<u8>> {
+        AutocompleteIndex::build(ids.iter().map(|id| id.as_str())).unwrap()
+    }
+
+    #[test]
+    fn it_completes_anywhere_in_ids() {
+        let ids = test_ids(50);
+        let index = test_index(&ids);
+        assert_eq!(50, index.len());
+
+        for (i, id) in ids.iter().enumerate() {
+            for start in &[0, 5, 30, 57] {
+                let typed = id[*start..*start + 6].to_lowercase();
+                let found = index.complete(&typed, 100);
+                assert!(found.contains(&id.as_str()), "{} not found for {}", i, typed);
+            }
+        }
+
+        // ids the text was found nearer the start of come first
+        let found = index.complete("HcS", 3);
+        assert_eq!(3, found.len());
+        assert!(found.iter().all(|id| id.starts_with("HcS")));
+        assert_eq!(vec![ids[0].as_str(), ids[1].as_str(), ids[2].as_str()], found);
+
+        assert!(index.complete("zzzzzzzz", 5).is_empty());
+        assert!(index.complete("", 5).is_empty());
+
+        // no match across the end of one id and the start of the next
+        let across = format!("{}\n{}", &ids[0][58..], &ids[1][..4]);
+        assert!(index.complete(&across, 5).is_empty());
+    }
+
+    #[test]
+    fn it_dedupes_confusable_ids() {
+        let ids = test_ids(2);
+        let index = AutocompleteIndex::build(vec![
+            ids[0].as_str(),
+            &ids[0].to_lowercase(),
+            ids[1].as_str(),
+        ])
+        .unwrap();
+        assert_eq!(2, index.len());
+        assert_eq!(vec![ids[0].as_str()], index.complete(&ids[0], 5));
+    }
+
+    #[test]
+    fn it_round_trips_bytes() {
+        let ids = test_ids(20);
+        let index = test_index(&ids);
+
+        let copy = AutocompleteIndex::from_bytes(index.as_bytes().to_vec()).unwrap();
+        assert_eq!(index.complete("aaa", 10), copy.complete("aaa", 10));
+
+        let mut bad = index.as_bytes().to_vec();
+        bad.pop();
+        assert!(AutocompleteIndex::from_bytes(bad).is_err());
+        assert_eq!(
+            Err(HcidError::from("BadIndexHeader")),
+            AutocompleteIndex::from_bytes(&b"HCIDAC00"[..]).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn it_rejects_corrupt_tables() {
+        let ids = test_ids(3);
+        let index = test_index(&ids);
+        let tables = HEADER_LEN + index.skel_len + index.ids_len;
+
+        let corrupt = |at: usize, n: u32| {
+            let mut bad = index.as_bytes().to_vec();
+            bad[at..at + 4].copy_from_slice(&n.to_le_bytes());
+            AutocompleteIndex::from_bytes(bad).map(|_| ())
+        };
+        let bad_data = Err(HcidError::from("BadIndexData"));
+
+        // skeleton starts: not from 0, mid skeleton, out of order
+        assert_eq!(bad_data, corrupt(tables, 10));
+        assert_eq!(bad_data, corrupt(tables + 4, 70));
+        assert_eq!(bad_data, corrupt(tables + 4, 128));
+        // id starts, and a suffix on a '\n'
+        assert_eq!(bad_data, corrupt(tables + 12 + 4, 0));
+        assert_eq!(bad_data, corrupt(tables + 24, 63));
+
+        assert!(corrupt(tables, 0).is_ok());
+
+        // header counts too large to add up
+        let bad_len = corrupt(MAGIC.len(), u32::MAX).unwrap_err();
+        assert!(bad_len.0.starts_with("BadIndexLen:"));
+
+        let empty = AutocompleteIndex::build(Vec::new()).unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.complete("HcS", 5).is_empty());
+    }
+
+    #[test]
+    fn it_ranks_every_match() {
+        let enc = HcidEncoding::with_kind("hcs0").unwrap();
+        let ids: Vec<String> = (0..5000u32)
+            .map(|i| {
+                let mut key = [0; 32];
+                key[..4].copy_from_slice(&i.to_be_bytes());
+                key[4..8].copy_from_slice(&i.wrapping_mul(2654435761).to_le_bytes());
+                enc.encode(&key).unwrap()
+            })
+            .collect();
+        let index = test_index(&ids);
+        assert_eq!(5000, index.len());
+
+        // the kind prefix matches every id at offset 0, so index order decides
+        let found = index.complete("hcs", 4);
+        let first: Vec<&str> = ids[..4].iter().map(|id| id.as_str()).collect();
+        assert_eq!(first, found);
+
+        let last = &ids[4999];
+        assert_eq!(vec![last.as_str()], index.complete(&last[8..20], 5));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn it_maps_saved_indexes() {
+        let ids = test_ids(20);
+        let index = test_index(&ids);
+
+        let path = std::env::temp_dir().join(format!("hcid-index-{}", std::process::id()));
+        index.save(&path).unwrap();
+        let mapped = AutocompleteIndex::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(20, mapped.len());
+        for id in &ids {
+            let found = mapped.complete(&id[50..], 5);
+            assert!(found.contains(&id.as_str()));
+            assert_eq!(index.complete(&id[50..], 5), found);
+        }
+    }
+}
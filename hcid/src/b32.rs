@@ -41,6 +41,17 @@ pub fn encode (data: &[u8]) -> Vec<u8> {
     out
 }
 
+/// the 5 bit value of an (upper case) holochain base32 character
+pub fn char_value(c: u8) -> Option<u8> {
+    if c < 51 || c as usize >= REV_LOOKUP.len() + 51 {
+        return None;
+    }
+    match REV_LOOKUP[(c - 51) as usize] {
+        255 => None,
+        v => Some(v),
+    }
+}
+
 /// decode an already sanitized holochain base32 string into a byte buffer
 pub fn decode (data: &[u8]) -> crate::HcidResult<Vec<u8>> {
     let mut out: Vec<u8> = vec![];
@@ -0,0 +1,97 @@
+//! Detection of visually confusable ids.
+//!
+//! Two ids are confusable when they differ only in letter case or in
+//! characters that transliteration maps together (0/O, 1/I/l, 2/Z, or
+//! unicode look-alikes folded by lenient normalization).
+
+use super::{normalize, NormalizeMode};
+use super::util::b32_translit;
+
+/// reduce an id to its canonical confusable form: normalized, transliterated
+/// and upper-cased. Characters that cannot be transliterated are kept as-is.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// assert_eq!("HCKCIO", hcid::skeleton("HcKcl0"));
+/// ```
+pub fn skeleton(id: &str) -> String {
+    normalize(id, NormalizeMode::Lenient)
+        .text
+        .chars()
+        .map(|c| {
+            if !c.is_ascii() {
+                return c;
+            }
+            match b32_translit(c as u8) {
+                Some(t) => t.to_ascii_uppercase() as char,
+                None => c,
+            }
+        })
+        .collect()
+}
+
+/// true if `a` and `b` are different strings that reduce to the same skeleton
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// assert!(hcid::confusable("HcKciDds5Oiog", "HcKcIDdS50iog"));
+/// assert!(!hcid::confusable("HcKciDds5Oiog", "HcKciDds5Oiog"));
+/// assert!(!hcid::confusable("HcKciDds5Oiog", "HcKciDds5Oioq"));
+/// ```
+pub fn confusable(a: &str, b: &str) -> bool {
+    a != b && skeleton(a) == skeleton(b)
+}
+
+/// find every id in `known` that `id` could be confused with
+pub fn find_confusable<'a, I>(id: &str, known: I) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let skel = skeleton(id);
+    known
+        .into_iter()
+        .filter(|k| *k != id && skeleton(k) == skel)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+    static TEST_ID_2: &str = "HcKcJAYWKF7xpsddq6ojeqbIVCp837fpcNz77479pND7KzBmH7nUcV4u4sN3vpr";
+
+    #[test]
+    fn it_detects_case_only_differences() {
+        assert!(confusable(TEST_ID_1, &TEST_ID_1.to_ascii_lowercase()));
+        assert!(confusable(TEST_ID_1, &TEST_ID_1.to_ascii_uppercase()));
+    }
+
+    #[test]
+    fn it_detects_transliteration_differences() {
+        let spoof = TEST_ID_1.replace("O", "0").replace("i", "l");
+        assert!(confusable(TEST_ID_1, &spoof));
+
+        let spoof = TEST_ID_1.replacen("O", "\u{041e}", 1);
+        assert!(confusable(TEST_ID_1, &spoof));
+    }
+
+    #[test]
+    fn it_ignores_distinct_ids() {
+        assert!(!confusable(TEST_ID_1, TEST_ID_2));
+        assert!(!confusable(TEST_ID_1, TEST_ID_1));
+    }
+
+    #[test]
+    fn it_finds_confusable_known_ids() {
+        let known = [TEST_ID_1, TEST_ID_2];
+        let spoof = TEST_ID_2.to_ascii_lowercase().replace("o", "0");
+
+        assert_eq!(vec![TEST_ID_2], find_confusable(&spoof, known.iter().cloned()));
+        assert!(find_confusable(TEST_ID_1, known.iter().cloned()).is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+//! Working out the kind of an id from its prefix, for callers that accept ids of any kind.
+
+use super::b32;
+use super::util::{b32_correct, char_upper, wipe};
+use super::{
+    known_kinds, normalize, prefix_distance, DecodePolicy, DecodeReport, HcidEncoding,
+    HcidEncodingConfig, HcidError, HcidResult, NormalizeMode, ERASURE_MARKERS,
+};
+
+/// the kind of the id `data`, read from its prefix. A prefix that matches no kind exactly (e.g.
+/// because of a typo) is read as the kind it is fewest characters away from, as long as no
+/// other kind is as close.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let id = enc.encode(&[7; 32]).unwrap();
+/// assert_eq!("hcs0", hcid::detect_kind(&id).unwrap());
+/// assert_eq!("hcs0", hcid::detect_kind(&id.replacen("HcS", "HxS", 1)).unwrap());
+/// ```
+pub fn detect_kind(data: &str) -> HcidResult<String> {
+    let mut text = normalize(data, NormalizeMode::Lenient).text;
+    let len = text.chars().count();
+    let prefix = read_prefix(&text);
+    wipe(&mut text);
+    let (prefix, char_erasures) = prefix?;
+
+    let mut nearest: Vec<String> = Vec::new();
+    let mut nearest_distance = usize::MAX;
+    for kind in known_kinds() {
+        let config = HcidEncodingConfig::new(&kind)?;
+        if config.encoded_char_count != len {
+            continue;
+        }
+
+        let distance = prefix_distance(&prefix, &config.prefix, &char_erasures);
+        if distance < nearest_distance {
+            nearest.clear();
+            nearest_distance = distance;
+        }
+        if distance == nearest_distance {
+            nearest.push(kind);
+        }
+    }
+
+    match nearest.len() {
+        0 => Err(HcidError(format!("NoKindForIdLen:{}", len))),
+        1 => Ok(nearest.remove(0)),
+        _ => Err(HcidError(format!("AmbiguousKind:{}", nearest.join(",")))),
+    }
+}
+
+/// decode an id of any kind, as detected by `detect_kind`, returning the kind along with the
+/// decode report
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let id = enc.encode(&[7; 32]).unwrap();
+///
+/// let (kind, report) = hcid::decode_any(&id.replacen("HcS", "HxS", 1)).unwrap();
+/// assert_eq!("hcs0", kind);
+/// assert_eq!(vec![7; 32], report.data);
+/// assert_eq!(1, report.prefix_repairs);
+/// ```
+pub fn decode_any(data: &str) -> HcidResult<(String, DecodeReport)> {
+    let kind = detect_kind(data)?;
+    let report = HcidEncoding::with_kind(&kind)?.decode_report(data, DecodePolicy::Full)?;
+    Ok((kind, report))
+}
+
+/// the 3 prefix bytes rendered by the leading characters of `text`, and which of those
+/// characters were unreadable
+fn read_prefix(text: &str) -> HcidResult<(Vec<u8>, Vec<u8>)> {
+    // 24 prefix bits are rendered in 5 chars; pad those out to a whole number of bytes. Chars
+    // left non-ascii by normalizing cannot be transliterated, and stand in as erasure markers.
+    let mut leading: Vec<u8> = text
+        .chars()
+        .take(5)
+        .map(|c| if c.is_ascii() { c as u8 } else { ERASURE_MARKERS[0] as u8 })
+        .collect();
+    if leading.len() < 5 {
+        return Err(HcidError(format!("BadIdLen:{}", text.chars().count())));
+    }
+    let mut erasures = vec![b'0'; 5];
+    let mut chars = b32_correct(&leading, &mut erasures);
+    wipe(&mut leading);
+    for c in chars.iter_mut() {
+        char_upper(c);
+    }
+    chars.resize(8, b'A');
+
+    let mut prefix = b32::decode(&chars)?;
+    prefix.truncate(3);
+    Ok((prefix, erasures))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_id(kind: &str) -> String {
+        super::super::test_encoding(kind).encode(&[9; 32]).unwrap()
+    }
+
+    #[test]
+    fn it_detects_exact_prefixes() {
+        for kind in &["hck0", "hca0", "hcs0", "hcz0"] {
+            assert_eq!(*kind, detect_kind(&test_id(kind)).unwrap());
+            assert_eq!(*kind, detect_kind(&test_id(kind).to_lowercase()).unwrap());
+        }
+    }
+
+    #[test]
+    fn it_detects_nearest_prefix() {
+        let id = test_id("hck0");
+        assert_eq!("hck0", detect_kind(&id.replacen("HcK", "HdK", 1)).unwrap());
+        assert_eq!("hck0", detect_kind(&id.replacen("HcK", "_cK", 1)).unwrap());
+    }
+
+    #[test]
+    fn it_counts_unknown_chars_as_one() {
+        let id = test_id("hck0");
+        assert_eq!("hck0", detect_kind(&id.replacen("HcK", "H\u{2603}K", 1)).unwrap());
+        assert_eq!("hck0", detect_kind(&format!("{}\u{2603}", &id[..62])).unwrap());
+        assert_eq!(
+            HcidError::from("NoKindForIdLen:62"),
+            detect_kind(&format!("{}\u{e9}", &id[..61])).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn it_rejects_ambiguous_prefixes() {
+        // the char rendering most of the kind code is gone, so several kinds are as near
+        let id = test_id("hck0").replacen("HcK", "Hc_", 1);
+        assert!(detect_kind(&id).unwrap_err().0.starts_with("AmbiguousKind:"));
+        assert!(detect_kind("HcK").is_err());
+    }
+}
@@ -0,0 +1,110 @@
+//! Ed25519 public key validation for key / agent kinds (`ed25519` feature), opted into with
+//! `HcidEncoding::with_kind_validated`.
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+
+use super::{HcidError, HcidResult};
+
+/// payload validator for kinds carrying an ed25519 public key: the bytes must
+/// decompress to a valid edwards point that is not of small order
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// // the identity point is of small order
+/// let mut identity = [0; 32];
+/// identity[0] = 1;
+/// assert_eq!(
+///     "HcidError(\"InvalidPublicKey:SmallOrder\")",
+///     format!("{:?}", hcid::validate_ed25519_public_key(&identity).unwrap_err()),
+/// );
+/// ```
+pub fn validate_ed25519_public_key(data: &[u8]) -> HcidResult<()> {
+    let compressed = match CompressedEdwardsY::from_slice(data) {
+        Ok(c) => c,
+        Err(_) => return Err(HcidError(String::from("InvalidPublicKey:BadLength"))),
+    };
+
+    match compressed.decompress() {
+        None => Err(HcidError(String::from("InvalidPublicKey:NotOnCurve"))),
+        Some(p) if p.is_small_order() => Err(HcidError(String::from("InvalidPublicKey:SmallOrder"))),
+        Some(_) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HcidEncoding;
+    use super::*;
+
+    // rfc8032 test 1 public key
+    static TEST_HEX_1: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    #[test]
+    fn it_accepts_valid_keys() {
+        let key = hex::decode(TEST_HEX_1).unwrap();
+        validate_ed25519_public_key(&key).unwrap();
+
+        for kind in &["hck0", "hca0"] {
+            let enc = HcidEncoding::with_kind_validated(kind).unwrap();
+            let id = enc.encode(&key).unwrap();
+            assert_eq!(key, enc.decode(&id).unwrap());
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_keys_on_encode() {
+        let enc = HcidEncoding::with_kind_validated("hca0").unwrap();
+
+        // y = 2 has no corresponding x on the curve
+        let mut bad = [0; 32];
+        bad[0] = 2;
+        assert_eq!(
+            "HcidError(\"InvalidPublicKey:NotOnCurve\")",
+            format!("{:?}", enc.encode(&bad).unwrap_err())
+        );
+
+        // all zero is a point of order 4
+        assert_eq!(
+            "HcidError(\"InvalidPublicKey:SmallOrder\")",
+            format!("{:?}", enc.encode(&[0; 32]).unwrap_err())
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_keys_on_decode() {
+        // TEST_ID_1 is not a valid public key
+        let enc = HcidEncoding::with_kind_validated("hck0").unwrap();
+        assert!(enc.decode(TEST_ID_1).is_err());
+
+        // unless validation is left out
+        let enc = HcidEncoding::with_kind("hck0").unwrap();
+        assert!(enc.decode(TEST_ID_1).is_ok());
+
+        // other kinds do not validate their payload
+        let enc = HcidEncoding::with_kind_validated("hcs0").unwrap();
+        assert!(enc.encode(&[0; 32]).is_ok());
+    }
+
+    #[test]
+    fn it_supports_custom_validators() {
+        fn no_zero_first_byte(data: &[u8]) -> HcidResult<()> {
+            if data[0] == 0 {
+                return Err("ZeroFirstByte".into());
+            }
+            Ok(())
+        }
+
+        let mut config = super::super::HcidEncodingConfig::new("hcs0").unwrap();
+        config.payload_validator = Some(no_zero_first_byte);
+        let enc = HcidEncoding::new(config).unwrap();
+
+        assert_eq!(
+            "HcidError(\"ZeroFirstByte\")",
+            format!("{:?}", enc.encode(&[0; 32]).unwrap_err())
+        );
+        assert!(enc.encode(&[1; 32]).is_ok());
+    }
+}
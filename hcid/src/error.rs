@@ -32,8 +32,8 @@ impl<'a> From<&'a str> for HcidError {
     }
 }
 
-impl From<reed_solomon::DecoderError> for HcidError {
-    fn from(error: reed_solomon::DecoderError) -> Self {
+impl From<super::rs::RsError> for HcidError {
+    fn from(error: super::rs::RsError) -> Self {
         Self(format!("{:?}", error))
     }
 }
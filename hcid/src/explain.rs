@@ -0,0 +1,224 @@
+//! Breaking an id down into its structural parts, for debugging.
+
+use std::ops::Range;
+
+use super::b32;
+use super::util::{b32_correct, b32_translit, cap_decode};
+use super::{HcidEncoding, HcidError, HcidResult, ERASURE_MARKERS};
+
+/// a capitalization parity segment of an id
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapSegment {
+    /// the characters of the segment
+    pub chars: Range<usize>,
+    /// the case bits read from its alpha characters (`1` for upper case), at most 8
+    pub bits: String,
+    /// the parity byte carried, if the segment had 8 readable alpha characters
+    pub byte: Option<u8>,
+}
+
+/// the structure of an id, see `HcidEncoding::explain`. Character indexes refer to `text`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    /// the id that was explained, after input normalization
+    pub text: String,
+    /// the fixed prefix characters of the kind, with their expected capitalization
+    pub prefix: String,
+    /// the characters rendering prefix bits
+    pub prefix_chars: Range<usize>,
+    /// the characters rendering key bits
+    pub key_chars: Range<usize>,
+    /// the characters rendering base parity bits (the last one is padded with zero bits)
+    pub base_parity_chars: Range<usize>,
+    /// characters whose 5 bits are split across two bytes
+    pub straddling_chars: Vec<usize>,
+    /// the capitalization parity segments, in parity byte order
+    pub cap_segments: Vec<CapSegment>,
+    /// true if every cap segment is all upper or all lower case, so cap parity is ignored
+    pub case_lost: bool,
+    /// characters that are not (and could not be transliterated into) base32
+    pub erased_chars: Vec<usize>,
+    /// characters read as a different base32 character: index, found, read as
+    pub transliterations: Vec<(usize, char, char)>,
+}
+
+impl HcidEncoding {
+    /// break `id` down into its structural parts: where the prefix, key, base parity and cap
+    /// parity segments sit, and which characters were unreadable or transliterated. Unlike
+    /// `decode`, this does not need `id` to be correct (only of the right length).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    ///
+    /// let explained = enc.explain(&id.replacen("Hc", "H(", 1)).unwrap();
+    /// assert_eq!("HcS", explained.prefix);
+    /// assert_eq!(4..56, explained.key_chars);
+    /// assert_eq!(3..18, explained.cap_segments[0].chars);
+    /// assert_eq!(vec![1], explained.erased_chars);
+    /// ```
+    pub fn explain(&self, id: &str) -> HcidResult<Explanation> {
+        let text = self.normalize(id).text;
+        // one byte per char; non-ascii chars left by normalizing cannot be transliterated, so
+        // they stand in as erasure markers
+        let bytes: Vec<u8> = text
+            .chars()
+            .map(|c| if c.is_ascii() { c as u8 } else { ERASURE_MARKERS[0] as u8 })
+            .collect();
+        if bytes.len() != self.config.encoded_char_count {
+            return Err(HcidError(format!(
+                "BadIdLen:{},Expected:{}",
+                bytes.len(),
+                self.config.encoded_char_count
+            )));
+        }
+
+        let mut char_erasures = vec![b'0'; bytes.len()];
+        let chars = b32_correct(&bytes, &mut char_erasures);
+
+        let mut erased_chars = Vec::new();
+        let mut transliterations = Vec::new();
+        for (i, (c, b)) in text.chars().zip(bytes.iter()).enumerate() {
+            match b32_translit(*b) {
+                None => erased_chars.push(i),
+                Some(t) if t != *b => transliterations.push((i, c, t as char)),
+                Some(_) => (),
+            }
+        }
+
+        let mut prefix = b32::encode(&self.config.prefix);
+        prefix.truncate(self.config.prefix_cap.len());
+        for (c, cap) in prefix.iter_mut().zip(self.config.prefix_cap.iter()) {
+            if *cap == b'0' {
+                c.make_ascii_lowercase();
+            }
+        }
+
+        let prefix_bits = self.config.prefix.len() * 8;
+        let key_bits = prefix_bits + self.config.key_byte_count * 8;
+        let base_bits = key_bits + self.config.base_parity_byte_count * 8;
+        let straddling_chars = (0..bytes.len())
+            .filter(|i| i * 5 / 8 != (i * 5 + 4) / 8)
+            .filter(|i| i * 5 + 4 >= prefix_bits && i * 5 + 4 < base_bits)
+            .collect();
+
+        let mut cap_segments = Vec::new();
+        let mut all_zro = true;
+        let mut all_one = true;
+        for i in 0..self.config.cap_parity_byte_count {
+            let seg_start =
+                self.config.prefix_cap.len() + i * self.config.cap_segment_char_count;
+            let seg = seg_start..seg_start + self.config.cap_segment_char_count;
+
+            let byte = cap_decode(seg.start, &chars[seg.clone()], &char_erasures)?;
+            match byte {
+                Some(0x00) => all_one = false,
+                Some(0xff) => all_zro = false,
+                Some(_) => {
+                    all_zro = false;
+                    all_one = false;
+                }
+                None => (),
+            }
+
+            let bits = chars[seg.clone()]
+                .iter()
+                .filter(|c| c.is_ascii_alphabetic())
+                .take(8)
+                .map(|c| if c.is_ascii_uppercase() { '1' } else { '0' })
+                .collect();
+            cap_segments.push(CapSegment {
+                chars: seg,
+                bits,
+                byte,
+            });
+        }
+
+        Ok(Explanation {
+            prefix: String::from_utf8_lossy(&prefix).to_string(),
+            prefix_chars: 0..prefix_bits.div_ceil(5),
+            key_chars: prefix_bits / 5..key_bits.div_ceil(5),
+            base_parity_chars: key_bits / 5..bytes.len(),
+            straddling_chars,
+            cap_segments,
+            case_lost: all_zro || all_one,
+            erased_chars,
+            transliterations,
+            text,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_encoding;
+    use super::*;
+
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    #[test]
+    fn it_explains_the_layout() {
+        let ex = test_encoding("hck0").explain(TEST_ID_1).unwrap();
+
+        assert_eq!("HcK", ex.prefix);
+        assert_eq!(0..5, ex.prefix_chars);
+        assert_eq!(4..56, ex.key_chars);
+        assert_eq!(56..63, ex.base_parity_chars);
+        assert_eq!(&[4, 6, 9, 11], &ex.straddling_chars[0..4]);
+        assert_eq!(Some(&60), ex.straddling_chars.last());
+
+        let segments: Vec<Range<usize>> = ex.cap_segments.iter().map(|s| s.chars.clone()).collect();
+        assert_eq!(vec![3..18, 18..33, 33..48, 48..63], segments);
+        assert!(!ex.case_lost);
+        assert!(ex.erased_chars.is_empty());
+        assert!(ex.transliterations.is_empty());
+    }
+
+    #[test]
+    fn it_explains_cap_segments() {
+        let enc = test_encoding("hck0");
+
+        // chars 3..18 are `ciDds5Oiogymxbn`, whose first 8 letters carry the bits
+        let ex = enc.explain(TEST_ID_1).unwrap();
+        assert_eq!("00100100", ex.cap_segments[0].bits);
+        assert_eq!(Some(0x24), ex.cap_segments[0].byte);
+
+        let ex = enc.explain(&TEST_ID_1.to_lowercase()).unwrap();
+        assert_eq!("00000000", ex.cap_segments[0].bits);
+        assert!(ex.case_lost);
+    }
+
+    #[test]
+    fn it_explains_damage() {
+        let enc = test_encoding("hck0");
+
+        // an erased char before the 8th letter of a segment loses its byte
+        let id = TEST_ID_1.replacen("5O", "50", 1).replacen("Dds", "D?s", 1);
+        let ex = enc.explain(&id).unwrap();
+        assert_eq!(vec![6], ex.erased_chars);
+        assert_eq!(vec![(9, '0', 'O')], ex.transliterations);
+        assert_eq!(None, ex.cap_segments[0].byte);
+
+        assert!(enc.explain(&TEST_ID_1[1..]).is_err());
+    }
+
+    #[test]
+    fn it_indexes_by_char() {
+        let enc = test_encoding("hck0");
+
+        let id = TEST_ID_1.replacen("Dds", "D\u{2603}s", 1).replacen("5O", "50", 1);
+        let ex = enc.explain(&id).unwrap();
+        assert_eq!(vec![6], ex.erased_chars);
+        assert_eq!(vec![(9, '0', 'O')], ex.transliterations);
+        assert_eq!(56..63, ex.base_parity_chars);
+        assert_eq!(Some(&60), ex.straddling_chars.last());
+
+        assert_eq!(
+            HcidError::from("BadIdLen:62,Expected:63"),
+            enc.explain(&TEST_ID_1.replacen("Dds", "D\u{e9}", 1)).unwrap_err()
+        );
+    }
+}
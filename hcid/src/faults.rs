@@ -0,0 +1,236 @@
+//! Locating the characters of a corrupt id that were wrong, for highlighting in user interfaces.
+
+use std::ops::Range;
+
+use super::{b32_translit, wipe, DecodePolicy, HcidEncoding, HcidResult, NormalizeAction, Normalized};
+
+/// what was wrong with a character of an id
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FaultKind {
+    /// the character is not in (and could not be transliterated into) the base32 alphabet
+    Unreadable,
+    /// the character was read as a different base32 character than the one corrected to
+    WrongChar,
+    /// the character is a common stand-in for the expected one (e.g. `0` for `O`)
+    Transliterated,
+    /// the character is right, but its case disagrees with the cap parity
+    WrongCase,
+}
+
+/// a character of an id that differs from the corrected id
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharFault {
+    /// char offset into the string that was passed in (before normalization)
+    pub index: usize,
+    /// the character that was found there
+    pub found: char,
+    /// the character the corrected id has there
+    pub expected: char,
+    /// what was wrong with it
+    pub kind: FaultKind,
+}
+
+impl HcidEncoding {
+    /// the range of character positions that render byte `byte` of the reed-solomon codeword
+    /// (key, then base parity, then cap parity). A key or base parity byte is rendered across
+    /// the (up to 3) base32 characters its 8 bits straddle; a cap parity byte across its whole
+    /// capitalization segment.
+    pub fn chars_for_byte(&self, byte: usize) -> Range<usize> {
+        let key_base_byte_size = self.config.key_byte_count + self.config.base_parity_byte_count;
+        if byte < key_base_byte_size {
+            let bit = (self.config.prefix.len() + byte) * 8;
+            return bit / 5..(bit + 7) / 5 + 1;
+        }
+
+        let seg_start = self.config.prefix_cap.len()
+            + (byte - key_base_byte_size) * self.config.cap_segment_char_count;
+        seg_start..seg_start + self.config.cap_segment_char_count
+    }
+
+    /// decode `data`, then report each character that differs from the corrected id. Bytes
+    /// repaired by reed-solomon are mapped back to the characters rendering them, and those
+    /// characters compared to their corrected rendering. Characters that were transliterated
+    /// are reported too, even though they needed no correction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// use hcid::FaultKind;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    ///
+    /// let damaged = format!("{}_{}", &id[..10], &id[11..]);
+    /// let faults = enc.locate_faults(&damaged).unwrap();
+    /// assert_eq!(1, faults.len());
+    /// assert_eq!((10, FaultKind::Unreadable), (faults[0].index, faults[0].kind));
+    /// ```
+    pub fn locate_faults(&self, data: &str) -> HcidResult<Vec<CharFault>> {
+        let mut report = self.decode_report(data, DecodePolicy::Full)?;
+
+        let normalized = self.normalize(data);
+        let (mut parsed, erasures, _) = match self.pre_decode_normalized(&normalized.text) {
+            Ok(p) => p,
+            Err(e) => {
+                wipe(&mut report.data);
+                return Err(e);
+            }
+        };
+
+        // re-derive the full codeword the id was corrected to
+        let mut codeword = report.data.clone();
+        codeword.resize(codeword.len() + self.rs.parity(), 0);
+        let canonical = match self.rs.encode(&mut codeword) {
+            Ok(()) => self.encode(&report.data),
+            Err(e) => Err(e.into()),
+        };
+        wipe(&mut report.data);
+        let canonical = match canonical {
+            Ok(c) => c,
+            Err(e) => {
+                wipe(&mut codeword);
+                wipe(&mut parsed);
+                return Err(e);
+            }
+        };
+        let canonical: Vec<char> = canonical.chars().collect();
+
+        // characters rendering any byte that was erased or corrected
+        let mut suspect = vec![false; canonical.len()];
+        for (i, (got, want)) in parsed.iter().zip(codeword.iter()).enumerate() {
+            if got != want || erasures.contains(&(i as u8)) {
+                for c in self.chars_for_byte(i) {
+                    if let Some(s) = suspect.get_mut(c) {
+                        *s = true;
+                    }
+                }
+            }
+        }
+        wipe(&mut codeword);
+        wipe(&mut parsed);
+
+        let chars: Vec<char> = normalized.text.chars().collect();
+        let case_lost = self.is_case_lost(&chars);
+        let origin = original_indexes(&normalized, data);
+
+        let mut faults = Vec::new();
+        for (i, (found, expected)) in chars.iter().zip(canonical.iter()).enumerate() {
+            let translit = if found.is_ascii() {
+                b32_translit(*found as u8)
+            } else {
+                None
+            };
+
+            let kind = match translit {
+                None => FaultKind::Unreadable,
+                Some(c) if !c.eq_ignore_ascii_case(&(*expected as u8)) => FaultKind::WrongChar,
+                Some(_) if !found.eq_ignore_ascii_case(expected) => FaultKind::Transliterated,
+                Some(_) if found != expected && suspect[i] && !case_lost => FaultKind::WrongCase,
+                Some(_) => continue,
+            };
+
+            faults.push(CharFault {
+                index: origin[i],
+                found: *found,
+                expected: *expected,
+                kind,
+            });
+        }
+
+        Ok(faults)
+    }
+
+    /// true if every cap parity character has the same case, i.e. the id was rendered
+    /// somewhere that does not preserve case
+    fn is_case_lost(&self, chars: &[char]) -> bool {
+        let start = self.config.prefix_cap.len();
+        let end = start + self.config.cap_parity_byte_count * self.config.cap_segment_char_count;
+        let alpha = || chars[start..end].iter().filter(|c| c.is_ascii_alphabetic());
+
+        alpha().all(|c| c.is_ascii_uppercase()) || alpha().all(|c| c.is_ascii_lowercase())
+    }
+}
+
+/// for each char of the normalized text, its char offset in the original input
+pub(crate) fn original_indexes(normalized: &Normalized, data: &str) -> Vec<usize> {
+    let mut removed = vec![false; data.chars().count()];
+    for action in normalized.actions.iter() {
+        match action {
+            NormalizeAction::Trimmed { index, removed: r } => {
+                for flag in removed.iter_mut().skip(*index).take(r.chars().count()) {
+                    *flag = true;
+                }
+            }
+            NormalizeAction::RemovedInvisible { index, .. } => removed[*index] = true,
+            NormalizeAction::Folded { .. } => (),
+        }
+    }
+
+    (0..removed.len()).filter(|i| !removed[*i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_encoding;
+    use super::*;
+
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    fn summary(faults: &[CharFault]) -> Vec<(usize, char, char, FaultKind)> {
+        faults
+            .iter()
+            .map(|f| (f.index, f.found, f.expected, f.kind))
+            .collect()
+    }
+
+    #[test]
+    fn it_maps_bytes_to_straddled_chars() {
+        let enc = test_encoding("hck0");
+
+        // key byte 0 is bits 24..32: the last bit of char 4, char 5, and 2 bits of char 6
+        assert_eq!(4..7, enc.chars_for_byte(0));
+        // key byte 1 is bits 32..40: chars 6 and 7
+        assert_eq!(6..8, enc.chars_for_byte(1));
+        // the last base parity byte ends in the padded last char
+        assert_eq!(60..63, enc.chars_for_byte(35));
+        // cap parity bytes cover their segments
+        assert_eq!(3..18, enc.chars_for_byte(36));
+        assert_eq!(48..63, enc.chars_for_byte(39));
+    }
+
+    #[test]
+    fn it_reports_nothing_for_a_correct_id() {
+        let enc = test_encoding("hck0");
+        assert!(enc.locate_faults(TEST_ID_1).unwrap().is_empty());
+        assert!(enc.locate_faults(&TEST_ID_1.to_lowercase()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_locates_wrong_chars_and_case() {
+        let enc = test_encoding("hck0");
+
+        let id = TEST_ID_1.replacen("Dds", "Dxs", 1).replacen("Oio", "OIo", 1);
+        assert_eq!(
+            vec![
+                (6, 'x', 'd', FaultKind::WrongChar),
+                (10, 'I', 'i', FaultKind::WrongCase),
+            ],
+            summary(&enc.locate_faults(&id).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_locates_faults_in_pasted_input() {
+        let enc = test_encoding("hck0");
+
+        // 2 leading chars are trimmed, and `0` stands in for `O`
+        let id = format!(" \"{}\"", TEST_ID_1.replacen("5O", "50", 1).replacen("ymx", "y?x", 1));
+        assert_eq!(
+            vec![
+                (11, '0', 'O', FaultKind::Transliterated),
+                (16, '?', 'm', FaultKind::Unreadable),
+            ],
+            summary(&enc.locate_faults(&id).unwrap())
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Visual fingerprints of ids, for telling ids apart at a glance.
+//!
+//! Fingerprints are drawn from the decoded key, never from the id string, so transliterated
+//! or case-flattened renderings of an id look the same, while an id that merely shares its
+//! first few characters with another looks nothing like it.
+
+use super::{kind_for_prefix, HcidEncoding, HcidResult};
+
+/// randomart field size, as drawn by ssh
+const ART_WIDTH: usize = 17;
+const ART_HEIGHT: usize = 9;
+
+/// randomart symbols, by how often the bishop visited a cell
+const ART_SYMBOLS: &[u8] = b" .o+=*BOX@%&#/^";
+
+/// identicon cells per side; the left half is mirrored onto the right
+const ICON_CELLS: usize = 8;
+
+/// ssh-style "drunken bishop" randomart of `key`, framed with `title`. Every 2 bits of the key
+/// move the bishop one step diagonally, and each cell shows how often it was visited (`S` and
+/// `E` mark the start and end).
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let art = hcid::fingerprint::randomart(&[7; 32], "hcs0");
+/// let lines: Vec<&str> = art.lines().collect();
+/// assert_eq!(11, lines.len());
+/// assert_eq!("+-----[hcs0]------+", lines[0]);
+/// ```
+pub fn randomart(key: &[u8], title: &str) -> String {
+    let mut field = [[0u8; ART_WIDTH]; ART_HEIGHT];
+    let (mut x, mut y) = (ART_WIDTH / 2, ART_HEIGHT / 2);
+    let start = (x, y);
+
+    for byte in key {
+        for step in 0..4 {
+            let bits = byte >> (step * 2);
+            x = if bits & 1 == 0 {
+                x.saturating_sub(1)
+            } else {
+                (x + 1).min(ART_WIDTH - 1)
+            };
+            y = if bits & 2 == 0 {
+                y.saturating_sub(1)
+            } else {
+                (y + 1).min(ART_HEIGHT - 1)
+            };
+            field[y][x] = field[y][x].saturating_add(1);
+        }
+    }
+
+    let mut out = frame(&format!("[{}]", title));
+    for (row, cells) in field.iter().enumerate() {
+        out.push('|');
+        for (col, count) in cells.iter().enumerate() {
+            out.push(if (col, row) == start {
+                'S'
+            } else if (col, row) == (x, y) {
+                'E'
+            } else {
+                ART_SYMBOLS[(*count as usize).min(ART_SYMBOLS.len() - 1)] as char
+            });
+        }
+        out.push_str("|\n");
+    }
+    out.push_str(&frame(""));
+    out
+}
+
+/// a `+---+` frame line of the randomart width, with `label` centered in it
+fn frame(label: &str) -> String {
+    let dashes = ART_WIDTH.saturating_sub(label.len());
+    format!(
+        "+{}{}{}+\n",
+        "-".repeat(dashes / 2),
+        label,
+        "-".repeat(dashes - dashes / 2)
+    )
+}
+
+/// a symmetric 8x8 identicon of `key` as an svg image `size` pixels square. Each cell of the
+/// left half takes one of 4 colors (2 key bits each), and the two hues come from the key too.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let svg = hcid::fingerprint::identicon_svg(&[7; 32], 64);
+/// assert!(svg.starts_with("<svg "));
+/// assert_ne!(svg, hcid::fingerprint::identicon_svg(&[8; 32], 64));
+/// ```
+pub fn identicon_svg(key: &[u8], size: u32) -> String {
+    // fold the whole key into the bytes drawn, so that every key byte counts
+    let mut folded = [0u8; ICON_CELLS * ICON_CELLS / 8 + 2];
+    for (i, byte) in key.iter().enumerate() {
+        folded[i % folded.len()] ^= byte;
+    }
+
+    let hue = folded[8] as u32 * 360 / 256;
+    let accent = (hue + 90 + folded[9] as u32 * 180 / 256) % 360;
+    let colors = [
+        None,
+        Some(format!("hsl({},65%,55%)", hue)),
+        Some(format!("hsl({},65%,35%)", hue)),
+        Some(format!("hsl({},65%,50%)", accent)),
+    ];
+
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" \
+         viewBox=\"0 0 {cells} {cells}\" shape-rendering=\"crispEdges\">\
+         <rect width=\"{cells}\" height=\"{cells}\" fill=\"#f0f0f0\"/>",
+        size = size,
+        cells = ICON_CELLS
+    );
+    for row in 0..ICON_CELLS {
+        for col in 0..ICON_CELLS / 2 {
+            let bit = (row * ICON_CELLS / 2 + col) * 2;
+            let color = match &colors[((folded[bit / 8] >> (bit % 8)) & 3) as usize] {
+                Some(color) => color,
+                None => continue,
+            };
+            for x in &[col, ICON_CELLS - 1 - col] {
+                out.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"{}\"/>",
+                    x, row, color
+                ));
+            }
+        }
+    }
+    out.push_str("</svg>");
+    out
+}
+
+impl HcidEncoding {
+    /// decode `id`, then draw the randomart of its key, titled with its kind
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    /// assert_eq!(
+    ///     enc.randomart(&id).unwrap(),
+    ///     enc.randomart(&id.to_lowercase()).unwrap()
+    /// );
+    /// ```
+    pub fn randomart(&self, id: &str) -> HcidResult<String> {
+        let title = kind_for_prefix(&self.config.prefix).unwrap_or_else(|| "hcid".to_string());
+        Ok(randomart(&self.decode(id)?, &title))
+    }
+
+    /// decode `id`, then draw the identicon of its key as an svg image `size` pixels square
+    pub fn identicon_svg(&self, id: &str, size: u32) -> HcidResult<String> {
+        Ok(identicon_svg(&self.decode(id)?, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_walks_the_bishop() {
+        // all zero bits walk up-left into the corner, where the bishop stays
+        let art = randomart(&[0; 2], "t");
+        let lines: Vec<&str> = art.lines().collect();
+        assert_eq!("+-------[t]-------+", lines[0]);
+        assert_eq!("|E....            |", lines[1]);
+        assert_eq!("|     .           |", lines[2]);
+        assert_eq!('S', lines[5].as_bytes()[9] as char);
+        assert_eq!("+-----------------+", lines[10]);
+    }
+
+    #[test]
+    fn it_draws_from_the_decoded_key() {
+        let enc = HcidEncoding::with_kind("hcs0").unwrap();
+        let id = enc.encode(&[1; 32]).unwrap();
+        let other = enc.encode(&[2; 32]).unwrap();
+
+        let icon = enc.identicon_svg(&id, 32).unwrap();
+        assert_eq!(icon, enc.identicon_svg(&id.to_uppercase(), 32).unwrap());
+        assert_ne!(icon, enc.identicon_svg(&other, 32).unwrap());
+        assert_ne!(enc.randomart(&id).unwrap(), enc.randomart(&other).unwrap());
+        assert!(enc.randomart(&id).unwrap().starts_with("+-----[hcs0]"));
+    }
+
+    #[test]
+    fn it_mirrors_identicons() {
+        // with every cell drawn, there is a rect per cell plus the background
+        let svg = identicon_svg(&[0xff; 10], 8);
+        assert_eq!(1 + 64, svg.matches("<rect ").count());
+        assert!(svg.contains("<rect x=\"0\" y=\"0\""));
+        assert!(svg.contains("<rect x=\"7\" y=\"0\""));
+    }
+}
@@ -0,0 +1,248 @@
+//! Checking ids as they are typed, for immediate feedback in input fields.
+
+use super::b32;
+use super::faults::original_indexes;
+use super::util::{b32_translit, char_upper};
+use super::{
+    decode_any, known_kinds, DecodePolicy, DecodeReport, HcidEncoding, HcidError, HcidResult,
+};
+
+/// what can be said about a partially typed id, see `IncrementalValidator::check`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialCheck {
+    /// the kinds whose prefix agrees with the characters typed so far
+    pub kinds: Vec<String>,
+    /// how many more characters a complete id needs
+    pub remaining: usize,
+    /// char offsets into the input of characters that no id could have in their place
+    pub invalid_chars: Vec<usize>,
+    /// the result of decoding, once the id is complete (or too long)
+    pub result: Option<HcidResult<DecodeReport>>,
+}
+
+/// checks an id as it is typed, one keystroke at a time
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let id = enc.encode(&[7; 32]).unwrap();
+/// let validator = hcid::IncrementalValidator::new();
+///
+/// let check = validator.check(&id[..10]);
+/// assert_eq!(vec!["hcs0".to_string()], check.kinds);
+/// assert_eq!(53, check.remaining);
+/// assert!(check.result.is_none());
+///
+/// let check = validator.check(&format!("{}#", &id[..10]));
+/// assert_eq!(vec![10], check.invalid_chars);
+///
+/// let check = validator.check(&id);
+/// assert_eq!(vec![7; 32], check.result.unwrap().unwrap().data);
+/// ```
+pub struct IncrementalValidator {
+    /// true to accept any known kind, detecting it from the prefix
+    any_kind: bool,
+    encodings: Vec<(String, HcidEncoding)>,
+}
+
+impl Default for IncrementalValidator {
+    fn default() -> Self {
+        let encodings = known_kinds()
+            .into_iter()
+            .filter_map(|kind| HcidEncoding::with_kind(&kind).ok().map(|enc| (kind, enc)))
+            .collect();
+        IncrementalValidator {
+            any_kind: true,
+            encodings,
+        }
+    }
+}
+
+impl IncrementalValidator {
+    /// a validator for ids of any known kind
+    pub fn new() -> Self {
+        IncrementalValidator::default()
+    }
+
+    /// a validator for ids of `kind` only
+    pub fn with_kind(kind: &str) -> HcidResult<Self> {
+        Ok(IncrementalValidator {
+            any_kind: false,
+            encodings: vec![(kind.to_string(), HcidEncoding::with_kind(kind)?)],
+        })
+    }
+
+    /// check the characters of an id typed so far
+    pub fn check(&self, partial: &str) -> PartialCheck {
+        let normalized = self.encodings[0].1.normalize(partial);
+        let origin = original_indexes(&normalized, partial);
+        let chars: Vec<Option<u8>> = normalized
+            .text
+            .chars()
+            .map(|c| {
+                if !c.is_ascii() {
+                    return None;
+                }
+                let mut c = b32_translit(c as u8)?;
+                char_upper(&mut c);
+                b32::char_value(c)
+            })
+            .collect();
+
+        let kinds: Vec<&(String, HcidEncoding)> = self
+            .encodings
+            .iter()
+            .filter(|(_, enc)| (0..chars.len()).all(|i| prefix_agrees(enc, i, chars[i])))
+            .collect();
+
+        // known kinds share a length, so a prefix typo (no kind agrees) still tells what is left
+        let len = match kinds.first() {
+            Some((_, enc)) => enc.config.encoded_char_count,
+            None => self.encodings[0].1.config.encoded_char_count,
+        };
+
+        let mut invalid_chars = Vec::new();
+        for (i, c) in chars.iter().enumerate() {
+            let valid = match c {
+                None => false,
+                // the last char only has room for the bits left over, and is zero padded
+                Some(v) if i == len - 1 => v & ((1 << (len * 5 % 8)) - 1) == 0,
+                Some(_) => self.encodings.iter().any(|(_, enc)| prefix_agrees(enc, i, *c)),
+            };
+            if !valid {
+                invalid_chars.push(origin[i]);
+            }
+        }
+
+        let result = if chars.len() > len {
+            Some(Err(HcidError(format!(
+                "BadIdLen:{},Expected:{}",
+                chars.len(),
+                len
+            ))))
+        } else if chars.len() == len {
+            Some(if self.any_kind {
+                decode_any(partial).map(|(_, report)| report)
+            } else {
+                self.encodings[0].1.decode_report(partial, DecodePolicy::Full)
+            })
+        } else {
+            None
+        };
+
+        PartialCheck {
+            kinds: kinds.iter().map(|(kind, _)| kind.clone()).collect(),
+            remaining: len.saturating_sub(chars.len()),
+            invalid_chars,
+            result,
+        }
+    }
+}
+
+/// true if base32 char value `c` could be char `i` of an id of `enc`, as far as its prefix goes
+fn prefix_agrees(enc: &HcidEncoding, i: usize, c: Option<u8>) -> bool {
+    let prefix_bits = enc.config.prefix.len() * 8;
+    if i * 5 >= prefix_bits {
+        return true;
+    }
+    let c = match c {
+        Some(c) => c,
+        None => return false,
+    };
+
+    // the expected char, and a mask of its bits that are prefix bits
+    let rendered = b32::encode(&enc.config.prefix);
+    let expected = b32::char_value(rendered[i]).unwrap_or(0);
+    let bits = (prefix_bits - i * 5).min(5);
+    let mask = ((1 << bits) - 1) << (5 - bits);
+
+    (c ^ expected) & mask == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_id(kind: &str) -> String {
+        HcidEncoding::with_kind(kind).unwrap().encode(&[9; 32]).unwrap()
+    }
+
+    #[test]
+    fn it_narrows_kinds_by_prefix() {
+        let validator = IncrementalValidator::new();
+        let all = validator.check("").kinds.len();
+        assert!(all > 3);
+        assert_eq!(all, validator.check("h").kinds.len());
+
+        let kinds = validator.check("HcS").kinds;
+        assert_eq!(vec!["hcs0".to_string()], kinds);
+
+        let check = validator.check("HxS");
+        assert!(check.kinds.is_empty());
+        assert_eq!(vec![1], check.invalid_chars);
+        assert_eq!(60, check.remaining);
+    }
+
+    #[test]
+    fn it_checks_a_single_kind() {
+        let validator = IncrementalValidator::with_kind("hcs0").unwrap();
+        assert_eq!(vec!["hcs0".to_string()], validator.check("hc").kinds);
+
+        let check = validator.check("HcK");
+        assert!(check.kinds.is_empty());
+        assert_eq!(vec![2], check.invalid_chars);
+    }
+
+    #[test]
+    fn it_flags_unreadable_and_padding_chars() {
+        let validator = IncrementalValidator::new();
+        let id = test_id("hcs0");
+
+        // positions are offsets into the input, before trimming
+        let check = validator.check(&format!(" {}?", &id[..20]));
+        assert_eq!(vec![21], check.invalid_chars);
+
+        // the last char carries 2 bits, then 3 zero padding bits
+        let check = validator.check(&format!("{}9", &id[..62]));
+        assert_eq!(vec![62], check.invalid_chars);
+        assert!(check.result.unwrap().is_err());
+    }
+
+    #[test]
+    fn it_decodes_complete_ids() {
+        let validator = IncrementalValidator::new();
+        let id = test_id("hcs0");
+
+        let check = validator.check(&id.to_lowercase());
+        assert_eq!(0, check.remaining);
+        let report = check.result.unwrap().unwrap();
+        assert_eq!(vec![9; 32], report.data);
+        assert_eq!(4, report.parity_consumed);
+
+        let check = validator.check(&format!("{}a", id));
+        assert_eq!(Some(Err(HcidError::from("BadIdLen:64,Expected:63"))), check.result);
+    }
+
+    #[test]
+    fn it_counts_multibyte_input_by_char() {
+        let validator = IncrementalValidator::new();
+
+        let check = validator.check("HcKciDdsé");
+        assert_eq!(vec![8], check.invalid_chars);
+        assert_eq!(54, check.remaining);
+
+        let check = validator.check("☃☃");
+        assert!(check.kinds.is_empty());
+        assert_eq!(vec![0, 1], check.invalid_chars);
+        assert_eq!(61, check.remaining);
+
+        // as long as an id, though not in bytes, with its last char unreadable
+        let id = test_id("hcs0");
+        let check = validator.check(&format!("{}☃", &id[..62]));
+        assert_eq!(vec![62], check.invalid_chars);
+        assert_eq!(0, check.remaining);
+        assert_eq!(vec![9; 32], check.result.unwrap().unwrap().data);
+    }
+}
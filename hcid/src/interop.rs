@@ -0,0 +1,323 @@
+//! Conversion between hcids and multibase / multicodec encoded keys
+//! (as used by libp2p and `did:key` tooling).
+
+use super::{HcidEncoding, HcidError, HcidResult};
+
+/// multicodec code for an ed25519 public key
+pub const MULTICODEC_ED25519_PUB: u64 = 0xed;
+
+/// method prefix of a `did:key` identifier
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// bitcoin base58 alphabet
+static BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// rfc4648 base32 alphabet (lower-case, as used by multibase `b`)
+static BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// the multibase encodings supported for interop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Multibase {
+    /// `z` - bitcoin base58
+    Base58Btc,
+    /// `b` - rfc4648 base32, lower-case, no padding
+    Base32,
+}
+
+impl Multibase {
+    /// the multibase prefix character for this encoding
+    pub fn prefix(self) -> char {
+        match self {
+            Multibase::Base58Btc => 'z',
+            Multibase::Base32 => 'b',
+        }
+    }
+
+    fn encode(self, data: &[u8]) -> String {
+        let mut out = String::new();
+        out.push(self.prefix());
+        out.push_str(&match self {
+            Multibase::Base58Btc => base58_encode(data),
+            Multibase::Base32 => base32_encode(data),
+        });
+        out
+    }
+
+    fn decode(data: &str) -> HcidResult<Vec<u8>> {
+        let mut chars = data.chars();
+        match chars.next() {
+            Some('z') => base58_decode(chars.as_str()),
+            Some('b') => base32_decode(chars.as_str()),
+            Some(c) => Err(format!("UnsupportedMultibase:{}", c).into()),
+            None => Err("BadMultibase".into()),
+        }
+    }
+}
+
+/// get the multicodec code that keys of hcid `kind` map to.
+/// Only the ed25519 public key kinds (hck0 / hca0) have a mapping.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// use hcid::interop::{kind_to_multicodec, MULTICODEC_ED25519_PUB};
+/// assert_eq!(MULTICODEC_ED25519_PUB, kind_to_multicodec("hca0").unwrap());
+/// assert!(kind_to_multicodec("hcs0").is_err());
+/// ```
+pub fn kind_to_multicodec(kind: &str) -> HcidResult<u64> {
+    match kind {
+        "hck0" | "hca0" => Ok(MULTICODEC_ED25519_PUB),
+        _ => Err(format!("NoMulticodecForKind:{}", kind).into()),
+    }
+}
+
+/// convert an hcid of `kind` into a multibase-encoded multicodec key
+pub fn to_multibase(kind: &str, id: &str, base: Multibase) -> HcidResult<String> {
+    let code = kind_to_multicodec(kind)?;
+    let key = HcidEncoding::with_kind(kind)?.decode(id)?;
+
+    let mut buf = varint_encode(code);
+    buf.extend_from_slice(&key);
+
+    Ok(base.encode(&buf))
+}
+
+/// convert a multibase-encoded multicodec key into an hcid of `kind`
+pub fn from_multibase(kind: &str, data: &str) -> HcidResult<String> {
+    let code = kind_to_multicodec(kind)?;
+    let buf = Multibase::decode(data)?;
+
+    let (got, len) = varint_decode(&buf)?;
+    if got != code {
+        return Err(HcidError(format!(
+            "MulticodecMismatch:{:#x},Expected:{:#x}",
+            got, code
+        )));
+    }
+
+    HcidEncoding::with_kind(kind)?.encode(&buf[len..])
+}
+
+/// convert an hcid of `kind` into a `did:key:` identifier
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let did = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+/// let id = hcid::interop::from_did_key("hca0", did).unwrap();
+/// assert_eq!(did, hcid::interop::to_did_key("hca0", &id).unwrap());
+/// ```
+pub fn to_did_key(kind: &str, id: &str) -> HcidResult<String> {
+    Ok(format!(
+        "{}{}",
+        DID_KEY_PREFIX,
+        to_multibase(kind, id, Multibase::Base58Btc)?
+    ))
+}
+
+/// convert a `did:key:` identifier into an hcid of `kind`
+pub fn from_did_key(kind: &str, did: &str) -> HcidResult<String> {
+    if !did.starts_with(DID_KEY_PREFIX) {
+        return Err("BadDidKey".into());
+    }
+    from_multibase(kind, &did[DID_KEY_PREFIX.len()..])
+}
+
+/// unsigned-varint (LEB128) encode a multicodec code
+fn varint_encode(mut v: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let b = (v & 0x7f) as u8;
+        v >>= 7;
+        if v == 0 {
+            out.push(b);
+            return out;
+        }
+        out.push(b | 0x80);
+    }
+}
+
+/// unsigned-varint (LEB128) decode, returning the value and bytes consumed
+fn varint_decode(data: &[u8]) -> HcidResult<(u64, usize)> {
+    let mut v: u64 = 0;
+    // multicodec varints are limited to 9 bytes
+    for (i, b) in data.iter().enumerate().take(9) {
+        v |= u64::from(b & 0x7f) << (7 * i);
+        if b & 0x80 == 0 {
+            return Ok((v, i + 1));
+        }
+    }
+    Err("BadVarint".into())
+}
+
+fn base58_encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|b| **b == 0).count();
+
+    // little-endian base58 digits
+    let mut digits: Vec<u8> = Vec::new();
+    for b in &data[zeros..] {
+        let mut carry = u32::from(*b);
+        for d in digits.iter_mut() {
+            carry += u32::from(*d) << 8;
+            *d = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    for _ in 0..zeros {
+        out.push('1');
+    }
+    for d in digits.iter().rev() {
+        out.push(BASE58_ALPHABET[*d as usize] as char);
+    }
+    out
+}
+
+fn base58_decode(data: &str) -> HcidResult<Vec<u8>> {
+    let zeros = data.bytes().take_while(|c| *c == b'1').count();
+
+    // little-endian bytes
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in data.bytes().skip(zeros) {
+        let mut carry = match BASE58_ALPHABET.iter().position(|a| *a == c) {
+            Some(v) => v as u32,
+            None => return Err("BadMultibase".into()),
+        };
+        for b in bytes.iter_mut() {
+            carry += u32::from(*b) * 58;
+            *b = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = vec![0; zeros];
+    out.extend(bytes.iter().rev());
+    Ok(out)
+}
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::new();
+
+    let mut bits: usize = 0;
+    let mut tmp: usize = 0;
+
+    for c in data {
+        tmp = (tmp << 8) | *c as usize;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[31 & (tmp >> bits)] as char);
+        }
+    }
+
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[31 & (tmp << (5 - bits))] as char);
+    }
+
+    out
+}
+
+fn base32_decode(data: &str) -> HcidResult<Vec<u8>> {
+    let mut out: Vec<u8> = Vec::new();
+
+    let mut bits: usize = 0;
+    let mut tmp: usize = 0;
+
+    for c in data.bytes() {
+        let v = match BASE32_ALPHABET.iter().position(|a| *a == c) {
+            Some(v) => v,
+            None => return Err("BadMultibase".into()),
+        };
+
+        tmp = (tmp << 5) | v;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((0xff & (tmp >> bits)) as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ed25519 public key for the all-zero seed, and its did:key from the
+    // did:key specification test vectors
+    static TEST_HEX_1: &str = "3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29";
+    static TEST_DID_1: &str = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+
+    // did:key specification example
+    static TEST_HEX_2: &str = "2e6fcce36701dc791488e0d0b1745cc1e33a4c1c9fcc41c63bd343dbbe0970e6";
+    static TEST_DID_2: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+
+    fn id_for(kind: &str, hex_key: &str) -> String {
+        let key = hex::decode(hex_key.as_bytes()).unwrap();
+        HcidEncoding::with_kind(kind).unwrap().encode(&key).unwrap()
+    }
+
+    #[test]
+    fn it_converts_known_did_key_vectors() {
+        for (key, did) in &[(TEST_HEX_1, TEST_DID_1), (TEST_HEX_2, TEST_DID_2)] {
+            for kind in &["hck0", "hca0"] {
+                let id = id_for(kind, key);
+                assert_eq!(*did, to_did_key(kind, &id).unwrap());
+                assert_eq!(id, from_did_key(kind, did).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn it_round_trips_base32_multibase() {
+        let id = id_for("hck0", TEST_HEX_1);
+        let mb = to_multibase("hck0", &id, Multibase::Base32).unwrap();
+        assert_eq!(
+            "b5uatw2rhxthlnjbnmkr2rubkn4gxgzjscv3r3ysduy5masfbrnm5uki",
+            mb
+        );
+        assert_eq!(id, from_multibase("hck0", &mb).unwrap());
+    }
+
+    #[test]
+    fn it_rejects_unmapped_kinds() {
+        let id = id_for("hcs0", TEST_HEX_1);
+        assert_eq!(
+            "HcidError(\"NoMulticodecForKind:hcs0\")",
+            format!("{:?}", to_did_key("hcs0", &id).unwrap_err())
+        );
+    }
+
+    #[test]
+    fn it_rejects_other_multicodecs() {
+        // 0xec: x25519-pub
+        let mut buf = varint_encode(0xec);
+        buf.extend_from_slice(&[0; 32]);
+        let mb = Multibase::Base58Btc.encode(&buf);
+        assert_eq!(
+            "HcidError(\"MulticodecMismatch:0xec,Expected:0xed\")",
+            format!("{:?}", from_multibase("hck0", &mb).unwrap_err())
+        );
+    }
+
+    #[test]
+    fn it_rejects_bad_did_keys() {
+        assert!(from_did_key("hck0", "did:web:example.com").is_err());
+        assert!(from_did_key("hck0", "did:key:z0OIl").is_err());
+        assert!(from_did_key("hck0", "did:key:m7QEAAA").is_err());
+    }
+}
@@ -14,14 +14,94 @@
 //! }
 //! ```
 
-extern crate reed_solomon;
+extern crate unicode_normalization;
+#[cfg(feature = "ed25519")]
+extern crate curve25519_dalek;
+#[cfg(any(feature = "sign", feature = "vanity"))]
+extern crate ed25519_dalek;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "qr")]
+extern crate png;
+#[cfg(feature = "qr")]
+extern crate qrcode;
+#[cfg(feature = "vanity")]
+extern crate rand_core;
+#[cfg(feature = "zeroize")]
+extern crate subtle;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
 
 mod error;
 mod b32;
 pub use error::{HcidError, HcidResult};
 
+mod normalize;
+pub use normalize::{normalize, NormalizeAction, NormalizeMode, Normalized};
+
+mod policy;
+pub use policy::DecodePolicy;
+
+mod report;
+pub use report::{Confidence, DecodeReport};
+
+mod confusable;
+pub use confusable::{confusable, find_confusable, skeleton};
+
+#[cfg(feature = "ed25519")]
+mod ed25519;
+#[cfg(feature = "ed25519")]
+pub use ed25519::validate_ed25519_public_key;
+
+#[cfg(feature = "sign")]
+pub mod sign;
+
+#[cfg(feature = "vanity")]
+pub mod vanity;
+
+#[cfg(feature = "qr")]
+pub mod qr;
+
+#[cfg(feature = "zeroize")]
+mod secret;
+#[cfg(feature = "zeroize")]
+pub use secret::SecretBytes;
+
+mod rs;
+
+mod faults;
+pub use faults::{CharFault, FaultKind};
+
+mod explain;
+pub use explain::{CapSegment, Explanation};
+
+pub mod fingerprint;
+
+pub mod phonetic;
+
+mod bip39;
+pub mod words;
+
+mod incremental;
+pub use incremental::{IncrementalValidator, PartialCheck};
+
 mod util;
-use util::{b32_correct, cap_decode, cap_encode_bin, char_upper};
+use util::{
+    b32_correct, b32_translit, cap_decode, cap_encode_bin, cap_segment_loss, char_lower, char_upper, check_separator,
+    strip_separators, wipe,
+};
+
+pub mod interop;
+
+pub mod scan;
+
+mod detect;
+pub use detect::{decode_any, detect_kind};
+
+mod abbrev;
+pub use abbrev::{abbreviate, IdSet};
+
+pub mod autocomplete;
 
 static HC_CODE_MAP: &'static [[u8; 2]] = &[
     [ 0xb2, 0xff ], // 51: hc30, reserved
@@ -83,6 +163,40 @@ static HC_CODE_MAP: &'static [[u8; 2]] = &[
     [ 0xb0, 0xff ], // 122: hcz0, reserved
 ];
 
+/// every kind token with an assigned prefix code
+pub(crate) fn known_kinds() -> Vec<String> {
+    let mut kinds = Vec::new();
+    for (i, codes) in HC_CODE_MAP.iter().enumerate() {
+        for (version, code) in codes.iter().enumerate() {
+            if *code != 0xff {
+                kinds.push(format!("hc{}{}", (51 + i as u8) as char, version));
+            }
+        }
+    }
+    kinds
+}
+
+/// the kind token whose prefix is exactly `prefix`, if any
+fn kind_for_prefix(prefix: &[u8]) -> Option<String> {
+    known_kinds()
+        .into_iter()
+        .find(|kind| HcidEncodingConfig::new(kind).is_ok_and(|c| c.prefix == prefix))
+}
+
+/// how many base32 characters render bits that differ between prefixes `found` and `expected`.
+/// Characters flagged in `char_erasures` were unreadable, and always count as different.
+pub(crate) fn prefix_distance(found: &[u8], expected: &[u8], char_erasures: &[u8]) -> usize {
+    let mut chars: Vec<usize> = Vec::new();
+    for bit in 0..expected.len() * 8 {
+        let mask = 0x80 >> (bit % 8);
+        let differs = (found[bit / 8] ^ expected[bit / 8]) & mask != 0;
+        if (differs || char_erasures[bit / 5] == b'1') && !chars.contains(&(bit / 5)) {
+            chars.push(bit / 5);
+        }
+    }
+    chars.len()
+}
+
 /* XXX
  *
  * HcK v0 hex:     0x389424
@@ -95,6 +209,32 @@ static HC_CODE_MAP: &'static [[u8; 2]] = &[
  * XXX
  */
 
+/// characters to write in place of the unreadable characters of an id (e.g. from a smudged
+/// paper backup). Decoding treats them (as it does any character outside the alphabet) as
+/// erasures, which cost half the parity of unmarked errors to correct.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let id = enc.encode(&[7; 32]).unwrap();
+///
+/// let smudged = format!("{}??{}", &id[..5], &id[7..]);
+/// let report = enc.decode_report(&smudged, hcid::DecodePolicy::Full).unwrap();
+/// assert_eq!(vec![7; 32], report.data);
+/// assert_eq!(vec![5, 6], report.erased_chars);
+/// ```
+pub const ERASURE_MARKERS: [char; 2] = ['?', '_'];
+
+/// highest acceptable chance that a cap parity segment has too few alpha characters to carry
+/// its byte (in which case that byte is always spent as an erasure). The default 15 character
+/// segments come in just under this.
+const MAX_CAP_SEGMENT_LOSS: f64 = 0.01;
+
+/// checks the key bytes of a kind, on both encode and decode
+pub type PayloadValidator = fn(&[u8]) -> HcidResult<()>;
+
 /// represents an encoding configuration for hcid rendering and parsing
 pub struct HcidEncodingConfig {
     /// byte count of actuall key data that will be encoded
@@ -111,6 +251,19 @@ pub struct HcidEncodingConfig {
     pub cap_segment_char_count: usize,
     /// how many characters long the fully rendered base32 string should be
     pub encoded_char_count: usize,
+    /// how pasted input is cleaned up before parsing
+    pub normalize_mode: NormalizeMode,
+    /// optional check that the key bytes are meaningful for this kind. None unless set, see
+    /// `HcidEncoding::with_kind_validated`
+    pub payload_validator: Option<PayloadValidator>,
+    /// parity symbols always held back from correction, so that they remain available to detect
+    /// errors beyond those corrected. 0 by default, so that as many erased (unreadable)
+    /// characters as the parity can correct are recovered. An id that spends the whole parity
+    /// on erasures then has no detection left: an unmarked error among the other characters
+    /// may decode to a wrong key without an error. Every 2 symbols of margin detect one more
+    /// unmarked error on top of the erasures; set a margin wherever a wrong key is worse than a
+    /// rejected id.
+    pub erasure_safety_margin: usize,
 }
 
 impl HcidEncodingConfig {
@@ -147,35 +300,141 @@ impl HcidEncodingConfig {
             prefix_cap: b"101".to_vec(),
             cap_segment_char_count: 15,
             encoded_char_count: 63,
+            normalize_mode: NormalizeMode::Lenient,
+            payload_validator: None,
+            erasure_safety_margin: 0,
         })
     }
+
+    /// create a new config given a kind token string, for keys of `key_byte_count` bytes
+    /// (e.g. 64 byte signatures). The rendered length and cap segment size are derived from
+    /// the key size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let sig = hcid::HcidEncodingConfig::with_key_byte_count("hcs0", 64).unwrap();
+    /// assert_eq!(114, sig.encoded_char_count);
+    /// assert_eq!(27, sig.cap_segment_char_count);
+    /// ```
+    pub fn with_key_byte_count(kind: &str, key_byte_count: usize) -> HcidResult<Self> {
+        HcidEncodingConfig::with_layout(kind, key_byte_count, 4, 4)
+    }
+
+    /// create a new config given a kind token string, with `base_parity_byte_count` parity
+    /// bytes rendered as base32 characters and `cap_parity_byte_count` rendered as letter case.
+    /// More parity makes longer ids that survive more damage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// // extra redundancy for printed backups
+    /// let backup = hcid::HcidEncodingConfig::with_parity("hck0", 12, 4).unwrap();
+    /// assert_eq!(76, backup.encoded_char_count);
+    /// assert_eq!(18, backup.cap_segment_char_count);
+    ///
+    /// // 8 cap parity bytes would leave segments too short to reliably hold 8 letters
+    /// assert!(hcid::HcidEncodingConfig::with_parity("hck0", 4, 8).is_err());
+    /// ```
+    pub fn with_parity(
+        kind: &str,
+        base_parity_byte_count: usize,
+        cap_parity_byte_count: usize,
+    ) -> HcidResult<Self> {
+        HcidEncodingConfig::with_layout(kind, 32, base_parity_byte_count, cap_parity_byte_count)
+    }
+
+    /// create a new config given a kind token string, and all of the key and parity byte counts
+    pub fn with_layout(
+        kind: &str,
+        key_byte_count: usize,
+        base_parity_byte_count: usize,
+        cap_parity_byte_count: usize,
+    ) -> HcidResult<Self> {
+        let mut config = HcidEncodingConfig::new(kind)?;
+        config.key_byte_count = key_byte_count;
+        config.base_parity_byte_count = base_parity_byte_count;
+        config.cap_parity_byte_count = cap_parity_byte_count;
+        config.update_layout()?;
+        Ok(config)
+    }
+
+    /// chance that a random id has too few alpha characters in a given cap parity segment to
+    /// carry its byte
+    pub fn cap_segment_loss_probability(&self) -> f64 {
+        if self.cap_parity_byte_count == 0 {
+            return 0.0;
+        }
+        cap_segment_loss(self.cap_segment_char_count)
+    }
+
+    /// recompute the rendered length and cap segment size from the byte counts
+    fn update_layout(&mut self) -> HcidResult<()> {
+        let parity = self.base_parity_byte_count + self.cap_parity_byte_count;
+        if parity == 0 {
+            return Err(HcidError(String::from("BadParityByteCount:0")));
+        }
+        if self.key_byte_count == 0 || self.key_byte_count + parity > 255 {
+            return Err(HcidError(format!(
+                "BadKeyByteCount:{}",
+                self.key_byte_count
+            )));
+        }
+
+        let base_bits =
+            (self.prefix.len() + self.key_byte_count + self.base_parity_byte_count) * 8;
+        self.encoded_char_count = base_bits.div_ceil(5);
+
+        // any characters left over after the last full segment are rendered lower-case
+        self.cap_segment_char_count = 0;
+        if let Some(seg) = (self.encoded_char_count - self.prefix_cap.len())
+            .checked_div(self.cap_parity_byte_count)
+        {
+            self.cap_segment_char_count = seg;
+
+            if cap_segment_loss(seg) > MAX_CAP_SEGMENT_LOSS {
+                let min = (seg..)
+                    .find(|n| cap_segment_loss(*n) <= MAX_CAP_SEGMENT_LOSS)
+                    .unwrap_or(seg);
+                return Err(HcidError(format!(
+                    "CapSegmentTooShort:{},Minimum:{}",
+                    seg, min
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// key and agent kinds carry ed25519 public keys
+#[cfg(feature = "ed25519")]
+fn kind_payload_validator(kind: &str) -> Option<PayloadValidator> {
+    match kind {
+        "hck0" | "hca0" => Some(validate_ed25519_public_key),
+        _ => None,
+    }
 }
 
 /// an instance that can encode / decode a particular hcid encoding configuration
 pub struct HcidEncoding {
     config: HcidEncodingConfig,
-    rs_enc: reed_solomon::Encoder,
-    rs_dec: reed_solomon::Decoder,
+    rs: rs::Codec,
 }
 
 impl HcidEncoding {
     /// create a new HcidEncoding instance from given HcidEncodingConfig
     pub fn new(config: HcidEncodingConfig) -> HcidResult<Self> {
-        // set up a reed-solomon encoder with proper parity count
-        let rs_enc = reed_solomon::Encoder::new(
-            config.base_parity_byte_count + config.cap_parity_byte_count,
-        );
-
-        // set up a reed-solomon decoder with proper parity count
-        let rs_dec = reed_solomon::Decoder::new(
-            config.base_parity_byte_count + config.cap_parity_byte_count,
-        );
+        // set up a reed-solomon codec with proper parity count
+        let parity = config.base_parity_byte_count + config.cap_parity_byte_count;
+        if parity >= rs::MAX_CODEWORD_LEN {
+            return Err(HcidError(format!("BadParityByteCount:{}", parity)));
+        }
+        let rs = rs::Codec::new(parity);
 
-        Ok(Self {
-            config,
-            rs_enc,
-            rs_dec,
-        })
+        Ok(Self { config, rs })
     }
 
     /// create a new config given a kind token string
@@ -192,6 +451,24 @@ impl HcidEncoding {
         HcidEncoding::new(HcidEncodingConfig::new(kind)?)
     }
 
+    /// like `with_kind`, but key and agent kinds (`hck0`, `hca0`) also check that their key is
+    /// a valid ed25519 public key, on both encode and decode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let hck0 = hcid::HcidEncoding::with_kind_validated("hck0").unwrap();
+    /// assert!(hck0.encode(&[0; 32]).is_err());
+    /// assert!(hcid::HcidEncoding::with_kind("hck0").unwrap().encode(&[0; 32]).is_ok());
+    /// ```
+    #[cfg(feature = "ed25519")]
+    pub fn with_kind_validated(kind: &str) -> HcidResult<Self> {
+        let mut config = HcidEncodingConfig::new(kind)?;
+        config.payload_validator = kind_payload_validator(kind);
+        HcidEncoding::new(config)
+    }
+
     /// encode a string to base32 with this instance's configuration
     pub fn encode(&self, data: &[u8]) -> HcidResult<String> {
         if data.len() != self.config.key_byte_count {
@@ -202,20 +479,27 @@ impl HcidEncoding {
             ))));
         }
 
+        self.validate_payload(data)?;
+
         // generate reed-solomon parity bytes
-        let full_parity = self.rs_enc.encode(data);
+        let mut full_parity = data.to_vec();
+        full_parity.resize(data.len() + self.rs.parity(), 0);
+        self.rs.encode(&mut full_parity)?;
 
         // extract the bytes that will be encoded as capitalization
-        let cap_bytes = &full_parity[full_parity.len() - self.config.cap_parity_byte_count..];
+        let cap_bytes =
+            full_parity[full_parity.len() - self.config.cap_parity_byte_count..].to_vec();
 
         // base is the bytes that will be base32 encoded
         let mut base = self.config.prefix.clone();
         base.extend_from_slice(
             &full_parity[0..full_parity.len() - self.config.cap_parity_byte_count],
         );
+        wipe(&mut full_parity[..]);
 
         // do the base32 encoding
         let mut base32 = b32::encode(&base);
+        wipe(&mut base);
 
         if base32.len() != self.config.encoded_char_count {
             return Err(HcidError(String::from(format!(
@@ -239,7 +523,14 @@ impl HcidEncoding {
             let bin = format!("{:08b}", cap_bytes[i]).into_bytes();
             cap_encode_bin(seg, &bin, 8)?;
         }
-        
+
+        // characters following the last segment carry no parity
+        let tail_start = self.config.prefix_cap.len()
+            + (cap_bytes.len() * self.config.cap_segment_char_count);
+        for c in base32[tail_start..].iter_mut() {
+            char_lower(c);
+        }
+
         // we only use ascii characters
         // use unchecked for performance / so we don't allocate again
         unsafe {
@@ -249,45 +540,281 @@ impl HcidEncoding {
     }
 
     /// decode the data from a base32 string with this instance's configuration.  Reed-Solomon can
-    /// correct up to 1/2 its parity size worth of erasures (if no other errors are present).
+    /// correct up to its parity size worth of erasures, or half that many unmarked errors.
+    /// Typos in the prefix characters are repaired, as the prefix is fixed for the kind, unless
+    /// they spell out the prefix of a different kind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    /// assert_eq!(vec![7; 32], enc.decode(&id.replacen("HcS", "HxS", 1)).unwrap());
+    ///
+    /// // `HcK` is the prefix of hck0 ids
+    /// assert!(enc.decode(&id.replacen("HcS", "HcK", 1)).is_err());
+    /// ```
     pub fn decode(&self, data: &str) -> HcidResult<Vec<u8>> {
+        self.decode_with_policy(data, DecodePolicy::Full)
+    }
+
+    /// decode the data from a base32 string, applying no more correction than `policy` allows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// use hcid::DecodePolicy;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    /// assert!(enc.decode_with_policy(&id, DecodePolicy::Strict).is_ok());
+    ///
+    /// // losing the case costs the 4 cap parity symbols
+    /// let lower = id.to_lowercase();
+    /// assert!(enc.decode_with_policy(&lower, DecodePolicy::TransliterationOnly).is_err());
+    /// assert!(enc.decode_with_policy(&lower, DecodePolicy::ErasuresUpTo(4)).is_ok());
+    /// ```
+    pub fn decode_with_policy(&self, data: &str, policy: DecodePolicy) -> HcidResult<Vec<u8>> {
+        Ok(self.decode_report(data, policy)?.data)
+    }
+
+    /// decode the data from a base32 string as `decode_with_policy` does, also reporting how
+    /// much parity was spent on correction and how much remains to detect further errors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// use hcid::{Confidence, DecodePolicy};
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    ///
+    /// let report = enc.decode_report(&id, DecodePolicy::Full).unwrap();
+    /// assert_eq!(Confidence::Verified { spare_parity: 8 }, report.confidence);
+    ///
+    /// let report = enc.decode_report(&id.to_uppercase(), DecodePolicy::Full).unwrap();
+    /// assert_eq!(4, report.parity_consumed);
+    /// assert_eq!("verified with 4 spare parity symbols", report.confidence.to_string());
+    /// ```
+    pub fn decode_report(&self, data: &str, policy: DecodePolicy) -> HcidResult<DecodeReport> {
         // get our parsed data with erasures
-        let (data, erasures) = self.pre_decode(data)?;
+        let (mut parsed, erasures, prefix_repairs) = self.pre_decode(data)?;
+
+        let res = self.correct(&parsed, &erasures, prefix_repairs, policy);
+
+        // don't leave key material lying around in scratch buffers
+        wipe(&mut parsed);
+
+        let mut res = res?;
+        res.erased_chars = self.erased_chars(data);
+        if let Err(e) = self.validate_payload(&res.data) {
+            wipe(&mut res.data);
+            return Err(e);
+        }
+
+        if policy == DecodePolicy::Strict {
+            // anything but the exact canonical rendering was normalized or transliterated
+            let mut canonical = self.encode(&res.data)?;
+            let is_canonical = canonical == data;
+            wipe(&mut canonical);
+            if !is_canonical {
+                wipe(&mut res.data);
+                return Err(HcidError(String::from("NonCanonicalId")));
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// decode the data from a base32 string into a buffer that is wiped on drop, for
+    /// secret-bearing kinds (e.g. seed backups)
+    #[cfg(feature = "zeroize")]
+    pub fn decode_secret(&self, data: &str) -> HcidResult<SecretBytes> {
+        Ok(SecretBytes::new(self.decode(data)?))
+    }
 
-        if erasures.len() > ( self.config.base_parity_byte_count + self.config.cap_parity_byte_count ) / 2 {
-            // our reed-solomon library makes bad corrections once erasure count exceeds 1/2 the
-            // parity count (it takes 2 parity symbols to find/correct one error, 1 parity symbol to
-            // correct a known erasure)
+    /// internal helper for running the configured payload validator, if any
+    fn validate_payload(&self, data: &[u8]) -> HcidResult<()> {
+        match self.config.payload_validator {
+            Some(validator) => validator(data),
+            None => Ok(()),
+        }
+    }
+
+    /// internal helper for applying reed-solomon correction to pre-decoded data
+    fn correct(
+        &self,
+        data: &[u8],
+        erasures: &[u8],
+        prefix_repairs: usize,
+        policy: DecodePolicy,
+    ) -> HcidResult<DecodeReport> {
+        let parity = self.config.base_parity_byte_count + self.config.cap_parity_byte_count;
+        let allowed = match policy {
+            DecodePolicy::Strict | DecodePolicy::TransliterationOnly => 0,
+            DecodePolicy::ErasuresUpTo(n) => n,
+            DecodePolicy::Full => data.len() + prefix_repairs,
+        };
+
+        // repaired prefix characters count as corrections, though they cost no parity
+        if prefix_repairs > allowed {
+            return Err(HcidError(format!(
+                "TooManyCorrections:{},Allowed:{}",
+                prefix_repairs, allowed
+            )));
+        }
+        let allowed = allowed - prefix_repairs;
+
+        // it takes 1 parity symbol to correct a known erasure (and 2 to find / correct an
+        // unmarked error)
+        let capacity = parity.saturating_sub(self.config.erasure_safety_margin);
+        if erasures.len() > capacity {
             return Err(HcidError(String::from("TooManyErrors")));
         }
 
         // optimise for the case where there are no transcription errors
         // this makes correcting more expensive if there *are*,
         // but on average makes the system more efficient
-        if self.pre_is_corrupt(&data, &erasures)? {
+        if self.pre_is_corrupt(data, erasures)? {
+            if erasures.len() > allowed {
+                return Err(HcidError(format!(
+                    "TooManyCorrections:{},Allowed:{}",
+                    erasures.len() + prefix_repairs,
+                    allowed + prefix_repairs
+                )));
+            }
+
             // apply reed-solomon correction
             // will "throw" on too many errors
-            Ok(
-                self.rs_dec.correct(&data, Some(&erasures[..]))?[0..self.config.key_byte_count]
-                    .to_vec(),
-            )
+            let mut corrected = data.to_vec();
+            let errors = match self.rs.correct(&mut corrected, erasures) {
+                Ok(errors) => errors,
+                Err(e) => {
+                    wipe(&mut corrected[..]);
+                    return Err(e.into());
+                }
+            };
+            if erasures.len() + 2 * errors > capacity {
+                wipe(&mut corrected[..]);
+                return Err(HcidError(String::from("TooManyErrors")));
+            }
+            if errors > 0 {
+                if let DecodePolicy::ErasuresUpTo(_) = policy {
+                    wipe(&mut corrected[..]);
+                    return Err(HcidError(format!("UnmarkedErrors:{}", errors)));
+                }
+            }
+            if erasures.len() + errors > allowed {
+                wipe(&mut corrected[..]);
+                return Err(HcidError(format!(
+                    "TooManyCorrections:{},Allowed:{}",
+                    erasures.len() + errors + prefix_repairs,
+                    allowed + prefix_repairs
+                )));
+            }
+
+            let out = corrected[0..self.config.key_byte_count].to_vec();
+            wipe(&mut corrected[..]);
+            let mut report = DecodeReport::new(out, parity, erasures.len(), errors);
+            report.prefix_repairs = prefix_repairs;
+            Ok(report)
         } else {
-            Ok(data[0..self.config.key_byte_count].to_vec())
+            let mut report = DecodeReport::new(
+                data[0..self.config.key_byte_count].to_vec(),
+                parity,
+                0,
+                0,
+            );
+            report.prefix_repairs = prefix_repairs;
+            Ok(report)
+        }
+    }
+
+    /// render an id in groups of `group` characters joined by `sep`, for easier reading aloud or
+    /// comparing on screen. The prefix characters form the first group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let key = enc.encode(&[0; 32]).unwrap();
+    /// let grouped = enc.display_grouped(&key, 5, '-').unwrap();
+    /// assert!(grouped.starts_with("HcS-ciaaa-aaaaa-"));
+    /// assert_eq!([0; 32].to_vec(), enc.decode_grouped(&grouped, '-').unwrap());
+    /// ```
+    pub fn display_grouped(&self, id: &str, group: usize, sep: char) -> HcidResult<String> {
+        if group == 0 {
+            return Err(HcidError(String::from("BadGroupSize:0")));
         }
+        check_separator(sep)?;
+        if id.len() != self.config.encoded_char_count || !id.is_ascii() {
+            return Err(HcidError(format!(
+                "BadIdLen:{},Expected:{}",
+                id.len(),
+                self.config.encoded_char_count
+            )));
+        }
+
+        let prefix_len = self.config.prefix_cap.len();
+        let mut out = String::from(&id[0..prefix_len]);
+        for (i, c) in id[prefix_len..].chars().enumerate() {
+            if i % group == 0 {
+                out.push(sep);
+            }
+            out.push(c);
+        }
+
+        Ok(out)
+    }
+
+    /// decode an id that was rendered with `display_grouped` (or pasted line-wrapped),
+    /// ignoring any `sep` characters and whitespace
+    pub fn decode_grouped(&self, data: &str, sep: char) -> HcidResult<Vec<u8>> {
+        check_separator(sep)?;
+        self.decode(&strip_separators(data, sep))
+    }
+
+    /// char offsets into `data` of the characters decoding treats as erasures: any
+    /// `ERASURE_MARKERS`, and anything else that is not (and cannot be transliterated into)
+    /// the base32 alphabet
+    pub fn erased_chars(&self, data: &str) -> Vec<usize> {
+        let mut normalized = self.normalize(data);
+        let origin = faults::original_indexes(&normalized, data);
+        let erased = normalized
+            .text
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| !c.is_ascii() || b32_translit(*c as u8).is_none())
+            .map(|(i, _)| origin[i])
+            .collect();
+        wipe(&mut normalized.text);
+        erased
+    }
+
+    /// apply this instance's input normalization to `data`, reporting what was changed.
+    /// `decode` and `is_corrupt` normalize their input the same way.
+    pub fn normalize(&self, data: &str) -> Normalized {
+        normalize(data, self.config.normalize_mode)
     }
 
     /// a lighter-weight check to determine if a base32 string is corrupt
     pub fn is_corrupt(&self, data: &str) -> HcidResult<bool> {
         // get our parsed data with erasures
-        let (data, erasures) = match self.pre_decode(data) {
+        let (mut data, erasures, prefix_repairs) = match self.pre_decode(data) {
             Ok(v) => v,
             Err(_) => return Ok(true),
         };
 
-        match self.pre_is_corrupt(&data, &erasures) {
-            Ok(v) => Ok(v),
+        let res = match self.pre_is_corrupt(&data, &erasures) {
+            Ok(v) => Ok(v || prefix_repairs > 0),
             Err(_) => Ok(true),
-        }
+        };
+
+        wipe(&mut data);
+
+        res
     }
 
     /// internal helper for is_corrupt checking
@@ -298,15 +825,33 @@ impl HcidEncoding {
         }
 
         // slightly more efficient reed-solomon corruption check
-        Ok(self.rs_dec.is_corrupted(&data))
+        Ok(self.rs.is_corrupt(data))
     }
 
     /// internal helper for preparing decoding
-    fn pre_decode(&self, data: &str) -> HcidResult<(Vec<u8>, Vec<u8>)> {
-        if data.len() != self.config.encoded_char_count {
+    fn pre_decode(&self, data: &str) -> HcidResult<(Vec<u8>, Vec<u8>, usize)> {
+        let mut data = self.normalize(data).text;
+        let res = self.pre_decode_normalized(&data);
+        wipe(&mut data);
+        res
+    }
+
+    /// internal helper for preparing decoding, once normalized. Returns the codeword bytes, the
+    /// erased byte indexes, and how many prefix characters were repaired.
+    fn pre_decode_normalized(&self, data: &str) -> HcidResult<(Vec<u8>, Vec<u8>, usize)> {
+        // one byte per char; non-ascii chars left by normalizing cannot be transliterated, so
+        // they stand in as erasure markers
+        let mut bytes: Vec<u8> = data
+            .chars()
+            .map(|c| if c.is_ascii() { c as u8 } else { ERASURE_MARKERS[0] as u8 })
+            .collect();
+
+        if bytes.len() != self.config.encoded_char_count {
+            let len = bytes.len();
+            wipe(&mut bytes);
             return Err(HcidError(String::from(format!(
                 "BadIdLen:{},Expected:{}",
-                data.len(),
+                len,
                 self.config.encoded_char_count
             ))));
         }
@@ -316,11 +861,12 @@ impl HcidEncoding {
         // byte_erasures are indexed from the 0th byte of the key+parity (ie. without the prefix).
         // Any byte of key, or base/cap parity could be erased.
         let mut byte_erasures = vec![b'0'; key_base_byte_size + self.config.cap_parity_byte_count];
-        let mut char_erasures = vec![b'0'; data.len()];
+        let mut char_erasures = vec![b'0'; bytes.len()];
 
         // correct any transliteration errors into our base32 alphabet
         // marking any unrecognizable characters as char-level erasures
-        let mut data = b32_correct(data.as_bytes(), &mut char_erasures);
+        let mut chars = b32_correct(&bytes, &mut char_erasures);
+        wipe(&mut bytes);
 
         // Pull out the parity data that was encoded as capitalization.  If its erasure,
         // determine the 
@@ -332,7 +878,7 @@ impl HcidEncoding {
             let char_idx = self.config.prefix_cap.len() + (i * self.config.cap_segment_char_count);
             match cap_decode(
                 char_idx,
-                &data[char_idx..char_idx + self.config.cap_segment_char_count],
+                &chars[char_idx..char_idx + self.config.cap_segment_char_count],
                 &char_erasures
             )? {
                 None => {
@@ -352,11 +898,9 @@ impl HcidEncoding {
         }
 
         // If either all caps or all lower case (or erasure), assume the casing was lost (eg. QR
-        // code, or dns segment); mark all cap-derived parity as erasures.  This allows validation
-        // of codeword if all remaining parity is intact and key is correct; since no parity
-        // capacity remains, no correction will be attempted.  There is only a low probability that
-        // any remaining errors will be detected, in this case.  However, we're no *worse* off than
-        // if we had no R-S parity at all.
+        // code, or dns segment); mark all cap-derived parity as erasures.  Correcting those uses
+        // one base parity symbol each, so fewer errors elsewhere can be corrected or detected in
+        // this case.  However, we're no *worse* off than if we had no cap parity at all.
         if all_zro || all_one {
             for i in 0..self.config.cap_parity_byte_count {
                 byte_erasures[key_base_byte_size + i] = b'1';
@@ -364,14 +908,24 @@ impl HcidEncoding {
         }
 
         // we have the cap data, uppercase everything
-        for c in data.iter_mut() {
+        for c in chars.iter_mut() {
             char_upper(c);
         }
 
         // do the base32 decode
-        let mut data = b32::decode(&data)?;
+        let data = b32::decode(&chars);
+        wipe(&mut chars);
+        let mut data = data?;
 
-        if &data[0..self.config.prefix.len()] != self.config.prefix.as_slice() {
+        // The prefix is not covered by parity, but it is fixed for the kind, so typos in it can be
+        // repaired outright.  A legible prefix that belongs to another kind is no typo though:
+        // the id is of that kind.  (A typo in the char shared with the key also damages the key's
+        // first byte, which is left to reed-solomon.)
+        let prefix = &data[0..self.config.prefix.len()];
+        let prefix_repairs = prefix_distance(prefix, &self.config.prefix, &char_erasures);
+        let prefix_erased = char_erasures[0..(prefix.len() * 8).div_ceil(5)].contains(&b'1');
+        if prefix_repairs > 0 && !prefix_erased && kind_for_prefix(prefix).is_some() {
+            wipe(&mut data);
             return Err(HcidError(String::from("PrefixMismatch")));
         }
 
@@ -384,16 +938,23 @@ impl HcidEncoding {
         // Sort through the char-level erasures (5 bits), and associate them with byte-level data (8
         // bits) -- in the (now prefix-free) data buffer, so that we mark the proper erasures for
         // reed-solomon correction.  Some of these chars span multiple bytes... we need to mark both.
-        for i in self.config.prefix_cap.len()..char_erasures.len() {
-            let c = char_erasures[i];
-            if c == b'1' {
+        // Only bits of the key and base parity count: the prefix is fixed, and the last char is
+        // padded with zero bits (which must not mark the first cap parity byte).
+        let prefix_bits = self.config.prefix.len() * 8;
+        let base_bits = prefix_bits + key_base_byte_size * 8;
+        for (i, c) in char_erasures.iter().enumerate().skip(self.config.prefix_cap.len()) {
+            if *c == b'1' {
                 // 1st and last bit of 5-bit segment may index different bytes
-                byte_erasures[( i * 5 + 0 ) / 8 - self.config.prefix.len()] = b'1';
-                byte_erasures[( i * 5 + 4 ) / 8 - self.config.prefix.len()] = b'1';
+                let first = (i * 5).max(prefix_bits);
+                let last = (i * 5 + 4).min(base_bits - 1);
+                if first <= last {
+                    byte_erasures[(first - prefix_bits) / 8] = b'1';
+                    byte_erasures[(last - prefix_bits) / 8] = b'1';
+                }
             }
         }
 
-        // translate erasures into the form expected by our reed-solomon lib
+        // translate erasures into the form expected by our reed-solomon codec
         let mut erasures: Vec<u8> = Vec::new();
         for i in 0..byte_erasures.len() {
             if byte_erasures[i] == b'1' {
@@ -402,10 +963,19 @@ impl HcidEncoding {
             }
         }
 
-        Ok((data, erasures))
+        Ok((data, erasures, prefix_repairs))
     }
 }
 
+/// an encoding for `kind` without payload validation, as test keys are random bytes, not
+/// necessarily valid curve points
+#[cfg(test)]
+pub(crate) fn test_encoding(kind: &str) -> HcidEncoding {
+    let mut config = HcidEncodingConfig::new(kind).unwrap();
+    config.payload_validator = None;
+    HcidEncoding::new(config).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -417,7 +987,7 @@ mod tests {
 
     #[test]
     fn it_encodes_1() {
-        let enc = HcidEncoding::with_kind("hck0").unwrap();
+        let enc = test_encoding("hck0");
 
         let input = hex::decode(TEST_HEX_1.as_bytes()).unwrap();
         let id = enc.encode(&input).unwrap();
@@ -426,9 +996,318 @@ mod tests {
 
     #[test]
     fn it_decodes_1() {
-        let enc = HcidEncoding::with_kind("hck0").unwrap();
+        let enc = test_encoding("hck0");
 
         let data = hex::encode(&enc.decode(TEST_ID_1).unwrap());
         assert_eq!(TEST_HEX_1, data);
     }
+
+    #[test]
+    fn it_decodes_pasted_1() {
+        let enc = test_encoding("hck0");
+
+        // quoted, full-width 'Ｋ', cyrillic 'О', trailing newline
+        let pasted = format!("\"{}\"\n", TEST_ID_1.replacen("K", "\u{ff2b}", 1).replacen("O", "\u{041e}", 1));
+        assert!(!enc.is_corrupt(&pasted).unwrap());
+        let data = hex::encode(enc.decode(&pasted).unwrap());
+        assert_eq!(TEST_HEX_1, data);
+
+        let mut config = HcidEncodingConfig::new("hck0").unwrap();
+        config.normalize_mode = NormalizeMode::Strict;
+        config.payload_validator = None;
+        let enc = HcidEncoding::new(config).unwrap();
+        assert!(enc.is_corrupt(&pasted).unwrap());
+        assert!(enc.decode(&pasted).is_err());
+    }
+
+    #[test]
+    fn it_decodes_unknown_chars_as_erasures_1() {
+        let enc = test_encoding("hck0");
+
+        // one unreadable char, whatever its utf-8 length
+        let typo = TEST_ID_1.replacen("Dds", "D\u{2603}s", 1);
+        let report = enc.decode_report(&typo, DecodePolicy::Full).unwrap();
+        assert_eq!(TEST_HEX_1, hex::encode(&report.data));
+        assert_eq!(vec![6], report.erased_chars);
+        assert_eq!(vec![6], enc.erased_chars(&typo));
+
+        // a char short, even if as long in bytes
+        let short = TEST_ID_1.replacen("Dds", "D\u{e9}", 1);
+        assert_eq!(
+            HcidError::from("BadIdLen:62,Expected:63"),
+            enc.decode(&short).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn it_decodes_with_policy_1() {
+        let enc = test_encoding("hck0");
+
+        let canonical = hex::decode(TEST_HEX_1).unwrap();
+        let translit = TEST_ID_1.replace("O", "0");
+        let lost_case = TEST_ID_1.to_uppercase();
+        let one_error = TEST_ID_1.replacen("Dds", "Ddx", 1);
+        let erased = TEST_ID_1.replacen("ymx", "y_x", 1);
+        let two_errors = one_error.replacen("ymx", "y_x", 1);
+
+        let decode = |id: &str, policy| enc.decode_with_policy(id, policy);
+
+        assert_eq!(canonical, decode(TEST_ID_1, DecodePolicy::Strict).unwrap());
+        assert!(decode(&translit, DecodePolicy::Strict).is_err());
+        assert!(decode(&format!(" {}", TEST_ID_1), DecodePolicy::Strict).is_err());
+
+        assert_eq!(canonical, decode(&translit, DecodePolicy::TransliterationOnly).unwrap());
+        assert!(decode(&lost_case, DecodePolicy::TransliterationOnly).is_err());
+        assert!(decode(&one_error, DecodePolicy::TransliterationOnly).is_err());
+
+        // an erased char costs the 2 byte symbols it straddles
+        assert_eq!(
+            "HcidError(\"TooManyCorrections:2,Allowed:1\")",
+            format!("{:?}", decode(&erased, DecodePolicy::ErasuresUpTo(1)).unwrap_err())
+        );
+        assert_eq!(canonical, decode(&erased, DecodePolicy::ErasuresUpTo(2)).unwrap());
+
+        // errors that were not marked are never just erasures
+        for policy in &[DecodePolicy::ErasuresUpTo(1), DecodePolicy::ErasuresUpTo(8)] {
+            assert_eq!(
+                "HcidError(\"UnmarkedErrors:1\")",
+                format!("{:?}", decode(&one_error, *policy).unwrap_err())
+            );
+        }
+        assert_eq!(
+            "HcidError(\"UnmarkedErrors:1\")",
+            format!("{:?}", decode(&two_errors, DecodePolicy::ErasuresUpTo(3)).unwrap_err())
+        );
+        assert_eq!(canonical, decode(&one_error, DecodePolicy::Full).unwrap());
+        assert_eq!(canonical, decode(&two_errors, DecodePolicy::Full).unwrap());
+    }
+
+    #[test]
+    fn it_repairs_prefix_typos_1() {
+        let enc = test_encoding("hck0");
+        let data = hex::decode(TEST_HEX_1).unwrap();
+
+        let id = TEST_ID_1.replacen("HcK", "HxK", 1);
+        assert!(enc.is_corrupt(&id).unwrap());
+        let report = enc.decode_report(&id, DecodePolicy::Full).unwrap();
+        assert_eq!(data, report.data);
+        assert_eq!((1, 0), (report.prefix_repairs, report.parity_consumed));
+
+        // repairs count as corrections
+        assert_eq!(
+            HcidError(String::from("TooManyCorrections:1,Allowed:0")),
+            enc.decode_with_policy(&id, DecodePolicy::TransliterationOnly).unwrap_err()
+        );
+        assert!(enc.decode_with_policy(&id, DecodePolicy::ErasuresUpTo(1)).is_ok());
+
+        // the prefix of another kind is not a typo
+        let id = TEST_ID_1.replacen("HcK", "HcA", 1);
+        assert_eq!(
+            HcidError(String::from("PrefixMismatch")),
+            enc.decode(&id).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn it_reports_parity_margin_1() {
+        let enc = test_encoding("hck0");
+
+        let report = |id: &str| enc.decode_report(id, DecodePolicy::Full).unwrap();
+
+        let r = report(TEST_ID_1);
+        assert_eq!((0, 0, 0, 8), (r.erasure_count, r.error_count, r.parity_consumed, r.parity_spare));
+        assert!(r.is_verified());
+
+        // one substituted character: located as an error
+        let r = report(&TEST_ID_1.replacen("Dds", "Ddx", 1));
+        assert_eq!((0, 1, 2, 6), (r.erasure_count, r.error_count, r.parity_consumed, r.parity_spare));
+
+        // lost case: every cap parity byte is erased
+        let r = report(&TEST_ID_1.to_lowercase());
+        assert_eq!((4, 0, 4, 4), (r.erasure_count, r.error_count, r.parity_consumed, r.parity_spare));
+
+        // one unreadable character spanning two bytes (and losing its segment's case byte),
+        // plus one substitution
+        let r = report(&TEST_ID_1.replacen("Dds", "D_s", 1).replacen("ymx", "ymy", 1));
+        assert_eq!((3, 1, 5, 3), (r.erasure_count, r.error_count, r.parity_consumed, r.parity_spare));
+        assert_eq!(Confidence::Verified { spare_parity: 3 }, r.confidence);
+        assert_eq!(hex::decode(TEST_HEX_1).unwrap(), r.data);
+    }
+
+    /// `id` with the characters at `positions` replaced by the `_` erasure marker
+    fn erase(id: &str, positions: &[usize]) -> String {
+        id.char_indices()
+            .map(|(i, c)| if positions.contains(&i) { '_' } else { c })
+            .collect()
+    }
+
+    #[test]
+    fn it_recovers_any_two_erased_chars() {
+        let enc = test_encoding("hck0");
+        let data = hex::decode(TEST_HEX_1).unwrap();
+
+        // an erased char costs at most 2 byte erasures, plus its cap parity segment byte;
+        // erased prefix chars are repaired without parity
+        for a in 0..TEST_ID_1.len() {
+            assert_eq!(data, enc.decode(&erase(TEST_ID_1, &[a])).unwrap());
+            for b in a + 1..TEST_ID_1.len() {
+                assert_eq!(data, enc.decode(&erase(TEST_ID_1, &[a, b])).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn it_recovers_or_rejects_three_erased_chars() {
+        let enc = test_encoding("hck0");
+        let data = hex::decode(TEST_HEX_1).unwrap();
+        let bound = 8;
+
+        // parity symbols an erased char costs: the key / base parity bytes its 5 bits fall in,
+        // and the cap parity byte of its segment, if it comes before the 8 letters that byte
+        // is read from
+        let id = TEST_ID_1.as_bytes();
+        let cap_end = |seg: usize| {
+            let start = 3 + seg * 15;
+            (start..start + 15)
+                .filter(|i| id[*i].is_ascii_alphabetic())
+                .nth(7)
+                .unwrap()
+        };
+        let symbols = |chars: &[usize]| {
+            let mut bytes = std::collections::BTreeSet::new();
+            for i in chars {
+                for bit in (i * 5)..(i * 5 + 5) {
+                    if (24..24 + 36 * 8).contains(&bit) {
+                        bytes.insert((bit - 24) / 8);
+                    }
+                }
+                let seg = (i - 3) / 15;
+                if *i <= cap_end(seg) {
+                    bytes.insert(36 + seg);
+                }
+            }
+            bytes.len()
+        };
+
+        let (mut fits, mut exceeds) = (0, 0);
+        for a in 5..TEST_ID_1.len() {
+            for b in a + 1..TEST_ID_1.len() {
+                for c in b + 1..TEST_ID_1.len() {
+                    let res = enc.decode(&erase(TEST_ID_1, &[a, b, c]));
+                    if symbols(&[a, b, c]) <= bound {
+                        assert_eq!(Ok(data.clone()), res, "chars {} {} {}", a, b, c);
+                        fits += 1;
+                    } else {
+                        assert_eq!(Err(HcidError(String::from("TooManyErrors"))), res);
+                        exceeds += 1;
+                    }
+                }
+            }
+        }
+
+        // every pattern is one or the other, and both occur
+        assert_eq!(58 * 57 * 56 / 6, fits + exceeds);
+        assert!(fits > 0 && exceeds > 0);
+    }
+
+    #[test]
+    fn it_recovers_any_erased_char_without_case() {
+        let enc = test_encoding("hck0");
+        let data = hex::decode(TEST_HEX_1).unwrap();
+        let lower = TEST_ID_1.to_lowercase();
+
+        // lost case costs the 4 cap parity symbols, leaving 4
+        for a in 5..lower.len() {
+            assert_eq!(data, enc.decode(&erase(&lower, &[a])).unwrap());
+            for b in a + 1..lower.len() {
+                if let Ok(d) = enc.decode(&erase(&lower, &[a, b])) {
+                    assert_eq!(data, d);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_holds_back_erasure_safety_margin() {
+        let mut config = HcidEncodingConfig::new("hck0").unwrap();
+        config.payload_validator = None;
+        config.erasure_safety_margin = 2;
+        let enc = HcidEncoding::new(config).unwrap();
+
+        // 4 cap + 2 base erasures leave the 2 symbols of margin
+        let id = erase(&TEST_ID_1.to_lowercase(), &[11]);
+        let report = enc.decode_report(&id, DecodePolicy::Full).unwrap();
+        assert_eq!((6, 2), (report.parity_consumed, report.parity_spare));
+
+        // 4 cap + 4 base erasures would need them
+        let id = erase(&TEST_ID_1.to_lowercase(), &[11, 30]);
+        assert!(test_encoding("hck0").decode(&id).is_ok());
+        assert_eq!(HcidError(String::from("TooManyErrors")), enc.decode(&id).unwrap_err());
+    }
+
+    #[test]
+    fn it_validates_parity_layout() {
+        let config = HcidEncodingConfig::with_parity("hck0", 4, 4).unwrap();
+        assert_eq!((63, 15), (config.encoded_char_count, config.cap_segment_char_count));
+        assert!(config.cap_segment_loss_probability() < 0.01);
+
+        let config = HcidEncodingConfig::with_parity("hck0", 6, 0).unwrap();
+        assert_eq!((66, 0), (config.encoded_char_count, config.cap_segment_char_count));
+        assert_eq!(0.0, config.cap_segment_loss_probability());
+
+        let err = |b, c| format!("{:?}", HcidEncodingConfig::with_parity("hck0", b, c).err().unwrap());
+        assert_eq!("HcidError(\"CapSegmentTooShort:13,Minimum:15\")", err(8, 5));
+        assert_eq!("HcidError(\"CapSegmentTooShort:7,Minimum:15\")", err(4, 8));
+        assert_eq!("HcidError(\"BadParityByteCount:0\")", err(0, 0));
+        assert_eq!("HcidError(\"BadKeyByteCount:32\")", err(200, 30));
+    }
+
+    #[test]
+    fn it_displays_grouped_1() {
+        let enc = test_encoding("hck0");
+
+        let grouped = enc.display_grouped(TEST_ID_1, 5, '-').unwrap();
+        assert_eq!(
+            "HcK-ciDds-5Oiog-ymxbn-HKEab-Q8iav-qs8dw-dVaGd-JW76V-p4gx4-7tQDf-GW4OW-c9w5i",
+            grouped
+        );
+    }
+
+    #[test]
+    fn it_decodes_grouped_1() {
+        let enc = test_encoding("hck0");
+
+        let grouped = enc.display_grouped(TEST_ID_1, 4, '-').unwrap();
+        let wrapped = format!("  {}\n  {}\n", &grouped[..30], &grouped[30..]);
+        let data = hex::encode(enc.decode_grouped(&wrapped, '-').unwrap());
+        assert_eq!(TEST_HEX_1, data);
+
+        let grouped = enc.display_grouped(TEST_ID_1, 5, ' ').unwrap();
+        let data = hex::encode(enc.decode_grouped(&grouped, ' ').unwrap());
+        assert_eq!(TEST_HEX_1, data);
+    }
+
+    #[test]
+    fn it_rejects_alphabet_separators() {
+        let enc = test_encoding("hck0");
+
+        assert!(enc.display_grouped(TEST_ID_1, 5, 'a').is_err());
+        assert!(enc.decode_grouped(TEST_ID_1, '3').is_err());
+        assert!(enc.display_grouped(TEST_ID_1, 5, '?').is_err());
+    }
+
+    #[test]
+    fn it_decodes_erasure_markers_1() {
+        let enc = test_encoding("hck0");
+        let data = hex::decode(TEST_HEX_1).unwrap();
+
+        // full-width question marks fold into markers, and offsets count the trimmed quote
+        let id = format!("'{}", TEST_ID_1.replacen("Dds", "??s", 1).replacen("W76", "W\u{ff1f}6", 1));
+        assert_eq!(vec![6, 7, 41], enc.erased_chars(&id));
+
+        let report = enc.decode_report(&id, DecodePolicy::Full).unwrap();
+        assert_eq!(data, report.data);
+        assert_eq!(vec![6, 7, 41], report.erased_chars);
+        assert_eq!(0, report.error_count);
+    }
 }
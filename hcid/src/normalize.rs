@@ -0,0 +1,237 @@
+//! Input normalization for ids pasted from chat apps, documents, etc.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// how much cleanup to apply to an id before base32 transliteration
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NormalizeMode {
+    /// pass the input through untouched
+    Strict,
+    /// trim whitespace and quotes, drop invisible characters,
+    /// and fold full-width / look-alike unicode characters into ascii
+    #[default]
+    Lenient,
+}
+
+/// a single change made while normalizing an id.
+/// Indexes are char offsets into the original input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NormalizeAction {
+    /// leading or trailing whitespace / quote characters were removed
+    Trimmed {
+        /// char offset of the first removed character
+        index: usize,
+        /// the characters removed
+        removed: String,
+    },
+    /// an invisible (zero-width, bom, soft-hyphen) character was removed
+    RemovedInvisible {
+        /// char offset of the removed character
+        index: usize,
+        /// the removed character
+        ch: char,
+    },
+    /// a non-ascii character was replaced with its ascii equivalent
+    Folded {
+        /// char offset of the replaced character
+        index: usize,
+        /// the original character
+        from: char,
+        /// the ascii replacement
+        to: char,
+    },
+}
+
+/// the result of normalizing an id
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalized {
+    /// the normalized text, ready for transliteration
+    pub text: String,
+    /// what was changed, in input order
+    pub actions: Vec<NormalizeAction>,
+}
+
+/// unicode characters that render like ascii letters, but which nfkc leaves alone
+static LOOK_ALIKES: &[(char, char)] = &[
+    // cyrillic
+    ('\u{0410}', 'A'), ('\u{0412}', 'B'), ('\u{0415}', 'E'), ('\u{041a}', 'K'),
+    ('\u{041c}', 'M'), ('\u{041d}', 'H'), ('\u{041e}', 'O'), ('\u{0420}', 'P'),
+    ('\u{0421}', 'C'), ('\u{0422}', 'T'), ('\u{0423}', 'Y'), ('\u{0425}', 'X'),
+    ('\u{0405}', 'S'), ('\u{0406}', 'I'), ('\u{0408}', 'J'),
+    ('\u{0430}', 'a'), ('\u{0435}', 'e'), ('\u{043e}', 'o'), ('\u{0440}', 'p'),
+    ('\u{0441}', 'c'), ('\u{0443}', 'y'), ('\u{0445}', 'x'), ('\u{0455}', 's'),
+    ('\u{0456}', 'i'), ('\u{0458}', 'j'), ('\u{0501}', 'd'),
+    // greek
+    ('\u{0391}', 'A'), ('\u{0392}', 'B'), ('\u{0395}', 'E'), ('\u{0396}', 'Z'),
+    ('\u{0397}', 'H'), ('\u{0399}', 'I'), ('\u{039a}', 'K'), ('\u{039c}', 'M'),
+    ('\u{039d}', 'N'), ('\u{039f}', 'O'), ('\u{03a1}', 'P'), ('\u{03a4}', 'T'),
+    ('\u{03a5}', 'Y'), ('\u{03a7}', 'X'),
+    ('\u{03bf}', 'o'), ('\u{03bd}', 'v'), ('\u{03ba}', 'k'),
+];
+
+/// characters that take no space when rendered
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00ad}' | '\u{200b}'..='\u{200f}' | '\u{2060}'..='\u{2064}' | '\u{feff}'
+    )
+}
+
+/// characters commonly wrapped around pasted ids
+fn is_trimmable(c: char) -> bool {
+    if c.is_whitespace() || is_invisible(c) {
+        return true;
+    }
+    matches!(
+        c,
+        '"' | '\'' | '`' | '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201c}' | '\u{201d}'
+            | '\u{201e}' | '\u{00ab}' | '\u{00bb}' | '<' | '>'
+    )
+}
+
+/// fold a single non-ascii character into an ascii alphanumeric (or erasure marker), if it
+/// has one
+fn fold(c: char) -> Option<char> {
+    let mut nfkc = std::iter::once(c).nfkc();
+    if let (Some(f), None) = (nfkc.next(), nfkc.next()) {
+        if f.is_ascii_alphanumeric() || crate::ERASURE_MARKERS.contains(&f) {
+            return Some(f);
+        }
+    }
+
+    LOOK_ALIKES
+        .iter()
+        .find(|(from, _)| *from == c)
+        .map(|(_, to)| *to)
+}
+
+/// clean up an id according to `mode`, reporting every change made.
+/// In `Strict` mode the input is returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// use hcid::{normalize, NormalizeMode};
+/// let n = normalize(" \"Hc\u{041a}ci\u{200b}\" ", NormalizeMode::Lenient);
+/// assert_eq!("HcKci", n.text);
+/// assert_eq!(3, n.actions.len());
+/// ```
+pub fn normalize(data: &str, mode: NormalizeMode) -> Normalized {
+    if mode == NormalizeMode::Strict {
+        return Normalized {
+            text: data.to_string(),
+            actions: Vec::new(),
+        };
+    }
+
+    let chars: Vec<char> = data.chars().collect();
+    let mut actions = Vec::new();
+
+    let start = chars.iter().take_while(|c| is_trimmable(**c)).count();
+    let end = start.max(chars.len() - chars.iter().rev().take_while(|c| is_trimmable(**c)).count());
+
+    if start > 0 {
+        actions.push(NormalizeAction::Trimmed {
+            index: 0,
+            removed: chars[..start].iter().collect(),
+        });
+    }
+
+    let mut text = String::with_capacity(end - start);
+    for (index, c) in chars.iter().enumerate().take(end).skip(start) {
+        let c = *c;
+        if c.is_ascii() {
+            text.push(c);
+        } else if is_invisible(c) {
+            actions.push(NormalizeAction::RemovedInvisible { index, ch: c });
+        } else if let Some(to) = fold(c) {
+            actions.push(NormalizeAction::Folded { index, from: c, to });
+            text.push(to);
+        } else {
+            // leave it for transliteration to mark as an erasure
+            text.push(c);
+        }
+    }
+
+    if end < chars.len() {
+        actions.push(NormalizeAction::Trimmed {
+            index: end,
+            removed: chars[end..].iter().collect(),
+        });
+    }
+
+    Normalized { text, actions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    #[test]
+    fn it_leaves_strict_input_alone() {
+        let input = format!(" '{}'\n", TEST_ID_1);
+        let n = normalize(&input, NormalizeMode::Strict);
+        assert_eq!(input, n.text);
+        assert!(n.actions.is_empty());
+    }
+
+    #[test]
+    fn it_trims_whitespace_and_quotes() {
+        let n = normalize(&format!("\u{201c}{}\u{201d}\r\n", TEST_ID_1), NormalizeMode::Lenient);
+        assert_eq!(TEST_ID_1, n.text);
+        assert_eq!(
+            vec![
+                NormalizeAction::Trimmed {
+                    index: 0,
+                    removed: "\u{201c}".to_string(),
+                },
+                NormalizeAction::Trimmed {
+                    index: 64,
+                    removed: "\u{201d}\r\n".to_string(),
+                },
+            ],
+            n.actions
+        );
+    }
+
+    #[test]
+    fn it_folds_full_width_and_look_alikes() {
+        // full-width 'Ｈ', cyrillic 'О', greek 'Ι', zero-width space
+        let n = normalize("\u{ff28}c\u{041e}\u{0399}\u{200b}x", NormalizeMode::Lenient);
+        assert_eq!("HcOIx", n.text);
+        assert_eq!(
+            vec![
+                NormalizeAction::Folded {
+                    index: 0,
+                    from: '\u{ff28}',
+                    to: 'H',
+                },
+                NormalizeAction::Folded {
+                    index: 2,
+                    from: '\u{041e}',
+                    to: 'O',
+                },
+                NormalizeAction::Folded {
+                    index: 3,
+                    from: '\u{0399}',
+                    to: 'I',
+                },
+                NormalizeAction::RemovedInvisible {
+                    index: 4,
+                    ch: '\u{200b}',
+                },
+            ],
+            n.actions
+        );
+    }
+
+    #[test]
+    fn it_keeps_unknown_characters_for_erasure() {
+        let n = normalize("Hc\u{2603}_", NormalizeMode::Lenient);
+        assert_eq!("Hc\u{2603}_", n.text);
+        assert!(n.actions.is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+//! Reading ids aloud, e.g. over a support call, and parsing what was heard back into an id.
+//!
+//! Letters are spoken with the NATO alphabet, digits as numbers. The cased form says
+//! "capital" or "small" before each letter, so the id comes back exactly. The compact form
+//! leaves case out; the id then comes back in lower case, which decodes through the
+//! case-lost path (the base parity alone), as for any id written without case.
+
+use super::util::wipe;
+use super::{normalize, HcidError, HcidResult, NormalizeMode, ERASURE_MARKERS};
+
+/// how letter case is spoken
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhoneticForm {
+    /// "capital" or "small" before every letter
+    Cased,
+    /// no case at all, for a shorter readout
+    Compact,
+}
+
+/// NATO alphabet words, a to z
+static LETTER_WORDS: [&str; 26] = [
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliet",
+    "kilo", "lima", "mike", "november", "oscar", "papa", "quebec", "romeo", "sierra", "tango",
+    "uniform", "victor", "whiskey", "xray", "yankee", "zulu",
+];
+
+/// digit words, 0 to 9
+static DIGIT_WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// other spellings of letter and digit words that transcripts use
+static WORD_ALIASES: &[(&str, char)] = &[
+    ("alfa", 'a'),
+    ("juliett", 'j'),
+    ("whisky", 'w'),
+    ("niner", '9'),
+    ("fife", '5'),
+    ("tree", '3'),
+];
+
+/// spoken for an unreadable (erased) character
+const UNKNOWN_WORD: &str = "unknown";
+
+static CAPITAL_WORDS: &[&str] = &["capital", "cap", "upper", "uppercase", "big"];
+static SMALL_WORDS: &[&str] = &["small", "lower", "lowercase", "little"];
+
+/// the spoken form of `id`, words separated by ", ". `id` is normalized first, as for
+/// `decode`. Ascii punctuation and whitespace (e.g. the separators of a grouped id) are not
+/// spoken, so the readout parses back to the bare id; any other character that cannot be read
+/// is spoken as "unknown", like an erasure marker.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// use hcid::phonetic::{to_phonetic, PhoneticForm};
+/// assert_eq!(
+///     "capital hotel, small charlie, capital kilo, small charlie, five",
+///     to_phonetic("HcKc5", PhoneticForm::Cased)
+/// );
+/// assert_eq!("hotel charlie kilo charlie five", to_phonetic("HcKc5", PhoneticForm::Compact));
+/// ```
+pub fn to_phonetic(id: &str, form: PhoneticForm) -> String {
+    let mut id = normalize(id, NormalizeMode::Lenient).text;
+    let words: Vec<String> = id
+        .chars()
+        .filter_map(|c| {
+            if let Some(d) = c.to_digit(10) {
+                Some(DIGIT_WORDS[d as usize].to_string())
+            } else if c.is_ascii_alphabetic() {
+                let word = LETTER_WORDS[(c.to_ascii_lowercase() as u8 - b'a') as usize];
+                Some(match (form, c.is_ascii_uppercase()) {
+                    (PhoneticForm::Compact, _) => word.to_string(),
+                    (PhoneticForm::Cased, true) => format!("capital {}", word),
+                    (PhoneticForm::Cased, false) => format!("small {}", word),
+                })
+            } else if c.is_ascii() && !ERASURE_MARKERS.contains(&c) {
+                None
+            } else {
+                Some(UNKNOWN_WORD.to_string())
+            }
+        })
+        .collect();
+    wipe(&mut id);
+
+    match form {
+        PhoneticForm::Cased => words.join(", "),
+        PhoneticForm::Compact => words.join(" "),
+    }
+}
+
+/// parse a spoken or transcribed id (as rendered by `to_phonetic`, or typed up from a call)
+/// back into the id, ready to `decode`.
+///
+/// Letters may be NATO words or plain letters, digits words or numerals, and "unknown" marks
+/// an unreadable character. A letter is upper case after "capital" (or "cap", "upper",
+/// "big"), and lower case after "small" (or "lower", "little") or with no case given.
+/// Punctuation between words is ignored.
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// assert_eq!(
+///     "HcKc5",
+///     hcid::phonetic::from_phonetic("Capital H, small c, capital kilo, Charlie... 5").unwrap()
+/// );
+/// ```
+pub fn from_phonetic(text: &str) -> HcidResult<String> {
+    let mut out = String::new();
+    let mut upper = None;
+
+    for token in text.split(|c: char| c.is_whitespace() || ",;:.!".contains(c)) {
+        let word: String = token
+            .chars()
+            .filter(|c| *c != '-' && *c != '\'')
+            .collect::<String>()
+            .to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+
+        if let Some(marker) = upper {
+            if CAPITAL_WORDS.contains(&word.as_str()) || SMALL_WORDS.contains(&word.as_str()) {
+                return Err(bad_marker(marker));
+            }
+        }
+        if CAPITAL_WORDS.contains(&word.as_str()) {
+            upper = Some(true);
+            continue;
+        }
+        if SMALL_WORDS.contains(&word.as_str()) {
+            upper = Some(false);
+            continue;
+        }
+
+        let c = match spoken_char(&word) {
+            Some(c) => c,
+            None => return Err(HcidError(format!("UnknownSpokenWord:{}", token))),
+        };
+        match upper.take() {
+            Some(true) if c.is_ascii_alphabetic() => out.push(c.to_ascii_uppercase()),
+            Some(false) | None if c.is_ascii_alphabetic() => out.push(c),
+            None => out.push(c),
+            Some(marker) => return Err(bad_marker(marker)),
+        }
+    }
+
+    if let Some(marker) = upper {
+        return Err(bad_marker(marker));
+    }
+    Ok(out)
+}
+
+/// the lower case letter, digit or erasure marker that (lower case) `word` speaks
+fn spoken_char(word: &str) -> Option<char> {
+    let mut chars = word.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_alphanumeric() || ERASURE_MARKERS.contains(&c) {
+            return Some(c);
+        }
+    }
+
+    if word == UNKNOWN_WORD {
+        return Some(ERASURE_MARKERS[0]);
+    }
+    if let Some(i) = LETTER_WORDS.iter().position(|w| *w == word) {
+        return Some((b'a' + i as u8) as char);
+    }
+    if let Some(i) = DIGIT_WORDS.iter().position(|w| *w == word) {
+        return Some((b'0' + i as u8) as char);
+    }
+    WORD_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == word)
+        .map(|(_, c)| *c)
+}
+
+/// a case marker not followed by a letter
+fn bad_marker(upper: bool) -> HcidError {
+    let marker = if upper { "capital" } else { "small" };
+    HcidError(format!("BadCaseMarker:{}", marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_transcripts() {
+        assert_eq!(
+            "HcK?3w",
+            from_phonetic("CAPITAL hotel; small Charlie - cap K, unknown, tree. whisky").unwrap()
+        );
+        assert_eq!("xj9", from_phonetic("x-ray juliett niner").unwrap());
+        assert_eq!("Ab", from_phonetic("big alfa little b").unwrap());
+    }
+
+    #[test]
+    fn it_rejects_bad_transcripts() {
+        assert_eq!(
+            Err(HcidError::from("UnknownSpokenWord:hello")),
+            from_phonetic("capital hotel hello")
+        );
+        assert_eq!(
+            Err(HcidError::from("BadCaseMarker:capital")),
+            from_phonetic("capital five")
+        );
+        assert_eq!(
+            Err(HcidError::from("BadCaseMarker:small")),
+            from_phonetic("alpha small")
+        );
+        assert_eq!(
+            Err(HcidError::from("BadCaseMarker:capital")),
+            from_phonetic("capital small alpha")
+        );
+    }
+
+    #[test]
+    fn it_speaks_erasures() {
+        let spoken = to_phonetic("Hc?_", PhoneticForm::Cased);
+        assert_eq!("capital hotel, small charlie, unknown, unknown", spoken);
+        assert_eq!("Hc??", from_phonetic(&spoken).unwrap());
+    }
+
+    #[test]
+    fn it_skips_separators() {
+        let enc = super::super::HcidEncoding::with_kind("hcs0").unwrap();
+        let grouped = enc.display_grouped(&enc.encode(&[7; 32]).unwrap(), 5, '-').unwrap();
+        assert!(grouped.contains('-'));
+
+        for form in &[PhoneticForm::Cased, PhoneticForm::Compact] {
+            let spoken = to_phonetic(&grouped, *form);
+            assert!(!spoken.contains('-'));
+            assert_eq!(vec![7; 32], enc.decode(&from_phonetic(&spoken).unwrap()).unwrap());
+        }
+        assert_eq!(
+            to_phonetic("HcS ci/aa", PhoneticForm::Cased),
+            to_phonetic("HcSciaa", PhoneticForm::Cased)
+        );
+    }
+
+    #[test]
+    fn it_speaks_unreadable_chars() {
+        // full-width 'Ｋ' folds to 'K', the snowman cannot be read
+        let spoken = to_phonetic(" Hc\u{ff2b}\u{2603}5 ", PhoneticForm::Cased);
+        assert_eq!("capital hotel, small charlie, capital kilo, unknown, five", spoken);
+        assert_eq!("HcK?5", from_phonetic(&spoken).unwrap());
+        assert_eq!(
+            "hotel charlie unknown",
+            to_phonetic("hc\u{e9}", PhoneticForm::Compact)
+        );
+    }
+}
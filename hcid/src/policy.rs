@@ -0,0 +1,22 @@
+//! Policies limiting how much correction decoding may apply.
+
+/// how much correction `HcidEncoding::decode_with_policy` may apply to an id.
+/// Machine-to-machine traffic should usually be `Strict`, while ids typed or
+/// pasted by humans can be decoded with `Full`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DecodePolicy {
+    /// only accept ids in their exact canonical form: no normalization,
+    /// transliteration or correction of any kind
+    Strict,
+    /// accept normalization and transliteration (0/O, 1/I/l, 2/Z), but reject
+    /// any id that would need Reed-Solomon correction (including lost case)
+    TransliterationOnly,
+    /// accept Reed-Solomon correction of at most this many known erasures (byte
+    /// symbols of unreadable characters or lost case). Errors that are not marked,
+    /// which correction would have to locate itself, are rejected.
+    ErasuresUpTo(usize),
+    /// apply as much correction as the parity allows (same as `decode`)
+    #[default]
+    Full,
+}
+
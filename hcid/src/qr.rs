@@ -0,0 +1,219 @@
+//! Rendering ids as QR codes, e.g. for badges and device labels.
+//!
+//! Ids are encoded in upper case, so that they fit QR alphanumeric mode (which packs 2
+//! characters into 11 bits, for a smaller code than byte mode). The cap parity is lost that
+//! way, but scanned ids are machine read, so the remaining base parity is plenty.
+
+use qrcode::bits::Bits;
+use qrcode::render::{svg, unicode};
+use qrcode::types::QrError;
+use qrcode::{Color, EcLevel, QrCode, Version};
+
+use super::{HcidError, HcidResult};
+
+/// scheme prefix that marks a QR code payload as an hcid
+pub const QR_SCHEME: &str = "HCID:";
+
+/// light modules around the code, as the QR spec requires
+const QUIET_ZONE: usize = 4;
+
+/// a QR code rendering of an id
+pub struct HcidQr {
+    code: QrCode,
+}
+
+impl HcidQr {
+    /// a QR code of `id` (in upper case, alphanumeric mode), prefixed with `QR_SCHEME` if
+    /// `with_scheme` is set. Uses medium (15%) QR error correction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let id = enc.encode(&[7; 32]).unwrap();
+    ///
+    /// let qr = hcid::qr::HcidQr::new(&id, true).unwrap();
+    /// assert_eq!(33, qr.width());
+    /// assert!(qr.to_svg(4).starts_with("<?xml"));
+    /// ```
+    pub fn new(id: &str, with_scheme: bool) -> HcidResult<Self> {
+        // ids are alphanumeric, and anything else may not fit alphanumeric mode
+        if let Some(c) = id.chars().find(|c| !c.is_ascii_alphanumeric()) {
+            return Err(HcidError(format!("BadQrChar:{}", c)));
+        }
+
+        let mut text = String::new();
+        if with_scheme {
+            text.push_str(QR_SCHEME);
+        }
+        text.push_str(&id.to_ascii_uppercase());
+
+        // the smallest version that fits
+        for version in 1..=40 {
+            let mut bits = Bits::new(Version::Normal(version));
+            let res = bits
+                .push_alphanumeric_data(text.as_bytes())
+                .and_then(|_| bits.push_terminator(EcLevel::M));
+            match res {
+                Ok(()) => {
+                    let code = QrCode::with_bits(bits, EcLevel::M).map_err(qr_error)?;
+                    return Ok(HcidQr { code });
+                }
+                Err(QrError::DataTooLong) => continue,
+                Err(e) => return Err(qr_error(e)),
+            }
+        }
+
+        Err(qr_error(QrError::DataTooLong))
+    }
+
+    /// modules per side, not counting the quiet zone
+    pub fn width(&self) -> usize {
+        self.code.width()
+    }
+
+    /// an svg image of the code, `module_px` pixels per module
+    pub fn to_svg(&self, module_px: u32) -> String {
+        self.code
+            .render::<svg::Color>()
+            .module_dimensions(module_px, module_px)
+            .build()
+    }
+
+    /// a greyscale png image of the code, `module_px` pixels per module
+    pub fn to_png(&self, module_px: u32) -> HcidResult<Vec<u8>> {
+        let (side, pixels) = self.to_pixels(module_px as usize);
+
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, side as u32, side as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut w| w.write_image_data(&pixels))
+            .map_err(|e| HcidError(format!("PngError:{}", e)))?;
+
+        Ok(out)
+    }
+
+    /// the code in unicode half blocks, two modules per character, for printing to a terminal.
+    /// Light modules are drawn as blocks, which suits the usual dark terminal background.
+    pub fn to_terminal(&self) -> String {
+        self.code
+            .render::<unicode::Dense1x2>()
+            .dark_color(unicode::Dense1x2::Light)
+            .light_color(unicode::Dense1x2::Dark)
+            .build()
+    }
+
+    /// the side length and row-major greyscale pixels of the code, quiet zone included
+    fn to_pixels(&self, module_px: usize) -> (usize, Vec<u8>) {
+        let width = self.code.width();
+        let colors = self.code.to_colors();
+        let side = (width + 2 * QUIET_ZONE) * module_px;
+
+        let mut pixels = vec![0xff; side * side];
+        for (i, color) in colors.iter().enumerate() {
+            if *color == Color::Light {
+                continue;
+            }
+            let x = (i % width + QUIET_ZONE) * module_px;
+            let y = (i / width + QUIET_ZONE) * module_px;
+            for row in y..y + module_px {
+                for p in pixels[row * side + x..row * side + x + module_px].iter_mut() {
+                    *p = 0;
+                }
+            }
+        }
+
+        (side, pixels)
+    }
+}
+
+/// the id in a scanned QR code payload, without any `QR_SCHEME` prefix
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// assert_eq!("HCSCI", hcid::qr::id_from_qr_text("hcid:HCSCI"));
+/// assert_eq!("HCSCI", hcid::qr::id_from_qr_text("HCSCI"));
+/// ```
+pub fn id_from_qr_text(text: &str) -> &str {
+    let text = text.trim();
+    match text.get(0..QR_SCHEME.len()) {
+        Some(scheme) if scheme.eq_ignore_ascii_case(QR_SCHEME) => &text[QR_SCHEME.len()..],
+        _ => text,
+    }
+}
+
+fn qr_error(e: QrError) -> HcidError {
+    HcidError(format!("QrError:{:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HcidEncoding;
+    use super::*;
+
+    // ed25519 public key for the all-zero seed
+    static TEST_HEX_1: &str = "3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29";
+
+    /// scan greyscale pixels with a pure-rust qr decoder
+    fn scan(side: usize, pixels: &[u8]) -> String {
+        let mut img = rqrr::PreparedImage::prepare_from_greyscale(side, side, |x, y| {
+            pixels[y * side + x]
+        });
+        let grids = img.detect_grids();
+        assert_eq!(1, grids.len());
+        grids[0].decode().unwrap().1
+    }
+
+    #[test]
+    fn it_round_trips_through_a_decoder() {
+        let enc = HcidEncoding::with_kind("hck0").unwrap();
+        let key = hex::decode(TEST_HEX_1).unwrap();
+        let id = enc.encode(&key).unwrap();
+
+        for with_scheme in &[false, true] {
+            let qr = HcidQr::new(&id, *with_scheme).unwrap();
+            let (side, pixels) = qr.to_pixels(3);
+            let text = scan(side, &pixels);
+
+            assert_eq!(*with_scheme, text.starts_with(QR_SCHEME));
+            assert_eq!(key, enc.decode(id_from_qr_text(&text)).unwrap());
+        }
+    }
+
+    #[test]
+    fn it_round_trips_png() {
+        let enc = HcidEncoding::with_kind("hcs0").unwrap();
+        let id = enc.encode(&[3; 32]).unwrap();
+        let png_bytes = HcidQr::new(&id, true).unwrap().to_png(2).unwrap();
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(png_bytes));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.width, info.height);
+
+        let text = scan(info.width as usize, &pixels);
+        assert_eq!(vec![3; 32], enc.decode(id_from_qr_text(&text)).unwrap());
+    }
+
+    #[test]
+    fn it_uses_alphanumeric_mode() {
+        let id = HcidEncoding::with_kind("hcs0").unwrap().encode(&[3; 32]).unwrap();
+
+        // 63 alphanumeric chars fit version 4 (33 modules) at medium correction, where 63
+        // bytes would need version 5
+        assert_eq!(33, HcidQr::new(&id, false).unwrap().width());
+        assert_eq!(
+            HcidQr::new(&id, false).unwrap().to_terminal(),
+            HcidQr::new(&id.to_lowercase(), false).unwrap().to_terminal()
+        );
+
+        assert!(HcidQr::new(&id.replacen("S", "?", 1), false).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! Detailed results of decoding an id.
+
+/// how far a decoded result can be trusted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Confidence {
+    /// parity symbols were left over after correction, and they agree with the result
+    Verified {
+        /// parity symbols that were not spent on correction
+        spare_parity: usize,
+    },
+    /// all parity was spent on correction, nothing is left to check the result
+    Unverified,
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Confidence::Verified { spare_parity } => {
+                write!(f, "verified with {} spare parity symbols", spare_parity)
+            }
+            Confidence::Unverified => write!(f, "unverified"),
+        }
+    }
+}
+
+/// the decoded key of an id, along with how much correction it took
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeReport {
+    /// the decoded (and corrected) key bytes
+    pub data: Vec<u8>,
+    /// byte symbols marked as erasures (unreadable characters, lost case)
+    pub erasure_count: usize,
+    /// byte symbols located and corrected by reed-solomon without being marked
+    pub error_count: usize,
+    /// parity symbols spent on correction: one per erasure, two per located error
+    pub parity_consumed: usize,
+    /// parity symbols left unused, available to detect errors beyond those corrected
+    pub parity_spare: usize,
+    /// how far the result can be trusted
+    pub confidence: Confidence,
+    /// prefix characters repaired to the kind's fixed prefix (these cost no parity)
+    pub prefix_repairs: usize,
+    /// char offsets into the decoded input of unreadable characters, e.g. `ERASURE_MARKERS`
+    pub erased_chars: Vec<usize>,
+}
+
+impl DecodeReport {
+    pub(crate) fn new(data: Vec<u8>, parity: usize, erasure_count: usize, error_count: usize) -> Self {
+        let parity_consumed = erasure_count + 2 * error_count;
+        let parity_spare = parity.saturating_sub(parity_consumed);
+        let confidence = if parity_spare > 0 {
+            Confidence::Verified {
+                spare_parity: parity_spare,
+            }
+        } else {
+            Confidence::Unverified
+        };
+
+        DecodeReport {
+            data,
+            erasure_count,
+            error_count,
+            parity_consumed,
+            parity_spare,
+            confidence,
+            prefix_repairs: 0,
+            erased_chars: Vec::new(),
+        }
+    }
+
+    /// true if spare parity symbols confirmed the result
+    pub fn is_verified(&self) -> bool {
+        self.confidence != Confidence::Unverified
+    }
+}
@@ -0,0 +1,413 @@
+//! Reed-Solomon coding over GF(256), as used for hcid parity.
+//!
+//! Uses the 0x11d field polynomial and a generator with roots α^0 .. α^(parity - 1), so parity
+//! is byte-identical to the `reed-solomon` 0.2 crate that earlier versions depended on. Decoding
+//! handles any mix of `e` unmarked errors and `f` marked erasures with `2e + f <= parity`.
+//!
+//! Everything works in place on caller-provided buffers with fixed-size scratch space, so this
+//! module needs neither `std` nor an allocator.
+
+/// longest codeword (data + parity) GF(256) can address
+pub const MAX_CODEWORD_LEN: usize = 255;
+
+/// GF(256) antilog table, doubled so products of two logs never need a modulo
+static EXP: [u8; 512] = gf_tables().0;
+
+/// GF(256) log table (LOG[0] is unused)
+static LOG: [u8; 256] = gf_tables().1;
+
+const fn gf_tables() -> ([u8; 512], [u8; 256]) {
+    let mut exp = [0; 512];
+    let mut log = [0; 256];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        exp[i + 255] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= 0x11d;
+        }
+        i += 1;
+    }
+    // keep the wrap-around entries simple to reason about
+    exp[510] = exp[0];
+    exp[511] = exp[1];
+    (exp, log)
+}
+
+fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    EXP[LOG[a as usize] as usize + LOG[b as usize] as usize]
+}
+
+fn div(a: u8, b: u8) -> u8 {
+    debug_assert!(b != 0);
+    if a == 0 {
+        return 0;
+    }
+    EXP[LOG[a as usize] as usize + 255 - LOG[b as usize] as usize]
+}
+
+/// α^power
+fn pow_alpha(power: usize) -> u8 {
+    EXP[power % 255]
+}
+
+fn inverse(a: u8) -> u8 {
+    div(1, a)
+}
+
+/// evaluate a polynomial stored lowest degree first
+fn eval_low(poly: &[u8], x: u8) -> u8 {
+    poly.iter().rev().fold(0, |acc, c| mul(acc, x) ^ c)
+}
+
+/// errors from reed-solomon decoding
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RsError {
+    /// more damage than the parity can locate and repair
+    TooManyErrors,
+    /// the codeword is empty, too long, or shorter than its parity
+    BadCodewordLen,
+    /// an erasure position is outside the codeword
+    BadErasure,
+}
+
+/// a reed-solomon codec for a fixed parity symbol count
+pub struct Codec {
+    parity: usize,
+    /// generator polynomial, highest degree first (generator[0] == 1)
+    generator: [u8; MAX_CODEWORD_LEN + 1],
+}
+
+impl Codec {
+    /// create a codec that adds `parity` symbols to each codeword
+    pub fn new(parity: usize) -> Self {
+        debug_assert!(parity < MAX_CODEWORD_LEN);
+
+        // multiply out (x - α^0)(x - α^1)...(x - α^(parity - 1))
+        let mut generator = [0; MAX_CODEWORD_LEN + 1];
+        generator[0] = 1;
+        for i in 0..parity {
+            let root = pow_alpha(i);
+            for j in (1..=i + 1).rev() {
+                generator[j] ^= mul(generator[j - 1], root);
+            }
+        }
+
+        Codec { parity, generator }
+    }
+
+    /// how many parity symbols each codeword carries
+    pub fn parity(&self) -> usize {
+        self.parity
+    }
+
+    /// fill in the trailing parity symbols of `codeword` from the data symbols preceding them
+    pub fn encode(&self, codeword: &mut [u8]) -> Result<(), RsError> {
+        self.check_len(codeword)?;
+        let data_len = codeword.len() - self.parity;
+
+        let (data, parity) = codeword.split_at_mut(data_len);
+        for p in parity.iter_mut() {
+            *p = 0;
+        }
+
+        // long division of data * x^parity by the generator, keeping the remainder
+        for d in data.iter() {
+            let coef = d ^ parity[0];
+            parity.copy_within(1.., 0);
+            parity[self.parity - 1] = 0;
+            if coef != 0 {
+                for (p, g) in parity.iter_mut().zip(self.generator[1..=self.parity].iter()) {
+                    *p ^= mul(*g, coef);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// true if `codeword` is not a valid codeword
+    pub fn is_corrupt(&self, codeword: &[u8]) -> bool {
+        (0..self.parity).any(|i| eval_high(codeword, pow_alpha(i)) != 0)
+    }
+
+    /// correct `codeword` in place. `erasures` lists the positions known to be unreliable.
+    /// Returns how many symbols outside of `erasures` had to be corrected.
+    pub fn correct(&self, codeword: &mut [u8], erasures: &[u8]) -> Result<usize, RsError> {
+        self.check_len(codeword)?;
+        let n = codeword.len();
+        if erasures.iter().any(|e| *e as usize >= n) {
+            return Err(RsError::BadErasure);
+        }
+
+        // syndromes, S_i = c(α^i)
+        let mut synd = [0; MAX_CODEWORD_LEN];
+        let synd = &mut synd[..self.parity];
+        for (i, s) in synd.iter_mut().enumerate() {
+            *s = eval_high(codeword, pow_alpha(i));
+        }
+        if synd.iter().all(|s| *s == 0) {
+            return Ok(0);
+        }
+
+        // position j holds the coefficient of x^(n - 1 - j), so its locator is α^(n - 1 - j)
+        let locator = |pos: usize| pow_alpha(n - 1 - pos);
+
+        // erasure locator, Γ(x) = Π (1 - X_k x)
+        let mut erased = [false; MAX_CODEWORD_LEN];
+        let mut lambda = [0; MAX_CODEWORD_LEN + 1];
+        lambda[0] = 1;
+        let mut erasure_count = 0;
+        for e in erasures {
+            let pos = *e as usize;
+            if erased[pos] {
+                continue;
+            }
+            erased[pos] = true;
+            erasure_count += 1;
+
+            let x = locator(pos);
+            for j in (1..=erasure_count).rev() {
+                lambda[j] ^= mul(lambda[j - 1], x);
+            }
+        }
+        if erasure_count > self.parity {
+            return Err(RsError::TooManyErrors);
+        }
+
+        // berlekamp-massey, seeded with the erasure locator, extends it to the errata locator
+        let mut prev = lambda;
+        let mut len = erasure_count;
+        for r in erasure_count..self.parity {
+            let mut delta = 0;
+            for i in 0..=r {
+                delta ^= mul(lambda[i], synd[r - i]);
+            }
+
+            // prev <- x * prev
+            prev.copy_within(0..MAX_CODEWORD_LEN, 1);
+            prev[0] = 0;
+
+            if delta == 0 {
+                continue;
+            }
+
+            let mut next = lambda;
+            for i in 0..=MAX_CODEWORD_LEN {
+                next[i] ^= mul(delta, prev[i]);
+            }
+
+            if 2 * len <= r + erasure_count {
+                let inv = inverse(delta);
+                for i in 0..=MAX_CODEWORD_LEN {
+                    prev[i] = mul(lambda[i], inv);
+                }
+                len = r + erasure_count + 1 - len;
+            }
+            lambda = next;
+        }
+
+        let degree = (0..=MAX_CODEWORD_LEN).rev().find(|i| lambda[*i] != 0).unwrap_or(0);
+        if degree != len || 2 * len - erasure_count > self.parity {
+            return Err(RsError::TooManyErrors);
+        }
+        let lambda = &lambda[..=degree];
+
+        // chien search: position j is in error if Λ(X_j^-1) == 0
+        let mut positions = [0; MAX_CODEWORD_LEN];
+        let mut found = 0;
+        for pos in 0..n {
+            if eval_low(lambda, inverse(locator(pos))) == 0 {
+                positions[found] = pos;
+                found += 1;
+            }
+        }
+        if found != degree {
+            return Err(RsError::TooManyErrors);
+        }
+
+        // errata evaluator, Ω(x) = S(x) Λ(x) mod x^parity
+        let mut omega = [0; MAX_CODEWORD_LEN];
+        let omega = &mut omega[..self.parity];
+        for (i, o) in omega.iter_mut().enumerate() {
+            for j in 0..=i.min(degree) {
+                *o ^= mul(lambda[j], synd[i - j]);
+            }
+        }
+
+        // formal derivative of Λ: in characteristic 2 only the odd terms survive
+        let mut deriv = [0; MAX_CODEWORD_LEN];
+        let deriv = &mut deriv[..degree];
+        for (i, d) in deriv.iter_mut().enumerate() {
+            if i % 2 == 0 {
+                *d = lambda[i + 1];
+            }
+        }
+
+        // forney: e_k = X_k Ω(X_k^-1) / Λ'(X_k^-1) (first consecutive root is α^0)
+        let mut errors = 0;
+        for pos in positions[..found].iter() {
+            let x = locator(*pos);
+            let x_inv = inverse(x);
+            let denom = eval_low(deriv, x_inv);
+            if denom == 0 {
+                return Err(RsError::TooManyErrors);
+            }
+            let magnitude = mul(x, div(eval_low(omega, x_inv), denom));
+            if magnitude != 0 && !erased[*pos] {
+                errors += 1;
+            }
+            codeword[*pos] ^= magnitude;
+        }
+
+        if self.is_corrupt(codeword) {
+            return Err(RsError::TooManyErrors);
+        }
+
+        Ok(errors)
+    }
+
+    fn check_len(&self, codeword: &[u8]) -> Result<(), RsError> {
+        if codeword.len() <= self.parity || codeword.len() > MAX_CODEWORD_LEN {
+            return Err(RsError::BadCodewordLen);
+        }
+        Ok(())
+    }
+}
+
+/// evaluate a polynomial stored highest degree first (the codeword layout)
+fn eval_high(poly: &[u8], x: u8) -> u8 {
+    poly.iter().fold(0, |acc, c| mul(acc, x) ^ c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// small xorshift generator, so tests are repeatable without extra dependencies
+    struct TestRng(u64);
+
+    impl TestRng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+
+        /// `count` distinct positions below `n`
+        fn positions(&mut self, n: usize, count: usize) -> Vec<usize> {
+            let mut out = Vec::new();
+            while out.len() < count {
+                let p = self.below(n);
+                if !out.contains(&p) {
+                    out.push(p);
+                }
+            }
+            out
+        }
+    }
+
+    fn encoded(codec: &Codec, data: &[u8]) -> Vec<u8> {
+        let mut codeword = data.to_vec();
+        codeword.resize(data.len() + codec.parity(), 0);
+        codec.encode(&mut codeword).unwrap();
+        codeword
+    }
+
+    #[test]
+    fn it_builds_field_tables() {
+        assert_eq!(0x1d, EXP[8]);
+        assert_eq!(1, EXP[255]);
+        for a in 1..=255_u8 {
+            assert_eq!(a, EXP[LOG[a as usize] as usize]);
+            assert_eq!(1, mul(a, inverse(a)));
+        }
+    }
+
+    #[test]
+    fn it_encodes_like_reed_solomon_0_2() {
+        // test vector from the reed-solomon 0.2.1 crate
+        let data: Vec<u8> = (0..30).collect();
+        let codeword = encoded(&Codec::new(8), &data);
+        assert_eq!(&data[..], &codeword[..30]);
+        assert_eq!(&[99, 26, 219, 193, 9, 94, 186, 143], &codeword[30..]);
+    }
+
+    #[test]
+    fn it_corrects_errors_and_erasures_up_to_capacity() {
+        let mut rng = TestRng(0x2545f4914f6cdd1d);
+        for parity in &[2, 4, 8, 16] {
+            let codec = Codec::new(*parity);
+            for _ in 0..300 {
+                let data: Vec<u8> = (0..32).map(|_| rng.next() as u8).collect();
+                let good = encoded(&codec, &data);
+                assert!(!codec.is_corrupt(&good));
+
+                let erasure_count = rng.below(parity + 1);
+                let error_count = rng.below((parity - erasure_count) / 2 + 1);
+                let damaged = rng.positions(good.len(), erasure_count + error_count);
+
+                let mut codeword = good.clone();
+                for p in damaged.iter() {
+                    codeword[*p] ^= 1 + rng.below(255) as u8;
+                }
+                let erasures: Vec<u8> = damaged[..erasure_count].iter().map(|p| *p as u8).collect();
+
+                let errors = codec.correct(&mut codeword, &erasures).unwrap();
+                assert_eq!(good, codeword);
+                assert_eq!(error_count, errors);
+            }
+        }
+    }
+
+    #[test]
+    fn it_detects_damage_beyond_capacity() {
+        let mut rng = TestRng(0x9e3779b97f4a7c15);
+        let codec = Codec::new(8);
+        let mut miscorrected = 0;
+        for _ in 0..500 {
+            let data: Vec<u8> = (0..32).map(|_| rng.next() as u8).collect();
+            let good = encoded(&codec, &data);
+
+            // 9 erasures can never be corrected
+            let mut codeword = good.clone();
+            let erasures: Vec<u8> = rng.positions(good.len(), 9).iter().map(|p| *p as u8).collect();
+            for p in erasures.iter() {
+                codeword[*p as usize] ^= 0x55;
+            }
+            assert_eq!(Err(RsError::TooManyErrors), codec.correct(&mut codeword, &erasures));
+
+            // 5 unmarked errors are either rejected, or "corrected" to some other codeword
+            let mut codeword = good.clone();
+            for p in rng.positions(good.len(), 5) {
+                codeword[p] ^= 1 + rng.below(255) as u8;
+            }
+            if codec.correct(&mut codeword, &[]).is_ok() {
+                assert!(!codec.is_corrupt(&codeword));
+                assert_ne!(good, codeword);
+                miscorrected += 1;
+            }
+        }
+        // a random word lands within 4 symbols of another codeword only rarely
+        assert!(miscorrected < 10);
+    }
+
+    #[test]
+    fn it_rejects_bad_lengths() {
+        let codec = Codec::new(8);
+        assert_eq!(Err(RsError::BadCodewordLen), codec.encode(&mut [0; 8]));
+        assert_eq!(Err(RsError::BadCodewordLen), codec.encode(&mut [0; 256]));
+        assert_eq!(Err(RsError::BadErasure), codec.correct(&mut [0; 20], &[20]));
+    }
+}
@@ -0,0 +1,257 @@
+//! Finding hcids embedded in free text (logs, chat messages, documents).
+//!
+//! Candidates are runs of ascii alphanumeric characters that start with the rendered prefix of
+//! a known kind (case-insensitively) and have the length of an id of that kind. Each candidate
+//! is then decoded, so only ids that are valid (or correctable) are reported. Kinds are scanned
+//! with their default configuration, i.e. 32 byte keys.
+
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+use super::{known_kinds, HcidEncoding, HcidEncodingConfig};
+
+/// an hcid found in scanned text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    /// the kind token of the id, e.g. `hck0`
+    pub kind: String,
+    /// byte offsets of the id in the scanned text
+    pub span: Range<usize>,
+    /// the decoded key bytes
+    pub key: Vec<u8>,
+    /// true if the id was corrupt, and reed-solomon correction was applied to decode it
+    pub corrected: bool,
+}
+
+/// every known kind, with its encoding and (upper-cased) rendered prefix
+struct Kinds(Vec<(String, Vec<u8>, HcidEncoding)>);
+
+impl Kinds {
+    fn new() -> Self {
+        let mut kinds = Vec::new();
+        for kind in known_kinds() {
+            let config = match HcidEncodingConfig::new(&kind) {
+                Ok(config) => config,
+                Err(_) => continue,
+            };
+            let prefix = config.prefix_cap.len();
+            let enc = match HcidEncoding::new(config) {
+                Ok(enc) => enc,
+                Err(_) => continue,
+            };
+
+            // the characters fully determined by the prefix bytes
+            let mut chars = b32_prefix(&enc.config.prefix);
+            chars.truncate(prefix);
+            kinds.push((kind, chars, enc));
+        }
+        Kinds(kinds)
+    }
+
+    /// decode `token` (found at byte offset `at`) if it is an id of a known kind
+    fn check(&self, token: &[u8], at: usize) -> Option<Match> {
+        let (kind, _, enc) = self.0.iter().find(|(_, prefix, enc)| {
+            token.len() == enc.config.encoded_char_count
+                && token[..prefix.len()].eq_ignore_ascii_case(prefix)
+        })?;
+
+        // tokens are ascii alphanumeric
+        let id = std::str::from_utf8(token).ok()?;
+        let corrected = enc.is_corrupt(id).ok()?;
+        let key = enc.decode(id).ok()?;
+
+        Some(Match {
+            kind: kind.clone(),
+            span: at..at + token.len(),
+            key,
+            corrected,
+        })
+    }
+
+    /// the next match in `text` from `*pos` on, advancing `*pos` past it. The first byte of
+    /// `text` is at offset `at` in the scanned input.
+    fn next_match(&self, text: &[u8], pos: &mut usize, at: usize) -> Option<Match> {
+        while *pos < text.len() {
+            if !text[*pos].is_ascii_alphanumeric() {
+                *pos += 1;
+                continue;
+            }
+
+            let start = *pos;
+            while *pos < text.len() && text[*pos].is_ascii_alphanumeric() {
+                *pos += 1;
+            }
+            if let Some(m) = self.check(&text[start..*pos], at + start) {
+                return Some(m);
+            }
+        }
+        None
+    }
+}
+
+/// base32 rendering of a prefix, upper-cased
+fn b32_prefix(prefix: &[u8]) -> Vec<u8> {
+    let mut chars = super::b32::encode(prefix);
+    chars.make_ascii_uppercase();
+    chars
+}
+
+/// iterator over the hcids found in a string, see `scan`
+pub struct Scanner<'a> {
+    text: &'a [u8],
+    pos: usize,
+    kinds: Kinds,
+}
+
+impl<'a> Iterator for Scanner<'a> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        self.kinds.next_match(self.text, &mut self.pos, 0)
+    }
+}
+
+/// find the hcids of any known kind in `text`
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let id = enc.encode(&[7; 32]).unwrap();
+///
+/// let text = format!("signed by {}, twice ({})", id, id.to_lowercase());
+/// let found: Vec<hcid::scan::Match> = hcid::scan::scan(&text).collect();
+/// assert_eq!(2, found.len());
+/// assert_eq!("hcs0", found[0].kind);
+/// assert_eq!(10..73, found[0].span);
+/// assert_eq!(vec![7; 32], found[0].key);
+/// assert!(!found[0].corrected);
+/// assert!(found[1].corrected);
+/// ```
+pub fn scan(text: &str) -> Scanner<'_> {
+    Scanner {
+        text: text.as_bytes(),
+        pos: 0,
+        kinds: Kinds::new(),
+    }
+}
+
+/// iterator over the hcids found in a reader, see `scan_reader`
+pub struct ReaderScanner<R> {
+    reader: R,
+    kinds: Kinds,
+    line: Vec<u8>,
+    pos: usize,
+    offset: usize,
+}
+
+impl<R: BufRead> Iterator for ReaderScanner<R> {
+    type Item = io::Result<Match>;
+
+    fn next(&mut self) -> Option<io::Result<Match>> {
+        // ids never span lines, so scan a line at a time
+        loop {
+            if let Some(m) = self.kinds.next_match(&self.line, &mut self.pos, self.offset) {
+                return Some(Ok(m));
+            }
+
+            self.offset += self.line.len();
+            self.line.clear();
+            self.pos = 0;
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => (),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// find the hcids of any known kind in the text read from `reader`, a line at a time, so that
+/// large files need not be loaded into memory. Spans are byte offsets from the start of the
+/// stream, and the input need not be valid utf-8.
+pub fn scan_reader<R: BufRead>(reader: R) -> ReaderScanner<R> {
+    ReaderScanner {
+        reader,
+        kinds: Kinds::new(),
+        line: Vec::new(),
+        pos: 0,
+        offset: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ed25519 public key for the all-zero seed
+    static TEST_HEX_1: &str = "3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29";
+
+    /// hcs0 has no payload validator, so any key will do
+    fn hcs0_id(key: u8) -> String {
+        HcidEncoding::with_kind("hcs0").unwrap().encode(&[key; 32]).unwrap()
+    }
+
+    #[test]
+    fn it_knows_rendered_prefixes() {
+        let kinds = Kinds::new();
+        let prefix = |kind: &str| kinds.0.iter().find(|k| k.0 == kind).unwrap().1.clone();
+        assert_eq!(b"HCK".to_vec(), prefix("hck0"));
+        assert_eq!(b"HCA".to_vec(), prefix("hca0"));
+        assert_eq!(b"HCS".to_vec(), prefix("hcs0"));
+    }
+
+    #[test]
+    fn it_ignores_lookalikes() {
+        let id = hcs0_id(1);
+        let text = format!(
+            "{} {}x x{} {} {}",
+            &id[1..],
+            id,
+            id,
+            id.replacen("HcS", "HcL", 1),
+            id.replace(&id[10..20], "9999999999")
+        );
+        assert_eq!(0, scan(&text).count());
+    }
+
+    #[test]
+    fn it_reports_corrections() {
+        let id = hcs0_id(2);
+        let typo = if &id[20..21] == "9" { "8" } else { "9" };
+        let damaged = format!("{}{}{}", &id[..20], typo, &id[21..]);
+        let text = format!("<{}>\n'{}'", damaged, id);
+
+        let found: Vec<Match> = scan(&text).collect();
+        assert_eq!(2, found.len());
+        assert_eq!((1..64, true), (found[0].span.clone(), found[0].corrected));
+        assert_eq!((67..130, false), (found[1].span.clone(), found[1].corrected));
+        assert_eq!(found[0].key, found[1].key);
+    }
+
+    #[test]
+    fn it_scans_readers_by_line() {
+        let key = hex::decode(TEST_HEX_1).unwrap();
+        let agent = HcidEncoding::with_kind("hck0").unwrap().encode(&key).unwrap();
+
+        let mut log = b"2019-01-01 start\n\xffbinary\xfe junk\n".to_vec();
+        let line_3 = log.len();
+        log.extend(format!("agent {} joined\n", agent).bytes());
+        log.extend(format!("signed {}\n", hcs0_id(3).to_uppercase()).bytes());
+
+        let found: Vec<Match> = scan_reader(io::Cursor::new(&log))
+            .map(|m| m.unwrap())
+            .collect();
+        assert_eq!(2, found.len());
+
+        assert_eq!("hck0", found[0].kind);
+        assert_eq!(line_3 + 6..line_3 + 69, found[0].span);
+        assert_eq!(agent.as_bytes(), &log[found[0].span.clone()]);
+        assert_eq!(key, found[0].key);
+
+        assert_eq!("hcs0", found[1].kind);
+        assert_eq!(vec![3; 32], found[1].key);
+        assert!(found[1].corrected);
+    }
+}
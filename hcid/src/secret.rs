@@ -0,0 +1,82 @@
+//! Decoded key material that is wiped on drop (`zeroize` feature).
+
+use std::ops::Deref;
+
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// a decoded buffer for secret-bearing kinds: zeroed when dropped,
+/// redacted in debug output, and compared in constant time
+#[derive(Clone)]
+pub struct SecretBytes(Zeroizing<Vec<u8>>);
+
+impl SecretBytes {
+    /// take ownership of `data`, which will be wiped on drop
+    pub fn new(data: Vec<u8>) -> Self {
+        SecretBytes(Zeroizing::new(data))
+    }
+
+    /// access the raw bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// compare against `other` in constant time
+    /// (only the lengths are compared in variable time)
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        self.0.as_slice().ct_eq(other).into()
+    }
+
+    /// unwrap into the underlying zeroizing buffer
+    pub fn into_inner(self) -> Zeroizing<Vec<u8>> {
+        self.0
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other.as_bytes())
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl std::fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SecretBytes([redacted; {}])", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_encoding;
+    use super::*;
+
+    static TEST_HEX_1: &str = "0c71db50d35d760b0ea2002ff20147c7c3a8e8030d35ef28ed1adaec9e329aba";
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    #[test]
+    fn it_decodes_secret() {
+        let enc = test_encoding("hck0");
+
+        let secret = enc.decode_secret(TEST_ID_1).unwrap();
+        assert!(secret.ct_eq(&hex::decode(TEST_HEX_1).unwrap()));
+        assert!(!secret.ct_eq(&[0; 32]));
+        assert!(!secret.ct_eq(&[0; 4]));
+        assert_eq!(secret, enc.decode_secret(&TEST_ID_1.to_lowercase()).unwrap());
+    }
+
+    #[test]
+    fn it_redacts_debug_output() {
+        let secret = SecretBytes::new(vec![42; 32]);
+        assert_eq!("SecretBytes([redacted; 32])", format!("{:?}", secret));
+    }
+}
@@ -0,0 +1,173 @@
+//! Ed25519 signing and verification keyed by hcids (`sign` feature).
+//!
+//! Agents are identified by `hca0` ids of their public key, and signatures are
+//! rendered as `hcs0` ids carrying the full 64 byte signature.
+
+use std::convert::TryFrom;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+
+use super::{HcidEncoding, HcidEncodingConfig, HcidError, HcidResult};
+
+/// hcid kind used for agent public keys
+const AGENT_KIND: &str = "hca0";
+
+/// hcid kind used for signatures
+const SIGNATURE_KIND: &str = "hcs0";
+
+/// byte count of an ed25519 signature
+const SIGNATURE_BYTE_COUNT: usize = 64;
+
+/// errors from signing or verifying with hcids
+#[derive(Debug, PartialEq, Clone)]
+pub enum SignError {
+    /// an agent or signature id could not be decoded
+    BadId(HcidError),
+    /// the secret seed is not 32 bytes
+    BadSecret,
+    /// the signature is not valid for this agent and message
+    BadSignature,
+}
+
+impl std::fmt::Display for SignError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for SignError {}
+
+impl From<HcidError> for SignError {
+    fn from(error: HcidError) -> Self {
+        SignError::BadId(error)
+    }
+}
+
+/// the encoding used to render 64 byte signatures as `hcs0` ids
+pub fn signature_encoding() -> HcidResult<HcidEncoding> {
+    HcidEncoding::new(HcidEncodingConfig::with_key_byte_count(
+        SIGNATURE_KIND,
+        SIGNATURE_BYTE_COUNT,
+    )?)
+}
+
+fn signing_key(secret: &[u8]) -> Result<SigningKey, SignError> {
+    match SigningKey::try_from(secret) {
+        Ok(k) => Ok(k),
+        Err(_) => Err(SignError::BadSecret),
+    }
+}
+
+/// get the `hca0` agent id for a 32 byte ed25519 secret seed
+pub fn agent_id(secret: &[u8]) -> Result<String, SignError> {
+    let public_key = signing_key(secret)?.verifying_key();
+    Ok(HcidEncoding::with_kind(AGENT_KIND)?.encode(public_key.as_bytes())?)
+}
+
+/// sign `message` with a 32 byte ed25519 secret seed, returning the `hcs0` signature id
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let secret = [7; 32];
+/// let agent = hcid::sign::agent_id(&secret).unwrap();
+/// let sig = hcid::sign::sign(&secret, b"hello").unwrap();
+/// assert!(hcid::sign::verify(&agent, b"hello", &sig).is_ok());
+/// assert_eq!(
+///     Err(hcid::sign::SignError::BadSignature),
+///     hcid::sign::verify(&agent, b"goodbye", &sig),
+/// );
+/// ```
+pub fn sign(secret: &[u8], message: &[u8]) -> Result<String, SignError> {
+    let signature = signing_key(secret)?.sign(message);
+    Ok(signature_encoding()?.encode(&signature.to_bytes())?)
+}
+
+/// verify that `sig_id` is a signature of `message` by the agent `agent_id`.
+/// Both ids are decoded (and corrected) as usual.
+pub fn verify(agent_id: &str, message: &[u8], sig_id: &str) -> Result<(), SignError> {
+    let public_key = HcidEncoding::with_kind(AGENT_KIND)?.decode(agent_id)?;
+    let public_key = match VerifyingKey::try_from(public_key.as_slice()) {
+        Ok(k) => k,
+        Err(_) => {
+            return Err(SignError::BadId(HcidError(String::from(
+                "InvalidPublicKey:NotOnCurve",
+            ))));
+        }
+    };
+
+    let signature = signature_encoding()?.decode(sig_id)?;
+    let signature = match Signature::from_slice(&signature) {
+        Ok(s) => s,
+        Err(_) => return Err(SignError::BadSignature),
+    };
+
+    match public_key.verify_strict(message, &signature) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(SignError::BadSignature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // rfc8032 test 1
+    static TEST_SECRET_1: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    static TEST_PUBLIC_1: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    static TEST_SIG_1: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    #[test]
+    fn it_signs_rfc8032_vector() {
+        let secret = hex::decode(TEST_SECRET_1).unwrap();
+
+        let agent = agent_id(&secret).unwrap();
+        let enc = HcidEncoding::with_kind("hca0").unwrap();
+        assert_eq!(hex::decode(TEST_PUBLIC_1).unwrap(), enc.decode(&agent).unwrap());
+
+        let sig = sign(&secret, b"").unwrap();
+        assert_eq!(114, sig.len());
+        assert!(sig.starts_with("HcS"));
+        assert_eq!(
+            hex::decode(TEST_SIG_1).unwrap(),
+            signature_encoding().unwrap().decode(&sig).unwrap()
+        );
+
+        verify(&agent, b"", &sig).unwrap();
+    }
+
+    #[test]
+    fn it_verifies_corrected_ids() {
+        let secret = hex::decode(TEST_SECRET_1).unwrap();
+        let agent = agent_id(&secret).unwrap();
+        let sig = sign(&secret, b"message").unwrap();
+
+        let agent = format!("{}_{}", &agent[..10], &agent[11..]);
+        let sig = sig.to_lowercase();
+        verify(&agent, b"message", &sig).unwrap();
+    }
+
+    #[test]
+    fn it_distinguishes_bad_ids_from_bad_signatures() {
+        let secret = hex::decode(TEST_SECRET_1).unwrap();
+        let agent = agent_id(&secret).unwrap();
+        let sig = sign(&secret, b"message").unwrap();
+
+        assert_eq!(Err(SignError::BadSignature), verify(&agent, b"massage", &sig));
+
+        let other = agent_id(&[1; 32]).unwrap();
+        assert_eq!(Err(SignError::BadSignature), verify(&other, b"message", &sig));
+
+        match verify(&agent[1..], b"message", &sig) {
+            Err(SignError::BadId(_)) => (),
+            r => panic!("expected BadId, got {:?}", r),
+        }
+        match verify(&agent, b"message", &agent) {
+            Err(SignError::BadId(_)) => (),
+            r => panic!("expected BadId, got {:?}", r),
+        }
+
+        assert_eq!(Err(SignError::BadSecret), sign(&[0; 31], b"message"));
+    }
+}
@@ -45,6 +45,18 @@ pub fn cap_decode(
     }
 }
 
+/// transliterate a single character into our base32 alphabet (case is preserved
+/// for alpha characters), or None if it cannot be translated
+pub fn b32_translit(c: u8) -> Option<u8> {
+    match c {
+        b'0' => Some(b'O'),
+        b'1' | b'l' | b'L' => Some(b'I'),
+        b'2' => Some(b'Z'),
+        b'A'..=b'Z' | b'a'..=b'z' | b'3'..=b'9' => Some(c),
+        _ => None,
+    }
+}
+
 /// correct and transliteration faults
 /// also note any invalid characters as erasures (character-level)
 pub fn b32_correct(data: &[u8], char_erasures: &mut Vec<u8>) -> Vec<u8> {
@@ -52,12 +64,9 @@ pub fn b32_correct(data: &[u8], char_erasures: &mut Vec<u8>) -> Vec<u8> {
 
     let len = data.len();
     for i in 0..len {
-        out.push(match data[i] {
-            b'0' => b'O',
-            b'1' | b'l' | b'L' => b'I',
-            b'2' => b'Z',
-            b'A'..=b'Z' | b'a'..=b'z' | b'3'..=b'9' => data[i],
-            _ => {
+        out.push(match b32_translit(data[i]) {
+            Some(c) => c,
+            None => {
                 // we cannot translate this character
                 // mark it as an erasure... see if we can continue
                 char_erasures[i] = b'1';
@@ -117,3 +126,45 @@ pub fn cap_encode_bin(seg: &mut [u8], bin: &[u8], min: usize) -> HcidResult<()>
     }
     Ok(())
 }
+
+/// chance that a segment of `char_count` random base32 characters holds fewer than the 8 alpha
+/// characters needed to carry a cap parity byte (25 of the 32 characters are letters)
+pub fn cap_segment_loss(char_count: usize) -> f64 {
+    let p: f64 = 25.0 / 32.0;
+    let mut loss = 0.0;
+    let mut choose = 1.0;
+    for k in 0..8.min(char_count + 1) {
+        if k > 0 {
+            choose = choose * (char_count + 1 - k) as f64 / k as f64;
+        }
+        loss += choose * p.powi(k as i32) * (1.0 - p).powi((char_count - k) as i32);
+    }
+    loss
+}
+
+/// make sure `sep` can be stripped without touching characters that carry id data
+pub fn check_separator(sep: char) -> HcidResult<()> {
+    if sep.is_ascii_alphanumeric() || crate::ERASURE_MARKERS.contains(&sep) {
+        return Err(format!("BadSeparator:{}", sep).into());
+    }
+    Ok(())
+}
+
+/// remove every `sep` and whitespace character from a grouped / wrapped id
+pub fn strip_separators(data: &str, sep: char) -> String {
+    data.chars()
+        .filter(|c| *c != sep && !c.is_whitespace())
+        .collect()
+}
+
+/// wipe a scratch buffer that may have held key material
+/// (only when built with the `zeroize` feature)
+#[cfg(feature = "zeroize")]
+pub fn wipe<Z: zeroize::Zeroize + ?Sized>(buf: &mut Z) {
+    buf.zeroize();
+}
+
+/// wipe a scratch buffer that may have held key material
+/// (only when built with the `zeroize` feature)
+#[cfg(not(feature = "zeroize"))]
+pub fn wipe<Z: ?Sized>(_buf: &mut Z) {}
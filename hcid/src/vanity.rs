@@ -0,0 +1,342 @@
+//! Vanity hcid generation (`vanity` feature).
+//!
+//! Generates ed25519 keypairs until the rendered id of the public key contains
+//! a chosen pattern. Cap parity decides the letter case of the rendered id, so
+//! patterns are always matched case-insensitively.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use ed25519_dalek::SigningKey;
+use rand_core::{OsRng, RngCore};
+
+use super::util::{b32_translit, wipe};
+use super::{HcidEncoding, HcidError, HcidResult};
+
+/// holochain base32 alphabet, as the value each character encodes
+static ALPHABET: &[u8] = b"ABCDEFGHIJKMNOPQRSTUVWXYZ3456789";
+
+/// how often generators report progress
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// where in the rendered id a vanity pattern must appear
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VanityPosition {
+    /// at the first character following the fixed kind prefix
+    Start,
+    /// at the end of the id
+    End,
+    /// starting at this character index
+    At(usize),
+    /// anywhere in the id
+    Anywhere,
+}
+
+/// a case-insensitive pattern to search for in generated ids
+#[derive(Debug, Clone, PartialEq)]
+pub struct VanityPattern {
+    pattern: Vec<u8>,
+    position: VanityPosition,
+}
+
+impl VanityPattern {
+    /// create a new pattern. Characters outside the hcid base32 alphabet (which
+    /// has no `0`, `1`, `2` or `L`) are transliterated the way ids are when
+    /// decoded, e.g. `holo` is searched for as `HOIO`.
+    pub fn new(pattern: &str, position: VanityPosition) -> HcidResult<Self> {
+        if pattern.is_empty() {
+            return Err(HcidError(String::from("EmptyVanityPattern")));
+        }
+
+        let mut out = Vec::with_capacity(pattern.len());
+        for c in pattern.chars() {
+            match Some(c)
+                .filter(char::is_ascii)
+                .and_then(|c| b32_translit(c as u8))
+            {
+                Some(t) => out.push(t.to_ascii_uppercase()),
+                None => return Err(HcidError(format!("BadVanityPatternChar:{}", c))),
+            }
+        }
+        let pattern = out;
+
+        Ok(VanityPattern { pattern, position })
+    }
+
+    /// true if `id` (rendered by `enc`) contains this pattern at the chosen position
+    pub fn matches(&self, enc: &HcidEncoding, id: &str) -> bool {
+        let id = id.as_bytes();
+        let eq = |at: usize| {
+            at + self.pattern.len() <= id.len()
+                && id[at..at + self.pattern.len()]
+                    .iter()
+                    .zip(self.pattern.iter())
+                    .all(|(a, b)| a.to_ascii_uppercase() == *b)
+        };
+
+        match self.offsets(enc) {
+            Some(offsets) => offsets.into_iter().any(eq),
+            None => false,
+        }
+    }
+
+    /// the candidate starting offsets for this pattern, None if it cannot fit
+    fn offsets(&self, enc: &HcidEncoding) -> Option<Vec<usize>> {
+        let len = enc.config.encoded_char_count;
+        if self.pattern.len() > len {
+            return None;
+        }
+        let last = len - self.pattern.len();
+
+        let at = match self.position {
+            VanityPosition::Start => (enc.config.prefix.len() * 8).div_ceil(5),
+            VanityPosition::End => last,
+            VanityPosition::At(at) => at,
+            VanityPosition::Anywhere => return Some((0..=last).collect()),
+        };
+
+        if at > last {
+            return None;
+        }
+        Some(vec![at])
+    }
+
+    /// probability that a random key renders with this pattern at `at`
+    fn probability_at(&self, enc: &HcidEncoding, at: usize) -> f64 {
+        let prefix_bits = enc.config.prefix.len() * 8;
+        let data_bits =
+            (enc.config.prefix.len() + enc.config.key_byte_count + enc.config.base_parity_byte_count)
+                * 8;
+
+        let mut p = 1.0;
+        for (i, c) in self.pattern.iter().enumerate() {
+            let v = ALPHABET.iter().position(|a| a == c).unwrap();
+            for bit in 0..5 {
+                let idx = (at + i) * 5 + bit;
+                let want = (v >> (4 - bit)) & 1;
+                if idx < prefix_bits {
+                    // fixed by the kind prefix
+                    let have = (enc.config.prefix[idx / 8] >> (7 - idx % 8)) & 1;
+                    if have as usize != want {
+                        return 0.0;
+                    }
+                } else if idx < data_bits {
+                    // key or parity, effectively random
+                    p /= 2.0;
+                } else if want != 0 {
+                    // zero padding
+                    return 0.0;
+                }
+            }
+        }
+        p
+    }
+}
+
+/// expected number of keypairs that must be generated before one renders
+/// (with encoding `enc`) to an id matching `pattern`
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// use hcid::vanity::{estimate_difficulty, VanityPattern, VanityPosition};
+/// let enc = hcid::HcidEncoding::with_kind("hca0").unwrap();
+/// // there is no `L` in the hcid alphabet, so this searches for `HOIO`
+/// let pattern = VanityPattern::new("holo", VanityPosition::Anywhere).unwrap();
+/// assert_eq!(pattern, VanityPattern::new("HOIO", VanityPosition::Anywhere).unwrap());
+/// let pattern = VanityPattern::new("hoi", VanityPosition::Start).unwrap();
+/// assert_eq!(32768.0, estimate_difficulty(&enc, &pattern).unwrap());
+/// ```
+pub fn estimate_difficulty(enc: &HcidEncoding, pattern: &VanityPattern) -> HcidResult<f64> {
+    let offsets = match pattern.offsets(enc) {
+        Some(o) => o,
+        None => return Err(HcidError(String::from("VanityPatternTooLong"))),
+    };
+
+    let miss = offsets
+        .iter()
+        .fold(1.0, |miss, at| miss * (1.0 - pattern.probability_at(enc, *at)));
+
+    if miss >= 1.0 {
+        return Err(HcidError(String::from("VanityPatternImpossible")));
+    }
+
+    Ok(1.0 / (1.0 - miss))
+}
+
+/// a generated keypair whose id matched the vanity pattern. The secret seed is wiped on drop
+/// (with the `zeroize` feature).
+pub struct VanityKey {
+    /// the rendered id of the public key
+    pub id: String,
+    /// the ed25519 public key
+    pub public_key: [u8; 32],
+    /// the ed25519 secret seed
+    pub secret_seed: [u8; 32],
+    /// how many keypairs were generated in total
+    pub attempts: u64,
+}
+
+impl Drop for VanityKey {
+    fn drop(&mut self) {
+        wipe(&mut self.secret_seed);
+    }
+}
+
+/// generate ed25519 keypairs on `threads` threads until the id of the public key, encoded as
+/// `kind`, matches `pattern`. `progress` is called periodically with the attempt count so far.
+pub fn generate<F>(
+    kind: &str,
+    pattern: &VanityPattern,
+    threads: usize,
+    mut progress: F,
+) -> HcidResult<VanityKey>
+where
+    F: FnMut(u64),
+{
+    let enc = HcidEncoding::with_kind(kind)?;
+    estimate_difficulty(&enc, pattern)?;
+
+    let found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+    let (send, recv) = mpsc::channel();
+
+    let mut key = std::thread::scope(|scope| -> HcidResult<VanityKey> {
+        for _ in 0..threads.max(1) {
+            let send = send.clone();
+            let (enc, found, attempts) = (&enc, &found, &attempts);
+            scope.spawn(move || {
+                let mut secret_seed = [0; 32];
+                while !found.load(Ordering::Relaxed) {
+                    OsRng.fill_bytes(&mut secret_seed);
+                    let public_key = SigningKey::from_bytes(&secret_seed)
+                        .verifying_key()
+                        .to_bytes();
+                    attempts.fetch_add(1, Ordering::Relaxed);
+
+                    let id = match enc.encode(&public_key) {
+                        Ok(id) => id,
+                        Err(e) => {
+                            let _ = send.send(Err(e));
+                            break;
+                        }
+                    };
+
+                    if pattern.matches(enc, &id) && !found.swap(true, Ordering::Relaxed) {
+                        let _ = send.send(Ok(VanityKey {
+                            id,
+                            public_key,
+                            secret_seed,
+                            attempts: 0,
+                        }));
+                        break;
+                    }
+                }
+                wipe(&mut secret_seed);
+            });
+        }
+        drop(send);
+
+        loop {
+            match recv.recv_timeout(PROGRESS_INTERVAL) {
+                Ok(res) => {
+                    found.store(true, Ordering::Relaxed);
+                    return res;
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => progress(attempts.load(Ordering::Relaxed)),
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(HcidError(String::from("VanityGeneratorStopped")));
+                }
+            }
+        }
+    })?;
+
+    // all threads are joined, the count is final
+    let attempts = attempts.load(Ordering::Relaxed);
+    progress(attempts);
+
+    key.attempts = attempts;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_ID_1: &str = "HcKciDds5OiogymxbnHKEabQ8iavqs8dwdVaGdJW76Vp4gx47tQDfGW4OWc9w5i";
+
+    #[test]
+    fn it_matches_case_insensitively() {
+        let enc = HcidEncoding::with_kind("hck0").unwrap();
+
+        let p = VanityPattern::new("dds5o", VanityPosition::Start).unwrap();
+        assert!(p.matches(&enc, TEST_ID_1));
+        let p = VanityPattern::new("C9W5I", VanityPosition::End).unwrap();
+        assert!(p.matches(&enc, TEST_ID_1));
+        let p = VanityPattern::new("gx47t", VanityPosition::At(45)).unwrap();
+        assert!(p.matches(&enc, TEST_ID_1));
+        let p = VanityPattern::new("gx47t", VanityPosition::At(44)).unwrap();
+        assert!(!p.matches(&enc, TEST_ID_1));
+        let p = VanityPattern::new("gx47t", VanityPosition::Anywhere).unwrap();
+        assert!(p.matches(&enc, TEST_ID_1));
+    }
+
+    #[test]
+    fn it_transliterates_patterns() {
+        let p = |s: &str| VanityPattern::new(s, VanityPosition::Anywhere);
+        assert_eq!(p("HOIO").unwrap(), p("holo").unwrap());
+        assert_eq!(p("OIIZ").unwrap(), p("01l2").unwrap());
+
+        for bad in &["", "a-b", "é", " x"] {
+            assert!(p(bad).is_err());
+        }
+        assert_eq!(Err(HcidError::from("BadVanityPatternChar:é")), p("aé"));
+    }
+
+    #[test]
+    fn it_estimates_difficulty_from_free_bits() {
+        let enc = HcidEncoding::with_kind("hck0").unwrap();
+        let est = |p: &str, pos| estimate_difficulty(&enc, &VanityPattern::new(p, pos).unwrap());
+
+        // fully random characters
+        assert_eq!(32.0, est("x", VanityPosition::Start).unwrap());
+        assert_eq!(1024.0, est("xy", VanityPosition::At(20)).unwrap());
+
+        // the last character holds only 2 bits, followed by zero padding
+        assert_eq!(4.0, est("i", VanityPosition::End).unwrap());
+        assert!(est("b", VanityPosition::End).is_err());
+
+        // the fifth character holds the last 4 prefix bits, then 1 key bit
+        assert_eq!(2.0, est("i", VanityPosition::At(4)).unwrap());
+        assert_eq!(1.0, est("hckc", VanityPosition::At(0)).unwrap());
+        assert!(est("x", VanityPosition::At(0)).is_err());
+
+        // too long / out of range
+        assert!(est("x", VanityPosition::At(63)).is_err());
+        assert!(est("xx", VanityPosition::At(62)).is_err());
+
+        let anywhere = est("xy", VanityPosition::Anywhere).unwrap();
+        assert!(anywhere > 15.0 && anywhere < 20.0);
+    }
+
+    #[test]
+    fn it_generates_matching_keys() {
+        let pattern = VanityPattern::new("9", VanityPosition::Start).unwrap();
+        let mut reports = 0;
+        let key = generate("hca0", &pattern, 2, |_| reports += 1).unwrap();
+
+        let enc = HcidEncoding::with_kind("hca0").unwrap();
+        assert!(pattern.matches(&enc, &key.id));
+        assert!(key.attempts > 0);
+        assert!(reports > 0);
+        assert_eq!(key.public_key.to_vec(), enc.decode(&key.id).unwrap());
+        assert_eq!(
+            key.public_key,
+            SigningKey::from_bytes(&key.secret_seed)
+                .verifying_key()
+                .to_bytes()
+        );
+    }
+}
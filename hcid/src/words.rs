@@ -0,0 +1,276 @@
+//! Rendering ids as BIP-39 English words, for reading aloud and paper backups.
+//!
+//! The words carry the kind code byte, then the key, then a checksum (the leading bits of
+//! reed-solomon parity over both), 11 bits per word. A 32 byte key takes 25 words. Parsing
+//! tolerates misspelled words, taking the nearest word of the list by edit distance, and
+//! words cut short to their first 4 letters, which are unique in the BIP-39 lists.
+
+use super::bip39::ENGLISH;
+use super::rs;
+use super::{kind_for_prefix, HcidEncoding, HcidError, HcidResult};
+
+/// bits carried per word
+const WORD_BITS: usize = 11;
+
+/// misspelled words are corrected to a word at most this many edits away
+const MAX_WORD_EDITS: usize = 2;
+
+impl HcidEncoding {
+    /// render the key `data` as words, see the module docs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate hcid;
+    /// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    /// let words = enc.to_words(&[7; 32]).unwrap();
+    /// assert_eq!(25, words.split(' ').count());
+    ///
+    /// // words are known by their first 4 letters, and the id can be rendered again
+    /// let short: Vec<&str> = words.split(' ').map(|w| &w[..w.len().min(4)]).collect();
+    /// let key = enc.from_words(&short.join(" ")).unwrap();
+    /// assert_eq!(enc.encode(&[7; 32]).unwrap(), enc.encode(&key).unwrap());
+    /// ```
+    pub fn to_words(&self, data: &[u8]) -> HcidResult<String> {
+        if data.len() != self.config.key_byte_count {
+            return Err(HcidError(format!(
+                "BadDataLen:{},Expected:{}",
+                data.len(),
+                self.config.key_byte_count
+            )));
+        }
+        self.validate_payload(data)?;
+
+        let mut bits = Vec::new();
+        push_bits(&mut bits, &self.words_payload(data)?);
+        bits.truncate(self.word_count() * WORD_BITS);
+
+        let words: Vec<&str> = bits
+            .chunks(WORD_BITS)
+            .map(|chunk| ENGLISH[chunk.iter().fold(0, |v, b| v << 1 | *b as usize)])
+            .collect();
+        Ok(words.join(" "))
+    }
+
+    /// parse words rendered by `to_words` back into the key, checking that they were rendered
+    /// for this encoding's kind. Words may be separated by whitespace, commas or hyphens, may be
+    /// cut short to their first 4 letters, and misspelled words are corrected to the nearest
+    /// word of the list.
+    pub fn from_words(&self, text: &str) -> HcidResult<Vec<u8>> {
+        let bits = parse_words(text)?;
+        if bits.len() != self.word_count() * WORD_BITS {
+            return Err(HcidError(format!(
+                "BadWordCount:{},Expected:{}",
+                bits.len() / WORD_BITS,
+                self.word_count()
+            )));
+        }
+
+        let code = bits_to_bytes(&bits[0..8])[0];
+        if code != self.words_code()? {
+            let kind = kind_for_prefix(&[0x38, code, 0x24]);
+            return Err(HcidError(format!(
+                "KindMismatch:{}",
+                kind.unwrap_or_else(|| format!("{:#04x}", code))
+            )));
+        }
+
+        let key_end = 8 + self.config.key_byte_count * 8;
+        let key = bits_to_bytes(&bits[8..key_end]);
+
+        // the checksum must agree with the kind and key
+        let mut expected = Vec::new();
+        push_bits(&mut expected, &self.words_payload(&key)?);
+        if expected[key_end..bits.len()] != bits[key_end..] {
+            return Err(HcidError(String::from("ChecksumMismatch")));
+        }
+
+        self.validate_payload(&key)?;
+        Ok(key)
+    }
+
+    /// words rendered for this encoding's key size: the kind byte, key and at least one
+    /// word's worth of checksum bits
+    fn word_count(&self) -> usize {
+        (8 + self.config.key_byte_count * 8).div_ceil(WORD_BITS) + 1
+    }
+
+    /// the kind code byte carried in words, the middle byte of the standard 3 byte prefix
+    fn words_code(&self) -> HcidResult<u8> {
+        match self.config.prefix.as_slice() {
+            [0x38, code, 0x24] => Ok(*code),
+            _ => Err(HcidError(String::from("NoWordsForPrefix"))),
+        }
+    }
+
+    /// kind code byte, key, and 3 bytes of parity, which the checksum bits are taken from
+    fn words_payload(&self, data: &[u8]) -> HcidResult<Vec<u8>> {
+        let mut payload = vec![self.words_code()?];
+        payload.extend_from_slice(data);
+        payload.resize(payload.len() + 3, 0);
+        rs::Codec::new(3).encode(&mut payload)?;
+        Ok(payload)
+    }
+}
+
+/// the kind of the id rendered as `text` by `HcidEncoding::to_words`
+///
+/// # Example
+///
+/// ```
+/// extern crate hcid;
+/// let enc = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+/// let words = enc.to_words(&[7; 32]).unwrap();
+/// assert_eq!("hcs0", hcid::words::kind_of_words(&words).unwrap());
+/// ```
+pub fn kind_of_words(text: &str) -> HcidResult<String> {
+    let bits = parse_words(text)?;
+    if bits.len() < 8 {
+        return Err(HcidError(String::from("BadWordCount:0")));
+    }
+
+    let code = bits_to_bytes(&bits[0..8])[0];
+    kind_for_prefix(&[0x38, code, 0x24])
+        .ok_or_else(|| HcidError(format!("UnknownKindCode:{:#04x}", code)))
+}
+
+/// the bits of the words of `text`, correcting misspelled words
+fn parse_words(text: &str) -> HcidResult<Vec<u8>> {
+    let mut bits = Vec::new();
+    for word in text
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '-')
+        .filter(|w| !w.is_empty())
+    {
+        let index = find_word(&word.to_lowercase())?;
+        for i in (0..WORD_BITS).rev() {
+            bits.push((index >> i & 1) as u8);
+        }
+    }
+    Ok(bits)
+}
+
+/// the index of `word` in the list, of the one word it is the (4 letter or longer) start of,
+/// or of the one word nearest to it
+fn find_word(word: &str) -> HcidResult<usize> {
+    let index = match ENGLISH.binary_search(&word) {
+        Ok(index) => return Ok(index),
+        Err(index) => index,
+    };
+    if word.len() >= 4 && index < ENGLISH.len() && ENGLISH[index].starts_with(word) {
+        return Ok(index);
+    }
+
+    let mut nearest = Vec::new();
+    let mut nearest_edits = MAX_WORD_EDITS + 1;
+    for (index, candidate) in ENGLISH.iter().enumerate() {
+        let edits = edit_distance(word, candidate);
+        if edits < nearest_edits {
+            nearest.clear();
+            nearest_edits = edits;
+        }
+        if edits == nearest_edits {
+            nearest.push(index);
+        }
+    }
+
+    match nearest.as_slice() {
+        [index] => Ok(*index),
+        [] => Err(HcidError(format!("UnknownWord:{}", word))),
+        _ => Err(HcidError(format!("AmbiguousWord:{}", word))),
+    }
+}
+
+/// levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = diagonal + if ca == *cb { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// append the bits of `bytes`, most significant first
+fn push_bits(bits: &mut Vec<u8>, bytes: &[u8]) {
+    for byte in bytes {
+        for i in (0..8).rev() {
+            bits.push(byte >> i & 1);
+        }
+    }
+}
+
+/// pack bits, most significant first, into bytes
+fn bits_to_bytes(bits: &[u8]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| chunk.iter().fold(0, |v, b| v << 1 | b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{test_encoding, HcidEncodingConfig};
+    use super::*;
+
+    #[test]
+    fn it_round_trips_words() {
+        let enc = test_encoding("hck0");
+        for key in &[[0u8; 32], [0xff; 32], [0x5a; 32]] {
+            let words = enc.to_words(key).unwrap();
+            assert_eq!(25, words.split(' ').count());
+            assert_eq!(key.to_vec(), enc.from_words(&words).unwrap());
+            assert_eq!(
+                key.to_vec(),
+                enc.from_words(&words.to_uppercase().replace(' ', "-")).unwrap()
+            );
+        }
+
+        let sig = HcidEncoding::new(HcidEncodingConfig::with_key_byte_count("hcs0", 64).unwrap())
+            .unwrap();
+        let words = sig.to_words(&[9; 64]).unwrap();
+        assert_eq!(49, words.split(' ').count());
+        assert_eq!(vec![9; 64], sig.from_words(&words).unwrap());
+    }
+
+    #[test]
+    fn it_corrects_misspelled_words() {
+        assert_eq!(Ok(0), find_word("abandn"));
+        assert_eq!(Ok(0), find_word("abbandon"));
+        assert_eq!(Ok(0), find_word("aban"));
+        assert_eq!(Ok(2047), find_word("zoo"));
+        assert_eq!(Err(HcidError::from("AmbiguousWord:ppear")), find_word("ppear"));
+        assert_eq!(Err(HcidError::from("UnknownWord:xyzzyxx")), find_word("xyzzyxx"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+    }
+
+    #[test]
+    fn it_checks_kind_and_checksum() {
+        let hck0 = test_encoding("hck0");
+        let words = test_encoding("hca0").to_words(&[1; 32]).unwrap();
+        assert_eq!("hca0", kind_of_words(&words).unwrap());
+        assert_eq!(
+            HcidError::from("KindMismatch:hca0"),
+            hck0.from_words(&words).unwrap_err()
+        );
+
+        // swapping two (different) key words breaks the checksum
+        let words = hck0.to_words(&[0x12; 32]).unwrap();
+        let mut list: Vec<&str> = words.split(' ').collect();
+        let (a, b) = (1..list.len() - 2)
+            .map(|i| (i, i + 1))
+            .find(|(a, b)| list[*a] != list[*b])
+            .unwrap();
+        list.swap(a, b);
+        assert_eq!(
+            HcidError::from("ChecksumMismatch"),
+            hck0.from_words(&list.join(" ")).unwrap_err()
+        );
+
+        assert!(hck0.from_words("abandon ability").is_err());
+    }
+}
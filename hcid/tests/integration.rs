@@ -3,6 +3,7 @@ extern crate hcid;
 extern crate hex;
 
 static FIXTURES: &'static str = include_str!("../../test/fixtures.json");
+static CONFIG_FIXTURES: &str = include_str!("../../test/config_fixtures.json");
 
 fn test_correct(e: &hcid::HcidEncoding, id: &str, data: &[u8]) {
     assert!(!e.is_corrupt(id).unwrap());
@@ -64,17 +65,81 @@ fn test(e: &hcid::HcidEncoding, test: &serde_json::Value) {
     }
 }
 
+/// fixture keys are random bytes, not necessarily valid curve points
+fn encoding(kind: &str) -> hcid::HcidEncoding {
+    let mut config = hcid::HcidEncodingConfig::new(kind).unwrap();
+    config.payload_validator = None;
+    hcid::HcidEncoding::new(config).unwrap()
+}
+
 #[test]
 fn it_can_execute_fixtures() {
     let fixtures: serde_json::Value = serde_json::from_str(FIXTURES).unwrap();
     let fixtures = fixtures.as_object().unwrap();
 
-    let e = hcid::HcidEncoding::with_kind("hck0").unwrap();
+    let e = encoding("hck0");
     test(&e, &fixtures["hck0"]);
 
-    let e = hcid::HcidEncoding::with_kind("hca0").unwrap();
+    let e = encoding("hca0");
     test(&e, &fixtures["hca0"]);
 
-    let e = hcid::HcidEncoding::with_kind("hcs0").unwrap();
+    let e = encoding("hcs0");
     test(&e, &fixtures["hcs0"]);
 }
+
+#[test]
+fn it_can_read_fixtures_aloud() {
+    use hcid::phonetic::{from_phonetic, to_phonetic, PhoneticForm};
+
+    let fixtures: serde_json::Value = serde_json::from_str(FIXTURES).unwrap();
+
+    for (kind, f) in fixtures.as_object().unwrap().iter() {
+        let e = encoding(kind);
+        let ids = f["correct"].as_array().unwrap().iter()
+            .chain(f["correctable"].as_array().unwrap().iter());
+
+        for t in ids {
+            let id = t[0].as_str().unwrap();
+            let data = hex::decode(t[1].as_str().unwrap()).unwrap();
+
+            // the cased form comes back exactly, erasure markers as `?`
+            let heard = from_phonetic(&to_phonetic(id, PhoneticForm::Cased)).unwrap();
+            assert_eq!(id.replace('_', "?"), heard);
+
+            assert_eq!(data, e.decode(&heard).unwrap());
+
+            // the compact form comes back in lower case
+            let heard = from_phonetic(&to_phonetic(id, PhoneticForm::Compact)).unwrap();
+            assert_eq!(id.to_lowercase().replace('_', "?"), heard);
+        }
+
+        // which undamaged ids still decode from
+        for t in f["correct"].as_array().unwrap().iter() {
+            let heard = from_phonetic(&to_phonetic(t[0].as_str().unwrap(), PhoneticForm::Compact));
+            let data = hex::decode(t[1].as_str().unwrap()).unwrap();
+            assert_eq!(data, e.decode(&heard.unwrap()).unwrap());
+        }
+    }
+}
+
+#[test]
+fn it_can_execute_config_fixtures() {
+    let fixtures: serde_json::Value = serde_json::from_str(CONFIG_FIXTURES).unwrap();
+
+    for f in fixtures.as_array().unwrap().iter() {
+        let count = |k: &str| f[k].as_u64().unwrap() as usize;
+
+        let mut config = hcid::HcidEncodingConfig::with_layout(
+            f["kind"].as_str().unwrap(),
+            count("keyByteCount"),
+            count("baseParityByteCount"),
+            count("capParityByteCount"),
+        ).unwrap();
+        config.payload_validator = None;
+        assert_eq!(count("encodedCharCount"), config.encoded_char_count);
+        assert_eq!(count("capSegmentCharCount"), config.cap_segment_char_count);
+
+        let e = hcid::HcidEncoding::new(config).unwrap();
+        test(&e, f);
+    }
+}